@@ -0,0 +1,711 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::array::{make_array, print_long_array};
+use crate::builder::{GenericListViewBuilder, PrimitiveBuilder};
+use crate::iterator::GenericListViewArrayIter;
+use crate::{
+    new_empty_array, Array, ArrayAccessor, ArrayRef, ArrowPrimitiveType, GenericListArray,
+    OffsetSizeTrait,
+};
+use arrow_buffer::{NullBuffer, ScalarBuffer};
+use arrow_data::{ArrayData, ArrayDataBuilder};
+use arrow_schema::{ArrowError, DataType, FieldRef};
+use std::any::Any;
+use std::sync::Arc;
+
+/// An array of [variable length lists], stored as an offset and a size for each list
+///
+/// Unlike [`GenericListArray`], the values of a list view need not follow those of the
+/// previous list view in the `values` child array, and may overlap with those of other
+/// list views.
+///
+/// Arrow defines [`ListViewArray`] with `i32` offsets and sizes, and
+/// [`LargeListViewArray`] with `i64` offsets and sizes.
+///
+/// Use [`GenericListViewBuilder`] to construct a [`GenericListViewArray`].
+///
+/// # Representation
+///
+/// For example, the following `ListViewArray` stores lists of integers, with the list at
+/// index 3 reusing the values of the list at index 0
+///
+/// ```text
+///                         ┌ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─
+///                                                            ┌ ─ ─ ─ ─ ┐  │
+///  ┌─────────────┐        │  ┌───┐     ┌───┐     ┌───┐         ┌───┐
+///  │   [1, 2]    │           │ 1 │     │ 3 │     │ 2 │       │ │ 3 │ │ 0  │
+///  ├─────────────┤        │  ├───┤     ├───┤     ├───┤         ├───┤
+///  │     []      │           │ 1 │     │ 0 │     │ 0 │       │ │ 4 │ │ 1  │
+///  ├─────────────┤        │  ├───┤     ├───┤     ├───┤         ├───┤
+///  │    NULL     │           │ 0 │     │ 0 │     │ 0 │       │ │ 5 │ │ 2  │
+///  ├─────────────┤        │  ├───┤     ├───┤     ├───┤         ├───┤
+///  │   [1, 2]    │           │ 1 │     │ 3 │     │ 2 │       │ │ 1 │ │ 3  │
+///  ├─────────────┤        │  ├───┤     ├───┤     ├───┤         ├───┤
+///  │  [3, 4, 5]  │           │ 1 │     │ 0 │     │ 3 │       │ │ 2 │ │ 4  │
+///  └─────────────┘        │  └───┘     └───┘     └───┘         └───┘
+///                          Validity   Offsets    Sizes       │  Values │  │
+///     Logical             │ (nulls)                            (Array)
+///      Values                                                └ ─ ─ ─ ─ ┘  │
+///                         │   ListViewArray
+///                         └ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─
+/// ```
+///
+/// [variable length lists]: https://arrow.apache.org/docs/format/Columnar.html#listview-layout
+pub struct GenericListViewArray<OffsetSize: OffsetSizeTrait> {
+    data_type: DataType,
+    nulls: Option<NullBuffer>,
+    values: ArrayRef,
+    value_offsets: ScalarBuffer<OffsetSize>,
+    value_sizes: ScalarBuffer<OffsetSize>,
+}
+
+impl<OffsetSize: OffsetSizeTrait> Clone for GenericListViewArray<OffsetSize> {
+    fn clone(&self) -> Self {
+        Self {
+            data_type: self.data_type.clone(),
+            nulls: self.nulls.clone(),
+            values: self.values.clone(),
+            value_offsets: self.value_offsets.clone(),
+            value_sizes: self.value_sizes.clone(),
+        }
+    }
+}
+
+impl<OffsetSize: OffsetSizeTrait> GenericListViewArray<OffsetSize> {
+    /// The data type constructor of list view array.
+    /// The input is the schema of the child array and
+    /// the output is the [`DataType`], ListView or LargeListView.
+    pub const DATA_TYPE_CONSTRUCTOR: fn(FieldRef) -> DataType = if OffsetSize::IS_LARGE {
+        DataType::LargeListView
+    } else {
+        DataType::ListView
+    };
+
+    /// Create a new [`GenericListViewArray`] from the provided parts
+    ///
+    /// # Errors
+    ///
+    /// Errors if
+    ///
+    /// * `offsets.len() != sizes.len()`
+    /// * `offsets.len() != nulls.len()`
+    /// * `offsets[i] < 0` or `sizes[i] < 0`
+    /// * `offsets[i] + sizes[i] > values.len()`
+    /// * `!field.is_nullable() && values.is_nullable()`
+    /// * `field.data_type() != values.data_type()`
+    pub fn try_new(
+        field: FieldRef,
+        offsets: ScalarBuffer<OffsetSize>,
+        sizes: ScalarBuffer<OffsetSize>,
+        values: ArrayRef,
+        nulls: Option<NullBuffer>,
+    ) -> Result<Self, ArrowError> {
+        let len = offsets.len();
+        if sizes.len() != len {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Length of sizes for {}ListViewArray, expected {len} got {}",
+                OffsetSize::PREFIX,
+                sizes.len(),
+            )));
+        }
+
+        for (i, (offset, size)) in offsets.iter().zip(sizes.iter()).enumerate() {
+            let offset = offset.to_usize().ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!("Offset at index {i} is negative"))
+            })?;
+            let size = size.to_usize().ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!("Size at index {i} is negative"))
+            })?;
+            if offset.saturating_add(size) > values.len() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Offset {offset} and size {size} at index {i} exceed length of values {}",
+                    values.len()
+                )));
+            }
+        }
+
+        if let Some(n) = nulls.as_ref() {
+            if n.len() != len {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Incorrect length of null buffer for {}ListViewArray, expected {len} got {}",
+                    OffsetSize::PREFIX,
+                    n.len(),
+                )));
+            }
+        }
+        if !field.is_nullable() && values.is_nullable() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Non-nullable field of {}ListViewArray {:?} cannot contain nulls",
+                OffsetSize::PREFIX,
+                field.name()
+            )));
+        }
+
+        if field.data_type() != values.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "{}ListViewArray expected data type {} got {} for {:?}",
+                OffsetSize::PREFIX,
+                field.data_type(),
+                values.data_type(),
+                field.name()
+            )));
+        }
+
+        Ok(Self {
+            data_type: Self::DATA_TYPE_CONSTRUCTOR(field),
+            nulls,
+            values,
+            value_offsets: offsets,
+            value_sizes: sizes,
+        })
+    }
+
+    /// Create a new [`GenericListViewArray`] from the provided parts
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Self::try_new`] returns an error
+    pub fn new(
+        field: FieldRef,
+        offsets: ScalarBuffer<OffsetSize>,
+        sizes: ScalarBuffer<OffsetSize>,
+        values: ArrayRef,
+        nulls: Option<NullBuffer>,
+    ) -> Self {
+        Self::try_new(field, offsets, sizes, values, nulls).unwrap()
+    }
+
+    /// Create a new [`GenericListViewArray`] of length `len` where all values are null
+    pub fn new_null(field: FieldRef, len: usize) -> Self {
+        let values = new_empty_array(field.data_type());
+        Self {
+            data_type: Self::DATA_TYPE_CONSTRUCTOR(field),
+            nulls: Some(NullBuffer::new_null(len)),
+            value_offsets: ScalarBuffer::from(vec![OffsetSize::usize_as(0); len]),
+            value_sizes: ScalarBuffer::from(vec![OffsetSize::usize_as(0); len]),
+            values,
+        }
+    }
+
+    /// Deconstruct this array into its constituent parts
+    pub fn into_parts(
+        self,
+    ) -> (
+        FieldRef,
+        ScalarBuffer<OffsetSize>,
+        ScalarBuffer<OffsetSize>,
+        ArrayRef,
+        Option<NullBuffer>,
+    ) {
+        let f = match self.data_type {
+            DataType::ListView(f) | DataType::LargeListView(f) => f,
+            _ => unreachable!(),
+        };
+        (
+            f,
+            self.value_offsets,
+            self.value_sizes,
+            self.values,
+            self.nulls,
+        )
+    }
+
+    /// Returns a reference to the offsets of this list view
+    ///
+    /// Unlike [`Self::value_offsets`] this returns the [`ScalarBuffer`]
+    /// allowing for zero-copy cloning
+    #[inline]
+    pub fn offsets(&self) -> &ScalarBuffer<OffsetSize> {
+        &self.value_offsets
+    }
+
+    /// Returns a reference to the sizes of this list view
+    ///
+    /// Unlike [`Self::value_sizes`] this returns the [`ScalarBuffer`]
+    /// allowing for zero-copy cloning
+    #[inline]
+    pub fn sizes(&self) -> &ScalarBuffer<OffsetSize> {
+        &self.value_sizes
+    }
+
+    /// Returns a reference to the values of this list view
+    #[inline]
+    pub fn values(&self) -> &ArrayRef {
+        &self.values
+    }
+
+    /// Returns a clone of the value type of this list view.
+    pub fn value_type(&self) -> DataType {
+        self.values.data_type().clone()
+    }
+
+    /// Returns ith value of this list view array.
+    /// # Safety
+    /// Caller must ensure that the index is within the array bounds
+    pub unsafe fn value_unchecked(&self, i: usize) -> ArrayRef {
+        let offset = self.value_offsets.get_unchecked(i).as_usize();
+        let size = self.value_sizes.get_unchecked(i).as_usize();
+        self.values.slice(offset, size)
+    }
+
+    /// Returns ith value of this list view array.
+    pub fn value(&self, i: usize) -> ArrayRef {
+        let offset = self.value_offsets[i].as_usize();
+        let size = self.value_sizes[i].as_usize();
+        self.values.slice(offset, size)
+    }
+
+    /// Returns the offset values in the offsets buffer
+    #[inline]
+    pub fn value_offsets(&self) -> &[OffsetSize] {
+        &self.value_offsets
+    }
+
+    /// Returns the size values in the sizes buffer
+    #[inline]
+    pub fn value_sizes(&self) -> &[OffsetSize] {
+        &self.value_sizes
+    }
+
+    /// Returns the offset for value at index `i`.
+    #[inline]
+    pub fn value_offset(&self, i: usize) -> OffsetSize {
+        self.value_offsets[i]
+    }
+
+    /// Returns the length for value at index `i`.
+    #[inline]
+    pub fn value_size(&self, i: usize) -> OffsetSize {
+        self.value_sizes[i]
+    }
+
+    /// constructs a new iterator
+    pub fn iter<'a>(&'a self) -> GenericListViewArrayIter<'a, OffsetSize> {
+        GenericListViewArrayIter::<'a, OffsetSize>::new(self)
+    }
+
+    #[inline]
+    fn get_type(data_type: &DataType) -> Option<&DataType> {
+        match (OffsetSize::IS_LARGE, data_type) {
+            (true, DataType::LargeListView(child)) | (false, DataType::ListView(child)) => {
+                Some(child.data_type())
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns a zero-copy slice of this array with the indicated offset and length.
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        Self {
+            data_type: self.data_type.clone(),
+            nulls: self.nulls.as_ref().map(|n| n.slice(offset, length)),
+            values: self.values.clone(),
+            value_offsets: self.value_offsets.slice(offset, length),
+            value_sizes: self.value_sizes.slice(offset, length),
+        }
+    }
+
+    /// Creates a [`GenericListViewArray`] from an iterator of primitive values
+    /// # Example
+    /// ```
+    /// # use arrow_array::ListViewArray;
+    /// # use arrow_array::types::Int32Type;
+    ///
+    /// let data = vec![
+    ///    Some(vec![Some(0), Some(1), Some(2)]),
+    ///    None,
+    ///    Some(vec![Some(3), None, Some(5)]),
+    ///    Some(vec![Some(6), Some(7)]),
+    /// ];
+    /// let list_array = ListViewArray::from_iter_primitive::<Int32Type, _, _>(data);
+    /// println!("{:?}", list_array);
+    /// ```
+    pub fn from_iter_primitive<T, P, I>(iter: I) -> Self
+    where
+        T: ArrowPrimitiveType,
+        P: IntoIterator<Item = Option<<T as ArrowPrimitiveType>::Native>>,
+        I: IntoIterator<Item = Option<P>>,
+    {
+        let iter = iter.into_iter();
+        let size_hint = iter.size_hint().0;
+        let mut builder =
+            GenericListViewBuilder::with_capacity(PrimitiveBuilder::<T>::new(), size_hint);
+
+        for i in iter {
+            match i {
+                Some(p) => {
+                    for t in p {
+                        builder.values().append_option(t);
+                    }
+                    builder.append(true);
+                }
+                None => builder.append(false),
+            }
+        }
+        builder.finish()
+    }
+}
+
+impl<OffsetSize: OffsetSizeTrait> From<ArrayData> for GenericListViewArray<OffsetSize> {
+    fn from(data: ArrayData) -> Self {
+        Self::try_new_from_array_data(data)
+            .expect("Expected infallible creation of GenericListViewArray from ArrayDataRef failed")
+    }
+}
+
+impl<OffsetSize: OffsetSizeTrait> From<GenericListViewArray<OffsetSize>> for ArrayData {
+    fn from(array: GenericListViewArray<OffsetSize>) -> Self {
+        let len = array.len();
+        let builder = ArrayDataBuilder::new(array.data_type)
+            .len(len)
+            .nulls(array.nulls)
+            .buffers(vec![
+                array.value_offsets.into_inner(),
+                array.value_sizes.into_inner(),
+            ])
+            .child_data(vec![array.values.to_data()]);
+
+        unsafe { builder.build_unchecked() }
+    }
+}
+
+impl<OffsetSize: OffsetSizeTrait> From<GenericListArray<OffsetSize>>
+    for GenericListViewArray<OffsetSize>
+{
+    fn from(value: GenericListArray<OffsetSize>) -> Self {
+        let (field, offsets, values, nulls) = value.into_parts();
+        let sizes = offsets.windows(2).map(|w| w[1] - w[0]).collect();
+        let len = offsets.len() - 1;
+        let offsets = offsets.into_inner().slice(0, len);
+
+        Self {
+            data_type: Self::DATA_TYPE_CONSTRUCTOR(field),
+            nulls,
+            values,
+            value_offsets: offsets,
+            value_sizes: sizes,
+        }
+    }
+}
+
+impl<OffsetSize: OffsetSizeTrait> GenericListViewArray<OffsetSize> {
+    fn try_new_from_array_data(data: ArrayData) -> Result<Self, ArrowError> {
+        if data.buffers().len() != 2 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "ListViewArray data should contain two buffers (value offsets and sizes), had {}",
+                data.buffers().len()
+            )));
+        }
+
+        if data.child_data().len() != 1 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "ListViewArray should contain a single child array (values array), had {}",
+                data.child_data().len()
+            )));
+        }
+
+        let values = data.child_data()[0].clone();
+
+        if let Some(child_data_type) = Self::get_type(data.data_type()) {
+            if values.data_type() != child_data_type {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "[Large]ListViewArray's child datatype {:?} does not \
+                             correspond to the ListView's datatype {:?}",
+                    values.data_type(),
+                    child_data_type
+                )));
+            }
+        } else {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "[Large]ListViewArray's datatype must be [Large]ListViewArray(). It is {:?}",
+                data.data_type()
+            )));
+        }
+
+        let values = make_array(values);
+        let value_offsets = ScalarBuffer::new(data.buffers()[0].clone(), data.offset(), data.len());
+        let value_sizes = ScalarBuffer::new(data.buffers()[1].clone(), data.offset(), data.len());
+
+        Ok(Self {
+            data_type: data.data_type().clone(),
+            nulls: data.nulls().cloned(),
+            values,
+            value_offsets,
+            value_sizes,
+        })
+    }
+}
+
+impl<OffsetSize: OffsetSizeTrait> Array for GenericListViewArray<OffsetSize> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn to_data(&self) -> ArrayData {
+        self.clone().into()
+    }
+
+    fn into_data(self) -> ArrayData {
+        self.into()
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> ArrayRef {
+        Arc::new(self.slice(offset, length))
+    }
+
+    fn len(&self) -> usize {
+        self.value_offsets.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.value_offsets.is_empty()
+    }
+
+    fn offset(&self) -> usize {
+        0
+    }
+
+    fn nulls(&self) -> Option<&NullBuffer> {
+        self.nulls.as_ref()
+    }
+
+    fn get_buffer_memory_size(&self) -> usize {
+        let mut size = self.values.get_buffer_memory_size();
+        size += self.value_offsets.inner().capacity();
+        size += self.value_sizes.inner().capacity();
+        if let Some(n) = self.nulls.as_ref() {
+            size += n.buffer().capacity();
+        }
+        size
+    }
+
+    fn get_array_memory_size(&self) -> usize {
+        let mut size = std::mem::size_of::<Self>() + self.values.get_array_memory_size();
+        size += self.value_offsets.inner().capacity();
+        size += self.value_sizes.inner().capacity();
+        if let Some(n) = self.nulls.as_ref() {
+            size += n.buffer().capacity();
+        }
+        size
+    }
+}
+
+impl<OffsetSize: OffsetSizeTrait> ArrayAccessor for &GenericListViewArray<OffsetSize> {
+    type Item = ArrayRef;
+
+    fn value(&self, index: usize) -> Self::Item {
+        GenericListViewArray::value(self, index)
+    }
+
+    unsafe fn value_unchecked(&self, index: usize) -> Self::Item {
+        GenericListViewArray::value_unchecked(self, index)
+    }
+}
+
+impl<OffsetSize: OffsetSizeTrait> std::fmt::Debug for GenericListViewArray<OffsetSize> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let prefix = OffsetSize::PREFIX;
+
+        write!(f, "{prefix}ListViewArray\n[\n")?;
+        print_long_array(self, f, |array, index, f| {
+            std::fmt::Debug::fmt(&array.value(index), f)
+        })?;
+        write!(f, "]")
+    }
+}
+
+/// A [`GenericListViewArray`] of variable size lists, storing offsets and sizes as `i32`.
+///
+// See [`ListViewBuilder`](crate::builder::ListViewBuilder) for how to construct a [`ListViewArray`]
+pub type ListViewArray = GenericListViewArray<i32>;
+
+/// A [`GenericListViewArray`] of variable size lists, storing offsets and sizes as `i64`.
+///
+// See [`LargeListViewBuilder`](crate::builder::LargeListViewBuilder) for how to construct a [`LargeListViewArray`]
+pub type LargeListViewArray = GenericListViewArray<i64>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{Int32Builder, ListBuilder};
+    use crate::cast::AsArray;
+    use crate::types::Int32Type;
+    use crate::Int32Array;
+    use arrow_buffer::Buffer;
+    use arrow_schema::Field;
+
+    fn create_list_view<O: OffsetSizeTrait>() -> GenericListViewArray<O> {
+        // [[1, 2], [], NULL, [1, 2], [3, 4, 5]]
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        let values = Arc::new(Int32Array::from(vec![3, 4, 5, 1, 2]));
+        let offsets = ScalarBuffer::from(
+            vec![3, 0, 0, 3, 0]
+                .into_iter()
+                .map(O::usize_as)
+                .collect::<Vec<_>>(),
+        );
+        let sizes = ScalarBuffer::from(
+            vec![2, 0, 0, 2, 3]
+                .into_iter()
+                .map(O::usize_as)
+                .collect::<Vec<_>>(),
+        );
+        let nulls = NullBuffer::from(vec![true, true, false, true, true]);
+        GenericListViewArray::new(field, offsets, sizes, values, Some(nulls))
+    }
+
+    fn test_generic_list_view_array<O: OffsetSizeTrait>() {
+        let list = create_list_view::<O>();
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.null_count(), 1);
+        assert_eq!(list.value_type(), DataType::Int32);
+        assert_eq!(list.value_size(4), O::usize_as(3));
+        assert_eq!(list.value_offset(3), O::usize_as(3));
+
+        let values: Vec<_> = list
+            .iter()
+            .map(|x| x.map(|x| x.as_primitive::<Int32Type>().values().to_vec()))
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                Some(vec![1, 2]),
+                Some(vec![]),
+                None,
+                Some(vec![1, 2]),
+                Some(vec![3, 4, 5])
+            ]
+        );
+
+        let sliced = list.slice(3, 2);
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(sliced.null_count(), 0);
+        assert_eq!(
+            sliced.value(1).as_primitive::<Int32Type>().values(),
+            &[3, 4, 5]
+        );
+
+        let data = sliced.to_data();
+        data.validate_full().unwrap();
+        let roundtrip = GenericListViewArray::<O>::from(data);
+        assert_eq!(roundtrip, sliced);
+
+        let data = list.to_data().slice(2, 2);
+        data.validate_full().unwrap();
+        let from_data = GenericListViewArray::<O>::from(data);
+        assert_eq!(from_data, list.slice(2, 2));
+    }
+
+    #[test]
+    fn test_list_view_array() {
+        test_generic_list_view_array::<i32>();
+    }
+
+    #[test]
+    fn test_large_list_view_array() {
+        test_generic_list_view_array::<i64>();
+    }
+
+    #[test]
+    fn test_list_view_array_equal_to_list() {
+        let mut builder = ListBuilder::new(Int32Builder::new());
+        builder.append_value([Some(1), Some(2)]);
+        builder.append_value([]);
+        builder.append_null();
+        builder.append_value([Some(1), Some(2)]);
+        builder.append_value([Some(3), Some(4), Some(5)]);
+        let list = builder.finish();
+
+        let from_list = ListViewArray::from(list);
+        assert_eq!(from_list.value_offsets(), &[0, 2, 2, 2, 4]);
+        assert_eq!(from_list.value_sizes(), &[2, 0, 0, 2, 3]);
+        assert_eq!(from_list, create_list_view::<i32>());
+    }
+
+    #[test]
+    fn test_list_view_array_new_null() {
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        let list = LargeListViewArray::new_null(field, 3);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.null_count(), 3);
+        list.to_data().validate_full().unwrap();
+    }
+
+    #[test]
+    fn test_list_view_array_invalid() {
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        let values = Arc::new(Int32Array::from(vec![1, 2, 3]));
+
+        let err = ListViewArray::try_new(
+            field.clone(),
+            ScalarBuffer::from(vec![0, 2]),
+            ScalarBuffer::from(vec![2, 2]),
+            values.clone(),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Offset 2 and size 2 at index 1 exceed length of values 3"
+        );
+
+        let err = ListViewArray::try_new(
+            field.clone(),
+            ScalarBuffer::from(vec![0, -1]),
+            ScalarBuffer::from(vec![2, 1]),
+            values.clone(),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Offset at index 1 is negative"
+        );
+
+        let err = ListViewArray::try_new(
+            field,
+            ScalarBuffer::from(vec![0]),
+            ScalarBuffer::from(vec![2, 1]),
+            values,
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Length of sizes for ListViewArray, expected 1 got 2"
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "ListViewArray data should contain two buffers (value offsets and sizes), had 1"
+    )]
+    fn test_list_view_array_invalid_buffers() {
+        let value_data = Int32Array::from(vec![1, 2]).into_data();
+        let data_type = DataType::ListView(Arc::new(Field::new("item", DataType::Int32, false)));
+        let data = ArrayData::builder(data_type)
+            .len(1)
+            .add_buffer(Buffer::from_slice_ref([0_i32]))
+            .add_child_data(value_data);
+        drop(ListViewArray::from(unsafe { data.build_unchecked() }));
+    }
+}
@@ -0,0 +1,377 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::builder::{ArrayBuilder, BufferBuilder};
+use crate::{ArrayRef, GenericListViewArray, OffsetSizeTrait};
+use arrow_buffer::{Buffer, NullBufferBuilder, ScalarBuffer};
+use arrow_schema::{Field, FieldRef};
+use std::any::Any;
+use std::sync::Arc;
+
+/// Builder for [`GenericListViewArray`]
+///
+/// Use [`ListViewBuilder`] to build [`ListViewArray`]s and [`LargeListViewBuilder`] to
+/// build [`LargeListViewArray`]s.
+///
+/// Like [`GenericListBuilder`], values are appended to [`Self::values`] and delimited
+/// with [`Self::append`], with each list view referencing the values appended since the
+/// previous one
+///
+/// # Example
+///
+/// ```
+/// # use arrow_array::{builder::ListViewBuilder, builder::StringBuilder, Array, StringArray};
+/// #
+/// let mut builder = ListViewBuilder::new(StringBuilder::new());
+///
+/// // [A, B, C]
+/// builder.values().append_value("A");
+/// builder.values().append_value("B");
+/// builder.values().append_value("C");
+/// builder.append(true);
+///
+/// // [ ] (empty list)
+/// builder.append(true);
+///
+/// // Null
+/// builder.append(false);
+///
+/// // [D]
+/// builder.append_value([Some("D")]);
+///
+/// let array = builder.finish();
+/// assert_eq!(array.value_offsets(), &[0, 3, 3, 3]);
+/// assert_eq!(array.value_sizes(), &[3, 0, 0, 1]);
+/// assert_eq!(
+///   array.values().as_ref(),
+///   &StringArray::from(vec!["A", "B", "C", "D"])
+/// );
+/// ```
+///
+/// [`GenericListBuilder`]: crate::builder::GenericListBuilder
+/// [`ListViewBuilder`]: crate::builder::ListViewBuilder
+/// [`ListViewArray`]: crate::array::ListViewArray
+/// [`LargeListViewBuilder`]: crate::builder::LargeListViewBuilder
+/// [`LargeListViewArray`]: crate::array::LargeListViewArray
+#[derive(Debug)]
+pub struct GenericListViewBuilder<OffsetSize: OffsetSizeTrait, T: ArrayBuilder> {
+    offsets_builder: BufferBuilder<OffsetSize>,
+    sizes_builder: BufferBuilder<OffsetSize>,
+    null_buffer_builder: NullBufferBuilder,
+    values_builder: T,
+    field: Option<FieldRef>,
+    /// The length of [`Self::values_builder`] when the last list view was appended
+    current_offset: usize,
+}
+
+impl<O: OffsetSizeTrait, T: ArrayBuilder + Default> Default for GenericListViewBuilder<O, T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<OffsetSize: OffsetSizeTrait, T: ArrayBuilder> GenericListViewBuilder<OffsetSize, T> {
+    /// Creates a new [`GenericListViewBuilder`] from a given values array builder
+    pub fn new(values_builder: T) -> Self {
+        let capacity = values_builder.len();
+        Self::with_capacity(values_builder, capacity)
+    }
+
+    /// Creates a new [`GenericListViewBuilder`] from a given values array builder
+    /// `capacity` is the number of items to pre-allocate space for in this builder
+    pub fn with_capacity(values_builder: T, capacity: usize) -> Self {
+        let current_offset = values_builder.len();
+        Self {
+            offsets_builder: BufferBuilder::new(capacity),
+            sizes_builder: BufferBuilder::new(capacity),
+            null_buffer_builder: NullBufferBuilder::new(capacity),
+            values_builder,
+            field: None,
+            current_offset,
+        }
+    }
+
+    /// Override the field passed to [`GenericListViewArray::new`]
+    ///
+    /// By default a nullable field is created with the name `item`
+    ///
+    /// Note: [`Self::finish`] and [`Self::finish_cloned`] will panic if the
+    /// field's data type does not match that of `T`
+    pub fn with_field(self, field: impl Into<FieldRef>) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+}
+
+impl<OffsetSize: OffsetSizeTrait, T: ArrayBuilder> ArrayBuilder
+    for GenericListViewBuilder<OffsetSize, T>
+where
+    T: 'static,
+{
+    /// Returns the builder as a non-mutable `Any` reference.
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    /// Returns the builder as a mutable `Any` reference.
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    /// Returns the boxed builder as a box of `Any`.
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    /// Returns the number of array slots in the builder
+    fn len(&self) -> usize {
+        self.null_buffer_builder.len()
+    }
+
+    /// Builds the array and reset this builder.
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(self.finish())
+    }
+
+    /// Builds the array without resetting the builder.
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(self.finish_cloned())
+    }
+}
+
+impl<OffsetSize: OffsetSizeTrait, T: ArrayBuilder> GenericListViewBuilder<OffsetSize, T>
+where
+    T: 'static,
+{
+    /// Returns the child array builder as a mutable reference.
+    ///
+    /// This mutable reference can be used to append values into the child array builder,
+    /// but you must call [`append`](#method.append) to delimit each distinct list value.
+    pub fn values(&mut self) -> &mut T {
+        &mut self.values_builder
+    }
+
+    /// Returns the child array builder as an immutable reference
+    pub fn values_ref(&self) -> &T {
+        &self.values_builder
+    }
+
+    /// Finish the current variable-length list view array slot, referencing the values
+    /// appended to [`Self::values`] since the previous slot
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of [`Self::values`] exceeds `OffsetSize::MAX`
+    #[inline]
+    pub fn append(&mut self, is_valid: bool) {
+        let offset = self.current_offset;
+        let end = self.values_builder.len();
+        self.offsets_builder
+            .append(OffsetSize::from_usize(offset).unwrap());
+        self.sizes_builder
+            .append(OffsetSize::from_usize(end - offset).unwrap());
+        self.null_buffer_builder.append(is_valid);
+        self.current_offset = end;
+    }
+
+    /// Append a value to this [`GenericListViewBuilder`]
+    ///
+    /// ```
+    /// # use arrow_array::builder::{Int32Builder, ListViewBuilder};
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::{Array, Int32Array};
+    /// # use arrow_array::types::Int32Type;
+    /// let mut builder = ListViewBuilder::new(Int32Builder::new());
+    ///
+    /// builder.append_value([Some(1), Some(2), Some(3)]);
+    /// builder.append_value([]);
+    /// builder.append_value([None]);
+    ///
+    /// let array = builder.finish();
+    /// assert_eq!(array.len(), 3);
+    ///
+    /// assert_eq!(array.value_offsets(), &[0, 3, 3]);
+    /// assert_eq!(array.value_sizes(), &[3, 0, 1]);
+    /// let values = array.values().as_primitive::<Int32Type>();
+    /// assert_eq!(values, &Int32Array::from(vec![Some(1), Some(2), Some(3), None]));
+    /// ```
+    #[inline]
+    pub fn append_value<I, V>(&mut self, i: I)
+    where
+        T: Extend<Option<V>>,
+        I: IntoIterator<Item = Option<V>>,
+    {
+        self.extend(std::iter::once(Some(i)))
+    }
+
+    /// Append a null to this [`GenericListViewBuilder`]
+    ///
+    /// See [`Self::append_value`] for an example use.
+    #[inline]
+    pub fn append_null(&mut self) {
+        self.append(false)
+    }
+
+    /// Appends an optional value into this [`GenericListViewBuilder`]
+    ///
+    /// If `Some` calls [`Self::append_value`] otherwise calls [`Self::append_null`]
+    #[inline]
+    pub fn append_option<I, V>(&mut self, i: Option<I>)
+    where
+        T: Extend<Option<V>>,
+        I: IntoIterator<Item = Option<V>>,
+    {
+        match i {
+            Some(i) => self.append_value(i),
+            None => self.append_null(),
+        }
+    }
+
+    /// Builds the [`GenericListViewArray`] and reset this builder.
+    pub fn finish(&mut self) -> GenericListViewArray<OffsetSize> {
+        let values = self.values_builder.finish();
+        let nulls = self.null_buffer_builder.finish();
+        let offsets = ScalarBuffer::from(self.offsets_builder.finish());
+        let sizes = ScalarBuffer::from(self.sizes_builder.finish());
+        self.current_offset = 0;
+
+        let field = match &self.field {
+            Some(f) => f.clone(),
+            None => Arc::new(Field::new("item", values.data_type().clone(), true)),
+        };
+
+        GenericListViewArray::new(field, offsets, sizes, values, nulls)
+    }
+
+    /// Builds the [`GenericListViewArray`] without resetting the builder.
+    pub fn finish_cloned(&self) -> GenericListViewArray<OffsetSize> {
+        let values = self.values_builder.finish_cloned();
+        let nulls = self.null_buffer_builder.finish_cloned();
+        let offsets = Buffer::from_slice_ref(self.offsets_builder.as_slice()).into();
+        let sizes = Buffer::from_slice_ref(self.sizes_builder.as_slice()).into();
+
+        let field = match &self.field {
+            Some(f) => f.clone(),
+            None => Arc::new(Field::new("item", values.data_type().clone(), true)),
+        };
+
+        GenericListViewArray::new(field, offsets, sizes, values, nulls)
+    }
+
+    /// Returns the current offsets buffer as a slice
+    pub fn offsets_slice(&self) -> &[OffsetSize] {
+        self.offsets_builder.as_slice()
+    }
+
+    /// Returns the current sizes buffer as a slice
+    pub fn sizes_slice(&self) -> &[OffsetSize] {
+        self.sizes_builder.as_slice()
+    }
+
+    /// Returns the current null buffer as a slice
+    pub fn validity_slice(&self) -> Option<&[u8]> {
+        self.null_buffer_builder.as_slice()
+    }
+}
+
+impl<O, B, V, E> Extend<Option<V>> for GenericListViewBuilder<O, B>
+where
+    O: OffsetSizeTrait,
+    B: ArrayBuilder + Extend<E>,
+    V: IntoIterator<Item = E>,
+{
+    #[inline]
+    fn extend<T: IntoIterator<Item = Option<V>>>(&mut self, iter: T) {
+        for v in iter {
+            match v {
+                Some(elements) => {
+                    self.values_builder.extend(elements);
+                    self.append(true);
+                }
+                None => self.append(false),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{Int32Builder, ListViewBuilder};
+    use crate::cast::AsArray;
+    use crate::types::Int32Type;
+    use crate::Array;
+    use arrow_schema::DataType;
+
+    fn test_generic_list_view_builder<O: OffsetSizeTrait>() {
+        let mut builder = GenericListViewBuilder::<O, _>::new(Int32Builder::new());
+
+        //  [[0, 1, 2], null, [3, null, 5], [6, 7]]
+        builder.values().append_value(0);
+        builder.values().append_value(1);
+        builder.values().append_value(2);
+        builder.append(true);
+        builder.append(false);
+        builder.append_value([Some(3), None, Some(5)]);
+        builder.append_option(Some([Some(6), Some(7)]));
+
+        let list = builder.finish();
+        list.to_data().validate_full().unwrap();
+        assert_eq!(
+            list.data_type(),
+            &GenericListViewArray::<O>::DATA_TYPE_CONSTRUCTOR(Arc::new(Field::new(
+                "item",
+                DataType::Int32,
+                true
+            )))
+        );
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.null_count(), 1);
+        assert_eq!(list.value_offsets(), [0, 3, 3, 6].map(O::usize_as));
+        assert_eq!(list.value_sizes(), [3, 0, 3, 2].map(O::usize_as));
+        assert_eq!(list.values().null_count(), 1);
+        assert!(builder.is_empty());
+
+        builder.append_value([Some(8)]);
+        let list = builder.finish_cloned();
+        assert_eq!(list.value_offsets(), [O::usize_as(0)]);
+        assert_eq!(list.value_sizes(), [O::usize_as(1)]);
+        assert_eq!(builder.len(), 1);
+    }
+
+    #[test]
+    fn test_list_view_builder() {
+        test_generic_list_view_builder::<i32>();
+    }
+
+    #[test]
+    fn test_large_list_view_builder() {
+        test_generic_list_view_builder::<i64>();
+    }
+
+    #[test]
+    fn test_list_view_builder_with_field() {
+        let field = Arc::new(Field::new("element", DataType::Int32, false));
+        let mut builder = ListViewBuilder::new(Int32Builder::new()).with_field(field.clone());
+        builder.append_value([Some(1), Some(2)]);
+        let list = builder.finish();
+        assert_eq!(list.data_type(), &DataType::ListView(field));
+        assert_eq!(list.value(0).as_primitive::<Int32Type>().values(), &[1, 2]);
+    }
+}
@@ -162,6 +162,8 @@ mod generic_bytes_builder;
 pub use generic_bytes_builder::*;
 mod generic_list_builder;
 pub use generic_list_builder::*;
+mod generic_list_view_builder;
+pub use generic_list_view_builder::*;
 mod map_builder;
 pub use map_builder::*;
 mod null_builder;
@@ -304,6 +306,12 @@ pub type ListBuilder<T> = GenericListBuilder<i32, T>;
 /// Builder for [`LargeListArray`](crate::array::LargeListArray)
 pub type LargeListBuilder<T> = GenericListBuilder<i64, T>;
 
+/// Builder for [`ListViewArray`](crate::array::ListViewArray)
+pub type ListViewBuilder<T> = GenericListViewBuilder<i32, T>;
+
+/// Builder for [`LargeListViewArray`](crate::array::LargeListViewArray)
+pub type LargeListViewBuilder<T> = GenericListViewBuilder<i64, T>;
+
 /// Builder for [`BinaryArray`](crate::array::BinaryArray)
 ///
 /// See examples on [`GenericBinaryBuilder`]
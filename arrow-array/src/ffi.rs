@@ -184,6 +184,14 @@ fn bit_width(data_type: &DataType, i: usize) -> Result<usize> {
                 "The datatype \"{data_type:?}\" expects 3 buffers, but requested {i}. Please verify that the C data interface is correctly implemented."
             )))
         }
+        // List views have two buffers, of offsets and sizes
+        (DataType::ListView(_), 1) | (DataType::ListView(_), 2) => i32::BITS as _,
+        (DataType::LargeListView(_), 1) | (DataType::LargeListView(_), 2) => i64::BITS as _,
+        (DataType::ListView(_), _) | (DataType::LargeListView(_), _) => {
+            return Err(ArrowError::CDataInterface(format!(
+                "The datatype \"{data_type:?}\" expects 3 buffers, but requested {i}. Please verify that the C data interface is correctly implemented."
+            )))
+        }
         // Variable-sized binaries: have two buffers.
         // LargeUtf8: first buffer is i64, second is in bytes
         (DataType::LargeUtf8, 1) | (DataType::LargeBinary, 1) | (DataType::LargeList(_), 1) => i64::BITS as _,
@@ -336,6 +344,8 @@ impl<'a> ImportedArrowArray<'a> {
             DataType::List(field)
             | DataType::FixedSizeList(field, _)
             | DataType::LargeList(field)
+            | DataType::ListView(field)
+            | DataType::LargeListView(field)
             | DataType::Map(field, _) => Ok([self.consume_child(0, field.data_type())?].to_vec()),
             DataType::Struct(fields) => {
                 assert!(fields.len() == self.array.num_children());
@@ -496,7 +506,7 @@ mod tests_to_then_from_ffi {
     use std::collections::HashMap;
     use std::mem::ManuallyDrop;
 
-    use arrow_buffer::NullBuffer;
+    use arrow_buffer::{NullBuffer, ScalarBuffer};
     use arrow_schema::Field;
 
     use crate::builder::UnionBuilder;
@@ -694,6 +704,45 @@ mod tests_to_then_from_ffi {
         test_generic_list::<i64>()
     }
 
+    fn test_generic_list_view<Offset: OffsetSizeTrait>() -> Result<()> {
+        // [[0, 1, 2], null, [], [1, 2], [3]]
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        let values = Arc::new(Int32Array::from(vec![0, 1, 2, 3]));
+        let offsets = ScalarBuffer::from_iter([0, 3, 3, 1, 3].map(Offset::usize_as));
+        let sizes = ScalarBuffer::from_iter([3, 0, 0, 2, 1].map(Offset::usize_as));
+        let nulls = NullBuffer::from(vec![true, false, true, true, true]);
+        let array = GenericListViewArray::<Offset>::new(field, offsets, sizes, values, Some(nulls));
+        let array = array.slice(1, 4);
+
+        // export it
+        let (ffi_array, schema) = to_ffi(&array.to_data())?;
+
+        // (simulate consumer) import it
+        let data = unsafe { from_ffi(ffi_array, &schema) }?;
+        let imported = GenericListViewArray::<Offset>::from(data);
+
+        // verify
+        assert_eq!(imported, array);
+        assert!(imported.is_null(0));
+        assert_eq!(
+            imported.value(2).as_primitive::<Int32Type>().values(),
+            &[1, 2]
+        );
+
+        // (drop/release)
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_view() -> Result<()> {
+        test_generic_list_view::<i32>()
+    }
+
+    #[test]
+    fn test_large_list_view() -> Result<()> {
+        test_generic_list_view::<i64>()
+    }
+
     fn test_generic_binary<Offset: OffsetSizeTrait>() -> Result<()> {
         // create an array natively
         let array: Vec<Option<&[u8]>> = vec![Some(b"a"), None, Some(b"aaa")];
@@ -15,10 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::schema::{Attributes, ComplexType, PrimitiveType, Record, Schema, TypeName};
+use crate::schema::{Attributes, ComplexType, PrimitiveType, Record, Schema, Type, TypeName};
 use arrow_schema::{
     ArrowError, DataType, Field, FieldRef, IntervalUnit, SchemaBuilder, SchemaRef, TimeUnit,
+    DECIMAL128_MAX_PRECISION, DECIMAL256_MAX_PRECISION,
 };
+use serde_json::Value;
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -29,7 +31,7 @@ use std::sync::Arc;
 /// To accommodate this we special case two-variant unions where one of the
 /// variants is the null type, and use this to derive arrow's notion of nullability
 #[derive(Debug, Copy, Clone)]
-enum Nulls {
+pub enum Nulls {
     /// The nulls are encoded as the first union variant
     NullFirst,
     /// The nulls are encoded as the second union variant
@@ -42,16 +44,115 @@ pub struct AvroDataType {
     nulls: Option<Nulls>,
     metadata: HashMap<String, String>,
     codec: Codec,
+    resolution: Option<ResolutionInfo>,
 }
 
 impl AvroDataType {
+    fn new(codec: Codec, metadata: HashMap<String, String>) -> Self {
+        Self {
+            nulls: None,
+            metadata,
+            codec,
+            resolution: None,
+        }
+    }
+
     /// Returns an arrow [`Field`] with the given name
     pub fn field_with_name(&self, name: &str) -> Field {
         let d = self.codec.data_type();
-        Field::new(name, d, self.nulls.is_some()).with_metadata(self.metadata.clone())
+        let nullable = match &self.resolution {
+            Some(r) => r.nullable,
+            None => self.nulls.is_some(),
+        };
+        // The null type is always nullable in arrow
+        let nullable = nullable || matches!(self.codec, Codec::Null);
+        Field::new(name, d, nullable).with_metadata(self.metadata.clone())
+    }
+
+    /// Returns the [`Codec`]
+    pub fn codec(&self) -> &Codec {
+        &self.codec
+    }
+
+    /// Returns the [`Nulls`] if this type is encoded as a nullable union
+    pub fn nullability(&self) -> Option<Nulls> {
+        self.nulls
+    }
+
+    /// Returns the [`ResolutionInfo`] if this type was resolved from a differing writer schema
+    pub fn resolution(&self) -> Option<&ResolutionInfo> {
+        self.resolution.as_ref()
+    }
+
+    /// The precision and scale of a decimal are encoded in its arrow [`DataType`]
+    fn remove_decimal_metadata(&mut self) {
+        if let Codec::Decimal(..) = self.codec {
+            self.metadata.remove("precision");
+            self.metadata.remove("scale");
+        }
     }
 }
 
+/// Describes how data encoded with a writer schema is decoded as a reader [`AvroDataType`]
+///
+/// The [`Codec`] of the [`AvroDataType`] describes the reader type, whilst its
+/// [`Nulls`] describe how the writer encodes nulls
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#schema-resolution>
+#[derive(Debug, Clone)]
+pub struct ResolutionInfo {
+    /// Whether the reader type is nullable
+    ///
+    /// A writer union with null can be read as a non-nullable type provided no nulls are
+    /// present, and any writer type can be read as a nullable union of that type
+    pub nullable: bool,
+    /// How the writer values are converted to the reader type, if at all
+    pub conversion: Option<Conversion>,
+}
+
+/// A conversion from a writer type to a reader type
+#[derive(Debug, Clone)]
+pub enum Conversion {
+    /// A primitive type promotion
+    Promotion(Promotion),
+    /// Maps the index of each writer enum symbol to the index of the reader symbol,
+    /// or `None` if it is not present in the reader schema and the reader has no default
+    Enum(Arc<[Option<i32>]>),
+    /// The fields of a writer record are projected onto the fields of a reader record
+    Record(ResolvedRecord),
+}
+
+/// A primitive writer type promoted to a wider reader type
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Promotion {
+    IntToLong,
+    IntToFloat,
+    IntToDouble,
+    LongToFloat,
+    LongToDouble,
+    FloatToDouble,
+}
+
+/// The resolution of a writer record against a reader record
+///
+/// The reader fields are the fields of [`Codec::Struct`]
+#[derive(Debug, Clone)]
+pub struct ResolvedRecord {
+    /// How each writer field, in writer order, is decoded
+    pub writer_fields: Arc<[ResolvedField]>,
+    /// The index and default value of each reader field absent from the writer schema
+    pub defaults: Arc<[(usize, Value)]>,
+}
+
+/// The resolution of a field of a writer record
+#[derive(Debug, Clone)]
+pub enum ResolvedField {
+    /// The writer field is decoded into the reader field with the given index
+    ToReader(usize),
+    /// The writer field is not present in the reader schema, and is skipped
+    Skip(AvroDataType),
+}
+
 /// A named [`AvroDataType`]
 #[derive(Debug, Clone)]
 pub struct AvroField {
@@ -69,15 +170,36 @@ impl AvroField {
     pub fn codec(&self) -> &Codec {
         &self.data_type.codec
     }
+
+    /// Returns the [`AvroDataType`]
+    pub fn data_type(&self) -> &AvroDataType {
+        &self.data_type
+    }
+
+    /// Returns the name of this field
+    pub fn name(&self) -> &str {
+        &self.name
+    }
 }
 
 impl<'a> TryFrom<&Schema<'a>> for AvroField {
     type Error = ArrowError;
 
     fn try_from(schema: &Schema<'a>) -> Result<Self, Self::Error> {
+        Self::try_new(schema, 0)
+    }
+}
+
+impl AvroField {
+    /// Create a new [`AvroField`] from the provided record [`Schema`]
+    ///
+    /// Arrow cannot represent recursive types, instead a recursive reference to a named
+    /// type is expanded `recursion_depth` times, after which it is decoded as JSON encoded
+    /// [`DataType::Utf8`], see [`Codec::Json`]
+    pub fn try_new(schema: &Schema<'_>, recursion_depth: usize) -> Result<Self, ArrowError> {
         match schema {
             Schema::Complex(ComplexType::Record(r)) => {
-                let mut resolver = Resolver::default();
+                let mut resolver = Resolver::new(schema, recursion_depth);
                 let data_type = make_data_type(schema, None, &mut resolver)?;
                 Ok(AvroField {
                     data_type,
@@ -89,6 +211,28 @@ impl<'a> TryFrom<&Schema<'a>> for AvroField {
             ))),
         }
     }
+
+    /// Returns an [`AvroField`] that decodes data encoded with the `writer` schema as the
+    /// arrow representation of the `reader` schema
+    ///
+    /// Returns an error if the schemas are not compatible
+    ///
+    /// <https://avro.apache.org/docs/1.11.1/specification/#schema-resolution>
+    pub fn resolve<'a>(writer: &'a Schema<'a>, reader: &'a Schema<'a>) -> Result<Self, ArrowError> {
+        match reader {
+            Schema::Complex(ComplexType::Record(r)) => {
+                let mut resolver = SchemaResolver::try_new(writer, reader)?;
+                let data_type = resolver.resolve(writer, None, reader, None)?;
+                Ok(AvroField {
+                    data_type,
+                    name: r.name.to_string(),
+                })
+            }
+            _ => Err(ArrowError::ParseError(format!(
+                "Expected record got {reader:?}"
+            ))),
+        }
+    }
 }
 
 /// An Avro encoding
@@ -111,14 +255,28 @@ pub enum Codec {
     TimestampMillis(bool),
     /// TimestampMicros(is_utc)
     TimestampMicros(bool),
+    /// TimestampNanos(is_utc)
+    TimestampNanos(bool),
     Fixed(i32),
+    /// Decimal(precision, scale, fixed_size)
+    ///
+    /// Encoded as `bytes` if `fixed_size` is `None`, otherwise as `fixed`
+    Decimal(u8, i8, Option<usize>),
+    /// A UUID encoded as a string
+    Uuid,
+    /// Enum(symbols)
+    Enum(Arc<[String]>),
     List(Arc<AvroDataType>),
+    Map(Arc<AvroDataType>),
     Struct(Arc<[AvroField]>),
     Duration,
+    /// A recursive type, decoded as JSON
+    Json(JsonSchema),
 }
 
 impl Codec {
-    fn data_type(&self) -> DataType {
+    /// Returns the arrow [`DataType`] of this codec
+    pub fn data_type(&self) -> DataType {
         match self {
             Self::Null => DataType::Null,
             Self::Boolean => DataType::Boolean,
@@ -137,10 +295,32 @@ impl Codec {
             Self::TimestampMicros(is_utc) => {
                 DataType::Timestamp(TimeUnit::Microsecond, is_utc.then(|| "+00:00".into()))
             }
+            Self::TimestampNanos(is_utc) => {
+                DataType::Timestamp(TimeUnit::Nanosecond, is_utc.then(|| "+00:00".into()))
+            }
             Self::Duration => DataType::Interval(IntervalUnit::MonthDayNano),
             Self::Fixed(size) => DataType::FixedSizeBinary(*size),
+            Self::Decimal(precision, scale, _) => match *precision <= DECIMAL128_MAX_PRECISION {
+                true => DataType::Decimal128(*precision, *scale),
+                false => DataType::Decimal256(*precision, *scale),
+            },
+            Self::Uuid => DataType::FixedSizeBinary(16),
+            Self::Enum(_) => {
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+            }
             Self::List(f) => DataType::List(Arc::new(f.field_with_name("item"))),
+            Self::Map(value) => {
+                let entries = DataType::Struct(
+                    vec![
+                        Arc::new(Field::new("key", DataType::Utf8, false)),
+                        Arc::new(value.field_with_name("value")),
+                    ]
+                    .into(),
+                );
+                DataType::Map(Arc::new(Field::new("entries", entries, false)), false)
+            }
             Self::Struct(f) => DataType::Struct(f.iter().map(|x| x.field()).collect()),
+            Self::Json(_) => DataType::Utf8,
         }
     }
 }
@@ -160,28 +340,243 @@ impl From<PrimitiveType> for Codec {
     }
 }
 
+/// The JSON encoding of Avro data, used to decode recursive types that cannot
+/// be represented in arrow
+///
+/// Records and maps are encoded as JSON objects, arrays as JSON arrays, and unions
+/// as the value of the selected variant. Bytes and fixed are encoded as strings with
+/// a code point for each byte, and logical types are encoded as their underlying type
+#[derive(Debug, Clone)]
+pub struct JsonSchema {
+    types: Arc<[JsonType]>,
+    root: usize,
+}
+
+impl JsonSchema {
+    /// Returns the [`JsonType`] of the root of this schema
+    pub fn root(&self) -> &JsonType {
+        &self.types[self.root]
+    }
+
+    /// Returns the [`JsonType`] referred to by `idx`
+    pub fn get(&self, idx: usize) -> &JsonType {
+        &self.types[idx]
+    }
+}
+
+/// A type within a [`JsonSchema`], with child types referred to by index
+#[derive(Debug, Clone)]
+pub enum JsonType {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Fixed(usize),
+    Enum(Arc<[String]>),
+    Array(usize),
+    Map(usize),
+    Record(Arc<[(String, usize)]>),
+    Union(Arc<[usize]>),
+}
+
+impl From<PrimitiveType> for JsonType {
+    fn from(value: PrimitiveType) -> Self {
+        match value {
+            PrimitiveType::Null => Self::Null,
+            PrimitiveType::Boolean => Self::Boolean,
+            PrimitiveType::Int => Self::Int,
+            PrimitiveType::Long => Self::Long,
+            PrimitiveType::Float => Self::Float,
+            PrimitiveType::Double => Self::Double,
+            PrimitiveType::Bytes => Self::Bytes,
+            PrimitiveType::String => Self::String,
+        }
+    }
+}
+
+/// Constructs a [`JsonSchema`], with each named type defined once to permit recursion
+struct JsonSchemaBuilder<'r, 'a> {
+    named: &'r NamedTypes<'a>,
+    types: Vec<JsonType>,
+    defined: HashMap<(&'a str, &'a str), usize>,
+}
+
+impl<'r, 'a> JsonSchemaBuilder<'r, 'a> {
+    fn build(
+        named: &'r NamedTypes<'a>,
+        schema: &'a Schema<'a>,
+        namespace: Option<&'a str>,
+    ) -> Result<JsonSchema, ArrowError> {
+        let mut builder = Self {
+            named,
+            types: vec![],
+            defined: Default::default(),
+        };
+        let root = builder.add(schema, namespace)?;
+        Ok(JsonSchema {
+            types: builder.types.into(),
+            root,
+        })
+    }
+
+    fn push(&mut self, t: JsonType) -> usize {
+        self.types.push(t);
+        self.types.len() - 1
+    }
+
+    fn define(&mut self, name: &'a str, namespace: Option<&'a str>, t: JsonType) -> usize {
+        let idx = self.push(t);
+        self.defined.insert((namespace.unwrap_or(""), name), idx);
+        idx
+    }
+
+    fn add(
+        &mut self,
+        schema: &'a Schema<'a>,
+        namespace: Option<&'a str>,
+    ) -> Result<usize, ArrowError> {
+        Ok(match schema {
+            Schema::TypeName(TypeName::Primitive(p))
+            | Schema::Type(Type {
+                r#type: TypeName::Primitive(p),
+                ..
+            }) => self.push((*p).into()),
+            Schema::TypeName(TypeName::Ref(name))
+            | Schema::Type(Type {
+                r#type: TypeName::Ref(name),
+                ..
+            }) => match self.defined.get(&qualify(name, namespace)) {
+                Some(idx) => *idx,
+                None => {
+                    let (schema, namespace) = self.named.get(name, namespace)?;
+                    self.add(schema, namespace)?
+                }
+            },
+            Schema::Union(variants) => {
+                let variants = variants
+                    .iter()
+                    .map(|v| self.add(v, namespace))
+                    .collect::<Result<_, ArrowError>>()?;
+                self.push(JsonType::Union(variants))
+            }
+            Schema::Complex(ComplexType::Record(r)) => {
+                let namespace = r.namespace.or(namespace);
+                // Define the record before its fields, as they may refer to it
+                let idx = self.define(r.name, namespace, JsonType::Null);
+                let fields = r
+                    .fields
+                    .iter()
+                    .map(|f| Ok((f.name.to_string(), self.add(&f.r#type, namespace)?)))
+                    .collect::<Result<_, ArrowError>>()?;
+                self.types[idx] = JsonType::Record(fields);
+                idx
+            }
+            Schema::Complex(ComplexType::Enum(e)) => {
+                let symbols = e.symbols.iter().map(|s| s.to_string()).collect();
+                self.define(e.name, e.namespace.or(namespace), JsonType::Enum(symbols))
+            }
+            Schema::Complex(ComplexType::Fixed(f)) => {
+                self.define(f.name, f.namespace.or(namespace), JsonType::Fixed(f.size))
+            }
+            Schema::Complex(ComplexType::Array(a)) => {
+                let items = self.add(&a.items, namespace)?;
+                self.push(JsonType::Array(items))
+            }
+            Schema::Complex(ComplexType::Map(m)) => {
+                let values = self.add(&m.values, namespace)?;
+                self.push(JsonType::Map(values))
+            }
+        })
+    }
+}
+
+/// Returns the namespace and name of a reference to a named type
+fn qualify<'a>(name: &'a str, namespace: Option<&'a str>) -> (&'a str, &'a str) {
+    name.rsplit_once('.')
+        .unwrap_or_else(|| (namespace.unwrap_or(""), name))
+}
+
 /// Resolves Avro type names to [`AvroDataType`]
 ///
 /// See <https://avro.apache.org/docs/1.11.1/specification/#names>
 #[derive(Debug, Default)]
 struct Resolver<'a> {
     map: HashMap<(&'a str, &'a str), AvroDataType>,
+    /// The named types of the schema, used to expand recursive references
+    named: NamedTypes<'a>,
+    /// The records currently being parsed
+    in_progress: Vec<(&'a str, &'a str)>,
+    /// The number of times to expand a recursive reference
+    recursion_depth: usize,
 }
 
 impl<'a> Resolver<'a> {
+    fn new(schema: &'a Schema<'a>, recursion_depth: usize) -> Self {
+        Self {
+            named: NamedTypes::new(schema),
+            recursion_depth,
+            ..Default::default()
+        }
+    }
+
     fn register(&mut self, name: &'a str, namespace: Option<&'a str>, schema: AvroDataType) {
-        self.map.insert((name, namespace.unwrap_or("")), schema);
+        self.map.insert((namespace.unwrap_or(""), name), schema);
     }
 
-    fn resolve(&self, name: &str, namespace: Option<&'a str>) -> Result<AvroDataType, ArrowError> {
-        let (namespace, name) = name
-            .rsplit_once('.')
-            .unwrap_or_else(|| (namespace.unwrap_or(""), name));
+    fn resolve(
+        &mut self,
+        name: &'a str,
+        namespace: Option<&'a str>,
+    ) -> Result<AvroDataType, ArrowError> {
+        let key = qualify(name, namespace);
+        if let Some(data_type) = self.map.get(&key) {
+            return Ok(data_type.clone());
+        }
 
-        self.map
-            .get(&(namespace, name))
-            .ok_or_else(|| ArrowError::ParseError(format!("Failed to resolve {namespace}.{name}")))
-            .cloned()
+        // A reference to a record that is still being parsed is recursive
+        let depth = self.in_progress.iter().filter(|x| **x == key).count();
+        if depth == 0 {
+            let (namespace, name) = key;
+            return Err(ArrowError::ParseError(format!(
+                "Failed to resolve {namespace}.{name}"
+            )));
+        }
+
+        let (schema, namespace) = self.named.get(name, namespace)?;
+        match depth <= self.recursion_depth {
+            true => make_data_type(schema, namespace, self),
+            false => {
+                let json = JsonSchemaBuilder::build(&self.named, schema, namespace)?;
+                let metadata = [(
+                    EXTENSION_TYPE_NAME_KEY.to_string(),
+                    "arrow.json".to_string(),
+                )];
+                Ok(AvroDataType::new(Codec::Json(json), metadata.into()))
+            }
+        }
+    }
+}
+
+/// The arrow metadata key identifying an extension type
+const EXTENSION_TYPE_NAME_KEY: &str = "ARROW:extension:name";
+
+/// Returns the [`Nulls`] and non-null variant of a union
+///
+/// Only the common case of a two-variant union where one of the variants is null is supported
+fn nullable_union<'s, 'a>(f: &'s [Schema<'a>]) -> Result<(Nulls, &'s Schema<'a>), ArrowError> {
+    let null = f
+        .iter()
+        .position(|x| x == &Schema::TypeName(TypeName::Primitive(PrimitiveType::Null)));
+    match (f.len() == 2, null) {
+        (true, Some(0)) => Ok((Nulls::NullFirst, &f[1])),
+        (true, Some(1)) => Ok((Nulls::NullSecond, &f[0])),
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "Union of {f:?} not currently supported"
+        ))),
     }
 }
 
@@ -197,36 +592,20 @@ fn make_data_type<'a>(
     resolver: &mut Resolver<'a>,
 ) -> Result<AvroDataType, ArrowError> {
     match schema {
-        Schema::TypeName(TypeName::Primitive(p)) => Ok(AvroDataType {
-            nulls: None,
-            metadata: Default::default(),
-            codec: (*p).into(),
-        }),
+        Schema::TypeName(TypeName::Primitive(p)) => {
+            Ok(AvroDataType::new((*p).into(), Default::default()))
+        }
         Schema::TypeName(TypeName::Ref(name)) => resolver.resolve(name, namespace),
         Schema::Union(f) => {
-            // Special case the common case of nullable primitives
-            let null = f
-                .iter()
-                .position(|x| x == &Schema::TypeName(TypeName::Primitive(PrimitiveType::Null)));
-            match (f.len() == 2, null) {
-                (true, Some(0)) => {
-                    let mut field = make_data_type(&f[1], namespace, resolver)?;
-                    field.nulls = Some(Nulls::NullFirst);
-                    Ok(field)
-                }
-                (true, Some(1)) => {
-                    let mut field = make_data_type(&f[0], namespace, resolver)?;
-                    field.nulls = Some(Nulls::NullSecond);
-                    Ok(field)
-                }
-                _ => Err(ArrowError::NotYetImplemented(format!(
-                    "Union of {f:?} not currently supported"
-                ))),
-            }
+            let (nulls, schema) = nullable_union(f)?;
+            let mut field = make_data_type(schema, namespace, resolver)?;
+            field.nulls = Some(nulls);
+            Ok(field)
         }
         Schema::Complex(c) => match c {
             ComplexType::Record(r) => {
                 let namespace = r.namespace.or(namespace);
+                resolver.in_progress.push((namespace.unwrap_or(""), r.name));
                 let fields = r
                     .fields
                     .iter()
@@ -237,42 +616,48 @@ fn make_data_type<'a>(
                         })
                     })
                     .collect::<Result<_, ArrowError>>()?;
+                resolver.in_progress.pop();
 
-                let field = AvroDataType {
-                    nulls: None,
-                    codec: Codec::Struct(fields),
-                    metadata: r.attributes.field_metadata(),
-                };
+                let field = AvroDataType::new(Codec::Struct(fields), r.attributes.field_metadata());
                 resolver.register(r.name, namespace, field.clone());
                 Ok(field)
             }
             ComplexType::Array(a) => {
                 let mut field = make_data_type(a.items.as_ref(), namespace, resolver)?;
-                Ok(AvroDataType {
-                    nulls: None,
-                    metadata: a.attributes.field_metadata(),
-                    codec: Codec::List(Arc::new(field)),
-                })
+                Ok(AvroDataType::new(
+                    Codec::List(Arc::new(field)),
+                    a.attributes.field_metadata(),
+                ))
             }
             ComplexType::Fixed(f) => {
                 let size = f.size.try_into().map_err(|e| {
                     ArrowError::ParseError(format!("Overflow converting size to i32: {e}"))
                 })?;
 
-                let field = AvroDataType {
-                    nulls: None,
-                    metadata: f.attributes.field_metadata(),
-                    codec: Codec::Fixed(size),
+                let codec = match (f.attributes.logical_type, size) {
+                    (Some("duration"), 12) => Codec::Duration,
+                    (Some("uuid"), 16) => Codec::Fixed(16),
+                    (Some("decimal"), _) => parse_decimal(&f.attributes, Some(f.size))?,
+                    _ => Codec::Fixed(size),
                 };
-                resolver.register(f.name, namespace, field.clone());
+                let mut field = AvroDataType::new(codec, f.attributes.field_metadata());
+                field.remove_decimal_metadata();
+                resolver.register(f.name, f.namespace.or(namespace), field.clone());
                 Ok(field)
             }
-            ComplexType::Enum(e) => Err(ArrowError::NotYetImplemented(format!(
-                "Enum of {e:?} not currently supported"
-            ))),
-            ComplexType::Map(m) => Err(ArrowError::NotYetImplemented(format!(
-                "Map of {m:?} not currently supported"
-            ))),
+            ComplexType::Enum(e) => {
+                let symbols = e.symbols.iter().map(|s| s.to_string()).collect();
+                let field = AvroDataType::new(Codec::Enum(symbols), e.attributes.field_metadata());
+                resolver.register(e.name, e.namespace.or(namespace), field.clone());
+                Ok(field)
+            }
+            ComplexType::Map(m) => {
+                let values = make_data_type(m.values.as_ref(), namespace, resolver)?;
+                Ok(AvroDataType::new(
+                    Codec::Map(Arc::new(values)),
+                    m.attributes.field_metadata(),
+                ))
+            }
         },
         Schema::Type(t) => {
             let mut field =
@@ -280,11 +665,8 @@ fn make_data_type<'a>(
 
             // https://avro.apache.org/docs/1.11.1/specification/#logical-types
             match (t.attributes.logical_type, &mut field.codec) {
-                (Some("decimal"), c @ Codec::Fixed(_)) => {
-                    return Err(ArrowError::NotYetImplemented(
-                        "Decimals are not currently supported".to_string(),
-                    ))
-                }
+                (Some("decimal"), c @ Codec::Binary) => *c = parse_decimal(&t.attributes, None)?,
+                (Some("uuid"), c @ Codec::Utf8) => *c = Codec::Uuid,
                 (Some("date"), c @ Codec::Int32) => *c = Codec::Date32,
                 (Some("time-millis"), c @ Codec::Int32) => *c = Codec::TimeMillis,
                 (Some("time-micros"), c @ Codec::Int64) => *c = Codec::TimeMicros,
@@ -296,6 +678,10 @@ fn make_data_type<'a>(
                 (Some("local-timestamp-micros"), c @ Codec::Int64) => {
                     *c = Codec::TimestampMicros(false)
                 }
+                (Some("timestamp-nanos"), c @ Codec::Int64) => *c = Codec::TimestampNanos(true),
+                (Some("local-timestamp-nanos"), c @ Codec::Int64) => {
+                    *c = Codec::TimestampNanos(false)
+                }
                 (Some("duration"), c @ Codec::Fixed(12)) => *c = Codec::Duration,
                 (Some(logical), _) => {
                     // Insert unrecognized logical type into metadata map
@@ -309,7 +695,378 @@ fn make_data_type<'a>(
                     field.metadata.insert(k.to_string(), v.to_string());
                 }
             }
+            field.remove_decimal_metadata();
             Ok(field)
         }
     }
 }
+
+/// Parses the precision and scale of a decimal logical type
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#decimal>
+fn parse_decimal(attributes: &Attributes<'_>, size: Option<usize>) -> Result<Codec, ArrowError> {
+    let get = |key: &str| {
+        attributes
+            .additional
+            .get(key)
+            .map(|v| {
+                v.as_u64()
+                    .ok_or_else(|| ArrowError::ParseError(format!("Invalid decimal {key}: {v}")))
+            })
+            .transpose()
+    };
+
+    let precision = get("precision")?
+        .ok_or_else(|| ArrowError::ParseError("Decimal requires precision".to_string()))?;
+    let scale = get("scale")?.unwrap_or(0);
+
+    if precision == 0 || precision > DECIMAL256_MAX_PRECISION as u64 {
+        return Err(ArrowError::ParseError(format!(
+            "Unsupported decimal precision {precision}"
+        )));
+    }
+    if scale > precision {
+        return Err(ArrowError::ParseError(format!(
+            "Decimal scale {scale} cannot exceed precision {precision}"
+        )));
+    }
+    if let Some(size) = size {
+        // The maximum number of base-10 digits representable in `size` bytes
+        let max_precision = ((8 * size).saturating_sub(1) as f64 * 2_f64.log10()).floor() as u64;
+        if precision > max_precision {
+            return Err(ArrowError::ParseError(format!(
+                "Decimal precision {precision} exceeds maximum of {max_precision} for fixed of size {size}"
+            )));
+        }
+    }
+    Ok(Codec::Decimal(precision as u8, scale as i8, size))
+}
+
+/// The named types defined within a [`Schema`]
+///
+/// Unlike [`Resolver`] this retains the [`Schema`] of each named type, along with
+/// the namespace enclosing its definition, allowing a writer and reader schema
+/// to be traversed in parallel
+#[derive(Debug, Default)]
+struct NamedTypes<'a> {
+    map: HashMap<(&'a str, &'a str), (&'a Schema<'a>, Option<&'a str>)>,
+}
+
+impl<'a> NamedTypes<'a> {
+    fn new(schema: &'a Schema<'a>) -> Self {
+        let mut named = Self::default();
+        named.collect(schema, None);
+        named
+    }
+
+    fn collect(&mut self, schema: &'a Schema<'a>, namespace: Option<&'a str>) {
+        let mut insert = |name: &'a str, ns: Option<&'a str>| {
+            self.map
+                .insert((ns.unwrap_or(""), name), (schema, namespace));
+        };
+        match schema {
+            Schema::TypeName(_) | Schema::Type(_) => {}
+            Schema::Union(u) => u.iter().for_each(|s| self.collect(s, namespace)),
+            Schema::Complex(c) => match c {
+                ComplexType::Record(r) => {
+                    let ns = r.namespace.or(namespace);
+                    insert(r.name, ns);
+                    r.fields.iter().for_each(|f| self.collect(&f.r#type, ns));
+                }
+                ComplexType::Enum(e) => insert(e.name, e.namespace.or(namespace)),
+                ComplexType::Fixed(f) => insert(f.name, f.namespace.or(namespace)),
+                ComplexType::Array(a) => self.collect(&a.items, namespace),
+                ComplexType::Map(m) => self.collect(&m.values, namespace),
+            },
+        }
+    }
+
+    /// Returns the definition and enclosing namespace of the named type `name`
+    fn get(
+        &self,
+        name: &'a str,
+        namespace: Option<&'a str>,
+    ) -> Result<(&'a Schema<'a>, Option<&'a str>), ArrowError> {
+        let (ns, name) = qualify(name, namespace);
+        self.map
+            .get(&(ns, name))
+            .copied()
+            .ok_or_else(|| ArrowError::ParseError(format!("Failed to resolve {ns}.{name}")))
+    }
+
+    /// If `schema` refers to a named type, returns its definition and enclosing namespace
+    fn deref(
+        &self,
+        schema: &'a Schema<'a>,
+        namespace: Option<&'a str>,
+    ) -> Result<(&'a Schema<'a>, Option<&'a str>), ArrowError> {
+        match schema {
+            Schema::TypeName(TypeName::Ref(name))
+            | Schema::Type(Type {
+                r#type: TypeName::Ref(name),
+                ..
+            }) => self.get(name, namespace),
+            _ => Ok((schema, namespace)),
+        }
+    }
+
+    /// Returns the [`Nulls`] of `schema` if it is a nullable union, along with the
+    /// definition of its non-null variant and enclosing namespace
+    fn deref_nullable(
+        &self,
+        schema: &'a Schema<'a>,
+        namespace: Option<&'a str>,
+    ) -> Result<(Option<Nulls>, &'a Schema<'a>, Option<&'a str>), ArrowError> {
+        let (nulls, schema) = match schema {
+            Schema::Union(u) => {
+                let (nulls, schema) = nullable_union(u)?;
+                (Some(nulls), schema)
+            }
+            _ => (None, schema),
+        };
+        let (schema, namespace) = self.deref(schema, namespace)?;
+        Ok((nulls, schema, namespace))
+    }
+}
+
+/// Resolves a writer [`Schema`] against a reader [`Schema`]
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#schema-resolution>
+struct SchemaResolver<'a> {
+    writer: NamedTypes<'a>,
+    reader: NamedTypes<'a>,
+    /// Used to construct the types of writer fields absent from the reader
+    writer_types: Resolver<'a>,
+    /// Used to construct the types of reader fields absent from the writer
+    reader_types: Resolver<'a>,
+    /// The reader records currently being resolved
+    records: Vec<&'a str>,
+}
+
+impl<'a> SchemaResolver<'a> {
+    fn try_new(writer: &'a Schema<'a>, reader: &'a Schema<'a>) -> Result<Self, ArrowError> {
+        // Register the named types of both schemas, validating they can be represented in arrow
+        let mut writer_types = Resolver::new(writer, 0);
+        make_data_type(writer, None, &mut writer_types)?;
+        let mut reader_types = Resolver::new(reader, 0);
+        make_data_type(reader, None, &mut reader_types)?;
+
+        Ok(Self {
+            writer: NamedTypes::new(writer),
+            reader: NamedTypes::new(reader),
+            writer_types,
+            reader_types,
+            records: vec![],
+        })
+    }
+
+    fn resolve(
+        &mut self,
+        writer: &'a Schema<'a>,
+        writer_ns: Option<&'a str>,
+        reader: &'a Schema<'a>,
+        reader_ns: Option<&'a str>,
+    ) -> Result<AvroDataType, ArrowError> {
+        let (writer_nulls, writer, writer_ns) = self.writer.deref_nullable(writer, writer_ns)?;
+        let (reader_nulls, reader, reader_ns) = self.reader.deref_nullable(reader, reader_ns)?;
+
+        let (mut data_type, conversion) = match (writer, reader) {
+            (Schema::Complex(ComplexType::Record(w)), Schema::Complex(ComplexType::Record(r))) => {
+                self.resolve_record(w, writer_ns, r, reader_ns)?
+            }
+            (Schema::Complex(ComplexType::Enum(w)), Schema::Complex(ComplexType::Enum(r))) => {
+                check_names("enum", w.name, r.name, &r.aliases)?;
+                let default = r
+                    .default
+                    .map(|d| {
+                        r.symbols.iter().position(|s| *s == d).ok_or_else(|| {
+                            ArrowError::ParseError(format!(
+                                "Default {d} is not a symbol of enum {}",
+                                r.name
+                            ))
+                        })
+                    })
+                    .transpose()?;
+                let mapping = w
+                    .symbols
+                    .iter()
+                    .map(|w| {
+                        let idx = r.symbols.iter().position(|r| r == w).or(default);
+                        idx.map(|x| x as i32)
+                    })
+                    .collect();
+                let data_type = make_data_type(reader, reader_ns, &mut self.reader_types)?;
+                (data_type, Some(Conversion::Enum(mapping)))
+            }
+            (Schema::Complex(ComplexType::Fixed(w)), Schema::Complex(ComplexType::Fixed(r))) => {
+                check_names("fixed", w.name, r.name, &r.aliases)?;
+                if w.size != r.size {
+                    return Err(ArrowError::ParseError(format!(
+                        "Cannot resolve writer fixed {} of size {} with reader fixed {} of size {}",
+                        w.name, w.size, r.name, r.size
+                    )));
+                }
+                self.resolve_primitive(writer, writer_ns, reader, reader_ns)?
+            }
+            (Schema::Complex(ComplexType::Array(w)), Schema::Complex(ComplexType::Array(r))) => {
+                let items = self.resolve(&w.items, writer_ns, &r.items, reader_ns)?;
+                let codec = Codec::List(Arc::new(items));
+                (
+                    AvroDataType::new(codec, r.attributes.field_metadata()),
+                    None,
+                )
+            }
+            (Schema::Complex(ComplexType::Map(w)), Schema::Complex(ComplexType::Map(r))) => {
+                let values = self.resolve(&w.values, writer_ns, &r.values, reader_ns)?;
+                let codec = Codec::Map(Arc::new(values));
+                (
+                    AvroDataType::new(codec, r.attributes.field_metadata()),
+                    None,
+                )
+            }
+            (
+                Schema::TypeName(TypeName::Primitive(_)) | Schema::Type(_),
+                Schema::TypeName(TypeName::Primitive(_)) | Schema::Type(_),
+            ) => self.resolve_primitive(writer, writer_ns, reader, reader_ns)?,
+            _ => return Err(incompatible(writer, reader)),
+        };
+
+        // The decoder reads the writer's union, producing values of the reader's nullability
+        data_type.nulls = writer_nulls;
+        let nullable = reader_nulls.is_some();
+        if conversion.is_some() || nullable != writer_nulls.is_some() {
+            data_type.resolution = Some(ResolutionInfo {
+                nullable,
+                conversion,
+            });
+        }
+        Ok(data_type)
+    }
+
+    fn resolve_record(
+        &mut self,
+        writer: &'a Record<'a>,
+        writer_ns: Option<&'a str>,
+        reader: &'a Record<'a>,
+        reader_ns: Option<&'a str>,
+    ) -> Result<(AvroDataType, Option<Conversion>), ArrowError> {
+        check_names("record", writer.name, reader.name, &reader.aliases)?;
+        if self.records.contains(&reader.name) {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Resolving recursive record {} is not currently supported",
+                reader.name
+            )));
+        }
+        self.records.push(reader.name);
+        let writer_ns = writer.namespace.or(writer_ns);
+        let reader_ns = reader.namespace.or(reader_ns);
+
+        let mut writer_fields = writer
+            .fields
+            .iter()
+            .map(|f| {
+                let data_type = make_data_type(&f.r#type, writer_ns, &mut self.writer_types)?;
+                Ok(ResolvedField::Skip(data_type))
+            })
+            .collect::<Result<Vec<_>, ArrowError>>()?;
+
+        let mut fields = Vec::with_capacity(reader.fields.len());
+        let mut defaults = vec![];
+        for (idx, field) in reader.fields.iter().enumerate() {
+            let data_type = match writer.fields.iter().position(|w| w.name == field.name) {
+                Some(writer_idx) => {
+                    writer_fields[writer_idx] = ResolvedField::ToReader(idx);
+                    let writer_type = &writer.fields[writer_idx].r#type;
+                    self.resolve(writer_type, writer_ns, &field.r#type, reader_ns)?
+                }
+                None => {
+                    let default = field.default.clone().ok_or_else(|| {
+                        ArrowError::ParseError(format!(
+                            "Field {} of reader record {} is not present in the writer schema and has no default",
+                            field.name, reader.name
+                        ))
+                    })?;
+                    defaults.push((idx, default));
+                    make_data_type(&field.r#type, reader_ns, &mut self.reader_types)?
+                }
+            };
+            fields.push(AvroField {
+                name: field.name.to_string(),
+                data_type,
+            });
+        }
+
+        let codec = Codec::Struct(fields.into());
+        let data_type = AvroDataType::new(codec, reader.attributes.field_metadata());
+        self.records.pop();
+        let conversion = Conversion::Record(ResolvedRecord {
+            writer_fields: writer_fields.into(),
+            defaults: defaults.into(),
+        });
+        Ok((data_type, Some(conversion)))
+    }
+
+    /// Resolves types without children, i.e. primitives and logical types
+    fn resolve_primitive(
+        &mut self,
+        writer: &'a Schema<'a>,
+        writer_ns: Option<&'a str>,
+        reader: &'a Schema<'a>,
+        reader_ns: Option<&'a str>,
+    ) -> Result<(AvroDataType, Option<Conversion>), ArrowError> {
+        let w = make_data_type(writer, writer_ns, &mut self.writer_types)?;
+        let r = make_data_type(reader, reader_ns, &mut self.reader_types)?;
+
+        // https://avro.apache.org/docs/1.11.1/specification/#schema-resolution
+        let promotion = match (&w.codec, &r.codec) {
+            (Codec::Int32, Codec::Int64) => Some(Promotion::IntToLong),
+            (Codec::Int32, Codec::Float32) => Some(Promotion::IntToFloat),
+            (Codec::Int32, Codec::Float64) => Some(Promotion::IntToDouble),
+            (Codec::Int64, Codec::Float32) => Some(Promotion::LongToFloat),
+            (Codec::Int64, Codec::Float64) => Some(Promotion::LongToDouble),
+            (Codec::Float32, Codec::Float64) => Some(Promotion::FloatToDouble),
+            // Strings and bytes share the same encoding
+            (Codec::Binary, Codec::Utf8) | (Codec::Utf8, Codec::Binary) => None,
+            (Codec::Decimal(_, _, w_size), Codec::Decimal(_, _, r_size)) if w_size != r_size => {
+                return Err(incompatible(writer, reader))
+            }
+            (w_codec, r_codec)
+                if std::mem::discriminant(w_codec) == std::mem::discriminant(r_codec)
+                    && w_codec.data_type() == r_codec.data_type() =>
+            {
+                None
+            }
+            _ => return Err(incompatible(writer, reader)),
+        };
+        Ok((r, promotion.map(Conversion::Promotion)))
+    }
+}
+
+/// Named types resolve if they have the same unqualified name, or the reader
+/// has an alias matching the unqualified name of the writer
+fn check_names(kind: &str, writer: &str, reader: &str, aliases: &[&str]) -> Result<(), ArrowError> {
+    let unqualified = |name: &str| {
+        name.rsplit_once('.')
+            .map(|x| x.1)
+            .unwrap_or(name)
+            .to_string()
+    };
+    let writer_name = unqualified(writer);
+    match unqualified(reader) == writer_name
+        || aliases.iter().any(|a| unqualified(a) == writer_name)
+    {
+        true => Ok(()),
+        false => Err(ArrowError::ParseError(format!(
+            "Cannot resolve writer {kind} {writer} with reader {kind} {reader}"
+        ))),
+    }
+}
+
+fn incompatible(writer: &Schema<'_>, reader: &Schema<'_>) -> ArrowError {
+    let json = |s: &Schema<'_>| serde_json::to_string(s).unwrap_or_else(|_| format!("{s:?}"));
+    ArrowError::ParseError(format!(
+        "Cannot resolve writer schema {} with reader schema {}",
+        json(writer),
+        json(reader)
+    ))
+}
@@ -23,9 +23,10 @@
 #![allow(unused)] // Temporary
 
 pub mod reader;
-mod schema;
+pub mod schema;
+pub mod writer;
 
-mod compression;
+pub mod compression;
 
 mod codec;
 
@@ -0,0 +1,133 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::reader::vlq::read_varint;
+use arrow_schema::ArrowError;
+
+/// A wrapper around a byte slice, providing low-level decoding for Avro
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#encodings>
+#[derive(Debug)]
+pub(crate) struct AvroCursor<'a> {
+    buf: &'a [u8],
+    start_len: usize,
+    /// Set if a read failed as it reached the end of `buf`
+    eof: bool,
+}
+
+impl<'a> AvroCursor<'a> {
+    pub(crate) fn new(buf: &'a [u8]) -> Self {
+        Self {
+            buf,
+            start_len: buf.len(),
+            eof: false,
+        }
+    }
+
+    /// Returns true if a read failed as it reached the end of the buffer, as opposed
+    /// to encountering invalid data
+    pub(crate) fn is_eof(&self) -> bool {
+        self.eof
+    }
+
+    /// Returns the current cursor position
+    #[inline]
+    pub(crate) fn position(&self) -> usize {
+        self.start_len - self.buf.len()
+    }
+
+    /// Read a single `u8`
+    #[inline]
+    pub(crate) fn get_u8(&mut self) -> Result<u8, ArrowError> {
+        match self.buf.first().copied() {
+            Some(x) => {
+                self.buf = &self.buf[1..];
+                Ok(x)
+            }
+            None => {
+                self.eof = true;
+                Err(ArrowError::ParseError("Unexpected EOF".to_string()))
+            }
+        }
+    }
+
+    #[inline]
+    pub(crate) fn get_bool(&mut self) -> Result<bool, ArrowError> {
+        Ok(self.get_u8()? != 0)
+    }
+
+    pub(crate) fn read_vlq(&mut self) -> Result<u64, ArrowError> {
+        let (val, offset) = match read_varint(self.buf) {
+            Some(x) => x,
+            None => {
+                // A truncated varint has the continuation bit set on all remaining bytes
+                self.eof = self.buf.len() < 10 && self.buf.iter().all(|b| b & 0x80 != 0);
+                return Err(ArrowError::ParseError("bad varint".to_string()));
+            }
+        };
+        self.buf = &self.buf[offset..];
+        Ok(val)
+    }
+
+    #[inline]
+    pub(crate) fn get_int(&mut self) -> Result<i32, ArrowError> {
+        let varint = self.read_vlq()?;
+        let val: u32 = varint
+            .try_into()
+            .map_err(|_| ArrowError::ParseError("varint overflow".to_string()))?;
+        Ok((val >> 1) as i32 ^ -((val & 1) as i32))
+    }
+
+    #[inline]
+    pub(crate) fn get_long(&mut self) -> Result<i64, ArrowError> {
+        let val = self.read_vlq()?;
+        Ok((val >> 1) as i64 ^ -((val & 1) as i64))
+    }
+
+    /// Read a length-prefixed byte sequence
+    pub(crate) fn get_bytes(&mut self) -> Result<&'a [u8], ArrowError> {
+        let len: usize = self.get_long()?.try_into().map_err(|_| {
+            ArrowError::ParseError("offset overflow reading avro bytes".to_string())
+        })?;
+        self.get_fixed(len)
+    }
+
+    #[inline]
+    pub(crate) fn get_float(&mut self) -> Result<f32, ArrowError> {
+        let bytes = self.get_fixed(4)?;
+        Ok(f32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    #[inline]
+    pub(crate) fn get_double(&mut self) -> Result<f64, ArrowError> {
+        let bytes = self.get_fixed(8)?;
+        Ok(f64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    /// Read exactly `n` bytes
+    pub(crate) fn get_fixed(&mut self, n: usize) -> Result<&'a [u8], ArrowError> {
+        if self.buf.len() < n {
+            self.eof = true;
+            return Err(ArrowError::ParseError(
+                "Unexpected EOF reading fixed".to_string(),
+            ));
+        }
+        let (ret, remaining) = self.buf.split_at(n);
+        self.buf = remaining;
+        Ok(ret)
+    }
+}
@@ -16,20 +16,41 @@
 // under the License.
 
 //! Read Avro data to Arrow
+//!
+//! ```no_run
+//! # use std::fs::File;
+//! # use std::io::BufReader;
+//! # use arrow_avro::reader::ReaderBuilder;
+//! let file = BufReader::new(File::open("data.avro").unwrap());
+//! let reader = ReaderBuilder::new().with_batch_size(1024).build(file).unwrap();
+//! for batch in reader {
+//!     println!("{} rows", batch.unwrap().num_rows());
+//! }
+//! ```
 
+use crate::codec::AvroField;
+use crate::compression::CompressionCodec;
 use crate::reader::block::{Block, BlockDecoder};
 use crate::reader::header::{Header, HeaderDecoder};
-use arrow_schema::ArrowError;
+use crate::reader::record::{RecordDecoder, RecordSkipper};
+use crate::schema::{AvroSchema, Fingerprint, SchemaStore};
+use arrow_array::{RecordBatch, RecordBatchReader};
+use arrow_schema::{ArrowError, SchemaRef};
+use std::collections::HashMap;
 use std::io::BufRead;
 
 mod header;
 
 mod block;
 
+mod cursor;
+
+mod record;
+
 mod vlq;
 
 /// Read a [`Header`] from the provided [`BufRead`]
-fn read_header<R: BufRead>(mut reader: R) -> Result<Header, ArrowError> {
+pub(crate) fn read_header<R: BufRead>(mut reader: R) -> Result<Header, ArrowError> {
     let mut decoder = HeaderDecoder::default();
     loop {
         let buf = reader.fill_buf()?;
@@ -71,13 +92,457 @@ fn read_blocks<R: BufRead>(mut reader: R) -> impl Iterator<Item = Result<Block,
     std::iter::from_fn(move || try_next().transpose())
 }
 
+/// Avro file reader builder
+#[derive(Debug)]
+pub struct ReaderBuilder {
+    /// Batch size (number of records to load each time)
+    ///
+    /// The default batch size when using the `ReaderBuilder` is 1024 records
+    batch_size: usize,
+    /// The reader schema, if different from the writer schema
+    reader_schema: Option<AvroSchema>,
+    /// The number of times a recursive record is expanded before falling back to JSON
+    recursion_depth: usize,
+}
+
+impl Default for ReaderBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReaderBuilder {
+    /// Create a new builder for configuring Avro parsing options
+    ///
+    /// The Arrow schema is derived from the writer schema embedded in the file header
+    pub fn new() -> Self {
+        Self {
+            batch_size: 1024,
+            reader_schema: None,
+            recursion_depth: 0,
+        }
+    }
+
+    /// Set the batch size (number of records to load at one time)
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the reader schema used to decode the data
+    ///
+    /// Data written with a different writer schema is resolved to the reader schema:
+    /// writer fields not present in the reader are skipped, reader fields not present in
+    /// the writer are filled with their default value, and compatible primitive types are
+    /// promoted, e.g. `int` to `long`
+    ///
+    /// By default the writer schema embedded in the file header is used
+    ///
+    /// <https://avro.apache.org/docs/1.11.1/specification/#schema-resolution>
+    pub fn with_reader_schema(mut self, schema: AvroSchema) -> Self {
+        self.reader_schema = Some(schema);
+        self
+    }
+
+    /// Set the number of times a recursive record type is expanded into nested arrow
+    /// [`DataType::Struct`], defaults to `0`
+    ///
+    /// As arrow types cannot be recursive, once this depth is reached any further
+    /// occurrence of the record is decoded as a [`DataType::Utf8`] containing the JSON
+    /// encoding of the Avro value, with the `arrow.json` extension type
+    ///
+    /// Recursive schemas are not currently supported with [`Self::with_reader_schema`]
+    ///
+    /// [`DataType::Struct`]: arrow_schema::DataType::Struct
+    /// [`DataType::Utf8`]: arrow_schema::DataType::Utf8
+    pub fn with_recursion_depth(mut self, depth: usize) -> Self {
+        self.recursion_depth = depth;
+        self
+    }
+
+    /// Create a [`Reader`] from the provided [`BufRead`]
+    ///
+    /// This reads the [Object Container File] header, returning an error if it is
+    /// invalid, the embedded schema cannot be represented in arrow, or it cannot be
+    /// resolved against the reader schema
+    ///
+    /// [Object Container File]: https://avro.apache.org/docs/1.11.1/specification/#object-container-files
+    pub fn build<R: BufRead>(self, mut reader: R) -> Result<Reader<R>, ArrowError> {
+        if self.batch_size == 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "batch size must be greater than 0".to_string(),
+            ));
+        }
+
+        let header = read_header(&mut reader)?;
+        let compression = header.compression()?;
+        let schema = header
+            .schema()?
+            .ok_or_else(|| ArrowError::ParseError("No Avro schema present in header".into()))?;
+        let root = match &self.reader_schema {
+            Some(reader_schema) => AvroField::resolve(&schema, &reader_schema.schema()?)?,
+            None => AvroField::try_new(&schema, self.recursion_depth)?,
+        };
+        let record_decoder = RecordDecoder::try_new(root.data_type())?;
+
+        Ok(Reader {
+            reader,
+            header,
+            compression,
+            batch_size: self.batch_size,
+            block_decoder: BlockDecoder::default(),
+            record_decoder,
+            block_data: vec![],
+            block_offset: 0,
+            block_remaining: 0,
+        })
+    }
+
+    /// Create a [`Decoder`] for framed Avro messages, looking up their writer schemas
+    /// in `schema_store`
+    ///
+    /// See [`Decoder`] for the supported message formats
+    pub fn build_decoder(self, schema_store: SchemaStore) -> Result<Decoder, ArrowError> {
+        if self.batch_size == 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "batch size must be greater than 0".to_string(),
+            ));
+        }
+        if let Some(reader_schema) = &self.reader_schema {
+            reader_schema.schema()?;
+        }
+
+        Ok(Decoder {
+            batch_size: self.batch_size,
+            reader_schema: self.reader_schema,
+            recursion_depth: self.recursion_depth,
+            schema_store,
+            decoders: Default::default(),
+            active: None,
+            partial: vec![],
+        })
+    }
+}
+
+/// Reads an Avro [Object Container File] as [`RecordBatch`]
+///
+/// See [`ReaderBuilder`] to construct
+///
+/// [Object Container File]: https://avro.apache.org/docs/1.11.1/specification/#object-container-files
+#[derive(Debug)]
+pub struct Reader<R> {
+    reader: R,
+    header: Header,
+    compression: Option<CompressionCodec>,
+    batch_size: usize,
+    block_decoder: BlockDecoder,
+    record_decoder: RecordDecoder,
+    /// The decompressed data of the current block
+    block_data: Vec<u8>,
+    /// The offset of the next record within `block_data`
+    block_offset: usize,
+    /// The number of records remaining in `block_data`
+    block_remaining: usize,
+}
+
+impl<R: BufRead> Reader<R> {
+    /// Returns the arrow schema of the records in this file
+    pub fn schema(&self) -> SchemaRef {
+        self.record_decoder.schema().clone()
+    }
+
+    /// Read the next [`Block`] from the underlying reader, if any
+    fn read_block(&mut self) -> Result<Option<Block>, ArrowError> {
+        loop {
+            let buf = self.reader.fill_buf()?;
+            if buf.is_empty() {
+                break;
+            }
+            let read = buf.len();
+            let decoded = self.block_decoder.decode(buf)?;
+            self.reader.consume(decoded);
+            if decoded != read {
+                break;
+            }
+        }
+        Ok(self.block_decoder.flush())
+    }
+
+    fn read(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        while self.record_decoder.len() < self.batch_size {
+            if self.block_remaining == 0 {
+                let block = match self.read_block()? {
+                    Some(block) => block,
+                    None => break,
+                };
+                if block.sync != self.header.sync() {
+                    return Err(ArrowError::ParseError(
+                        "Block sync marker does not match header".to_string(),
+                    ));
+                }
+                self.block_data = match self.compression {
+                    Some(c) => c.decompress(&block.data)?,
+                    None => block.data,
+                };
+                self.block_offset = 0;
+                self.block_remaining = block.count;
+                continue;
+            }
+
+            let to_read = self
+                .block_remaining
+                .min(self.batch_size - self.record_decoder.len());
+            let data = &self.block_data[self.block_offset..];
+            self.block_offset += self.record_decoder.decode(data, to_read)?;
+            self.block_remaining -= to_read;
+        }
+
+        if self.record_decoder.is_empty() {
+            return Ok(None);
+        }
+        self.record_decoder.flush().map(Some)
+    }
+}
+
+impl<R: BufRead> Iterator for Reader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
+
+impl<R: BufRead> RecordBatchReader for Reader<R> {
+    fn schema(&self) -> SchemaRef {
+        self.record_decoder.schema().clone()
+    }
+}
+
+/// A push-based decoder of framed Avro messages
+///
+/// Each message is a single Avro encoded record, prefixed with an identifier of its
+/// writer schema in one of the following formats:
+///
+/// * [Single object encoding]: the two byte marker `C3 01`, followed by the little-endian
+///   64-bit [`Fingerprint::Rabin`] of the writer schema
+/// * [Confluent wire format]: a zero magic byte, followed by the big-endian 32-bit
+///   [`Fingerprint::Id`] assigned to the writer schema by a schema registry
+///
+/// The writer schemas are looked up in the [`SchemaStore`] provided to
+/// [`ReaderBuilder::build_decoder`]. If a reader schema is provided with
+/// [`ReaderBuilder::with_reader_schema`], messages are resolved to it, otherwise each
+/// [`RecordBatch`] has the schema of the writer schema of its messages.
+///
+/// A [`RecordBatch`] only contains messages with the same writer schema
+///
+/// ```
+/// # use arrow_avro::reader::ReaderBuilder;
+/// # use arrow_avro::schema::{AvroSchema, SchemaStore};
+/// let schema = AvroSchema::new(r#"{"type":"record","name":"r","fields":[{"name":"a","type":"long"}]}"#);
+/// let mut store = SchemaStore::new();
+/// store.register_id(1, schema);
+///
+/// let mut decoder = ReaderBuilder::new().build_decoder(store).unwrap();
+/// // Two Confluent wire format messages with schema id 1, with values 1 and 2
+/// let data = [0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 1, 4];
+/// assert_eq!(decoder.decode(&data).unwrap(), data.len());
+/// let batch = decoder.flush().unwrap().unwrap();
+/// assert_eq!(batch.num_rows(), 2);
+/// ```
+///
+/// [Single object encoding]: https://avro.apache.org/docs/1.11.1/specification/#single-object-encoding
+/// [Confluent wire format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+#[derive(Debug)]
+pub struct Decoder {
+    batch_size: usize,
+    reader_schema: Option<AvroSchema>,
+    recursion_depth: usize,
+    schema_store: SchemaStore,
+    /// The decoder for each writer schema encountered
+    decoders: HashMap<Fingerprint, (RecordDecoder, RecordSkipper)>,
+    /// The writer schema of the buffered records
+    active: Option<Fingerprint>,
+    /// The start of a message split across calls to [`Self::decode`]
+    partial: Vec<u8>,
+}
+
+/// The result of decoding a single message
+enum Message {
+    /// A message of the given length was decoded
+    Decoded(usize),
+    /// The buffer ends before the end of the message
+    Incomplete,
+    /// The message has a different writer schema to the buffered records
+    SchemaChange,
+}
+
+impl Decoder {
+    /// Decode messages from `data`, returning the number of bytes read
+    ///
+    /// This method returns once `batch_size` records have been buffered, or the next
+    /// message has a different writer schema to the buffered records, in which case
+    /// [`Self::flush`] should be called before decoding the remaining data. Any trailing
+    /// partial message is buffered internally, and completed by subsequent calls
+    pub fn decode(&mut self, data: &[u8]) -> Result<usize, ArrowError> {
+        let mut read = 0;
+        while read < data.len() && self.capacity() > 0 {
+            let prior = self.partial.len();
+            let message = match prior {
+                0 => self.decode_message(&data[read..])?,
+                _ => {
+                    let mut buf = std::mem::take(&mut self.partial);
+                    buf.extend_from_slice(&data[read..]);
+                    let message = self.decode_message(&buf);
+                    buf.truncate(prior);
+                    self.partial = buf;
+                    message?
+                }
+            };
+            match message {
+                Message::Decoded(len) => {
+                    self.partial.clear();
+                    read += len - prior;
+                }
+                Message::Incomplete => {
+                    self.partial.extend_from_slice(&data[read..]);
+                    read = data.len();
+                }
+                Message::SchemaChange => break,
+            }
+        }
+        Ok(read)
+    }
+
+    /// Decode a single message from the start of `buf`
+    fn decode_message(&mut self, buf: &[u8]) -> Result<Message, ArrowError> {
+        let (fingerprint, header_len) = match read_message_header(buf)? {
+            Some(x) => x,
+            None => return Ok(Message::Incomplete),
+        };
+
+        if self.active != Some(fingerprint) {
+            if self.capacity() != self.batch_size {
+                return Ok(Message::SchemaChange);
+            }
+            if !self.decoders.contains_key(&fingerprint) {
+                let decoder = self.make_decoder(&fingerprint)?;
+                self.decoders.insert(fingerprint, decoder);
+            }
+            self.active = Some(fingerprint);
+        }
+
+        let (decoder, skipper) = self.decoders.get_mut(&fingerprint).unwrap();
+        let body = &buf[header_len..];
+        // Determine the length of the record before decoding it, so that a partial
+        // record is not appended to the decoder
+        match skipper.record_len(body)? {
+            Some(len) => {
+                decoder.decode(&body[..len], 1)?;
+                Ok(Message::Decoded(header_len + len))
+            }
+            None => Ok(Message::Incomplete),
+        }
+    }
+
+    fn make_decoder(
+        &self,
+        fingerprint: &Fingerprint,
+    ) -> Result<(RecordDecoder, RecordSkipper), ArrowError> {
+        let writer_schema = self.schema_store.lookup(fingerprint).ok_or_else(|| {
+            ArrowError::ParseError(format!("No Avro schema found for {fingerprint:?}"))
+        })?;
+        let writer_schema = writer_schema.schema()?;
+        let writer = AvroField::try_new(&writer_schema, self.recursion_depth)?;
+        let root = match &self.reader_schema {
+            Some(reader_schema) => AvroField::resolve(&writer_schema, &reader_schema.schema()?)?,
+            None => writer.clone(),
+        };
+        let decoder = RecordDecoder::try_new(root.data_type())?;
+        let skipper = RecordSkipper::try_new(writer.data_type())?;
+        Ok((decoder, skipper))
+    }
+
+    /// Flushes the buffered records into a [`RecordBatch`]
+    ///
+    /// Returns `Ok(None)` if no records are buffered
+    pub fn flush(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        let decoder = self.active.and_then(|f| self.decoders.get_mut(&f));
+        match decoder {
+            Some((decoder, _)) if !decoder.is_empty() => decoder.flush().map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns the number of records that can be decoded before the batch is full
+    pub fn capacity(&self) -> usize {
+        let buffered = self
+            .active
+            .and_then(|f| self.decoders.get(&f))
+            .map(|(decoder, _)| decoder.len())
+            .unwrap_or_default();
+        self.batch_size - buffered
+    }
+}
+
+/// The marker prefixing single object encoded messages
+const SINGLE_OBJECT_MAGIC: [u8; 2] = [0xC3, 0x01];
+
+/// The magic byte prefixing Confluent wire format messages
+const CONFLUENT_MAGIC: u8 = 0;
+
+/// Reads the header of a framed message, returning the [`Fingerprint`] of its writer
+/// schema and the length of the header, or `None` if `buf` ends before the end of the header
+fn read_message_header(buf: &[u8]) -> Result<Option<(Fingerprint, usize)>, ArrowError> {
+    match buf.first().copied() {
+        Some(b) if b == SINGLE_OBJECT_MAGIC[0] => {
+            if buf.len() > 1 && buf[1] != SINGLE_OBJECT_MAGIC[1] {
+                return Err(ArrowError::ParseError(format!(
+                    "Invalid single object encoding marker {:#04x}{:02x}",
+                    buf[0], buf[1]
+                )));
+            }
+            Ok(buf.get(2..10).map(|f| {
+                let fingerprint = u64::from_le_bytes(f.try_into().unwrap());
+                (Fingerprint::Rabin(fingerprint), 10)
+            }))
+        }
+        Some(CONFLUENT_MAGIC) => Ok(buf.get(1..5).map(|id| {
+            let id = u32::from_be_bytes(id.try_into().unwrap());
+            (Fingerprint::Id(id), 5)
+        })),
+        Some(b) => Err(ArrowError::ParseError(format!(
+            "Unrecognized message header {b:#04x}, expected single object encoding or Confluent wire format"
+        ))),
+        None => Ok(None),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::compression::CompressionCodec;
-    use crate::reader::{read_blocks, read_header};
+    use crate::reader::{read_blocks, read_header, ReaderBuilder};
+    use crate::schema::{AvroSchema, Fingerprint, SchemaStore};
     use crate::test_util::arrow_test_data;
+    use crate::writer::Writer;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int32Type, Int64Type};
+    use arrow_array::*;
+    use arrow_schema::{ArrowError, DataType, Field, Fields, Schema, TimeUnit};
     use std::fs::File;
     use std::io::BufReader;
+    use std::sync::Arc;
+
+    fn read_file(file: &str, batch_size: usize) -> RecordBatch {
+        let file = File::open(arrow_test_data(file)).unwrap();
+        let reader = ReaderBuilder::new()
+            .with_batch_size(batch_size)
+            .build(BufReader::new(file))
+            .unwrap();
+
+        let schema = reader.schema();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        arrow_select::concat::concat_batches(&schema, &batches).unwrap()
+    }
 
     #[test]
     fn test_mux() {
@@ -104,4 +569,632 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_alltypes() {
+        let files = [
+            "avro/alltypes_plain.avro",
+            "avro/alltypes_plain.snappy.avro",
+            "avro/alltypes_plain.zstandard.avro",
+        ];
+
+        let expected = RecordBatch::try_from_iter_with_nullable([
+            (
+                "id",
+                Arc::new(Int32Array::from(vec![4, 5, 6, 7, 2, 3, 0, 1])) as _,
+                true,
+            ),
+            (
+                "bool_col",
+                Arc::new(BooleanArray::from_iter((0..8).map(|x| Some(x % 2 == 0)))) as _,
+                true,
+            ),
+            (
+                "tinyint_col",
+                Arc::new(Int32Array::from_iter_values((0..8).map(|x| x % 2))) as _,
+                true,
+            ),
+            (
+                "smallint_col",
+                Arc::new(Int32Array::from_iter_values((0..8).map(|x| x % 2))) as _,
+                true,
+            ),
+            (
+                "int_col",
+                Arc::new(Int32Array::from_iter_values((0..8).map(|x| x % 2))) as _,
+                true,
+            ),
+            (
+                "bigint_col",
+                Arc::new(Int64Array::from_iter_values((0..8).map(|x| (x % 2) * 10))) as _,
+                true,
+            ),
+            (
+                "float_col",
+                Arc::new(Float32Array::from_iter_values(
+                    (0..8).map(|x| (x % 2) as f32 * 1.1),
+                )) as _,
+                true,
+            ),
+            (
+                "double_col",
+                Arc::new(Float64Array::from_iter_values(
+                    (0..8).map(|x| (x % 2) as f64 * 10.1),
+                )) as _,
+                true,
+            ),
+            (
+                "date_string_col",
+                Arc::new(BinaryArray::from_iter_values([
+                    [48, 51, 47, 48, 49, 47, 48, 57],
+                    [48, 51, 47, 48, 49, 47, 48, 57],
+                    [48, 52, 47, 48, 49, 47, 48, 57],
+                    [48, 52, 47, 48, 49, 47, 48, 57],
+                    [48, 50, 47, 48, 49, 47, 48, 57],
+                    [48, 50, 47, 48, 49, 47, 48, 57],
+                    [48, 49, 47, 48, 49, 47, 48, 57],
+                    [48, 49, 47, 48, 49, 47, 48, 57],
+                ])) as _,
+                true,
+            ),
+            (
+                "string_col",
+                Arc::new(BinaryArray::from_iter_values((0..8).map(|x| [48 + x % 2]))) as _,
+                true,
+            ),
+            (
+                "timestamp_col",
+                Arc::new(
+                    TimestampMicrosecondArray::from_iter_values([
+                        1235865600000000, // 2009-03-01T00:00:00.000
+                        1235865660000000, // 2009-03-01T00:01:00.000
+                        1238544000000000, // 2009-04-01T00:00:00.000
+                        1238544060000000, // 2009-04-01T00:01:00.000
+                        1233446400000000, // 2009-02-01T00:00:00.000
+                        1233446460000000, // 2009-02-01T00:01:00.000
+                        1230768000000000, // 2009-01-01T00:00:00.000
+                        1230768060000000, // 2009-01-01T00:01:00.000
+                    ])
+                    .with_timezone("+00:00"),
+                ) as _,
+                true,
+            ),
+        ])
+        .unwrap();
+
+        for file in files {
+            for batch_size in [1, 3, 8, 1024] {
+                let batch = read_file(file, batch_size);
+                assert_eq!(batch, expected, "{file} with batch size {batch_size}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_length_decimal() {
+        let batch = read_file("avro/fixed_length_decimal.avro", 8);
+        let expected = Decimal128Array::from_iter_values((1..=24).map(|x| x * 100))
+            .with_precision_and_scale(25, 2)
+            .unwrap();
+        assert_eq!(batch.num_columns(), 1);
+        assert_eq!(batch.column(0).as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_batch_size() {
+        let file = File::open(arrow_test_data("avro/alltypes_plain.avro")).unwrap();
+        let reader = ReaderBuilder::new()
+            .with_batch_size(3)
+            .build(BufReader::new(file))
+            .unwrap();
+
+        let expected = Schema::new(vec![
+            Field::new("id", DataType::Int32, true),
+            Field::new("bool_col", DataType::Boolean, true),
+            Field::new("tinyint_col", DataType::Int32, true),
+            Field::new("smallint_col", DataType::Int32, true),
+            Field::new("int_col", DataType::Int32, true),
+            Field::new("bigint_col", DataType::Int64, true),
+            Field::new("float_col", DataType::Float32, true),
+            Field::new("double_col", DataType::Float64, true),
+            Field::new("date_string_col", DataType::Binary, true),
+            Field::new("string_col", DataType::Binary, true),
+            Field::new(
+                "timestamp_col",
+                DataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".into())),
+                true,
+            ),
+        ]);
+        assert_eq!(reader.schema().as_ref(), &expected);
+
+        let rows: Vec<_> = reader.map(|b| b.unwrap().num_rows()).collect();
+        assert_eq!(rows, &[3, 3, 2]);
+    }
+
+    #[test]
+    fn test_zero_batch_size() {
+        let err = ReaderBuilder::new()
+            .with_batch_size(0)
+            .build(&b"Obj\x01"[..])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: batch size must be greater than 0"
+        );
+    }
+
+    fn zigzag(v: i64, out: &mut Vec<u8>) {
+        let mut n = ((v << 1) ^ (v >> 63)) as u64;
+        while n >= 0x80 {
+            out.push(0x80 | n as u8);
+            n >>= 7;
+        }
+        out.push(n as u8);
+    }
+
+    #[test]
+    fn test_records_span_blocks() {
+        let schema = br#"{"type":"record","name":"r","fields":[{"name":"a","type":"long"}]}"#;
+        let sync = [7; 16];
+
+        let mut file = b"Obj\x01".to_vec();
+        zigzag(1, &mut file);
+        zigzag(11, &mut file);
+        file.extend_from_slice(b"avro.schema");
+        zigzag(schema.len() as _, &mut file);
+        file.extend_from_slice(schema);
+        zigzag(0, &mut file);
+        file.extend_from_slice(&sync);
+
+        for block in [&[1, 2, 3][..], &[4, 5]] {
+            let mut data = vec![];
+            block.iter().for_each(|v| zigzag(*v, &mut data));
+            zigzag(block.len() as _, &mut file);
+            zigzag(data.len() as _, &mut file);
+            file.extend_from_slice(&data);
+            file.extend_from_slice(&sync);
+        }
+
+        let reader = ReaderBuilder::new()
+            .with_batch_size(2)
+            .build(file.as_slice())
+            .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let values: Vec<_> = batches
+            .iter()
+            .map(|b| b.column(0).as_primitive::<Int64Type>().values().to_vec())
+            .collect();
+        assert_eq!(values, vec![vec![1, 2], vec![3, 4], vec![5]]);
+
+        // Corrupt the final sync marker
+        let len = file.len();
+        file[len - 1] = 0;
+        let reader = ReaderBuilder::new().build(file.as_slice()).unwrap();
+        let err = reader.collect::<Result<Vec<_>, _>>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Block sync marker does not match header"
+        );
+    }
+
+    /// Writes `batch` to an in-memory Avro file, and reads it back with `reader_schema`
+    fn read_with_schema(
+        batch: &RecordBatch,
+        reader_schema: &str,
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut writer = Writer::try_new(vec![], batch.schema()).unwrap();
+        writer.write(batch).unwrap();
+        let file = writer.into_inner();
+
+        let reader = ReaderBuilder::new()
+            .with_reader_schema(AvroSchema::new(reader_schema))
+            .build(file.as_slice())?;
+        let schema = reader.schema();
+        let batches = reader.collect::<Result<Vec<_>, _>>()?;
+        Ok(arrow_select::concat::concat_batches(&schema, &batches).unwrap())
+    }
+
+    fn writer_batch() -> RecordBatch {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![]),
+            Some(vec![Some(3)]),
+        ]);
+        let inner = StructArray::from(vec![(
+            Arc::new(Field::new("x", DataType::Utf8, false)),
+            Arc::new(StringArray::from(vec!["p", "q", "r"])) as ArrayRef,
+        )]);
+        let inner_field = Field::new("e", inner.data_type().clone(), false);
+        RecordBatch::try_from_iter_with_nullable(vec![
+            (
+                "a",
+                Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+                false,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from(vec![Some("x"), None, Some("z")])),
+                true,
+            ),
+            (
+                "c",
+                Arc::new(Float32Array::from(vec![0.5, 1.5, 2.5])),
+                false,
+            ),
+            (
+                "d",
+                Arc::new(Int64Array::from(vec![Some(7), None, Some(9)])),
+                true,
+            ),
+            ("e", Arc::new(inner), false),
+            ("f", Arc::new(list), false),
+        ])
+        .unwrap()
+        .with_schema(Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Float32, false),
+            Field::new("d", DataType::Int64, true),
+            inner_field,
+            Field::new_list("f", Field::new("item", DataType::Int32, true), false),
+        ])))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_schema_resolution() {
+        let batch = writer_batch();
+        let reader_schema = r#"{
+            "type": "record",
+            "name": "topLevelRecord",
+            "fields": [
+                {"name": "f", "type": {"type": "array", "items": ["null", "long"]}},
+                {"name": "a", "type": "double"},
+                {"name": "b", "type": ["null", "bytes"]},
+                {"name": "c", "type": "double"},
+                {"name": "g", "type": "string", "default": "hello"},
+                {"name": "h", "type": ["null", "int"], "default": null},
+                {"name": "i", "type": {"type": "array", "items": "int"}, "default": [4, 5]}
+            ]
+        }"#;
+        let read = read_with_schema(&batch, reader_schema).unwrap();
+
+        let expected_schema = Schema::new(vec![
+            Field::new_list("f", Field::new("item", DataType::Int64, true), false),
+            Field::new("a", DataType::Float64, false),
+            Field::new("b", DataType::Binary, true),
+            Field::new("c", DataType::Float64, false),
+            Field::new("g", DataType::Utf8, false),
+            Field::new("h", DataType::Int32, true),
+            Field::new_list("i", Field::new("item", DataType::Int32, false), false),
+        ]);
+        assert_eq!(read.schema().as_ref(), &expected_schema);
+        assert_eq!(read.num_rows(), 3);
+
+        let f = read.column(0).as_list::<i32>();
+        assert_eq!(f.value_offsets(), &[0, 2, 2, 3]);
+        assert_eq!(f.values().as_primitive::<Int64Type>().values(), &[1, 2, 3]);
+
+        let a = read
+            .column(1)
+            .as_primitive::<arrow_array::types::Float64Type>();
+        assert_eq!(a.values(), &[1., 2., 3.]);
+
+        let b = read.column(2).as_binary::<i32>();
+        let b: Vec<_> = b.iter().collect();
+        assert_eq!(b, vec![Some(&b"x"[..]), None, Some(b"z")]);
+
+        let c = read
+            .column(3)
+            .as_primitive::<arrow_array::types::Float64Type>();
+        assert_eq!(c.values(), &[0.5, 1.5, 2.5]);
+
+        let g = read.column(4).as_string::<i32>();
+        assert_eq!(g, &StringArray::from(vec!["hello"; 3]));
+
+        let h = read.column(5).as_primitive::<Int32Type>();
+        assert_eq!(h.null_count(), 3);
+
+        let i = read.column(6).as_list::<i32>();
+        assert_eq!(i.value_offsets(), &[0, 2, 4, 6]);
+        assert_eq!(
+            i.values().as_primitive::<Int32Type>().values(),
+            &[4, 5, 4, 5, 4, 5]
+        );
+    }
+
+    #[test]
+    fn test_schema_resolution_errors() {
+        let batch = writer_batch();
+        let record = |fields: &str| {
+            format!(r#"{{"type": "record", "name": "topLevelRecord", "fields": [{fields}]}}"#)
+        };
+
+        let err = read_with_schema(&batch, &record(r#"{"name": "z", "type": "int"}"#))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Parser error: Field z of reader record topLevelRecord is not present in the writer schema and has no default"
+        );
+
+        let err = read_with_schema(&batch, &record(r#"{"name": "a", "type": "boolean"}"#))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            r#"Parser error: Cannot resolve writer schema "int" with reader schema "boolean""#
+        );
+
+        // Types cannot be narrowed
+        let err = read_with_schema(&batch, &record(r#"{"name": "c", "type": "int"}"#))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            r#"Parser error: Cannot resolve writer schema "float" with reader schema "int""#
+        );
+
+        let err = read_with_schema(
+            &batch,
+            &record(r#"{"name": "z", "type": "int", "default": "foo"}"#),
+        )
+        .unwrap_err()
+        .to_string();
+        assert_eq!(
+            err,
+            r#"Parser error: Invalid default for field z: Parser error: Invalid default value "foo""#
+        );
+
+        let err = read_with_schema(
+            &batch,
+            r#"{"type": "record", "name": "other", "fields": []}"#,
+        )
+        .unwrap_err()
+        .to_string();
+        assert_eq!(
+            err,
+            "Parser error: Cannot resolve writer record topLevelRecord with reader record other"
+        );
+
+        // Records may be renamed with an alias
+        let read = read_with_schema(
+            &batch,
+            r#"{"type": "record", "name": "other", "aliases": ["topLevelRecord"], "fields": []}"#,
+        )
+        .unwrap();
+        assert_eq!(read.num_rows(), 3);
+        assert_eq!(read.num_columns(), 0);
+
+        // A nullable writer field can be read as non-nullable if it contains no nulls
+        let err = read_with_schema(&batch, &record(r#"{"name": "b", "type": "string"}"#))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Parser error: Found null value for non-nullable reader type"
+        );
+        let filtered = batch.slice(0, 1);
+        let read =
+            read_with_schema(&filtered, &record(r#"{"name": "b", "type": "string"}"#)).unwrap();
+        assert_eq!(
+            read.schema().field(0),
+            &Field::new("b", DataType::Utf8, false)
+        );
+        assert_eq!(read.column(0).as_string::<i32>().value(0), "x");
+    }
+
+    /// Returns a single object encoded message with the given fingerprint and record
+    fn single_object(fingerprint: Fingerprint, record: &[u8]) -> Vec<u8> {
+        let mut out = vec![0xC3, 0x01];
+        match fingerprint {
+            Fingerprint::Rabin(f) => out.extend_from_slice(&f.to_le_bytes()),
+            Fingerprint::Id(_) => unreachable!(),
+        }
+        out.extend_from_slice(record);
+        out
+    }
+
+    /// Returns a Confluent wire format message with the given schema id and record
+    fn confluent(id: u32, record: &[u8]) -> Vec<u8> {
+        let mut out = vec![0];
+        out.extend_from_slice(&id.to_be_bytes());
+        out.extend_from_slice(record);
+        out
+    }
+
+    #[test]
+    fn test_decoder_single_object() {
+        let mut store = SchemaStore::new();
+        let long_schema = AvroSchema::new(
+            r#"{"type":"record","name":"r","fields":[{"name":"a","type":"long"},{"name":"b","type":"string"}]}"#,
+        );
+        let long_fp = store.register(long_schema).unwrap();
+        let int_schema =
+            AvroSchema::new(r#"{"type":"record","name":"r","fields":[{"name":"a","type":"int"}]}"#);
+        let int_fp = store.register(int_schema).unwrap();
+
+        let mut data = vec![];
+        for (a, b) in [(1, "x"), (-2, "yy"), (300, "")] {
+            let mut record = vec![];
+            zigzag(a, &mut record);
+            zigzag(b.len() as _, &mut record);
+            record.extend_from_slice(b.as_bytes());
+            data.extend(single_object(long_fp, &record));
+        }
+        let mut record = vec![];
+        zigzag(7, &mut record);
+        data.extend(single_object(int_fp, &record));
+
+        // Feed the data in chunks of varying size, splitting messages across calls
+        for chunk_size in [1, 3, 7, data.len()] {
+            let mut decoder = ReaderBuilder::new()
+                .with_batch_size(2)
+                .build_decoder(store.clone())
+                .unwrap();
+
+            let mut batches = vec![];
+            let mut offset = 0;
+            while offset < data.len() {
+                let end = (offset + chunk_size).min(data.len());
+                let read = decoder.decode(&data[offset..end]).unwrap();
+                offset += read;
+                if read == 0 || decoder.capacity() == 0 {
+                    batches.extend(decoder.flush().unwrap());
+                }
+            }
+            batches.extend(decoder.flush().unwrap());
+            assert!(decoder.flush().unwrap().is_none());
+
+            let rows: Vec<_> = batches.iter().map(|b| b.num_rows()).collect();
+            // The batch size and change of writer schema delimit the batches
+            assert_eq!(rows, vec![2, 1, 1], "chunk size {chunk_size}");
+
+            let a = batches[0].column(0).as_primitive::<Int64Type>();
+            assert_eq!(a.values(), &[1, -2]);
+            let b = batches[1].column(1).as_string::<i32>();
+            assert_eq!(b.value(0), "");
+            let a = batches[2].column(0).as_primitive::<Int32Type>();
+            assert_eq!(a.values(), &[7]);
+        }
+    }
+
+    #[test]
+    fn test_decoder_confluent() {
+        let mut store = SchemaStore::new();
+        store.register_id(
+            1,
+            AvroSchema::new(r#"{"type":"record","name":"r","fields":[{"name":"a","type":"int"}]}"#),
+        );
+        store.register_id(
+            2,
+            AvroSchema::new(
+                r#"{"type":"record","name":"r","fields":[{"name":"b","type":"string"},{"name":"a","type":"long"}]}"#,
+            ),
+        );
+        // Resolve both writer schemas to a common reader schema
+        let reader_schema = AvroSchema::new(
+            r#"{"type":"record","name":"r","fields":[{"name":"a","type":"double"}]}"#,
+        );
+        let mut decoder = ReaderBuilder::new()
+            .with_reader_schema(reader_schema)
+            .build_decoder(store)
+            .unwrap();
+
+        let mut data = confluent(1, &[2]);
+        data.extend(confluent(2, &[2, b'z', 6]));
+        let read = decoder.decode(&data).unwrap();
+        assert_eq!(read, 6);
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(
+            batch.schema().as_ref(),
+            &Schema::new(vec![Field::new("a", DataType::Float64, false)])
+        );
+        assert_eq!(
+            batch
+                .column(0)
+                .as_primitive::<arrow_array::types::Float64Type>()
+                .values(),
+            &[1.]
+        );
+
+        assert_eq!(decoder.decode(&data[read..]).unwrap(), data.len() - read);
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(
+            batch
+                .column(0)
+                .as_primitive::<arrow_array::types::Float64Type>()
+                .values(),
+            &[3.]
+        );
+
+        let err = decoder.decode(&confluent(3, &[2])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: No Avro schema found for Id(3)"
+        );
+
+        let err = decoder.decode(&[0xC3, 0x02]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Invalid single object encoding marker 0xc302"
+        );
+
+        let err = decoder.decode(&[0x4F]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Unrecognized message header 0x4f, expected single object encoding or Confluent wire format"
+        );
+    }
+
+    #[test]
+    fn test_recursive_schema() {
+        let schema = AvroSchema::new(
+            r#"{
+                "type": "record",
+                "name": "LongList",
+                "fields": [
+                    {"name": "value", "type": "long"},
+                    {"name": "tags", "type": {"type": "array", "items": "string"}},
+                    {"name": "next", "type": ["null", "LongList"]}
+                ]
+            }"#,
+        );
+        let mut store = SchemaStore::new();
+        let fingerprint = store.register(schema.clone()).unwrap();
+
+        // The list 1 -> 2 -> 3, where the first element is tagged "a"
+        let mut record = vec![];
+        zigzag(1, &mut record);
+        record.extend_from_slice(&[2, 2, b'a', 0]);
+        for v in [2, 3] {
+            zigzag(1, &mut record);
+            zigzag(v, &mut record);
+            record.push(0);
+        }
+        zigzag(0, &mut record);
+        let data = single_object(fingerprint, &record);
+
+        let decode = |depth: usize| {
+            let mut decoder = ReaderBuilder::new()
+                .with_recursion_depth(depth)
+                .build_decoder(store.clone())
+                .unwrap();
+            assert_eq!(decoder.decode(&data).unwrap(), data.len());
+            decoder.flush().unwrap().unwrap()
+        };
+
+        let batch = decode(0);
+        let next_field = batch.schema().field(2).clone();
+        assert_eq!(next_field.data_type(), &DataType::Utf8);
+        assert_eq!(
+            next_field.metadata().get("ARROW:extension:name").unwrap(),
+            "arrow.json"
+        );
+        let tags = batch.column(1).as_list::<i32>();
+        assert_eq!(tags.value(0).as_string::<i32>().value(0), "a");
+        assert_eq!(
+            batch.column(2).as_string::<i32>().value(0),
+            r#"{"value":2,"tags":[],"next":{"value":3,"tags":[],"next":null}}"#
+        );
+
+        let batch = decode(1);
+        let next = batch.column(2).as_struct();
+        assert_eq!(next.column(0).as_primitive::<Int64Type>().value(0), 2);
+        assert_eq!(
+            next.column(2).as_string::<i32>().value(0),
+            r#"{"value":3,"tags":[],"next":null}"#
+        );
+
+        let err = ReaderBuilder::new()
+            .with_reader_schema(schema)
+            .build_decoder(store.clone())
+            .unwrap()
+            .decode(&data)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: Resolving recursive record LongList is not currently supported"
+        );
+    }
 }
@@ -0,0 +1,1346 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decoder for Avro records
+
+use crate::codec::{
+    AvroDataType, Codec, Conversion, JsonSchema, JsonType, Nulls, Promotion, ResolvedField,
+};
+use crate::reader::cursor::AvroCursor;
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::*;
+use arrow_schema::{
+    ArrowError, DataType, Field as ArrowField, FieldRef, Fields, Schema as ArrowSchema, SchemaRef,
+    DECIMAL128_MAX_PRECISION,
+};
+use serde_json::Value;
+use std::sync::Arc;
+
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// Decodes avro encoded data into [`RecordBatch`]
+#[derive(Debug)]
+pub struct RecordDecoder {
+    schema: SchemaRef,
+    fields: Vec<Decoder>,
+    projection: Option<Projection>,
+    num_rows: usize,
+}
+
+impl RecordDecoder {
+    /// Create a new [`RecordDecoder`] from the provided [`AvroDataType`]
+    ///
+    /// Returns an error if `data_type` is not a non-nullable record
+    pub fn try_new(data_type: &AvroDataType) -> Result<Self, ArrowError> {
+        match Decoder::try_new(data_type)? {
+            Decoder::Record(fields, encodings, projection) => Ok(Self {
+                schema: Arc::new(ArrowSchema::new(fields)),
+                fields: encodings,
+                projection,
+                num_rows: 0,
+            }),
+            _ => Err(ArrowError::ParseError(format!(
+                "Expected record got {data_type:?}"
+            ))),
+        }
+    }
+
+    /// Returns the [`SchemaRef`] of the decoded [`RecordBatch`]
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Returns the number of buffered records
+    pub fn len(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Returns true if there are no buffered records
+    pub fn is_empty(&self) -> bool {
+        self.num_rows == 0
+    }
+
+    /// Decode `count` records from `buf`, returning the number of bytes read
+    ///
+    /// Unlike the container decoders, `buf` must contain at least `count` whole records
+    pub fn decode(&mut self, buf: &[u8], count: usize) -> Result<usize, ArrowError> {
+        let mut cursor = AvroCursor::new(buf);
+        for _ in 0..count {
+            decode_record(&mut self.fields, self.projection.as_mut(), &mut cursor)?;
+        }
+        self.num_rows += count;
+        Ok(cursor.position())
+    }
+
+    /// Flush the decoded records into a [`RecordBatch`]
+    pub fn flush(&mut self) -> Result<RecordBatch, ArrowError> {
+        let arrays = self
+            .fields
+            .iter_mut()
+            .map(|x| x.flush(None))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let options = RecordBatchOptions::new().with_row_count(Some(self.num_rows));
+        self.num_rows = 0;
+        RecordBatch::try_new_with_options(self.schema.clone(), arrays, &options)
+    }
+}
+
+#[derive(Debug)]
+enum Decoder {
+    Null(usize),
+    Boolean(BooleanBufferBuilder),
+    Int32(Vec<i32>),
+    Int64(Vec<i64>),
+    Float32(Vec<f32>),
+    Float64(Vec<f64>),
+    /// A writer primitive promoted to a reader type
+    IntToLong(Vec<i64>),
+    IntToFloat(Vec<f32>),
+    IntToDouble(Vec<f64>),
+    LongToFloat(Vec<f32>),
+    LongToDouble(Vec<f64>),
+    FloatToDouble(Vec<f64>),
+    Date32(Vec<i32>),
+    TimeMillis(Vec<i32>),
+    TimeMicros(Vec<i64>),
+    /// TimestampMillis(is_utc, values)
+    TimestampMillis(bool, Vec<i64>),
+    /// TimestampMicros(is_utc, values)
+    TimestampMicros(bool, Vec<i64>),
+    /// TimestampNanos(is_utc, values)
+    TimestampNanos(bool, Vec<i64>),
+    Binary(OffsetBufferBuilder<i32>, Vec<u8>),
+    String(OffsetBufferBuilder<i32>, Vec<u8>),
+    Fixed(i32, Vec<u8>),
+    /// Decimal128(precision, scale, fixed_size, values)
+    Decimal128(u8, i8, Option<usize>, Vec<i128>),
+    /// Decimal256(precision, scale, fixed_size, values)
+    Decimal256(u8, i8, Option<usize>, Vec<i256>),
+    Uuid(Vec<u8>),
+    /// Enum(symbols, writer_to_reader, keys)
+    Enum(ArrayRef, Option<Arc<[Option<i32>]>>, Vec<i32>),
+    Duration(Vec<IntervalMonthDayNano>),
+    List(FieldRef, OffsetBufferBuilder<i32>, Box<Decoder>),
+    /// Map(entries, offsets, key_offsets, key_data, values)
+    Map(
+        FieldRef,
+        OffsetBufferBuilder<i32>,
+        OffsetBufferBuilder<i32>,
+        Vec<u8>,
+        Box<Decoder>,
+    ),
+    Record(Fields, Vec<Decoder>, Option<Projection>),
+    /// Json(schema, offsets, values)
+    Json(JsonSchema, OffsetBufferBuilder<i32>, Vec<u8>),
+    Nullable(Nulls, NullBufferBuilder, Box<Decoder>),
+    /// A nullable writer union read as a non-nullable reader type
+    NonNullable(Nulls, Box<Decoder>),
+}
+
+/// Projects the fields of a writer record onto the fields of a reader record
+#[derive(Debug)]
+struct Projection {
+    /// How to decode each writer field, in writer order
+    writer_fields: Vec<FieldProjection>,
+    /// The index and default value of each reader field not present in the writer
+    defaults: Vec<(usize, Value)>,
+}
+
+#[derive(Debug)]
+enum FieldProjection {
+    /// Decode into the reader field with the given index
+    ToReader(usize),
+    /// Skip a field not present in the reader
+    Skip(Skipper),
+}
+
+/// Decode a single record, applying `projection` if any
+fn decode_record(
+    encodings: &mut [Decoder],
+    projection: Option<&mut Projection>,
+    buf: &mut AvroCursor<'_>,
+) -> Result<(), ArrowError> {
+    match projection {
+        None => {
+            for encoding in encodings {
+                encoding.decode(buf)?;
+            }
+        }
+        Some(projection) => {
+            for field in &mut projection.writer_fields {
+                match field {
+                    FieldProjection::ToReader(idx) => encodings[*idx].decode(buf)?,
+                    FieldProjection::Skip(skipper) => skipper.skip(buf)?,
+                }
+            }
+            for (idx, default) in &projection.defaults {
+                encodings[*idx].append_default(default)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+impl Decoder {
+    fn try_new(data_type: &AvroDataType) -> Result<Self, ArrowError> {
+        let conversion = data_type.resolution().and_then(|r| r.conversion.as_ref());
+        let decoder = match (conversion, data_type.codec()) {
+            (Some(Conversion::Promotion(p)), _) => match p {
+                Promotion::IntToLong => Self::IntToLong(Vec::with_capacity(DEFAULT_CAPACITY)),
+                Promotion::IntToFloat => Self::IntToFloat(Vec::with_capacity(DEFAULT_CAPACITY)),
+                Promotion::IntToDouble => Self::IntToDouble(Vec::with_capacity(DEFAULT_CAPACITY)),
+                Promotion::LongToFloat => Self::LongToFloat(Vec::with_capacity(DEFAULT_CAPACITY)),
+                Promotion::LongToDouble => Self::LongToDouble(Vec::with_capacity(DEFAULT_CAPACITY)),
+                Promotion::FloatToDouble => {
+                    Self::FloatToDouble(Vec::with_capacity(DEFAULT_CAPACITY))
+                }
+            },
+            (Some(Conversion::Enum(mapping)), Codec::Enum(symbols)) => {
+                let values = StringArray::from_iter_values(symbols.iter());
+                let keys = Vec::with_capacity(DEFAULT_CAPACITY);
+                Self::Enum(Arc::new(values), Some(mapping.clone()), keys)
+            }
+            (Some(Conversion::Record(resolved)), Codec::Struct(fields)) => {
+                let mut arrow_fields = Vec::with_capacity(fields.len());
+                let mut encodings = Vec::with_capacity(fields.len());
+                for avro_field in fields.iter() {
+                    encodings.push(Self::try_new(avro_field.data_type())?);
+                    arrow_fields.push(avro_field.field());
+                }
+
+                let writer_fields = resolved
+                    .writer_fields
+                    .iter()
+                    .map(|f| match f {
+                        ResolvedField::ToReader(idx) => Ok(FieldProjection::ToReader(*idx)),
+                        ResolvedField::Skip(d) => Ok(FieldProjection::Skip(Skipper::try_new(d)?)),
+                    })
+                    .collect::<Result<_, ArrowError>>()?;
+
+                // Validate the defaults eagerly to report errors when constructing the decoder
+                for (idx, default) in resolved.defaults.iter() {
+                    let mut decoder = Self::try_new(fields[*idx].data_type())?;
+                    decoder.append_default(default).map_err(|e| {
+                        ArrowError::ParseError(format!(
+                            "Invalid default for field {}: {e}",
+                            fields[*idx].name()
+                        ))
+                    })?;
+                }
+
+                let projection = Projection {
+                    writer_fields,
+                    defaults: resolved.defaults.to_vec(),
+                };
+                Self::Record(arrow_fields.into(), encodings, Some(projection))
+            }
+            (Some(c), codec) => {
+                return Err(ArrowError::ParseError(format!(
+                    "Invalid conversion {c:?} for {codec:?}"
+                )))
+            }
+            (None, codec) => match codec {
+                Codec::Null => Self::Null(0),
+                Codec::Boolean => Self::Boolean(BooleanBufferBuilder::new(DEFAULT_CAPACITY)),
+                Codec::Int32 => Self::Int32(Vec::with_capacity(DEFAULT_CAPACITY)),
+                Codec::Int64 => Self::Int64(Vec::with_capacity(DEFAULT_CAPACITY)),
+                Codec::Float32 => Self::Float32(Vec::with_capacity(DEFAULT_CAPACITY)),
+                Codec::Float64 => Self::Float64(Vec::with_capacity(DEFAULT_CAPACITY)),
+                Codec::Binary => Self::Binary(
+                    OffsetBufferBuilder::new(DEFAULT_CAPACITY),
+                    Vec::with_capacity(DEFAULT_CAPACITY),
+                ),
+                Codec::Utf8 => Self::String(
+                    OffsetBufferBuilder::new(DEFAULT_CAPACITY),
+                    Vec::with_capacity(DEFAULT_CAPACITY),
+                ),
+                Codec::Date32 => Self::Date32(Vec::with_capacity(DEFAULT_CAPACITY)),
+                Codec::TimeMillis => Self::TimeMillis(Vec::with_capacity(DEFAULT_CAPACITY)),
+                Codec::TimeMicros => Self::TimeMicros(Vec::with_capacity(DEFAULT_CAPACITY)),
+                Codec::TimestampMillis(is_utc) => {
+                    Self::TimestampMillis(*is_utc, Vec::with_capacity(DEFAULT_CAPACITY))
+                }
+                Codec::TimestampMicros(is_utc) => {
+                    Self::TimestampMicros(*is_utc, Vec::with_capacity(DEFAULT_CAPACITY))
+                }
+                Codec::TimestampNanos(is_utc) => {
+                    Self::TimestampNanos(*is_utc, Vec::with_capacity(DEFAULT_CAPACITY))
+                }
+                Codec::Fixed(size) => Self::Fixed(*size, Vec::with_capacity(DEFAULT_CAPACITY)),
+                Codec::Decimal(precision, scale, size) => {
+                    match *precision <= DECIMAL128_MAX_PRECISION {
+                        true => Self::Decimal128(*precision, *scale, *size, Vec::new()),
+                        false => Self::Decimal256(*precision, *scale, *size, Vec::new()),
+                    }
+                }
+                Codec::Uuid => Self::Uuid(Vec::with_capacity(DEFAULT_CAPACITY)),
+                Codec::Enum(symbols) => {
+                    let values = StringArray::from_iter_values(symbols.iter());
+                    Self::Enum(Arc::new(values), None, Vec::with_capacity(DEFAULT_CAPACITY))
+                }
+                Codec::Duration => Self::Duration(Vec::with_capacity(DEFAULT_CAPACITY)),
+                Codec::List(item) => {
+                    let decoder = Self::try_new(item)?;
+                    Self::List(
+                        Arc::new(item.field_with_name("item")),
+                        OffsetBufferBuilder::new(DEFAULT_CAPACITY),
+                        Box::new(decoder),
+                    )
+                }
+                Codec::Map(value) => {
+                    let entries = match data_type.codec().data_type() {
+                        DataType::Map(entries, _) => entries,
+                        _ => unreachable!(),
+                    };
+                    Self::Map(
+                        entries,
+                        OffsetBufferBuilder::new(DEFAULT_CAPACITY),
+                        OffsetBufferBuilder::new(DEFAULT_CAPACITY),
+                        Vec::with_capacity(DEFAULT_CAPACITY),
+                        Box::new(Self::try_new(value)?),
+                    )
+                }
+                Codec::Struct(fields) => {
+                    let mut arrow_fields = Vec::with_capacity(fields.len());
+                    let mut encodings = Vec::with_capacity(fields.len());
+                    for avro_field in fields.iter() {
+                        let encoding = Self::try_new(avro_field.data_type())?;
+                        arrow_fields.push(avro_field.field());
+                        encodings.push(encoding);
+                    }
+                    Self::Record(arrow_fields.into(), encodings, None)
+                }
+                Codec::Json(schema) => Self::Json(
+                    schema.clone(),
+                    OffsetBufferBuilder::new(DEFAULT_CAPACITY),
+                    Vec::with_capacity(DEFAULT_CAPACITY),
+                ),
+            },
+        };
+
+        let nullable = data_type.resolution().map(|r| r.nullable);
+        Ok(match (data_type.nullability(), nullable) {
+            (Some(nulls), Some(false)) => Self::NonNullable(nulls, Box::new(decoder)),
+            (Some(nulls), _) => Self::Nullable(
+                nulls,
+                NullBufferBuilder::new(DEFAULT_CAPACITY),
+                Box::new(decoder),
+            ),
+            (None, _) => decoder,
+        })
+    }
+
+    /// Append a null record
+    fn append_null(&mut self) {
+        match self {
+            Self::Null(count) => *count += 1,
+            Self::Boolean(b) => b.append(false),
+            Self::Int32(v) | Self::Date32(v) | Self::TimeMillis(v) => v.push(0),
+            Self::Int64(v)
+            | Self::TimeMicros(v)
+            | Self::TimestampMillis(_, v)
+            | Self::TimestampMicros(_, v)
+            | Self::TimestampNanos(_, v)
+            | Self::IntToLong(v) => v.push(0),
+            Self::Float32(v) | Self::IntToFloat(v) | Self::LongToFloat(v) => v.push(0.),
+            Self::Float64(v)
+            | Self::IntToDouble(v)
+            | Self::LongToDouble(v)
+            | Self::FloatToDouble(v) => v.push(0.),
+            Self::Binary(offsets, _) | Self::String(offsets, _) | Self::Json(_, offsets, _) => {
+                offsets.push_length(0)
+            }
+            Self::Fixed(size, v) => v.resize(v.len() + *size as usize, 0),
+            Self::Decimal128(_, _, _, v) => v.push(0),
+            Self::Decimal256(_, _, _, v) => v.push(i256::ZERO),
+            Self::Uuid(v) => v.extend_from_slice(&[0; 16]),
+            Self::Enum(_, _, v) => v.push(0),
+            Self::Duration(v) => v.push(IntervalMonthDayNano::ZERO),
+            Self::List(_, offsets, _) | Self::Map(_, offsets, _, _, _) => offsets.push_length(0),
+            Self::Record(_, encodings, _) => encodings.iter_mut().for_each(|x| x.append_null()),
+            Self::Nullable(_, nulls, e) => {
+                nulls.append_null();
+                e.append_null()
+            }
+            Self::NonNullable(_, e) => e.append_null(),
+        }
+    }
+
+    /// Append the JSON encoded default value of a reader field not present in the writer schema
+    ///
+    /// <https://avro.apache.org/docs/1.11.1/specification/#schema-record>
+    fn append_default(&mut self, value: &Value) -> Result<(), ArrowError> {
+        let err = || ArrowError::ParseError(format!("Invalid default value {value}"));
+        match (self, value) {
+            (Self::Nullable(_, nulls, e), Value::Null) => {
+                nulls.append_null();
+                e.append_null();
+            }
+            (Self::Nullable(_, nulls, e), v) => {
+                e.append_default(v)?;
+                nulls.append_non_null();
+            }
+            (Self::NonNullable(_, e), v) => e.append_default(v)?,
+            (Self::Null(count), Value::Null) => *count += 1,
+            (Self::Boolean(values), Value::Bool(b)) => values.append(*b),
+            (Self::Int32(values) | Self::Date32(values) | Self::TimeMillis(values), v) => {
+                let v = v.as_i64().and_then(|x| i32::try_from(x).ok());
+                values.push(v.ok_or_else(err)?)
+            }
+            (
+                Self::Int64(values)
+                | Self::TimeMicros(values)
+                | Self::TimestampMillis(_, values)
+                | Self::TimestampMicros(_, values)
+                | Self::TimestampNanos(_, values)
+                | Self::IntToLong(values),
+                v,
+            ) => values.push(v.as_i64().ok_or_else(err)?),
+            (Self::Float32(values) | Self::IntToFloat(values) | Self::LongToFloat(values), v) => {
+                values.push(v.as_f64().ok_or_else(err)? as f32)
+            }
+            (
+                Self::Float64(values)
+                | Self::IntToDouble(values)
+                | Self::LongToDouble(values)
+                | Self::FloatToDouble(values),
+                v,
+            ) => values.push(v.as_f64().ok_or_else(err)?),
+            (Self::String(offsets, values), Value::String(s)) => {
+                offsets.push_length(s.len());
+                values.extend_from_slice(s.as_bytes());
+            }
+            (Self::Json(_, offsets, values), v) => {
+                let start = values.len();
+                serde_json::to_writer(&mut *values, v)
+                    .map_err(|e| ArrowError::JsonError(e.to_string()))?;
+                offsets.push_length(values.len() - start);
+            }
+            (Self::Binary(offsets, values), Value::String(s)) => {
+                let data = default_bytes(s).ok_or_else(err)?;
+                offsets.push_length(data.len());
+                values.extend_from_slice(&data);
+            }
+            (Self::Fixed(size, values), Value::String(s)) => {
+                let data = default_bytes(s).filter(|d| d.len() == *size as usize);
+                values.extend_from_slice(&data.ok_or_else(err)?);
+            }
+            (Self::Decimal128(_, _, _, values), Value::String(s)) => {
+                let data = default_bytes(s).ok_or_else(err)?;
+                values.push(i128::from_be_bytes(sign_extend(&data)?));
+            }
+            (Self::Decimal256(_, _, _, values), Value::String(s)) => {
+                let data = default_bytes(s).ok_or_else(err)?;
+                values.push(i256::from_be_bytes(sign_extend(&data)?));
+            }
+            (Self::Uuid(values), Value::String(s)) => {
+                values.extend_from_slice(&parse_uuid(s.as_bytes())?)
+            }
+            (Self::Enum(symbols, _, values), Value::String(s)) => {
+                let symbols = symbols.as_string::<i32>();
+                let idx = symbols.iter().position(|x| x == Some(s.as_str()));
+                values.push(idx.ok_or_else(err)? as i32);
+            }
+            (Self::Duration(values), Value::String(s)) => {
+                let data = default_bytes(s).filter(|d| d.len() == 12);
+                values.push(parse_duration(&data.ok_or_else(err)?));
+            }
+            (Self::List(_, offsets, e), Value::Array(items)) => {
+                for item in items {
+                    e.append_default(item)?;
+                }
+                offsets.push_length(items.len());
+            }
+            (Self::Map(_, offsets, key_offsets, key_data, e), Value::Object(entries)) => {
+                for (key, value) in entries {
+                    key_offsets.push_length(key.len());
+                    key_data.extend_from_slice(key.as_bytes());
+                    e.append_default(value)?;
+                }
+                offsets.push_length(entries.len());
+            }
+            (Self::Record(fields, encodings, _), Value::Object(values)) => {
+                for (field, encoding) in fields.iter().zip(encodings) {
+                    let value = values.get(field.name()).ok_or_else(|| {
+                        ArrowError::ParseError(format!(
+                            "Default value {value} is missing field {}",
+                            field.name()
+                        ))
+                    })?;
+                    encoding.append_default(value)?;
+                }
+            }
+            _ => return Err(err()),
+        }
+        Ok(())
+    }
+
+    /// Decode a single record from `buf`
+    fn decode(&mut self, buf: &mut AvroCursor<'_>) -> Result<(), ArrowError> {
+        match self {
+            Self::Null(x) => *x += 1,
+            Self::Boolean(values) => values.append(buf.get_bool()?),
+            Self::Int32(values) | Self::Date32(values) | Self::TimeMillis(values) => {
+                values.push(buf.get_int()?)
+            }
+            Self::Int64(values)
+            | Self::TimeMicros(values)
+            | Self::TimestampMillis(_, values)
+            | Self::TimestampMicros(_, values)
+            | Self::TimestampNanos(_, values) => values.push(buf.get_long()?),
+            Self::Float32(values) => values.push(buf.get_float()?),
+            Self::Float64(values) => values.push(buf.get_double()?),
+            Self::IntToLong(values) => values.push(buf.get_int()? as i64),
+            Self::IntToFloat(values) => values.push(buf.get_int()? as f32),
+            Self::IntToDouble(values) => values.push(buf.get_int()? as f64),
+            Self::LongToFloat(values) => values.push(buf.get_long()? as f32),
+            Self::LongToDouble(values) => values.push(buf.get_long()? as f64),
+            Self::FloatToDouble(values) => values.push(buf.get_float()? as f64),
+            Self::Binary(offsets, values) | Self::String(offsets, values) => {
+                let data = buf.get_bytes()?;
+                offsets.push_length(data.len());
+                values.extend_from_slice(data);
+            }
+            Self::Fixed(size, values) => values.extend_from_slice(buf.get_fixed(*size as usize)?),
+            Self::Decimal128(_, _, size, values) => {
+                let raw = match size {
+                    Some(size) => buf.get_fixed(*size)?,
+                    None => buf.get_bytes()?,
+                };
+                values.push(i128::from_be_bytes(sign_extend(raw)?));
+            }
+            Self::Decimal256(_, _, size, values) => {
+                let raw = match size {
+                    Some(size) => buf.get_fixed(*size)?,
+                    None => buf.get_bytes()?,
+                };
+                values.push(i256::from_be_bytes(sign_extend(raw)?));
+            }
+            Self::Uuid(values) => {
+                let s = buf.get_bytes()?;
+                values.extend_from_slice(&parse_uuid(s)?);
+            }
+            Self::Enum(symbols, mapping, values) => {
+                let idx = buf.get_int()?;
+                let len = mapping.as_ref().map(|m| m.len()).unwrap_or(symbols.len());
+                if idx < 0 || idx as usize >= len {
+                    return Err(ArrowError::ParseError(format!(
+                        "Enum index {idx} out of range for {len} symbols"
+                    )));
+                }
+                let idx = match mapping {
+                    Some(mapping) => mapping[idx as usize].ok_or_else(|| {
+                        ArrowError::ParseError(format!(
+                            "Writer enum symbol {idx} is not present in the reader schema, which has no default"
+                        ))
+                    })?,
+                    None => idx,
+                };
+                values.push(idx);
+            }
+            Self::Duration(values) => values.push(parse_duration(buf.get_fixed(12)?)),
+            Self::List(_, offsets, e) => {
+                let len = read_array_blocks(buf, |cursor| e.decode(cursor))?;
+                offsets.push_length(len);
+            }
+            Self::Map(_, offsets, key_offsets, key_data, values) => {
+                let len = read_array_blocks(buf, |cursor| {
+                    let key = cursor.get_bytes()?;
+                    key_offsets.push_length(key.len());
+                    key_data.extend_from_slice(key);
+                    values.decode(cursor)
+                })?;
+                offsets.push_length(len);
+            }
+            Self::Record(_, encodings, projection) => {
+                decode_record(encodings, projection.as_mut(), buf)?
+            }
+            Self::Json(schema, offsets, values) => {
+                let start = values.len();
+                decode_json(schema, schema.root(), buf, values)?;
+                offsets.push_length(values.len() - start);
+            }
+            Self::Nullable(nulls, null_buffer, e) => {
+                let is_valid = read_union_branch(buf, *nulls)?;
+                null_buffer.append(is_valid);
+                match is_valid {
+                    true => e.decode(buf)?,
+                    false => e.append_null(),
+                }
+            }
+            Self::NonNullable(nulls, e) => match read_union_branch(buf, *nulls)? {
+                true => e.decode(buf)?,
+                false => {
+                    return Err(ArrowError::ParseError(
+                        "Found null value for non-nullable reader type".to_string(),
+                    ))
+                }
+            },
+        }
+        Ok(())
+    }
+
+    /// Flush decoded records to an [`ArrayRef`]
+    fn flush(&mut self, nulls: Option<NullBuffer>) -> Result<ArrayRef, ArrowError> {
+        Ok(match self {
+            Self::Nullable(_, n, e) => e.flush(n.finish())?,
+            Self::NonNullable(_, e) => e.flush(nulls)?,
+            Self::Null(size) => Arc::new(NullArray::new(std::mem::replace(size, 0))),
+            Self::Boolean(b) => Arc::new(BooleanArray::new(b.finish(), nulls)),
+            Self::Int32(values) => Arc::new(flush_primitive::<Int32Type>(values, nulls)),
+            Self::Date32(values) => Arc::new(flush_primitive::<Date32Type>(values, nulls)),
+            Self::Int64(values) => Arc::new(flush_primitive::<Int64Type>(values, nulls)),
+            Self::TimeMillis(values) => {
+                Arc::new(flush_primitive::<Time32MillisecondType>(values, nulls))
+            }
+            Self::TimeMicros(values) => {
+                Arc::new(flush_primitive::<Time64MicrosecondType>(values, nulls))
+            }
+            Self::TimestampMillis(is_utc, values) => Arc::new(
+                flush_primitive::<TimestampMillisecondType>(values, nulls)
+                    .with_timezone_opt(is_utc.then(|| "+00:00")),
+            ),
+            Self::TimestampMicros(is_utc, values) => Arc::new(
+                flush_primitive::<TimestampMicrosecondType>(values, nulls)
+                    .with_timezone_opt(is_utc.then(|| "+00:00")),
+            ),
+            Self::TimestampNanos(is_utc, values) => Arc::new(
+                flush_primitive::<TimestampNanosecondType>(values, nulls)
+                    .with_timezone_opt(is_utc.then(|| "+00:00")),
+            ),
+            Self::Decimal128(precision, scale, _, values) => Arc::new(
+                flush_primitive::<Decimal128Type>(values, nulls)
+                    .with_precision_and_scale(*precision, *scale)?,
+            ),
+            Self::Decimal256(precision, scale, _, values) => Arc::new(
+                flush_primitive::<Decimal256Type>(values, nulls)
+                    .with_precision_and_scale(*precision, *scale)?,
+            ),
+            Self::Uuid(values) => {
+                let values = Buffer::from_vec(flush_values(values));
+                Arc::new(FixedSizeBinaryArray::try_new(16, values, nulls)?)
+            }
+            Self::Enum(symbols, _, values) => {
+                let keys = flush_primitive::<Int32Type>(values, nulls);
+                Arc::new(DictionaryArray::try_new(keys, symbols.clone())?)
+            }
+            Self::IntToLong(values) => Arc::new(flush_primitive::<Int64Type>(values, nulls)),
+            Self::Float32(values) | Self::IntToFloat(values) | Self::LongToFloat(values) => {
+                Arc::new(flush_primitive::<Float32Type>(values, nulls))
+            }
+            Self::Float64(values)
+            | Self::IntToDouble(values)
+            | Self::LongToDouble(values)
+            | Self::FloatToDouble(values) => {
+                Arc::new(flush_primitive::<Float64Type>(values, nulls))
+            }
+            Self::Duration(values) => {
+                Arc::new(flush_primitive::<IntervalMonthDayNanoType>(values, nulls))
+            }
+            Self::Binary(offsets, values) => {
+                let offsets = flush_offsets(offsets);
+                let values = Buffer::from_vec(flush_values(values));
+                Arc::new(BinaryArray::new(offsets, values, nulls))
+            }
+            Self::String(offsets, values) | Self::Json(_, offsets, values) => {
+                let offsets = flush_offsets(offsets);
+                let values = Buffer::from_vec(flush_values(values));
+                Arc::new(StringArray::try_new(offsets, values, nulls)?)
+            }
+            Self::Fixed(size, values) => {
+                let values = Buffer::from_vec(flush_values(values));
+                Arc::new(FixedSizeBinaryArray::try_new(*size, values, nulls)?)
+            }
+            Self::List(field, offsets, values) => {
+                let values = values.flush(None)?;
+                let offsets = flush_offsets(offsets);
+                Arc::new(ListArray::try_new(field.clone(), offsets, values, nulls)?)
+            }
+            Self::Map(entries, offsets, key_offsets, key_data, values) => {
+                let keys = StringArray::try_new(
+                    flush_offsets(key_offsets),
+                    Buffer::from_vec(flush_values(key_data)),
+                    None,
+                )?;
+                let values = values.flush(None)?;
+                let entry_fields = match entries.data_type() {
+                    DataType::Struct(fields) => fields.clone(),
+                    _ => unreachable!(),
+                };
+                let entries_array =
+                    StructArray::try_new(entry_fields, vec![Arc::new(keys), values], None)?;
+                let offsets = flush_offsets(offsets);
+                Arc::new(MapArray::try_new(
+                    entries.clone(),
+                    offsets,
+                    entries_array,
+                    nulls,
+                    false,
+                )?)
+            }
+            Self::Record(fields, encodings, _) => {
+                let arrays = encodings
+                    .iter_mut()
+                    .map(|x| x.flush(None))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Arc::new(StructArray::try_new(fields.clone(), arrays, nulls)?)
+            }
+        })
+    }
+}
+
+/// Decodes the blocks of an Avro array or map, calling `decode` for each item
+/// and returning the total number of items
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#arrays-1>
+fn read_array_blocks(
+    buf: &mut AvroCursor<'_>,
+    mut decode: impl FnMut(&mut AvroCursor<'_>) -> Result<(), ArrowError>,
+) -> Result<usize, ArrowError> {
+    let mut total = 0;
+    loop {
+        // A negative block count is followed by the size of the block in bytes
+        let count = match buf.get_long()? {
+            0 => break,
+            c if c < 0 => {
+                buf.get_long()?;
+                c.unsigned_abs()
+            }
+            c => c as u64,
+        };
+        let count = usize::try_from(count)
+            .map_err(|_| ArrowError::ParseError(format!("Block count {count} exceeds usize")))?;
+        for _ in 0..count {
+            decode(buf)?;
+        }
+        total += count;
+    }
+    Ok(total)
+}
+
+/// Reads the branch of a nullable union, returning `true` if the value is not null
+fn read_union_branch(buf: &mut AvroCursor<'_>, nulls: Nulls) -> Result<bool, ArrowError> {
+    match (buf.get_long()?, nulls) {
+        (0, Nulls::NullFirst) | (1, Nulls::NullSecond) => Ok(false),
+        (0, Nulls::NullSecond) | (1, Nulls::NullFirst) => Ok(true),
+        (idx, _) => Err(ArrowError::ParseError(format!(
+            "Invalid union branch {idx} for nullable type"
+        ))),
+    }
+}
+
+/// Determines the encoded length of records without decoding them
+#[derive(Debug)]
+pub(crate) struct RecordSkipper(Skipper);
+
+impl RecordSkipper {
+    /// Create a new [`RecordSkipper`] for records with the writer schema `data_type`
+    pub(crate) fn try_new(data_type: &AvroDataType) -> Result<Self, ArrowError> {
+        Skipper::try_new(data_type).map(Self)
+    }
+
+    /// Returns the length of the record at the start of `buf`, or `None` if `buf`
+    /// ends before the end of the record
+    pub(crate) fn record_len(&mut self, buf: &[u8]) -> Result<Option<usize>, ArrowError> {
+        let mut cursor = AvroCursor::new(buf);
+        match self.0.skip(&mut cursor) {
+            Ok(()) => Ok(Some(cursor.position())),
+            Err(_) if cursor.is_eof() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Skips the encoded values of a writer field not present in the reader schema
+#[derive(Debug)]
+enum Skipper {
+    Null,
+    Boolean,
+    Int32,
+    Int64,
+    Float32,
+    Float64,
+    Bytes,
+    Fixed(usize),
+    List(Box<Skipper>),
+    Map(Box<Skipper>),
+    Record(Vec<Skipper>),
+    /// Json(schema, scratch)
+    Json(JsonSchema, Vec<u8>),
+    Nullable(Nulls, Box<Skipper>),
+}
+
+impl Skipper {
+    fn try_new(data_type: &AvroDataType) -> Result<Self, ArrowError> {
+        let skipper = match data_type.codec() {
+            Codec::Null => Self::Null,
+            Codec::Boolean => Self::Boolean,
+            Codec::Int32 | Codec::Date32 | Codec::TimeMillis | Codec::Enum(_) => Self::Int32,
+            Codec::Int64
+            | Codec::TimeMicros
+            | Codec::TimestampMillis(_)
+            | Codec::TimestampMicros(_)
+            | Codec::TimestampNanos(_) => Self::Int64,
+            Codec::Float32 => Self::Float32,
+            Codec::Float64 => Self::Float64,
+            Codec::Binary | Codec::Utf8 | Codec::Uuid | Codec::Decimal(_, _, None) => Self::Bytes,
+            Codec::Fixed(size) => Self::Fixed(*size as usize),
+            Codec::Decimal(_, _, Some(size)) => Self::Fixed(*size),
+            Codec::Duration => Self::Fixed(12),
+            Codec::List(item) => Self::List(Box::new(Self::try_new(item)?)),
+            Codec::Map(value) => Self::Map(Box::new(Self::try_new(value)?)),
+            Codec::Struct(fields) => Self::Record(
+                fields
+                    .iter()
+                    .map(|f| Self::try_new(f.data_type()))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Codec::Json(schema) => Self::Json(schema.clone(), vec![]),
+        };
+        Ok(match data_type.nullability() {
+            Some(nulls) => Self::Nullable(nulls, Box::new(skipper)),
+            None => skipper,
+        })
+    }
+
+    fn skip(&mut self, buf: &mut AvroCursor<'_>) -> Result<(), ArrowError> {
+        match self {
+            Self::Null => {}
+            Self::Boolean => {
+                buf.get_bool()?;
+            }
+            Self::Int32 => {
+                buf.get_int()?;
+            }
+            Self::Int64 => {
+                buf.get_long()?;
+            }
+            Self::Float32 => {
+                buf.get_fixed(4)?;
+            }
+            Self::Float64 => {
+                buf.get_fixed(8)?;
+            }
+            Self::Bytes => {
+                buf.get_bytes()?;
+            }
+            Self::Fixed(size) => {
+                buf.get_fixed(*size)?;
+            }
+            Self::List(item) => {
+                read_array_blocks(buf, |cursor| item.skip(cursor))?;
+            }
+            Self::Map(value) => {
+                read_array_blocks(buf, |cursor| {
+                    cursor.get_bytes()?;
+                    value.skip(cursor)
+                })?;
+            }
+            Self::Record(fields) => {
+                for field in fields {
+                    field.skip(buf)?;
+                }
+            }
+            Self::Json(schema, scratch) => {
+                scratch.clear();
+                decode_json(schema, schema.root(), buf, scratch)?;
+            }
+            Self::Nullable(nulls, skipper) => {
+                if read_union_branch(buf, *nulls)? {
+                    skipper.skip(buf)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Decodes an Avro value of type `t` as JSON, appending it to `out`
+fn decode_json(
+    schema: &JsonSchema,
+    t: &JsonType,
+    buf: &mut AvroCursor<'_>,
+    out: &mut Vec<u8>,
+) -> Result<(), ArrowError> {
+    let to_json = |out: &mut Vec<u8>, v: Value| {
+        serde_json::to_writer(out, &v).map_err(|e| ArrowError::JsonError(e.to_string()))
+    };
+    match t {
+        JsonType::Null => out.extend_from_slice(b"null"),
+        JsonType::Boolean => to_json(out, buf.get_bool()?.into())?,
+        JsonType::Int => to_json(out, buf.get_int()?.into())?,
+        JsonType::Long => to_json(out, buf.get_long()?.into())?,
+        JsonType::Float => to_json(out, buf.get_float()?.into())?,
+        JsonType::Double => to_json(out, buf.get_double()?.into())?,
+        JsonType::Bytes => {
+            let bytes = buf.get_bytes()?;
+            to_json(
+                out,
+                bytes.iter().map(|b| *b as char).collect::<String>().into(),
+            )?
+        }
+        JsonType::Fixed(size) => {
+            let bytes = buf.get_fixed(*size)?;
+            to_json(
+                out,
+                bytes.iter().map(|b| *b as char).collect::<String>().into(),
+            )?
+        }
+        JsonType::String => {
+            let s = std::str::from_utf8(buf.get_bytes()?)
+                .map_err(|e| ArrowError::ParseError(format!("Invalid UTF-8 string: {e}")))?;
+            to_json(out, s.into())?
+        }
+        JsonType::Enum(symbols) => {
+            let idx = buf.get_int()?;
+            let symbol = usize::try_from(idx)
+                .ok()
+                .and_then(|i| symbols.get(i))
+                .ok_or_else(|| ArrowError::ParseError(format!("Invalid enum index {idx}")))?;
+            to_json(out, symbol.as_str().into())?
+        }
+        JsonType::Array(item) => {
+            out.push(b'[');
+            let mut first = true;
+            read_array_blocks(buf, |buf| {
+                if !std::mem::take(&mut first) {
+                    out.push(b',');
+                }
+                decode_json(schema, schema.get(*item), buf, out)
+            })?;
+            out.push(b']');
+        }
+        JsonType::Map(value) => {
+            out.push(b'{');
+            let mut first = true;
+            read_array_blocks(buf, |buf| {
+                if !std::mem::take(&mut first) {
+                    out.push(b',');
+                }
+                decode_json(schema, &JsonType::String, buf, out)?;
+                out.push(b':');
+                decode_json(schema, schema.get(*value), buf, out)
+            })?;
+            out.push(b'}');
+        }
+        JsonType::Record(fields) => {
+            out.push(b'{');
+            for (i, (name, field)) in fields.iter().enumerate() {
+                if i != 0 {
+                    out.push(b',');
+                }
+                to_json(out, name.as_str().into())?;
+                out.push(b':');
+                decode_json(schema, schema.get(*field), buf, out)?;
+            }
+            out.push(b'}');
+        }
+        JsonType::Union(variants) => {
+            let idx = buf.get_long()?;
+            let variant = usize::try_from(idx)
+                .ok()
+                .and_then(|i| variants.get(i))
+                .ok_or_else(|| ArrowError::ParseError(format!("Invalid union branch {idx}")))?;
+            decode_json(schema, schema.get(*variant), buf, out)?
+        }
+    }
+    Ok(())
+}
+
+/// Parses an Avro duration, three little-endian unsigned integers: months, days and milliseconds
+fn parse_duration(data: &[u8]) -> IntervalMonthDayNano {
+    let months = u32::from_le_bytes(data[0..4].try_into().unwrap());
+    let days = u32::from_le_bytes(data[4..8].try_into().unwrap());
+    let millis = u32::from_le_bytes(data[8..12].try_into().unwrap());
+    IntervalMonthDayNano::new(months as i32, days as i32, millis as i64 * 1_000_000)
+}
+
+/// Parses the JSON encoding of a bytes or fixed default value, where each byte
+/// is encoded as the unicode code point of the same value
+fn default_bytes(s: &str) -> Option<Vec<u8>> {
+    s.chars().map(|c| u8::try_from(c).ok()).collect()
+}
+
+/// Sign extends the big-endian two's complement `raw` to `N` bytes
+fn sign_extend<const N: usize>(raw: &[u8]) -> Result<[u8; N], ArrowError> {
+    let negative = raw.first().map(|b| b & 0x80 != 0).unwrap_or(false);
+    let fill = if negative { 0xFF } else { 0 };
+    let raw = match raw.len().checked_sub(N) {
+        // Any additional leading bytes must only contain the sign extension
+        Some(extra) => match raw[..extra].iter().all(|b| *b == fill) {
+            true => &raw[extra..],
+            false => {
+                return Err(ArrowError::ParseError(format!(
+                    "Decimal of {} bytes exceeds {N} bytes",
+                    raw.len()
+                )))
+            }
+        },
+        None => raw,
+    };
+    let mut out = [fill; N];
+    out[N - raw.len()..].copy_from_slice(raw);
+    Ok(out)
+}
+
+/// Parses the string representation of a UUID, e.g. `f81d4fae-7dec-11d0-a765-00a0c91e6bf6`
+fn parse_uuid(s: &[u8]) -> Result<[u8; 16], ArrowError> {
+    let err = || ArrowError::ParseError(format!("Invalid UUID: {}", String::from_utf8_lossy(s)));
+    if s.len() != 36 || [8, 13, 18, 23].iter().any(|i| s[*i] != b'-') {
+        return Err(err());
+    }
+    let mut out = [0_u8; 16];
+    let mut digits = s.iter().filter(|c| **c != b'-');
+    for byte in out.iter_mut() {
+        let mut next = || {
+            let c = *digits.next().ok_or_else(err)?;
+            (c as char).to_digit(16).ok_or_else(err)
+        };
+        *byte = (next()? << 4 | next()?) as u8;
+    }
+    Ok(out)
+}
+
+#[inline]
+fn flush_values<T>(values: &mut Vec<T>) -> Vec<T> {
+    std::mem::replace(values, Vec::with_capacity(DEFAULT_CAPACITY))
+}
+
+#[inline]
+fn flush_offsets(offsets: &mut OffsetBufferBuilder<i32>) -> OffsetBuffer<i32> {
+    std::mem::replace(offsets, OffsetBufferBuilder::new(DEFAULT_CAPACITY)).finish()
+}
+
+#[inline]
+fn flush_primitive<T: ArrowPrimitiveType>(
+    values: &mut Vec<T::Native>,
+    nulls: Option<NullBuffer>,
+) -> PrimitiveArray<T> {
+    PrimitiveArray::new(flush_values(values).into(), nulls)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::AvroField;
+    use crate::schema::Schema;
+    use arrow_schema::TimeUnit;
+
+    fn zigzag(v: i64) -> Vec<u8> {
+        let mut n = ((v << 1) ^ (v >> 63)) as u64;
+        let mut out = vec![];
+        while n >= 0x80 {
+            out.push(0x80 | n as u8);
+            n >>= 7;
+        }
+        out.push(n as u8);
+        out
+    }
+
+    #[test]
+    fn test_decode_nested() {
+        let schema: Schema = serde_json::from_str(
+            r#"{
+                "type": "record",
+                "name": "test",
+                "fields": [
+                    {"name": "a", "type": ["null", "int"]},
+                    {"name": "b", "type": {"type": "array", "items": "string"}},
+                    {"name": "c", "type": {"type": "fixed", "name": "f", "size": 2}},
+                    {"name": "d", "type": ["null", {
+                        "type": "record",
+                        "name": "inner",
+                        "fields": [{"name": "e", "type": "double"}]
+                    }]}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let field = AvroField::try_from(&schema).unwrap();
+        let mut decoder = RecordDecoder::try_new(field.data_type()).unwrap();
+
+        let mut buf = vec![];
+        // Record 1: a = 5, b = ["x", "yz"] in a single block, c = [1, 2], d = {e: 1.5}
+        buf.extend(zigzag(1));
+        buf.extend(zigzag(5));
+        buf.extend(zigzag(2));
+        buf.extend(zigzag(1));
+        buf.extend(b"x");
+        buf.extend(zigzag(2));
+        buf.extend(b"yz");
+        buf.extend(zigzag(0));
+        buf.extend([1, 2]);
+        buf.extend(zigzag(1));
+        buf.extend(1.5_f64.to_le_bytes());
+        // Record 2: a = null, b = ["w"] in a sized block, c = [3, 4], d = null
+        buf.extend(zigzag(0));
+        buf.extend(zigzag(-1));
+        buf.extend(zigzag(2));
+        buf.extend(zigzag(1));
+        buf.extend(b"w");
+        buf.extend(zigzag(0));
+        buf.extend([3, 4]);
+        buf.extend(zigzag(0));
+
+        assert_eq!(decoder.decode(&buf, 2).unwrap(), buf.len());
+        assert_eq!(decoder.len(), 2);
+        let batch = decoder.flush().unwrap();
+        assert!(decoder.is_empty());
+        assert_eq!(batch.num_rows(), 2);
+
+        let a = batch.column(0).as_primitive::<Int32Type>();
+        assert_eq!(a, &Int32Array::from(vec![Some(5), None]));
+
+        let b = batch.column(1).as_list::<i32>();
+        assert_eq!(b.value_offsets(), &[0, 2, 3]);
+        let b_values = b.values().as_string::<i32>();
+        assert_eq!(b_values, &StringArray::from(vec!["x", "yz", "w"]));
+
+        let c = batch.column(2).as_fixed_size_binary();
+        assert_eq!(c.value(0), &[1, 2]);
+        assert_eq!(c.value(1), &[3, 4]);
+
+        let d = batch.column(3).as_struct();
+        assert!(d.is_valid(0));
+        assert!(d.is_null(1));
+        let e = d.column(0).as_primitive::<Float64Type>();
+        assert_eq!(e.value(0), 1.5);
+    }
+
+    #[test]
+    fn test_invalid_union_branch() {
+        let schema: Schema = serde_json::from_str(
+            r#"{"type": "record", "name": "t", "fields": [{"name": "a", "type": ["int", "null"]}]}"#,
+        )
+        .unwrap();
+        let field = AvroField::try_from(&schema).unwrap();
+        let mut decoder = RecordDecoder::try_new(field.data_type()).unwrap();
+        let err = decoder.decode(&zigzag(2), 1).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Parser error: Invalid union branch 2 for nullable type"
+        );
+    }
+
+    #[test]
+    fn test_decode_logical_types() {
+        let schema: Schema = serde_json::from_str(
+            r#"{
+                "type": "record",
+                "name": "test",
+                "fields": [
+                    {"name": "e", "type": {"type": "enum", "name": "E", "symbols": ["A", "B", "C"]}},
+                    {"name": "e2", "type": ["null", "E"]},
+                    {"name": "u", "type": {"type": "string", "logicalType": "uuid"}},
+                    {"name": "d", "type": {
+                        "type": "fixed", "name": "dec", "size": 3,
+                        "logicalType": "decimal", "precision": 6, "scale": 2
+                    }},
+                    {"name": "d2", "type": {
+                        "type": "bytes", "logicalType": "decimal", "precision": 40, "scale": 0
+                    }},
+                    {"name": "t", "type": {"type": "long", "logicalType": "local-timestamp-nanos"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let field = AvroField::try_from(&schema).unwrap();
+        let mut decoder = RecordDecoder::try_new(field.data_type()).unwrap();
+
+        let mut buf = vec![];
+        for (e, e2, uuid, d, d2, t) in [
+            (
+                2,
+                Some(0),
+                "f81d4fae-7dec-11d0-a765-00a0c91e6bf6",
+                [0xFF, 0xFF, 0x85],
+                &[0x01][..],
+                1,
+            ),
+            (
+                0,
+                None,
+                "00000000-0000-0000-0000-000000000000",
+                [0x00, 0x01, 0x00],
+                &[0xFF, 0x7F][..],
+                -1,
+            ),
+        ] {
+            buf.extend(zigzag(e));
+            match e2 {
+                Some(v) => {
+                    buf.extend(zigzag(1));
+                    buf.extend(zigzag(v));
+                }
+                None => buf.extend(zigzag(0)),
+            }
+            buf.extend(zigzag(uuid.len() as _));
+            buf.extend(uuid.as_bytes());
+            buf.extend(d);
+            buf.extend(zigzag(d2.len() as _));
+            buf.extend(d2);
+            buf.extend(zigzag(t));
+        }
+
+        decoder.decode(&buf, 2).unwrap();
+        let batch = decoder.flush().unwrap();
+
+        let e = batch.column(0).as_dictionary::<Int32Type>();
+        assert_eq!(e.keys(), &Int32Array::from(vec![2, 0]));
+        assert_eq!(e.values().as_ref(), &StringArray::from(vec!["A", "B", "C"]));
+        let e2 = batch.column(1).as_dictionary::<Int32Type>();
+        assert_eq!(e2.keys(), &Int32Array::from(vec![Some(0), None]));
+
+        let u = batch.column(2).as_fixed_size_binary();
+        assert_eq!(
+            u.value(0),
+            &[
+                0xf8, 0x1d, 0x4f, 0xae, 0x7d, 0xec, 0x11, 0xd0, 0xa7, 0x65, 0x00, 0xa0, 0xc9, 0x1e,
+                0x6b, 0xf6
+            ]
+        );
+        assert_eq!(u.value(1), &[0; 16]);
+
+        let d = batch.column(3).as_primitive::<Decimal128Type>();
+        assert_eq!(d.data_type(), &DataType::Decimal128(6, 2));
+        assert_eq!(d.values(), &[-123, 256]);
+
+        let d2 = batch.column(4).as_primitive::<Decimal256Type>();
+        assert_eq!(d2.data_type(), &DataType::Decimal256(40, 0));
+        assert_eq!(d2.values(), &[i256::ONE, i256::from_i128(-129)]);
+
+        let t = batch.column(5).as_primitive::<TimestampNanosecondType>();
+        assert_eq!(
+            t.data_type(),
+            &DataType::Timestamp(TimeUnit::Nanosecond, None)
+        );
+        assert_eq!(t.values(), &[1, -1]);
+
+        let err = decoder.decode(&zigzag(3), 1).unwrap_err().to_string();
+        assert_eq!(err, "Parser error: Enum index 3 out of range for 3 symbols");
+    }
+
+    #[test]
+    fn test_parse_uuid() {
+        let err = parse_uuid(b"f81d4fae7dec11d0a76500a0c91e6bf6").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Invalid UUID: f81d4fae7dec11d0a76500a0c91e6bf6"
+        );
+        let err = parse_uuid(b"g81d4fae-7dec-11d0-a765-00a0c91e6bf6").unwrap_err();
+        assert!(err.to_string().contains("Invalid UUID"));
+        let uuid = parse_uuid(b"F81D4FAE-7DEC-11D0-A765-00A0C91E6BF6").unwrap();
+        assert_eq!(uuid[0], 0xF8);
+    }
+
+    #[test]
+    fn test_sign_extend() {
+        assert_eq!(sign_extend::<4>(&[0x80]).unwrap(), [0xFF, 0xFF, 0xFF, 0x80]);
+        assert_eq!(sign_extend::<4>(&[0x7F]).unwrap(), [0, 0, 0, 0x7F]);
+        assert_eq!(sign_extend::<4>(&[]).unwrap(), [0; 4]);
+        assert_eq!(
+            sign_extend::<2>(&[0xFF, 0xFF, 0x80, 0x00]).unwrap(),
+            [0x80, 0x00]
+        );
+        let err = sign_extend::<2>(&[0x01, 0x00, 0x00]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Decimal of 3 bytes exceeds 2 bytes"
+        );
+    }
+
+    #[test]
+    fn test_resolve_enum_and_skip() {
+        let writer: Schema = serde_json::from_str(
+            r#"{
+                "type": "record",
+                "name": "test",
+                "fields": [
+                    {"name": "e", "type": {"type": "enum", "name": "E", "symbols": ["A", "B", "C"]}},
+                    {"name": "skip", "type": {"type": "map", "values": ["null", "string"]}},
+                    {"name": "l", "type": "long"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let reader: Schema = serde_json::from_str(
+            r#"{
+                "type": "record",
+                "name": "test",
+                "fields": [
+                    {"name": "l", "type": ["null", "float"]},
+                    {"name": "e", "type": {
+                        "type": "enum", "name": "E", "symbols": ["C", "A", "UNKNOWN"], "default": "UNKNOWN"
+                    }}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let field = AvroField::resolve(&writer, &reader).unwrap();
+        let mut decoder = RecordDecoder::try_new(field.data_type()).unwrap();
+
+        let mut buf = vec![];
+        for (e, l) in [(0, 1), (1, 2), (2, 3)] {
+            buf.extend(zigzag(e));
+            // A map with a single block of two entries
+            buf.extend(zigzag(2));
+            buf.extend(zigzag(1));
+            buf.extend(b"k");
+            buf.extend(zigzag(0));
+            buf.extend(zigzag(1));
+            buf.extend(b"m");
+            buf.extend(zigzag(1));
+            buf.extend(zigzag(2));
+            buf.extend(b"vv");
+            buf.extend(zigzag(0));
+            buf.extend(zigzag(l));
+        }
+        assert_eq!(decoder.decode(&buf, 3).unwrap(), buf.len());
+        let batch = decoder.flush().unwrap();
+
+        let l = batch.column(0).as_primitive::<Float32Type>();
+        assert_eq!(l, &Float32Array::from(vec![1., 2., 3.]));
+        assert!(batch.schema().field(0).is_nullable());
+
+        let e = batch.column(1).as_dictionary::<Int32Type>();
+        assert_eq!(e.keys(), &Int32Array::from(vec![1, 2, 0]));
+        assert_eq!(
+            e.values().as_ref(),
+            &StringArray::from(vec!["C", "A", "UNKNOWN"])
+        );
+
+        // Without a reader default, unknown writer symbols are an error
+        let reader: Schema = serde_json::from_str(
+            r#"{
+                "type": "record",
+                "name": "test",
+                "fields": [{"name": "e", "type": {"type": "enum", "name": "E", "symbols": ["A"]}}]
+            }"#,
+        )
+        .unwrap();
+        let field = AvroField::resolve(&writer, &reader).unwrap();
+        let mut decoder = RecordDecoder::try_new(field.data_type()).unwrap();
+        let err = decoder.decode(&buf, 3).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Parser error: Writer enum symbol 1 is not present in the reader schema, which has no default"
+        );
+    }
+}
@@ -44,3 +44,56 @@ impl VLQDecoder {
         None
     }
 }
+
+/// Read a varint from `buf` returning the decoded `u64` and the number of bytes read
+#[inline]
+pub(crate) fn read_varint(buf: &[u8]) -> Option<(u64, usize)> {
+    let mut value = 0_u64;
+    for (count, byte) in buf.iter().take(10).enumerate() {
+        let byte = *byte;
+        value |= u64::from(byte & 0x7F) << (count * 7);
+        if byte & 0x80 == 0 {
+            // The 10th byte may only contribute a single bit
+            if count == 9 && byte > 1 {
+                return None;
+            }
+            return Some((value, count + 1));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_var(mut n: u64, dst: &mut [u8]) -> usize {
+        let mut i = 0;
+        while n >= 0x80 {
+            dst[i] = 0x80 | (n as u8);
+            i += 1;
+            n >>= 7;
+        }
+        dst[i] = n as u8;
+        i + 1
+    }
+
+    fn varint_test(a: u64) {
+        let mut buf = [0_u8; 10];
+        let len = encode_var(a, &mut buf);
+        assert_eq!(read_varint(&buf[..len]).unwrap(), (a, len));
+        assert_eq!(read_varint(&buf).unwrap(), (a, len));
+        assert!(read_varint(&buf[..len - 1]).is_none());
+    }
+
+    #[test]
+    fn test_varint() {
+        varint_test(0);
+        varint_test(4395932);
+        for i in 0..64 {
+            varint_test((1 << i) - 1);
+            varint_test(1 << i);
+        }
+        varint_test(u64::MAX);
+    }
+}
@@ -15,12 +15,210 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use arrow_schema::{ArrowError, DataType, Field as ArrowField, IntervalUnit, TimeUnit};
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use std::collections::HashMap;
 
 /// The metadata key used for storing the JSON encoded [`Schema`]
 pub const SCHEMA_METADATA_KEY: &str = "avro.schema";
 
+/// A JSON encoded Avro schema
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#schema-declaration>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvroSchema {
+    json: String,
+}
+
+impl AvroSchema {
+    /// Create a new [`AvroSchema`] from its JSON encoding
+    ///
+    /// The JSON is validated when the schema is used
+    pub fn new(json: impl Into<String>) -> Self {
+        Self { json: json.into() }
+    }
+
+    /// Returns the JSON encoding of this schema
+    pub fn json_string(&self) -> &str {
+        &self.json
+    }
+
+    /// Parses the JSON encoding of this schema
+    pub(crate) fn schema(&self) -> Result<Schema<'_>, ArrowError> {
+        serde_json::from_str(&self.json)
+            .map_err(|e| ArrowError::ParseError(format!("Failed to parse Avro schema JSON: {e}")))
+    }
+
+    /// Returns the [Parsing Canonical Form] of this schema
+    ///
+    /// [Parsing Canonical Form]: https://avro.apache.org/docs/1.11.1/specification/#parsing-canonical-form-for-schemas
+    pub fn canonical_form(&self) -> Result<String, ArrowError> {
+        let mut out = String::new();
+        write_canonical(&mut out, &self.schema()?, None);
+        Ok(out)
+    }
+
+    /// Returns the 64-bit Rabin fingerprint of the [canonical form](Self::canonical_form)
+    ///
+    /// <https://avro.apache.org/docs/1.11.1/specification/#schema-fingerprints>
+    pub fn fingerprint(&self) -> Result<Fingerprint, ArrowError> {
+        let canonical = self.canonical_form()?;
+        Ok(Fingerprint::Rabin(rabin(canonical.as_bytes())))
+    }
+}
+
+/// Identifies the writer schema of an Avro encoded message
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Fingerprint {
+    /// The 64-bit Rabin fingerprint of a schema, as used by [single object encoding]
+    ///
+    /// [single object encoding]: https://avro.apache.org/docs/1.11.1/specification/#single-object-encoding
+    Rabin(u64),
+    /// A schema id assigned by a schema registry, as used by the [Confluent wire format]
+    ///
+    /// [Confluent wire format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+    Id(u32),
+}
+
+/// An in-memory store of [`AvroSchema`] keyed by [`Fingerprint`]
+#[derive(Debug, Clone, Default)]
+pub struct SchemaStore {
+    schemas: HashMap<Fingerprint, AvroSchema>,
+}
+
+impl SchemaStore {
+    /// Create a new, empty [`SchemaStore`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `schema` under its [`Fingerprint::Rabin`], returning the fingerprint
+    pub fn register(&mut self, schema: AvroSchema) -> Result<Fingerprint, ArrowError> {
+        let fingerprint = schema.fingerprint()?;
+        self.schemas.insert(fingerprint, schema);
+        Ok(fingerprint)
+    }
+
+    /// Register `schema` under the schema registry `id`, returning the fingerprint
+    pub fn register_id(&mut self, id: u32, schema: AvroSchema) -> Fingerprint {
+        let fingerprint = Fingerprint::Id(id);
+        self.schemas.insert(fingerprint, schema);
+        fingerprint
+    }
+
+    /// Returns the [`AvroSchema`] with the given [`Fingerprint`], if any
+    pub fn lookup(&self, fingerprint: &Fingerprint) -> Option<&AvroSchema> {
+        self.schemas.get(fingerprint)
+    }
+}
+
+/// The CRC-64-AVRO fingerprint of an empty buffer
+const EMPTY_FINGERPRINT: u64 = 0xc15d213aa4d7a795;
+
+const RABIN_TABLE: [u64; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut fp = i as u64;
+        let mut j = 0;
+        while j < 8 {
+            fp = (fp >> 1) ^ (EMPTY_FINGERPRINT & (fp & 1).wrapping_neg());
+            j += 1;
+        }
+        table[i] = fp;
+        i += 1;
+    }
+    table
+};
+
+/// Computes the CRC-64-AVRO fingerprint of `data`
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#schema-fingerprints>
+fn rabin(data: &[u8]) -> u64 {
+    data.iter().fold(EMPTY_FINGERPRINT, |fp, b| {
+        (fp >> 8) ^ RABIN_TABLE[((fp ^ *b as u64) & 0xff) as usize]
+    })
+}
+
+/// Returns the fullname of a named type, and the namespace of any types it encloses
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#names>
+fn full_name<'a>(name: &'a str, namespace: Option<&'a str>) -> (String, Option<&'a str>) {
+    match name.rsplit_once('.') {
+        Some((ns, _)) => (name.to_string(), Some(ns)),
+        None => match namespace.filter(|ns| !ns.is_empty()) {
+            Some(ns) => (format!("{ns}.{name}"), Some(ns)),
+            None => (name.to_string(), None),
+        },
+    }
+}
+
+/// Writes the Parsing Canonical Form of `schema` to `out`, stripping all attributes
+/// other than those that affect the encoding, and replacing names with fullnames
+fn write_canonical<'a>(out: &mut String, schema: &'a Schema<'a>, namespace: Option<&'a str>) {
+    let quote = |s: &str| Value::from(s).to_string();
+    match schema {
+        Schema::TypeName(t) | Schema::Type(Type { r#type: t, .. }) => match t {
+            TypeName::Primitive(p) => out.push_str(&json!(p).to_string()),
+            TypeName::Ref(name) => out.push_str(&quote(&full_name(name, namespace).0)),
+        },
+        Schema::Union(variants) => {
+            out.push('[');
+            for (idx, variant) in variants.iter().enumerate() {
+                if idx != 0 {
+                    out.push(',');
+                }
+                write_canonical(out, variant, namespace);
+            }
+            out.push(']');
+        }
+        Schema::Complex(ComplexType::Record(r)) => {
+            let (name, namespace) = full_name(r.name, r.namespace.or(namespace));
+            out.push_str(&format!(
+                r#"{{"name":{},"type":"record","fields":["#,
+                quote(&name)
+            ));
+            for (idx, field) in r.fields.iter().enumerate() {
+                if idx != 0 {
+                    out.push(',');
+                }
+                out.push_str(&format!(r#"{{"name":{},"type":"#, quote(field.name)));
+                write_canonical(out, &field.r#type, namespace);
+                out.push('}');
+            }
+            out.push_str("]}");
+        }
+        Schema::Complex(ComplexType::Enum(e)) => {
+            let (name, _) = full_name(e.name, e.namespace.or(namespace));
+            let symbols: Vec<_> = e.symbols.iter().map(|s| quote(s)).collect();
+            out.push_str(&format!(
+                r#"{{"name":{},"type":"enum","symbols":[{}]}}"#,
+                quote(&name),
+                symbols.join(",")
+            ));
+        }
+        Schema::Complex(ComplexType::Array(a)) => {
+            out.push_str(r#"{"type":"array","items":"#);
+            write_canonical(out, &a.items, namespace);
+            out.push('}');
+        }
+        Schema::Complex(ComplexType::Map(m)) => {
+            out.push_str(r#"{"type":"map","values":"#);
+            write_canonical(out, &m.values, namespace);
+            out.push('}');
+        }
+        Schema::Complex(ComplexType::Fixed(f)) => {
+            let (name, _) = full_name(f.name, f.namespace.or(namespace));
+            out.push_str(&format!(
+                r#"{{"name":{},"type":"fixed","size":{}}}"#,
+                quote(&name),
+                f.size
+            ));
+        }
+    }
+}
+
 /// Either a [`PrimitiveType`] or a reference to a previously defined named type
 ///
 /// <https://avro.apache.org/docs/1.11.1/specification/#names>
@@ -144,8 +342,22 @@ pub struct Field<'a> {
     pub doc: Option<&'a str>,
     #[serde(borrow)]
     pub r#type: Schema<'a>,
-    #[serde(borrow, default)]
-    pub default: Option<&'a str>,
+    /// The JSON encoded default value, used when reading data written without this field
+    ///
+    /// An explicit `null` default is represented as `Some(Value::Null)`
+    #[serde(
+        default,
+        deserialize_with = "deserialize_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default: Option<Value>,
+}
+
+/// Distinguishes an explicit `null` default from an absent default
+fn deserialize_default<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Value>, D::Error> {
+    Value::deserialize(deserializer).map(Some)
 }
 
 /// An enumeration
@@ -207,6 +419,140 @@ pub struct Fixed<'a> {
     pub attributes: Attributes<'a>,
 }
 
+/// Returns the JSON encoded Avro schema for a record with the given `name` and `fields`
+///
+/// This is the inverse of the mapping performed by [`AvroField`], that is a record written
+/// with this schema will be read back with the same arrow types
+///
+/// [`AvroField`]: crate::codec::AvroField
+pub(crate) fn record_to_avro_json(
+    name: &str,
+    namespace: Option<&str>,
+    fields: &[impl AsRef<ArrowField>],
+) -> Result<Value, ArrowError> {
+    // Nested named types are placed in a namespace derived from their parent
+    let child_namespace = match namespace {
+        Some(ns) => format!("{ns}.{name}"),
+        None => name.to_string(),
+    };
+    let fields = fields
+        .iter()
+        .map(|f| {
+            let f = f.as_ref();
+            Ok(json!({
+                "name": f.name(),
+                "type": field_to_avro_json(f, &child_namespace)?,
+            }))
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    let mut record = json!({
+        "type": "record",
+        "name": name,
+        "fields": fields,
+    });
+    if let Some(namespace) = namespace {
+        record["namespace"] = json!(namespace);
+    }
+    Ok(record)
+}
+
+/// Returns the JSON encoded Avro schema of `field`, wrapping it in a union with
+/// `"null"` if it is nullable
+fn field_to_avro_json(field: &ArrowField, namespace: &str) -> Result<Value, ArrowError> {
+    let name = field.name();
+    let schema = match field.data_type() {
+        DataType::Null => return Ok(json!("null")),
+        DataType::Boolean => json!("boolean"),
+        DataType::Int32 => json!("int"),
+        DataType::Int64 => json!("long"),
+        DataType::Float32 => json!("float"),
+        DataType::Float64 => json!("double"),
+        DataType::Binary => json!("bytes"),
+        DataType::Utf8 => json!("string"),
+        DataType::Date32 => json!({"type": "int", "logicalType": "date"}),
+        DataType::Time32(TimeUnit::Millisecond) => {
+            json!({"type": "int", "logicalType": "time-millis"})
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            json!({"type": "long", "logicalType": "time-micros"})
+        }
+        DataType::Timestamp(unit, tz) => {
+            let logical_type = match (unit, tz.is_some()) {
+                (TimeUnit::Millisecond, true) => "timestamp-millis",
+                (TimeUnit::Millisecond, false) => "local-timestamp-millis",
+                (TimeUnit::Microsecond, true) => "timestamp-micros",
+                (TimeUnit::Microsecond, false) => "local-timestamp-micros",
+                (TimeUnit::Nanosecond, true) => "timestamp-nanos",
+                (TimeUnit::Nanosecond, false) => "local-timestamp-nanos",
+                (TimeUnit::Second, _) => {
+                    return Err(ArrowError::NotYetImplemented(
+                        "Writing second precision timestamps to Avro is not supported".to_string(),
+                    ))
+                }
+            };
+            json!({"type": "long", "logicalType": logical_type})
+        }
+        DataType::Decimal128(precision, scale) | DataType::Decimal256(precision, scale) => {
+            if *scale < 0 {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Writing decimal with negative scale {scale} to Avro is not supported"
+                )));
+            }
+            json!({
+                "type": "bytes",
+                "logicalType": "decimal",
+                "precision": precision,
+                "scale": scale,
+            })
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => json!({
+            "type": "fixed",
+            "name": name,
+            "namespace": namespace,
+            "size": 12,
+            "logicalType": "duration",
+        }),
+        DataType::FixedSizeBinary(size) => json!({
+            "type": "fixed",
+            "name": name,
+            "namespace": namespace,
+            "size": size,
+        }),
+        DataType::List(item) => json!({
+            "type": "array",
+            "items": field_to_avro_json(item, &format!("{namespace}.{name}"))?,
+        }),
+        DataType::Map(entries, _) => {
+            let (key, value) = match entries.data_type() {
+                DataType::Struct(f) if f.len() == 2 => (&f[0], &f[1]),
+                d => return Err(ArrowError::InvalidArgumentError(format!("Invalid map {d}"))),
+            };
+            if key.data_type() != &DataType::Utf8 {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Writing map with {} keys to Avro is not supported",
+                    key.data_type()
+                )));
+            }
+            json!({
+                "type": "map",
+                "values": field_to_avro_json(value, &format!("{namespace}.{name}"))?,
+            })
+        }
+        DataType::Struct(fields) => record_to_avro_json(name, Some(namespace), fields)?,
+        d => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Writing {d} to Avro is not currently supported"
+            )))
+        }
+    };
+
+    Ok(match field.is_nullable() {
+        true => json!(["null", schema]),
+        false => schema,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -363,9 +709,22 @@ mod tests {
             }))
         );
 
-        // Recursive schema are not supported
-        let err = AvroField::try_from(&schema).unwrap_err().to_string();
-        assert_eq!(err, "Parser error: Failed to resolve .LongList");
+        // Recursive references beyond the recursion depth are decoded as JSON
+        let field = AvroField::try_from(&schema).unwrap().field();
+        let next = ArrowField::new("next", DataType::Utf8, true)
+            .with_metadata([("ARROW:extension:name".to_string(), "arrow.json".to_string())].into());
+        let expected = DataType::Struct(Fields::from(vec![
+            ArrowField::new("value", DataType::Int64, false),
+            next.clone(),
+        ]));
+        assert_eq!(field.data_type(), &expected);
+
+        let field = AvroField::try_new(&schema, 1).unwrap().field();
+        let nested = DataType::Struct(Fields::from(vec![
+            ArrowField::new("value", DataType::Int64, false),
+            ArrowField::new("next", expected, true),
+        ]));
+        assert_eq!(field.data_type(), &nested);
 
         let schema: Schema = serde_json::from_str(
             r#"{
@@ -509,4 +868,115 @@ mod tests {
             }))
         );
     }
+
+    #[test]
+    fn test_decimal() {
+        let decimal = |t: &str| {
+            let json = format!(
+                r#"{{"type": "record", "name": "r", "fields": [{{"name": "d", "type": {t}}}]}}"#
+            );
+            let schema: Schema = serde_json::from_str(&json).unwrap();
+            AvroField::try_from(&schema).map(|f| f.field())
+        };
+
+        let field = decimal(r#"{"type": "bytes", "logicalType": "decimal", "precision": 4}"#);
+        let expected = DataType::Struct(Fields::from(vec![arrow_schema::Field::new(
+            "d",
+            DataType::Decimal128(4, 0),
+            false,
+        )]));
+        assert_eq!(field.unwrap().data_type(), &expected);
+
+        let err = decimal(r#"{"type": "bytes", "logicalType": "decimal"}"#).unwrap_err();
+        assert_eq!(err.to_string(), "Parser error: Decimal requires precision");
+
+        let err =
+            decimal(r#"{"type": "bytes", "logicalType": "decimal", "precision": 2, "scale": 3}"#)
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Decimal scale 3 cannot exceed precision 2"
+        );
+
+        let err = decimal(
+            r#"{"type": "fixed", "name": "f", "size": 2, "logicalType": "decimal", "precision": 5}"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Decimal precision 5 exceeds maximum of 4 for fixed of size 2"
+        );
+    }
+
+    #[test]
+    fn test_canonical_form() {
+        let schema = AvroSchema::new(
+            r#"{
+                "type": "record",
+                "name": "r",
+                "namespace": "org.example",
+                "doc": "A record",
+                "aliases": ["s"],
+                "fields": [
+                    {"name": "a", "type": {"type": "int", "logicalType": "date"}, "default": 0},
+                    {"name": "b", "type": ["null", {
+                        "type": "record",
+                        "name": "inner",
+                        "fields": [
+                            {"name": "f", "type": {"type": "fixed", "name": "other.f", "size": 4}},
+                            {"name": "e", "type": {"type": "enum", "name": "e", "symbols": ["X", "Y"]}}
+                        ]
+                    }]},
+                    {"name": "c", "type": {"type": "array", "items": "inner"}},
+                    {"name": "d", "type": {"type": "map", "values": "other.f"}},
+                    {"name": "g", "type": {"type": "e"}}
+                ]
+            }"#,
+        );
+        assert_eq!(
+            schema.canonical_form().unwrap(),
+            concat!(
+                r#"{"name":"org.example.r","type":"record","fields":["#,
+                r#"{"name":"a","type":"int"},"#,
+                r#"{"name":"b","type":["null",{"name":"org.example.inner","type":"record","fields":["#,
+                r#"{"name":"f","type":{"name":"other.f","type":"fixed","size":4}},"#,
+                r#"{"name":"e","type":{"name":"org.example.e","type":"enum","symbols":["X","Y"]}}]}]},"#,
+                r#"{"name":"c","type":{"type":"array","items":"org.example.inner"}},"#,
+                r#"{"name":"d","type":{"type":"map","values":"other.f"}},"#,
+                r#"{"name":"g","type":"org.example.e"}]}"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        // Test vectors from the Avro specification test suite
+        let null = AvroSchema::new(r#"{"type": "null"}"#);
+        assert_eq!(null.canonical_form().unwrap(), r#""null""#);
+        assert_eq!(
+            null.fingerprint().unwrap(),
+            Fingerprint::Rabin(7195948357588979594)
+        );
+        let int = AvroSchema::new(r#""int""#);
+        assert_eq!(
+            int.fingerprint().unwrap(),
+            Fingerprint::Rabin(8247732601305521295)
+        );
+
+        // Schemas that differ only in attributes that do not affect the encoding
+        let a = AvroSchema::new(
+            r#"{"type":"record","name":"r","fields":[{"name":"a","type":"long"}]}"#,
+        );
+        let b = AvroSchema::new(
+            r#"{"type": "record", "name": "r", "doc": "docs", "fields": [{"name": "a", "type": "long", "default": 1}]}"#,
+        );
+        assert_eq!(a.fingerprint().unwrap(), b.fingerprint().unwrap());
+
+        let mut store = SchemaStore::new();
+        let fingerprint = store.register(a.clone()).unwrap();
+        assert_eq!(store.lookup(&fingerprint), Some(&a));
+        assert_eq!(store.register_id(5, b.clone()), Fingerprint::Id(5));
+        assert_eq!(store.lookup(&Fingerprint::Id(5)), Some(&b));
+        assert_eq!(store.lookup(&Fingerprint::Id(6)), None);
+    }
 }
@@ -0,0 +1,289 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Encoder for Avro records
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{i256, IntervalMonthDayNano, NullBuffer};
+use arrow_schema::{ArrowError, DataType, Field, IntervalUnit, TimeUnit};
+
+/// Write `value` as a zig-zag encoded variable length integer
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#primitive-types-1>
+pub(crate) fn write_long(out: &mut Vec<u8>, value: i64) {
+    let mut n = ((value << 1) ^ (value >> 63)) as u64;
+    while n >= 0x80 {
+        out.push(0x80 | n as u8);
+        n >>= 7;
+    }
+    out.push(n as u8);
+}
+
+/// Write `value` prefixed by its length
+pub(crate) fn write_bytes(out: &mut Vec<u8>, value: &[u8]) {
+    write_long(out, value.len() as i64);
+    out.extend_from_slice(value);
+}
+
+/// Encodes the rows of a [`RecordBatch`] as Avro records
+#[derive(Debug)]
+pub(crate) struct RecordEncoder<'a> {
+    fields: Vec<FieldEncoder<'a>>,
+}
+
+impl<'a> RecordEncoder<'a> {
+    pub(crate) fn try_new(batch: &'a RecordBatch) -> Result<Self, ArrowError> {
+        let schema = batch.schema_ref();
+        let fields = schema
+            .fields()
+            .iter()
+            .zip(batch.columns())
+            .map(|(f, c)| FieldEncoder::try_new(c.as_ref(), f))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { fields })
+    }
+
+    /// Append the record at `idx` to `out`
+    pub(crate) fn encode(&self, out: &mut Vec<u8>, idx: usize) -> Result<(), ArrowError> {
+        for field in &self.fields {
+            field.encode(out, idx)?;
+        }
+        Ok(())
+    }
+}
+
+/// Encodes the values of an [`Array`] with the Avro schema derived from a [`Field`]
+#[derive(Debug)]
+struct FieldEncoder<'a> {
+    name: &'a str,
+    /// Whether this field is encoded as a union with `"null"`
+    nullable: bool,
+    nulls: Option<NullBuffer>,
+    encoder: Encoder<'a>,
+}
+
+#[derive(Debug)]
+enum Encoder<'a> {
+    Null,
+    Boolean(&'a BooleanArray),
+    Int(&'a [i32]),
+    Long(&'a [i64]),
+    Float(&'a [f32]),
+    Double(&'a [f64]),
+    Bytes(&'a [i32], &'a [u8]),
+    Fixed(&'a FixedSizeBinaryArray),
+    Decimal128(&'a [i128]),
+    Decimal256(&'a [i256]),
+    Duration(&'a [IntervalMonthDayNano]),
+    List(&'a [i32], Box<FieldEncoder<'a>>),
+    /// Map(offsets, key_offsets, key_data, values)
+    Map(&'a [i32], &'a [i32], &'a [u8], Box<FieldEncoder<'a>>),
+    Record(Vec<FieldEncoder<'a>>),
+}
+
+impl<'a> FieldEncoder<'a> {
+    fn try_new(array: &'a dyn Array, field: &'a Field) -> Result<Self, ArrowError> {
+        let encoder = match field.data_type() {
+            DataType::Null => Encoder::Null,
+            DataType::Boolean => Encoder::Boolean(array.as_boolean()),
+            DataType::Int32 => Encoder::Int(array.as_primitive::<Int32Type>().values()),
+            DataType::Date32 => Encoder::Int(array.as_primitive::<Date32Type>().values()),
+            DataType::Time32(TimeUnit::Millisecond) => {
+                Encoder::Int(array.as_primitive::<Time32MillisecondType>().values())
+            }
+            DataType::Int64 => Encoder::Long(array.as_primitive::<Int64Type>().values()),
+            DataType::Time64(TimeUnit::Microsecond) => {
+                Encoder::Long(array.as_primitive::<Time64MicrosecondType>().values())
+            }
+            DataType::Timestamp(TimeUnit::Millisecond, _) => {
+                Encoder::Long(array.as_primitive::<TimestampMillisecondType>().values())
+            }
+            DataType::Timestamp(TimeUnit::Microsecond, _) => {
+                Encoder::Long(array.as_primitive::<TimestampMicrosecondType>().values())
+            }
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+                Encoder::Long(array.as_primitive::<TimestampNanosecondType>().values())
+            }
+            DataType::Decimal128(_, _) => {
+                Encoder::Decimal128(array.as_primitive::<Decimal128Type>().values())
+            }
+            DataType::Decimal256(_, _) => {
+                Encoder::Decimal256(array.as_primitive::<Decimal256Type>().values())
+            }
+            DataType::Float32 => Encoder::Float(array.as_primitive::<Float32Type>().values()),
+            DataType::Float64 => Encoder::Double(array.as_primitive::<Float64Type>().values()),
+            DataType::Binary => {
+                let a = array.as_binary::<i32>();
+                Encoder::Bytes(a.value_offsets(), a.value_data())
+            }
+            DataType::Utf8 => {
+                let a = array.as_string::<i32>();
+                Encoder::Bytes(a.value_offsets(), a.value_data())
+            }
+            DataType::FixedSizeBinary(_) => Encoder::Fixed(array.as_fixed_size_binary()),
+            DataType::Interval(IntervalUnit::MonthDayNano) => {
+                Encoder::Duration(array.as_primitive::<IntervalMonthDayNanoType>().values())
+            }
+            DataType::List(item) => {
+                let a = array.as_list::<i32>();
+                let values = FieldEncoder::try_new(a.values().as_ref(), item)?;
+                Encoder::List(a.value_offsets(), Box::new(values))
+            }
+            DataType::Map(entries, _) => {
+                let a = array.as_map();
+                let value_field = match entries.data_type() {
+                    DataType::Struct(f) => &f[1],
+                    _ => unreachable!(),
+                };
+                let keys = a.keys().as_string::<i32>();
+                let values = FieldEncoder::try_new(a.values().as_ref(), value_field)?;
+                Encoder::Map(
+                    a.value_offsets(),
+                    keys.value_offsets(),
+                    keys.value_data(),
+                    Box::new(values),
+                )
+            }
+            DataType::Struct(fields) => {
+                let a = array.as_struct();
+                let fields = fields
+                    .iter()
+                    .zip(a.columns())
+                    .map(|(f, c)| FieldEncoder::try_new(c.as_ref(), f))
+                    .collect::<Result<_, _>>()?;
+                Encoder::Record(fields)
+            }
+            d => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Writing {d} to Avro is not currently supported"
+                )))
+            }
+        };
+
+        Ok(Self {
+            name: field.name(),
+            // The null type is not wrapped in a union
+            nullable: field.is_nullable() && !matches!(encoder, Encoder::Null),
+            nulls: array.logical_nulls(),
+            encoder,
+        })
+    }
+
+    fn encode(&self, out: &mut Vec<u8>, idx: usize) -> Result<(), ArrowError> {
+        let is_null = self.nulls.as_ref().map(|n| n.is_null(idx)).unwrap_or(false);
+        match (self.nullable, is_null) {
+            // Nullable fields are written as the union ["null", T]
+            (true, true) => {
+                write_long(out, 0);
+                return Ok(());
+            }
+            (true, false) => write_long(out, 1),
+            (false, true) if !matches!(self.encoder, Encoder::Null) => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Found null value in non-nullable field {}",
+                    self.name
+                )))
+            }
+            (false, _) => {}
+        }
+
+        match &self.encoder {
+            Encoder::Null => {}
+            Encoder::Boolean(a) => out.push(a.value(idx) as u8),
+            Encoder::Int(values) => write_long(out, values[idx] as i64),
+            Encoder::Long(values) => write_long(out, values[idx]),
+            Encoder::Float(values) => out.extend_from_slice(&values[idx].to_le_bytes()),
+            Encoder::Double(values) => out.extend_from_slice(&values[idx].to_le_bytes()),
+            Encoder::Bytes(offsets, values) => {
+                let start = offsets[idx] as usize;
+                let end = offsets[idx + 1] as usize;
+                write_bytes(out, &values[start..end])
+            }
+            Encoder::Fixed(a) => out.extend_from_slice(a.value(idx)),
+            Encoder::Decimal128(values) => write_decimal(out, &values[idx].to_be_bytes()),
+            Encoder::Decimal256(values) => write_decimal(out, &values[idx].to_be_bytes()),
+            Encoder::Duration(values) => {
+                let v = values[idx];
+                let (months, days, millis) = duration_parts(v).ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Cannot represent {v:?} as an Avro duration in field {}",
+                        self.name
+                    ))
+                })?;
+                out.extend_from_slice(&months.to_le_bytes());
+                out.extend_from_slice(&days.to_le_bytes());
+                out.extend_from_slice(&millis.to_le_bytes());
+            }
+            Encoder::List(offsets, values) => {
+                let start = offsets[idx] as usize;
+                let end = offsets[idx + 1] as usize;
+                if end > start {
+                    write_long(out, (end - start) as i64);
+                    for i in start..end {
+                        values.encode(out, i)?;
+                    }
+                }
+                write_long(out, 0);
+            }
+            Encoder::Map(offsets, key_offsets, key_data, values) => {
+                let start = offsets[idx] as usize;
+                let end = offsets[idx + 1] as usize;
+                if end > start {
+                    write_long(out, (end - start) as i64);
+                    for i in start..end {
+                        let key_start = key_offsets[i] as usize;
+                        let key_end = key_offsets[i + 1] as usize;
+                        write_bytes(out, &key_data[key_start..key_end]);
+                        values.encode(out, i)?;
+                    }
+                }
+                write_long(out, 0);
+            }
+            Encoder::Record(fields) => {
+                for field in fields {
+                    field.encode(out, idx)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Write the big-endian two's complement `value` using the minimum number of bytes
+fn write_decimal(out: &mut Vec<u8>, value: &[u8]) {
+    let fill = if value[0] & 0x80 != 0 { 0xFF } else { 0 };
+    // Retain a leading byte that preserves the sign
+    let skip = value
+        .windows(2)
+        .take_while(|w| w[0] == fill && (w[1] & 0x80) == (fill & 0x80))
+        .count();
+    write_bytes(out, &value[skip..]);
+}
+
+/// Avro durations are three unsigned 32-bit integers, with millisecond precision
+fn duration_parts(v: IntervalMonthDayNano) -> Option<(u32, u32, u32)> {
+    if v.nanoseconds % 1_000_000 != 0 {
+        return None;
+    }
+    Some((
+        v.months.try_into().ok()?,
+        v.days.try_into().ok()?,
+        (v.nanoseconds / 1_000_000).try_into().ok()?,
+    ))
+}
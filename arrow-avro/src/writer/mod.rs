@@ -0,0 +1,493 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Write Arrow data to Avro
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{Int32Array, RecordBatch};
+//! # use arrow_avro::compression::CompressionCodec;
+//! # use arrow_avro::writer::WriterBuilder;
+//! # use arrow_schema::{DataType, Field, Schema};
+//! let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+//! let batch = RecordBatch::try_new(
+//!     schema.clone(),
+//!     vec![Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]))],
+//! )
+//! .unwrap();
+//!
+//! let mut writer = WriterBuilder::new(schema)
+//!     .with_compression(Some(CompressionCodec::Snappy))
+//!     .build(Vec::new())
+//!     .unwrap();
+//! writer.write(&batch).unwrap();
+//! let avro = writer.into_inner();
+//! ```
+
+use crate::compression::{CompressionCodec, CODEC_METADATA_KEY};
+use crate::schema::{record_to_avro_json, SCHEMA_METADATA_KEY};
+use crate::writer::encoder::{write_bytes, write_long, RecordEncoder};
+use arrow_array::{RecordBatch, RecordBatchWriter};
+use arrow_schema::{ArrowError, SchemaRef};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io::Write;
+
+mod encoder;
+
+/// The name given to the top-level Avro record
+const RECORD_NAME: &str = "topLevelRecord";
+
+const MAGIC: &[u8; 4] = b"Obj\x01";
+
+/// Avro file writer builder
+#[derive(Debug, Clone)]
+pub struct WriterBuilder {
+    schema: SchemaRef,
+    compression: Option<CompressionCodec>,
+}
+
+impl WriterBuilder {
+    /// Create a new builder for writing [`RecordBatch`] with the provided schema
+    pub fn new(schema: SchemaRef) -> Self {
+        Self {
+            schema,
+            compression: None,
+        }
+    }
+
+    /// Set the [`CompressionCodec`] used for blocks, defaults to `None`
+    pub fn with_compression(mut self, compression: Option<CompressionCodec>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Create a new [`Writer`], writing the file header to `writer`
+    ///
+    /// Returns an error if the schema contains types that cannot be written to Avro
+    pub fn build<W: Write>(self, mut writer: W) -> Result<Writer<W>, ArrowError> {
+        let avro_schema = record_to_avro_json(RECORD_NAME, None, self.schema.fields())?;
+        let codec = self.compression.map(|c| c.name()).unwrap_or("null");
+        let sync = sync_marker();
+
+        let mut header = MAGIC.to_vec();
+        write_long(&mut header, 2);
+        write_bytes(&mut header, SCHEMA_METADATA_KEY.as_bytes());
+        write_bytes(&mut header, avro_schema.to_string().as_bytes());
+        write_bytes(&mut header, CODEC_METADATA_KEY.as_bytes());
+        write_bytes(&mut header, codec.as_bytes());
+        write_long(&mut header, 0);
+        header.extend_from_slice(&sync);
+        writer.write_all(&header)?;
+
+        Ok(Writer {
+            writer,
+            schema: self.schema,
+            compression: self.compression,
+            sync,
+            buffer: Vec::with_capacity(1024),
+        })
+    }
+}
+
+/// Writes [`RecordBatch`] to an Avro [Object Container File]
+///
+/// Each call to [`Writer::write`] writes a single block. See [`WriterBuilder`] to construct
+///
+/// [Object Container File]: https://avro.apache.org/docs/1.11.1/specification/#object-container-files
+#[derive(Debug)]
+pub struct Writer<W: Write> {
+    writer: W,
+    schema: SchemaRef,
+    compression: Option<CompressionCodec>,
+    sync: [u8; 16],
+    /// Scratch space for encoding blocks
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> Writer<W> {
+    /// Create a new [`Writer`] with no compression
+    pub fn try_new(writer: W, schema: SchemaRef) -> Result<Self, ArrowError> {
+        WriterBuilder::new(schema).build(writer)
+    }
+
+    /// Returns the schema of this writer
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Write a [`RecordBatch`] as a single block
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        if batch.schema_ref().fields() != self.schema.fields() {
+            return Err(ArrowError::SchemaError(
+                "Cannot write record batch with different schema".to_string(),
+            ));
+        }
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        self.buffer.clear();
+        let encoder = RecordEncoder::try_new(batch)?;
+        for idx in 0..batch.num_rows() {
+            encoder.encode(&mut self.buffer, idx)?;
+        }
+
+        let compressed;
+        let data = match self.compression {
+            Some(c) => {
+                compressed = c.compress(&self.buffer)?;
+                &compressed
+            }
+            None => &self.buffer,
+        };
+
+        let mut prefix = Vec::with_capacity(20);
+        write_long(&mut prefix, batch.num_rows() as i64);
+        write_long(&mut prefix, data.len() as i64);
+        self.writer.write_all(&prefix)?;
+        self.writer.write_all(data)?;
+        self.writer.write_all(&self.sync)?;
+        Ok(())
+    }
+
+    /// Flush the underlying writer
+    pub fn flush(&mut self) -> Result<(), ArrowError> {
+        Ok(self.writer.flush()?)
+    }
+
+    /// Gets a reference to the underlying writer
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Gets a mutable reference to the underlying writer
+    ///
+    /// It is inadvisable to directly write to the underlying writer
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Unwraps this `Writer<W>`, returning the underlying writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> RecordBatchWriter for Writer<W> {
+    fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write(batch)
+    }
+
+    fn close(mut self) -> Result<(), ArrowError> {
+        self.flush()
+    }
+}
+
+/// Generates a random sync marker
+fn sync_marker() -> [u8; 16] {
+    let state = RandomState::new();
+    let mut sync = [0; 16];
+    for (idx, chunk) in sync.chunks_exact_mut(8).enumerate() {
+        let mut hasher = state.build_hasher();
+        hasher.write_usize(idx);
+        chunk.copy_from_slice(&hasher.finish().to_le_bytes());
+    }
+    sync
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderBuilder;
+    use arrow_array::builder::{
+        Int64Builder, ListBuilder, MapBuilder, MapFieldNames, StringBuilder,
+    };
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::*;
+    use arrow_array::*;
+    use arrow_buffer::{i256, IntervalMonthDayNano, NullBuffer};
+    use arrow_schema::{DataType, Field, Fields, IntervalUnit, Schema, TimeUnit};
+    use std::sync::Arc;
+
+    fn round_trip(batch: &RecordBatch, compression: Option<CompressionCodec>) -> RecordBatch {
+        let mut writer = WriterBuilder::new(batch.schema())
+            .with_compression(compression)
+            .build(Vec::new())
+            .unwrap();
+        writer.write(batch).unwrap();
+        writer.write(batch).unwrap();
+        let buf = writer.into_inner();
+
+        let reader = ReaderBuilder::new().build(buf.as_slice()).unwrap();
+        let schema = reader.schema();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let read = arrow_select::concat::concat_batches(&schema, &batches).unwrap();
+        assert_eq!(read.num_rows(), batch.num_rows() * 2);
+        read.slice(0, batch.num_rows())
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut list = ListBuilder::new(StringBuilder::new());
+        list.append_value([Some("a"), None]);
+        list.append_null();
+        list.append_value(Vec::<Option<&str>>::new());
+        list.append_value([Some("bc")]);
+        let list = list.finish();
+
+        let struct_fields = Fields::from(vec![
+            Field::new("x", DataType::Float64, false),
+            Field::new("y", DataType::Boolean, true),
+        ]);
+        let structs = StructArray::new(
+            struct_fields.clone(),
+            vec![
+                Arc::new(Float64Array::from(vec![1.5, 0., -2., 3.25])),
+                Arc::new(BooleanArray::from(vec![
+                    Some(true),
+                    None,
+                    Some(false),
+                    None,
+                ])),
+            ],
+            Some(NullBuffer::from(vec![true, false, true, true])),
+        );
+
+        let names = MapFieldNames {
+            entry: "entries".to_string(),
+            key: "key".to_string(),
+            value: "value".to_string(),
+        };
+        let mut map = MapBuilder::new(Some(names), StringBuilder::new(), Int64Builder::new());
+        map.keys().append_value("a");
+        map.values().append_value(1);
+        map.keys().append_value("b");
+        map.values().append_null();
+        map.append(true).unwrap();
+        map.append(false).unwrap();
+        map.append(true).unwrap();
+        map.keys().append_value("c");
+        map.values().append_value(3);
+        map.append(true).unwrap();
+        let map = map.finish();
+
+        let batch = RecordBatch::try_from_iter_with_nullable([
+            ("null", Arc::new(NullArray::new(4)) as ArrayRef, true),
+            (
+                "int",
+                Arc::new(Int32Array::from(vec![
+                    Some(1),
+                    None,
+                    Some(-3),
+                    Some(i32::MAX),
+                ])) as _,
+                true,
+            ),
+            (
+                "long",
+                Arc::new(Int64Array::from(vec![i64::MIN, 0, 5, i64::MAX])) as _,
+                false,
+            ),
+            (
+                "float",
+                Arc::new(Float32Array::from(vec![1.5, f32::NAN, 0., -1.])) as _,
+                false,
+            ),
+            (
+                "string",
+                Arc::new(StringArray::from(vec![
+                    Some("foo"),
+                    Some(""),
+                    None,
+                    Some("bar"),
+                ])) as _,
+                true,
+            ),
+            (
+                "binary",
+                Arc::new(BinaryArray::from_vec(vec![b"a", b"", b"bc", b"def"])) as _,
+                false,
+            ),
+            (
+                "date",
+                Arc::new(Date32Array::from(vec![0, 1, -1, 19000])) as _,
+                false,
+            ),
+            (
+                "time_ms",
+                Arc::new(Time32MillisecondArray::from(vec![0, 1, 2, 3])) as _,
+                false,
+            ),
+            (
+                "time_us",
+                Arc::new(Time64MicrosecondArray::from(vec![0, 1, 2, 3])) as _,
+                false,
+            ),
+            (
+                "ts_ms",
+                Arc::new(TimestampMillisecondArray::from(vec![0, 1, 2, 3]).with_timezone("+00:00"))
+                    as _,
+                false,
+            ),
+            (
+                "ts_us",
+                Arc::new(TimestampMicrosecondArray::from(vec![0, 1, 2, 3])) as _,
+                false,
+            ),
+            (
+                "fixed",
+                Arc::new(
+                    FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                        vec![Some(b"ab"), None, Some(b"cd"), Some(b"ef")].into_iter(),
+                        2,
+                    )
+                    .unwrap(),
+                ) as _,
+                true,
+            ),
+            (
+                "duration",
+                Arc::new(IntervalMonthDayNanoArray::from(vec![
+                    IntervalMonthDayNano::new(1, 2, 3_000_000),
+                    IntervalMonthDayNano::new(0, 0, 0),
+                    IntervalMonthDayNano::new(12, 31, 86_400_000_000_000),
+                    IntervalMonthDayNano::new(0, 1, 0),
+                ])) as _,
+                false,
+            ),
+            (
+                "ts_ns",
+                Arc::new(TimestampNanosecondArray::from(vec![0, 1, 2, 3]).with_timezone("+00:00"))
+                    as _,
+                false,
+            ),
+            (
+                "decimal128",
+                Arc::new(
+                    Decimal128Array::from(vec![Some(0), Some(-1), None, Some(i128::MAX / 10)])
+                        .with_precision_and_scale(38, 2)
+                        .unwrap(),
+                ) as _,
+                true,
+            ),
+            (
+                "decimal256",
+                Arc::new(
+                    Decimal256Array::from(vec![
+                        i256::from_i128(128),
+                        i256::from_i128(-129),
+                        i256::MAX,
+                        i256::MIN,
+                    ])
+                    .with_precision_and_scale(76, 10)
+                    .unwrap(),
+                ) as _,
+                false,
+            ),
+            ("list", Arc::new(list) as _, true),
+            ("map", Arc::new(map) as _, true),
+            ("struct", Arc::new(structs) as _, true),
+        ])
+        .unwrap();
+
+        for compression in [
+            None,
+            Some(CompressionCodec::Deflate),
+            Some(CompressionCodec::Snappy),
+            Some(CompressionCodec::ZStandard),
+        ] {
+            let read = round_trip(&batch, compression);
+            assert_eq!(read.schema().as_ref(), batch.schema().as_ref());
+            // NaN != NaN, so compare the float column separately
+            for (idx, (a, b)) in read.columns().iter().zip(batch.columns()).enumerate() {
+                if idx == 3 {
+                    let a = a.as_primitive::<Float32Type>();
+                    let b = b.as_primitive::<Float32Type>();
+                    assert!(a
+                        .iter()
+                        .zip(b)
+                        .all(|(a, b)| a.unwrap().total_cmp(&b.unwrap()).is_eq()));
+                } else {
+                    assert_eq!(a, b, "{compression:?}: {}", batch.schema().field(idx));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new_list("b", Field::new("item", DataType::Utf8, false), true),
+            Field::new(
+                "c",
+                DataType::Struct(vec![Field::new("d", DataType::FixedSizeBinary(3), false)].into()),
+                true,
+            ),
+            Field::new("e", DataType::Timestamp(TimeUnit::Millisecond, None), false),
+        ]));
+        let writer = Writer::try_new(Vec::new(), schema).unwrap();
+        let buf = writer.into_inner();
+
+        let reader = ReaderBuilder::new().build(buf.as_slice()).unwrap();
+        let expected = r#"{"fields":[{"name":"a","type":"int"},{"name":"b","type":["null",{"items":"string","type":"array"}]},{"name":"c","type":["null",{"fields":[{"name":"d","type":{"name":"d","namespace":"topLevelRecord.c","size":3,"type":"fixed"}}],"name":"c","namespace":"topLevelRecord","type":"record"}]},{"name":"e","type":{"logicalType":"local-timestamp-millis","type":"long"}}],"name":"topLevelRecord","type":"record"}"#;
+        let header = crate::reader::read_header(buf.as_slice()).unwrap();
+        let actual = header.get(SCHEMA_METADATA_KEY).unwrap();
+        assert_eq!(std::str::from_utf8(actual).unwrap(), expected);
+        assert_eq!(header.get(CODEC_METADATA_KEY).unwrap(), b"null");
+        assert_eq!(reader.collect::<Vec<_>>().len(), 0);
+    }
+
+    #[test]
+    fn test_errors() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::UInt64, false)]));
+        let err = Writer::try_new(Vec::new(), schema).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: Writing UInt64 to Avro is not currently supported"
+        );
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            DataType::Interval(IntervalUnit::MonthDayNano),
+            false,
+        )]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(IntervalMonthDayNanoArray::from(vec![
+                IntervalMonthDayNano::new(0, 0, 1),
+            ]))],
+        )
+        .unwrap();
+        let mut writer = Writer::try_new(Vec::new(), schema).unwrap();
+        let err = writer.write(&batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot represent IntervalMonthDayNano { months: 0, days: 0, nanoseconds: 1 } as an Avro duration in field a"
+        );
+
+        let other =
+            RecordBatch::try_from_iter([("b", Arc::new(Int32Array::from(vec![1])) as ArrayRef)])
+                .unwrap();
+        let err = writer.write(&other).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Cannot write record batch with different schema"
+        );
+    }
+}
@@ -180,3 +180,75 @@ pub(crate) fn cast_list<I: OffsetSizeTrait, O: OffsetSizeTrait>(
         nulls,
     )))
 }
+
+/// Cast a List/LargeList array to a ListView/LargeListView array, referencing the same values
+pub(crate) fn cast_list_to_list_view<O: OffsetSizeTrait>(
+    array: &dyn Array,
+    to: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let list = array.as_list::<O>();
+    let values = cast_with_options(list.values(), to.data_type(), cast_options)?;
+    let offsets = list.offsets();
+    let sizes = offsets.windows(2).map(|w| w[1] - w[0]).collect();
+    let offsets = offsets.inner().slice(0, list.len());
+
+    Ok(Arc::new(GenericListViewArray::<O>::new(
+        to.clone(),
+        offsets,
+        sizes,
+        values,
+        list.nulls().cloned(),
+    )))
+}
+
+/// Cast a ListView/LargeListView array to a List/LargeList array
+///
+/// As list views may be out of order or overlap, the values of each
+/// non-null list view are copied in order to a new values array
+pub(crate) fn cast_list_view_to_list<O: OffsetSizeTrait>(
+    array: &dyn Array,
+    to: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let list = array.as_list_view::<O>();
+    let values = list.values().to_data();
+    let mut mutable = MutableArrayData::new(vec![&values], false, values.len());
+
+    let lengths = (0..list.len()).map(|i| match list.is_valid(i) {
+        true => {
+            let start = list.value_offset(i).as_usize();
+            let size = list.value_size(i).as_usize();
+            mutable.extend(0, start, start + size);
+            size
+        }
+        false => 0,
+    });
+    let offsets = OffsetBuffer::<O>::from_lengths(lengths);
+
+    let values = make_array(mutable.freeze());
+    let values = cast_with_options(values.as_ref(), to.data_type(), cast_options)?;
+    Ok(Arc::new(GenericListArray::<O>::new(
+        to.clone(),
+        offsets,
+        values,
+        list.nulls().cloned(),
+    )))
+}
+
+/// Helper function that takes a ListView/LargeListView array and casts the inner datatype.
+pub(crate) fn cast_list_view_values<O: OffsetSizeTrait>(
+    array: &dyn Array,
+    to: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let list = array.as_list_view::<O>();
+    let values = cast_with_options(list.values(), to.data_type(), cast_options)?;
+    Ok(Arc::new(GenericListViewArray::<O>::new(
+        to.clone(),
+        list.offsets().clone(),
+        list.sizes().clone(),
+        values,
+        list.nulls().cloned(),
+    )))
+}
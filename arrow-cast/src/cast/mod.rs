@@ -127,6 +127,8 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Utf8View
             | List(_)
             | LargeList(_)
+            | ListView(_)
+            | LargeListView(_)
             | FixedSizeList(_, _)
             | Struct(_)
             | Map(_, _)
@@ -141,6 +143,12 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (List(list_from) | LargeList(list_from), List(list_to) | LargeList(list_to)) => {
             can_cast_types(list_from.data_type(), list_to.data_type())
         }
+        (List(list_from), ListView(list_to))
+        | (LargeList(list_from), LargeListView(list_to))
+        | (ListView(list_from), List(list_to) | ListView(list_to))
+        | (LargeListView(list_from), LargeList(list_to) | LargeListView(list_to)) => {
+            can_cast_types(list_from.data_type(), list_to.data_type())
+        }
         (List(list_from) | LargeList(list_from), Utf8 | LargeUtf8) => {
             can_cast_types(list_from.data_type(), to_type)
         }
@@ -715,6 +723,8 @@ pub fn cast_with_options(
             | Utf8View
             | List(_)
             | LargeList(_)
+            | ListView(_)
+            | LargeListView(_)
             | FixedSizeList(_, _)
             | Struct(_)
             | Map(_, _)
@@ -758,6 +768,14 @@ pub fn cast_with_options(
             let array = array.as_list::<i64>();
             cast_list_to_fixed_size_list::<i64>(array, field, *size, cast_options)
         }
+        (List(_), ListView(to)) => cast_list_to_list_view::<i32>(array, to, cast_options),
+        (LargeList(_), LargeListView(to)) => cast_list_to_list_view::<i64>(array, to, cast_options),
+        (ListView(_), List(to)) => cast_list_view_to_list::<i32>(array, to, cast_options),
+        (LargeListView(_), LargeList(to)) => cast_list_view_to_list::<i64>(array, to, cast_options),
+        (ListView(_), ListView(to)) => cast_list_view_values::<i32>(array, to, cast_options),
+        (LargeListView(_), LargeListView(to)) => {
+            cast_list_view_values::<i64>(array, to, cast_options)
+        }
         (List(_) | LargeList(_), _) => match to_type {
             Utf8 => value_to_string::<i32>(array, cast_options),
             LargeUtf8 => value_to_string::<i64>(array, cast_options),
@@ -2419,7 +2437,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use arrow_buffer::{Buffer, IntervalDayTime, NullBuffer};
+    use arrow_buffer::{Buffer, IntervalDayTime, NullBuffer, ScalarBuffer};
     use chrono::NaiveDate;
     use half::f16;
 
@@ -6801,6 +6819,11 @@ mod tests {
         cast_from_null_to_other(&data_type);
         let data_type = DataType::LargeList(Arc::new(Field::new("item", DataType::Int32, true)));
         cast_from_null_to_other(&data_type);
+        let data_type = DataType::ListView(Arc::new(Field::new("item", DataType::Int32, true)));
+        cast_from_null_to_other(&data_type);
+        let data_type =
+            DataType::LargeListView(Arc::new(Field::new("item", DataType::Int32, true)));
+        cast_from_null_to_other(&data_type);
         let data_type =
             DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Int32, true)), 4);
         cast_from_null_to_other(&data_type);
@@ -7157,6 +7180,72 @@ mod tests {
         assert_eq!(&expected.value(2), &actual.value(2));
     }
 
+    #[test]
+    fn test_cast_list_to_list_view() {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(0), Some(1), Some(2)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(3), None]),
+        ]);
+        let list = list.slice(1, 3);
+        let field = Arc::new(Field::new("item", DataType::Int64, true));
+        let to_type = DataType::ListView(field.clone());
+        assert!(can_cast_types(list.data_type(), &to_type));
+
+        let list_view = cast(&list, &to_type).unwrap();
+        let list_view = list_view.as_list_view::<i32>();
+        assert_eq!(list_view.value_offsets(), &[3, 3, 3]);
+        assert_eq!(list_view.value_sizes(), &[0, 0, 2]);
+        assert!(list_view.is_null(0));
+        let expected = Int64Array::from(vec![Some(3), None]);
+        assert_eq!(list_view.value(2).as_ref(), &expected as &dyn Array);
+
+        // and back again
+        let back = cast(list_view, &DataType::List(field)).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            None,
+            Some(vec![]),
+            Some(vec![Some(3), None]),
+        ]);
+        assert_eq!(back.as_list::<i32>(), &expected);
+    }
+
+    #[test]
+    fn test_cast_list_view_to_list() {
+        // [[2, 3], null, [0, 1, 2], [1]]
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        let list_view = LargeListViewArray::new(
+            field,
+            ScalarBuffer::from(vec![2, 0, 0, 1]),
+            ScalarBuffer::from(vec![2, 4, 3, 1]),
+            Arc::new(Int32Array::from(vec![0, 1, 2, 3])),
+            Some(NullBuffer::from(vec![true, false, true, true])),
+        );
+        let to_type = DataType::LargeList(Arc::new(Field::new("item", DataType::Utf8, true)));
+        assert!(can_cast_types(list_view.data_type(), &to_type));
+        assert!(!can_cast_types(
+            list_view.data_type(),
+            &DataType::List(Arc::new(Field::new("item", DataType::Int32, true)))
+        ));
+
+        let list = cast(&list_view, &to_type).unwrap();
+        let list = list.as_list::<i64>();
+        assert_eq!(list.value_offsets(), &[0, 2, 2, 5, 6]);
+        assert!(list.is_null(1));
+        let values = list.values().as_string::<i32>();
+        let values: Vec<_> = values.iter().map(Option::unwrap).collect();
+        assert_eq!(values, ["2", "3", "0", "1", "2", "1"]);
+
+        // casting the values of a list view
+        let to_type = DataType::LargeListView(Arc::new(Field::new("item", DataType::Utf8, true)));
+        let casted = cast(&list_view, &to_type).unwrap();
+        let casted = casted.as_list_view::<i64>();
+        assert_eq!(casted.value_offsets(), list_view.value_offsets());
+        assert_eq!(casted.value_sizes(), list_view.value_sizes());
+        assert_eq!(casted.values().as_string::<i32>().value(3), "3");
+    }
+
     #[test]
     fn test_cast_list_to_fsl() {
         // There four noteworthy cases we should handle:
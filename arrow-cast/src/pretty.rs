@@ -291,6 +291,35 @@ mod tests {
         assert_eq!(expected, actual, "Actual result:\n{table}");
     }
 
+    #[test]
+    fn test_pretty_format_list_view() {
+        let mut builder = ListViewBuilder::new(Int32Builder::new());
+        builder.append_value([Some(1), None, Some(3)]);
+        builder.append_null();
+        builder.append_value([]);
+        let array = Arc::new(builder.finish()) as ArrayRef;
+
+        let batch = RecordBatch::try_from_iter([("d1", array)]).unwrap();
+        let table = pretty_format_batches(&[batch]).unwrap().to_string();
+        let expected = vec![
+            "+----------+",
+            "| d1       |",
+            "+----------+",
+            "| [1, , 3] |",
+            "|          |",
+            "| []       |",
+            "+----------+",
+        ];
+
+        let actual: Vec<&str> = table.lines().collect();
+
+        assert_eq!(
+            expected, actual,
+            "Actual result:
+{table}"
+        );
+    }
+
     #[test]
     fn test_pretty_format_fixed_size_list() {
         // define a schema.
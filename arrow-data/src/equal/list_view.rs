@@ -0,0 +1,83 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::data::{count_nulls, ArrayData};
+use arrow_buffer::ArrowNativeType;
+use num::Integer;
+
+use super::equal_range;
+
+pub(super) fn list_view_equal<T: ArrowNativeType + Integer>(
+    lhs: &ArrayData,
+    rhs: &ArrayData,
+    lhs_start: usize,
+    rhs_start: usize,
+    len: usize,
+) -> bool {
+    let lhs_offsets = lhs.buffer::<T>(0);
+    let lhs_sizes = lhs.buffer::<T>(1);
+    let rhs_offsets = rhs.buffer::<T>(0);
+    let rhs_sizes = rhs.buffer::<T>(1);
+
+    let lhs_values = &lhs.child_data()[0];
+    let rhs_values = &rhs.child_data()[0];
+
+    // unlike lists, the values of adjacent list views need not be contiguous,
+    // so are compared item by item
+    let value_equal = |lhs_pos: usize, rhs_pos: usize| {
+        let lhs_size = lhs_sizes[lhs_pos].as_usize();
+        let rhs_size = rhs_sizes[rhs_pos].as_usize();
+
+        lhs_size == rhs_size
+            && equal_range(
+                lhs_values,
+                rhs_values,
+                lhs_offsets[lhs_pos].as_usize(),
+                rhs_offsets[rhs_pos].as_usize(),
+                lhs_size,
+            )
+    };
+
+    let lhs_null_count = count_nulls(lhs.nulls(), lhs_start, len);
+    let rhs_null_count = count_nulls(rhs.nulls(), rhs_start, len);
+
+    if lhs_null_count != rhs_null_count {
+        return false;
+    }
+
+    if lhs_null_count == 0 {
+        (0..len).all(|i| value_equal(lhs_start + i, rhs_start + i))
+    } else {
+        // get a ref of the parent null buffer bytes, to use in testing for nullness
+        let lhs_nulls = lhs.nulls().unwrap();
+        let rhs_nulls = rhs.nulls().unwrap();
+
+        (0..len).all(|i| {
+            let lhs_pos = lhs_start + i;
+            let rhs_pos = rhs_start + i;
+
+            let lhs_is_null = lhs_nulls.is_null(lhs_pos);
+            let rhs_is_null = rhs_nulls.is_null(rhs_pos);
+
+            if lhs_is_null != rhs_is_null {
+                return false;
+            }
+
+            lhs_is_null || value_equal(lhs_pos, rhs_pos)
+        })
+    }
+}
@@ -16,13 +16,16 @@
 // under the License.
 
 use crate::data::ArrayData;
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::DataType;
 
 use super::equal_range;
 
-/// The current implementation of comparison of run array support physical comparison.
-/// Comparing run encoded array based on logical indices (`lhs_start`, `rhs_start`) will
-/// be time consuming as converting from logical index to physical index cannot be done
-/// in constant time. The current comparison compares the underlying physical arrays.
+/// Logically compares two run end encoded arrays.
+///
+/// The arrays may have arbitrary offsets and differing run boundaries, with the
+/// comparison walking the runs of both arrays in lockstep, comparing the values
+/// of each overlapping pair of runs once.
 pub(super) fn run_equal(
     lhs: &ArrayData,
     rhs: &ArrayData,
@@ -30,57 +33,58 @@ pub(super) fn run_equal(
     rhs_start: usize,
     len: usize,
 ) -> bool {
-    if lhs_start != 0
-        || rhs_start != 0
-        || (lhs.len() != len && rhs.len() != len)
-        || lhs.offset() > 0
-        || rhs.offset() > 0
-    {
-        unimplemented!("Logical comparison for run array not supported.")
+    match lhs.child_data()[0].data_type() {
+        DataType::Int16 => run_equal_impl::<i16>(lhs, rhs, lhs_start, rhs_start, len),
+        DataType::Int32 => run_equal_impl::<i32>(lhs, rhs, lhs_start, rhs_start, len),
+        DataType::Int64 => run_equal_impl::<i64>(lhs, rhs, lhs_start, rhs_start, len),
+        d => unreachable!("invalid run ends data type {d}"),
     }
+}
 
-    if lhs.len() != rhs.len() {
-        return false;
-    }
+/// Returns the run ends of the provided run end encoded array
+fn run_ends<T: ArrowNativeType>(data: &ArrayData) -> &[T] {
+    let run_ends = &data.child_data()[0];
+    &run_ends.buffer::<T>(0)[..run_ends.len()]
+}
 
-    let lhs_child_data = lhs.child_data();
-    let lhs_run_ends_array = &lhs_child_data[0];
-    let lhs_values_array = &lhs_child_data[1];
+fn run_equal_impl<T: ArrowNativeType>(
+    lhs: &ArrayData,
+    rhs: &ArrayData,
+    lhs_start: usize,
+    rhs_start: usize,
+    len: usize,
+) -> bool {
+    let lhs_run_ends = run_ends::<T>(lhs);
+    let rhs_run_ends = run_ends::<T>(rhs);
+    let lhs_values = &lhs.child_data()[1];
+    let rhs_values = &rhs.child_data()[1];
 
-    let rhs_child_data = rhs.child_data();
-    let rhs_run_ends_array = &rhs_child_data[0];
-    let rhs_values_array = &rhs_child_data[1];
+    // Logical positions within the run ends
+    let mut lhs_pos = lhs.offset() + lhs_start;
+    let mut rhs_pos = rhs.offset() + rhs_start;
+    let lhs_end = lhs_pos + len;
 
-    if lhs_run_ends_array.len() != rhs_run_ends_array.len() {
-        return false;
-    }
+    // Physical indices of the runs containing the current positions
+    let mut lhs_idx = lhs_run_ends.partition_point(|x| x.as_usize() <= lhs_pos);
+    let mut rhs_idx = rhs_run_ends.partition_point(|x| x.as_usize() <= rhs_pos);
 
-    if lhs_values_array.len() != rhs_values_array.len() {
-        return false;
-    }
+    while lhs_pos < lhs_end {
+        if !equal_range(lhs_values, rhs_values, lhs_idx, rhs_idx, 1) {
+            return false;
+        }
 
-    // check run ends array are equal. The length of the physical array
-    // is used to validate the child arrays.
-    let run_ends_equal = equal_range(
-        lhs_run_ends_array,
-        rhs_run_ends_array,
-        lhs_start,
-        rhs_start,
-        lhs_run_ends_array.len(),
-    );
+        let lhs_run_end = lhs_run_ends[lhs_idx].as_usize();
+        let rhs_run_end = rhs_run_ends[rhs_idx].as_usize();
+        let step = (lhs_run_end - lhs_pos).min(rhs_run_end - rhs_pos);
 
-    // if run ends array are not the same return early without validating
-    // values array.
-    if !run_ends_equal {
-        return false;
+        lhs_pos += step;
+        rhs_pos += step;
+        if lhs_pos == lhs_run_end {
+            lhs_idx += 1;
+        }
+        if rhs_pos == rhs_run_end {
+            rhs_idx += 1;
+        }
     }
-
-    // check values array are equal
-    equal_range(
-        lhs_values_array,
-        rhs_values_array,
-        lhs_start,
-        rhs_start,
-        rhs_values_array.len(),
-    )
+    true
 }
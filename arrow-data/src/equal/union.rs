@@ -20,66 +20,28 @@ use arrow_schema::{DataType, UnionFields, UnionMode};
 
 use super::equal_range;
 
-#[allow(clippy::too_many_arguments)]
-fn equal_dense(
-    lhs: &ArrayData,
-    rhs: &ArrayData,
-    lhs_type_ids: &[i8],
-    rhs_type_ids: &[i8],
-    lhs_offsets: &[i32],
-    rhs_offsets: &[i32],
-    lhs_fields: &UnionFields,
-    rhs_fields: &UnionFields,
-) -> bool {
-    let offsets = lhs_offsets.iter().zip(rhs_offsets.iter());
-
-    lhs_type_ids
-        .iter()
-        .zip(rhs_type_ids.iter())
-        .zip(offsets)
-        .all(|((l_type_id, r_type_id), (l_offset, r_offset))| {
-            let lhs_child_index = lhs_fields
-                .iter()
-                .position(|(r, _)| r == *l_type_id)
-                .unwrap();
-            let rhs_child_index = rhs_fields
-                .iter()
-                .position(|(r, _)| r == *r_type_id)
-                .unwrap();
-            let lhs_values = &lhs.child_data()[lhs_child_index];
-            let rhs_values = &rhs.child_data()[rhs_child_index];
+/// Returns the index of the child array corresponding to `type_id`
+fn child_index(fields: &UnionFields, type_id: i8) -> usize {
+    fields.iter().position(|(t, _)| t == type_id).unwrap()
+}
 
-            equal_range(
-                lhs_values,
-                rhs_values,
-                *l_offset as usize,
-                *r_offset as usize,
-                1,
-            )
-        })
+/// Returns the position within its child array of the element at `idx`
+fn child_offset(data: &ArrayData, mode: UnionMode, idx: usize) -> usize {
+    match mode {
+        UnionMode::Dense => data.buffer::<i32>(1)[idx] as usize,
+        UnionMode::Sparse => data.offset() + idx,
+    }
 }
 
-fn equal_sparse(
-    lhs: &ArrayData,
-    rhs: &ArrayData,
-    lhs_start: usize,
-    rhs_start: usize,
-    len: usize,
-) -> bool {
-    lhs.child_data()
-        .iter()
-        .zip(rhs.child_data())
-        .all(|(lhs_values, rhs_values)| {
-            equal_range(
-                lhs_values,
-                rhs_values,
-                lhs_start + lhs.offset(),
-                rhs_start + rhs.offset(),
-                len,
-            )
-        })
+fn union_fields(data: &ArrayData) -> (&UnionFields, UnionMode) {
+    match data.data_type() {
+        DataType::Union(fields, mode) => (fields, *mode),
+        _ => unreachable!(),
+    }
 }
 
+/// Logically compares two union arrays, comparing only the child value
+/// selected by each element's type id
 pub(super) fn union_equal(
     lhs: &ArrayData,
     rhs: &ArrayData,
@@ -87,41 +49,49 @@ pub(super) fn union_equal(
     rhs_start: usize,
     len: usize,
 ) -> bool {
-    let lhs_type_ids = lhs.buffer::<i8>(0);
-    let rhs_type_ids = rhs.buffer::<i8>(0);
+    let lhs_type_ids = &lhs.buffer::<i8>(0)[lhs_start..lhs_start + len];
+    let rhs_type_ids = &rhs.buffer::<i8>(0)[rhs_start..rhs_start + len];
 
-    let lhs_type_id_range = &lhs_type_ids[lhs_start..lhs_start + len];
-    let rhs_type_id_range = &rhs_type_ids[rhs_start..rhs_start + len];
+    if lhs_type_ids != rhs_type_ids {
+        return false;
+    }
 
-    match (lhs.data_type(), rhs.data_type()) {
-        (
-            DataType::Union(lhs_fields, UnionMode::Dense),
-            DataType::Union(rhs_fields, UnionMode::Dense),
-        ) => {
-            let lhs_offsets = lhs.buffer::<i32>(1);
-            let rhs_offsets = rhs.buffer::<i32>(1);
+    let (lhs_fields, lhs_mode) = union_fields(lhs);
+    let (rhs_fields, rhs_mode) = union_fields(rhs);
 
-            let lhs_offsets_range = &lhs_offsets[lhs_start..lhs_start + len];
-            let rhs_offsets_range = &rhs_offsets[rhs_start..rhs_start + len];
+    // Compare runs of elements with the same type id
+    let mut run_start = 0;
+    while run_start < len {
+        let type_id = lhs_type_ids[run_start];
+        let run_end = lhs_type_ids[run_start..]
+            .iter()
+            .position(|t| *t != type_id)
+            .map(|x| run_start + x)
+            .unwrap_or(len);
 
-            lhs_type_id_range == rhs_type_id_range
-                && equal_dense(
-                    lhs,
-                    rhs,
-                    lhs_type_id_range,
-                    rhs_type_id_range,
-                    lhs_offsets_range,
-                    rhs_offsets_range,
-                    lhs_fields,
-                    rhs_fields,
-                )
-        }
-        (DataType::Union(_, UnionMode::Sparse), DataType::Union(_, UnionMode::Sparse)) => {
-            lhs_type_id_range == rhs_type_id_range
-                && equal_sparse(lhs, rhs, lhs_start, rhs_start, len)
+        let lhs_values = &lhs.child_data()[child_index(lhs_fields, type_id)];
+        let rhs_values = &rhs.child_data()[child_index(rhs_fields, type_id)];
+
+        let equal = match (lhs_mode, rhs_mode) {
+            // The values of a run are contiguous in sparse children
+            (UnionMode::Sparse, UnionMode::Sparse) => equal_range(
+                lhs_values,
+                rhs_values,
+                lhs.offset() + lhs_start + run_start,
+                rhs.offset() + rhs_start + run_start,
+                run_end - run_start,
+            ),
+            _ => (run_start..run_end).all(|idx| {
+                let lhs_offset = child_offset(lhs, lhs_mode, lhs_start + idx);
+                let rhs_offset = child_offset(rhs, rhs_mode, rhs_start + idx);
+                equal_range(lhs_values, rhs_values, lhs_offset, rhs_offset, 1)
+            }),
+        };
+
+        if !equal {
+            return false;
         }
-        _ => unimplemented!(
-            "Logical equality not yet implemented between dense and sparse union arrays"
-        ),
+        run_start = run_end;
     }
+    true
 }
@@ -0,0 +1,62 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use super::{Extend, _MutableArrayData};
+use crate::ArrayData;
+use arrow_buffer::ArrowNativeType;
+
+pub(super) fn build_extend<T: ArrowNativeType>(array: &ArrayData) -> Extend {
+    let offsets = array.buffer::<T>(0);
+    let sizes = array.buffer::<T>(1);
+    Box::new(
+        move |mutable: &mut _MutableArrayData, index: usize, start: usize, len: usize| {
+            let mut dst_offset = mutable.child_data[0].len();
+            // the range of values to copy, coalescing list views with contiguous values
+            let mut values: Option<(usize, usize)> = None;
+
+            for i in start..start + len {
+                let offset = offsets[i].as_usize();
+                let size = sizes[i].as_usize();
+
+                mutable.buffer1.push(T::from_usize(dst_offset).expect("offset overflow"));
+                mutable.buffer2.push(sizes[i]);
+                dst_offset += size;
+
+                values = match values {
+                    Some((start, end)) if end == offset => Some((start, offset + size)),
+                    Some((start, end)) => {
+                        mutable.child_data[0].extend(index, start, end);
+                        Some((offset, offset + size))
+                    }
+                    None => Some((offset, offset + size)),
+                };
+            }
+
+            if let Some((start, end)) = values {
+                mutable.child_data[0].extend(index, start, end);
+            }
+        },
+    )
+}
+
+pub(super) fn extend_nulls<T: ArrowNativeType>(mutable: &mut _MutableArrayData, len: usize) {
+    let offset = T::from_usize(mutable.child_data[0].len()).expect("offset overflow");
+    (0..len).for_each(|_| {
+        mutable.buffer1.push(offset);
+        mutable.buffer2.push(T::usize_as(0));
+    })
+}
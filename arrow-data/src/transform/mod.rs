@@ -28,8 +28,10 @@ mod boolean;
 mod fixed_binary;
 mod fixed_size_list;
 mod list;
+mod list_view;
 mod null;
 mod primitive;
+mod run;
 mod structure;
 mod union;
 mod utils;
@@ -219,10 +221,9 @@ fn build_extend(array: &ArrayData) -> Extend {
         DataType::LargeUtf8 | DataType::LargeBinary => variable_size::build_extend::<i64>(array),
         DataType::BinaryView | DataType::Utf8View => unreachable!("should use build_extend_view"),
         DataType::Map(_, _) | DataType::List(_) => list::build_extend::<i32>(array),
-        DataType::ListView(_) | DataType::LargeListView(_) => {
-            unimplemented!("ListView/LargeListView not implemented")
-        }
+        DataType::ListView(_) => list_view::build_extend::<i32>(array),
         DataType::LargeList(_) => list::build_extend::<i64>(array),
+        DataType::LargeListView(_) => list_view::build_extend::<i64>(array),
         DataType::Dictionary(_, _) => unreachable!("should use build_extend_dictionary"),
         DataType::Struct(_) => structure::build_extend(array),
         DataType::FixedSizeBinary(_) => fixed_binary::build_extend(array),
@@ -232,7 +233,12 @@ fn build_extend(array: &ArrayData) -> Extend {
             UnionMode::Sparse => union::build_extend_sparse(array),
             UnionMode::Dense => union::build_extend_dense(array),
         },
-        DataType::RunEndEncoded(_, _) => todo!(),
+        DataType::RunEndEncoded(run_ends, _) => match run_ends.data_type() {
+            DataType::Int16 => run::build_extend::<i16>(array),
+            DataType::Int32 => run::build_extend::<i32>(array),
+            DataType::Int64 => run::build_extend::<i64>(array),
+            _ => unreachable!(),
+        },
     }
 }
 
@@ -265,10 +271,9 @@ fn build_extend_nulls(data_type: &DataType) -> ExtendNulls {
         DataType::LargeUtf8 | DataType::LargeBinary => variable_size::extend_nulls::<i64>,
         DataType::BinaryView | DataType::Utf8View => primitive::extend_nulls::<u128>,
         DataType::Map(_, _) | DataType::List(_) => list::extend_nulls::<i32>,
-        DataType::ListView(_) | DataType::LargeListView(_) => {
-            unimplemented!("ListView/LargeListView not implemented")
-        }
+        DataType::ListView(_) => list_view::extend_nulls::<i32>,
         DataType::LargeList(_) => list::extend_nulls::<i64>,
+        DataType::LargeListView(_) => list_view::extend_nulls::<i64>,
         DataType::Dictionary(child_data_type, _) => match child_data_type.as_ref() {
             DataType::UInt8 => primitive::extend_nulls::<u8>,
             DataType::UInt16 => primitive::extend_nulls::<u16>,
@@ -288,7 +293,12 @@ fn build_extend_nulls(data_type: &DataType) -> ExtendNulls {
             UnionMode::Sparse => union::extend_nulls_sparse,
             UnionMode::Dense => union::extend_nulls_dense,
         },
-        DataType::RunEndEncoded(_, _) => todo!(),
+        DataType::RunEndEncoded(run_ends, _) => match run_ends.data_type() {
+            DataType::Int16 => run::extend_nulls::<i16>,
+            DataType::Int32 => run::extend_nulls::<i32>,
+            DataType::Int64 => run::extend_nulls::<i64>,
+            _ => unreachable!(),
+        },
     })
 }
 
@@ -386,7 +396,11 @@ impl<'a> MutableArrayData<'a> {
                 new_buffers(data_type, *capacity)
             }
             (
-                DataType::List(_) | DataType::LargeList(_) | DataType::FixedSizeList(_, _),
+                DataType::List(_)
+                | DataType::LargeList(_)
+                | DataType::ListView(_)
+                | DataType::LargeListView(_)
+                | DataType::FixedSizeList(_, _),
                 Capacities::List(capacity, _),
             ) => {
                 array_capacity = *capacity;
@@ -425,10 +439,11 @@ impl<'a> MutableArrayData<'a> {
             | DataType::Utf8View
             | DataType::Interval(_)
             | DataType::FixedSizeBinary(_) => vec![],
-            DataType::ListView(_) | DataType::LargeListView(_) => {
-                unimplemented!("ListView/LargeListView not implemented")
-            }
-            DataType::Map(_, _) | DataType::List(_) | DataType::LargeList(_) => {
+            DataType::Map(_, _)
+            | DataType::List(_)
+            | DataType::LargeList(_)
+            | DataType::ListView(_)
+            | DataType::LargeListView(_) => {
                 let children = arrays
                     .iter()
                     .map(|array| &array.child_data()[0])
@@ -579,6 +594,9 @@ impl<'a> MutableArrayData<'a> {
 
         let extend_nulls = build_extend_nulls(data_type);
 
+        // run end encoded arrays have no validity buffer, with nulls encoded in their values
+        let use_nulls = use_nulls && !matches!(data_type, DataType::RunEndEncoded(_, _));
+
         let extend_null_bits = arrays
             .iter()
             .map(|array| build_extend_null_bits(array, use_nulls))
@@ -672,10 +690,12 @@ impl<'a> MutableArrayData<'a> {
     ///
     pub fn extend_nulls(&mut self, len: usize) {
         self.data.len += len;
-        let bit_len = bit_util::ceil(self.data.len, 8);
-        let nulls = self.data.null_buffer();
-        nulls.resize(bit_len, 0);
-        self.data.null_count += len;
+        if !matches!(self.data.data_type, DataType::RunEndEncoded(_, _)) {
+            let bit_len = bit_util::ceil(self.data.len, 8);
+            let nulls = self.data.null_buffer();
+            nulls.resize(bit_len, 0);
+            self.data.null_count += len;
+        }
         (self.extend_nulls)(&mut self.data, len);
     }
 
@@ -708,7 +728,10 @@ impl<'a> MutableArrayData<'a> {
         let data = self.data;
 
         let buffers = match data.data_type {
-            DataType::Null | DataType::Struct(_) | DataType::FixedSizeList(_, _) => {
+            DataType::Null
+            | DataType::Struct(_)
+            | DataType::FixedSizeList(_, _)
+            | DataType::RunEndEncoded(_, _) => {
                 vec![]
             }
             DataType::BinaryView | DataType::Utf8View => {
@@ -716,7 +739,12 @@ impl<'a> MutableArrayData<'a> {
                 b.insert(0, data.buffer1.into());
                 b
             }
-            DataType::Utf8 | DataType::Binary | DataType::LargeUtf8 | DataType::LargeBinary => {
+            DataType::Utf8
+            | DataType::Binary
+            | DataType::LargeUtf8
+            | DataType::LargeBinary
+            | DataType::ListView(_)
+            | DataType::LargeListView(_) => {
                 vec![data.buffer1.into(), data.buffer2.into()]
             }
             DataType::Union(_, mode) => {
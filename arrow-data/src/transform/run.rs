@@ -0,0 +1,124 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Run end encoded arrays have no validity buffer, with `child_data[0]` holding the
+//! run ends and `child_data[1]` the value of each run.
+//!
+//! `buffer2` records the source of the last run as two `u64`, the index of the source
+//! array and the physical index of the run within it, or [`NULL_RUN`] for a run of nulls.
+//! This allows an extend that continues the last run to lengthen it, instead of
+//! appending a new run with an equal value.
+
+use super::{Extend, _MutableArrayData};
+use crate::ArrayData;
+use arrow_buffer::ArrowNativeType;
+
+/// The source recorded for a run appended by [`extend_nulls`]
+const NULL_RUN: [u64; 2] = [u64::MAX, u64::MAX];
+
+/// Returns the source of the last run appended to `mutable`, if any
+fn last_run(mutable: &_MutableArrayData) -> Option<[u64; 2]> {
+    match mutable.buffer2.typed_data::<u64>() {
+        [array, physical] => Some([*array, *physical]),
+        _ => None,
+    }
+}
+
+/// Lengthens the last run of `mutable` by `len`
+fn extend_last_run<T: ArrowNativeType>(mutable: &mut _MutableArrayData, len: usize) {
+    let run_ends = mutable.child_data[0].data.buffer1.typed_data_mut::<T>();
+    let last = run_ends.last_mut().unwrap();
+    *last = T::from_usize(last.as_usize() + len).expect("run end overflow");
+}
+
+/// Appends the ends of runs with lengths `lens`, recording `source` as the source of the
+/// last of them. The values of these runs must be appended to `child_data[1]` separately
+fn push_runs<T: ArrowNativeType>(
+    mutable: &mut _MutableArrayData,
+    lens: impl IntoIterator<Item = usize>,
+    source: [u64; 2],
+) {
+    let run_ends = &mut mutable.child_data[0].data;
+    let mut end = run_ends
+        .buffer1
+        .typed_data::<T>()
+        .last()
+        .map(|x| x.as_usize())
+        .unwrap_or_default();
+    for len in lens {
+        end += len;
+        run_ends
+            .buffer1
+            .push(T::from_usize(end).expect("run end overflow"));
+        run_ends.len += 1;
+    }
+
+    mutable.buffer2.clear();
+    mutable.buffer2.extend_from_slice(&source);
+}
+
+pub(super) fn build_extend<T: ArrowNativeType>(array: &ArrayData) -> Extend {
+    let offset = array.offset();
+    let run_ends = array.child_data()[0].buffer::<T>(0);
+
+    Box::new(
+        move |mutable: &mut _MutableArrayData, index: usize, start: usize, len: usize| {
+            if len == 0 {
+                return;
+            }
+            let start = offset + start;
+            let end = start + len;
+
+            // the physical indices of the runs containing the first and last elements
+            let first = run_ends.partition_point(|x| x.as_usize() <= start);
+            let last = run_ends.partition_point(|x| x.as_usize() < end);
+
+            let run_len = |i: usize| {
+                let run_start = match i {
+                    0 => 0,
+                    _ => run_ends[i - 1].as_usize(),
+                };
+                run_ends[i].as_usize().min(end) - run_start.max(start)
+            };
+
+            let first_source = [index as u64, first as u64];
+            let values_start = if last_run(mutable) == Some(first_source) {
+                extend_last_run::<T>(mutable, run_len(first));
+                first + 1
+            } else {
+                first
+            };
+            if values_start <= last {
+                let source = [index as u64, last as u64];
+                push_runs::<T>(mutable, (values_start..=last).map(run_len), source);
+                mutable.child_data[1].extend(index, values_start, last + 1);
+            }
+        },
+    )
+}
+
+pub(super) fn extend_nulls<T: ArrowNativeType>(mutable: &mut _MutableArrayData, len: usize) {
+    if len == 0 {
+        return;
+    }
+    if last_run(mutable) == Some(NULL_RUN) {
+        extend_last_run::<T>(mutable, len);
+    } else {
+        push_runs::<T>(mutable, [len], NULL_RUN);
+        mutable.child_data[1].extend_nulls(1);
+    }
+}
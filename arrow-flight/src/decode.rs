@@ -418,6 +418,29 @@ impl DecodedFlightData {
     pub fn app_metadata(&self) -> Bytes {
         self.inner.app_metadata.clone()
     }
+
+    /// return the custom metadata of the IPC message in the inner flight data
+    ///
+    /// For a [`DecodedPayload::RecordBatch`] this is the per-batch metadata, e.g. as
+    /// encoded by [`IpcDataGenerator::encoded_batch_with_metadata`]. It is empty if the
+    /// message has no custom metadata
+    ///
+    /// [`IpcDataGenerator::encoded_batch_with_metadata`]: arrow_ipc::writer::IpcDataGenerator::encoded_batch_with_metadata
+    pub fn custom_metadata(&self) -> HashMap<String, String> {
+        let mut custom_metadata = HashMap::new();
+        let message = match arrow_ipc::root_as_message(&self.inner.data_header[..]) {
+            Ok(message) => message,
+            Err(_) => return custom_metadata,
+        };
+        if let Some(fb_custom_metadata) = message.custom_metadata() {
+            for kv in fb_custom_metadata.into_iter() {
+                if let (Some(k), Some(v)) = (kv.key(), kv.value()) {
+                    custom_metadata.insert(k.to_string(), v.to_string());
+                }
+            }
+        }
+        custom_metadata
+    }
 }
 
 /// The result of decoding [`FlightData`]
@@ -26,12 +26,13 @@ use arrow_array::{
 };
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::flight_descriptor::DescriptorType;
-use arrow_flight::FlightDescriptor;
 use arrow_flight::{
     decode::{DecodedPayload, FlightDataDecoder, FlightRecordBatchStream},
     encode::FlightDataEncoderBuilder,
     error::FlightError,
 };
+use arrow_flight::{FlightData, FlightDescriptor, SchemaAsIpc};
+use arrow_ipc::writer::{DictionaryTracker, IpcDataGenerator, IpcWriteOptions};
 use arrow_schema::{DataType, Field, Fields, Schema, SchemaRef};
 use bytes::Bytes;
 use futures::{StreamExt, TryStreamExt};
@@ -233,6 +234,33 @@ async fn test_app_metadata() {
     assert!(matches!(message2.payload, DecodedPayload::RecordBatch(_)));
 }
 
+#[tokio::test]
+async fn test_batch_custom_metadata() {
+    let batch = make_primitive_batch(5);
+    let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+
+    let options = IpcWriteOptions::default();
+    let schema_data = FlightData::from(SchemaAsIpc::new(&batch.schema(), &options));
+    let mut tracker = DictionaryTracker::new(false);
+    let (dictionaries, encoded) = IpcDataGenerator::default()
+        .encoded_batch_with_metadata(&batch, &metadata, &mut tracker, &options)
+        .unwrap();
+    assert!(dictionaries.is_empty());
+
+    let flight_data = vec![Ok(schema_data), Ok(FlightData::from(encoded))];
+    let decode_stream = FlightDataDecoder::new(futures::stream::iter(flight_data));
+    let decoded_data: Vec<_> = decode_stream.try_collect().await.expect("decode");
+
+    assert_eq!(decoded_data.len(), 2);
+    assert!(matches!(decoded_data[0].payload, DecodedPayload::Schema(_)));
+    assert!(decoded_data[0].custom_metadata().is_empty());
+    match &decoded_data[1].payload {
+        DecodedPayload::RecordBatch(decoded) => assert_eq!(decoded, &batch),
+        p => panic!("unexpected payload {p:?}"),
+    }
+    assert_eq!(decoded_data[1].custom_metadata(), metadata);
+}
+
 #[tokio::test]
 async fn test_max_message_size() {
     let input_batch_stream = futures::stream::iter(vec![Ok(make_primitive_batch(5))]);
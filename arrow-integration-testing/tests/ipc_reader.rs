@@ -19,7 +19,7 @@
 //! in `testing/arrow-ipc-stream/integration/...`
 
 use arrow::error::ArrowError;
-use arrow::ipc::reader::{FileReader, StreamDecoder, StreamReader};
+use arrow::ipc::reader::{FileReader, FileReaderBuilder, StreamDecoder, StreamReader};
 use arrow::util::test_util::arrow_test_data;
 use arrow_buffer::Buffer;
 use arrow_integration_testing::read_gzip_json;
@@ -86,9 +86,45 @@ fn read_1_0_0_bigendian() {
         let err = reader.err().unwrap();
         assert!(matches!(err, ArrowError::IpcError(_)));
         assert_eq!(err.to_string(), "Ipc error: the endianness of the source system does not match the endianness of the target system.");
+
+        verify_big_endian(&testdata, path);
     });
 }
 
+/// Verifies the big endian integration test files can be read by converting them to
+/// native endianness
+fn verify_big_endian(testdata: &str, path: &str) {
+    let version = "1.0.0-bigendian";
+    let arrow_json = read_gzip_json(version, path);
+    let prefix = format!("{testdata}/arrow-ipc-stream/integration/{version}/{path}");
+
+    let file = File::open(format!("{prefix}.arrow_file")).unwrap();
+    let mut reader = FileReaderBuilder::new()
+        .with_ensure_native_endian(true)
+        .build(file)
+        .unwrap();
+    assert!(arrow_json.equals_reader(&mut reader).unwrap());
+
+    let file = File::open(format!("{prefix}.stream")).unwrap();
+    let mut reader = StreamReader::try_new(file, None)
+        .unwrap()
+        .with_ensure_native_endian(true);
+    assert!(arrow_json.equals_reader(&mut reader).unwrap());
+
+    let expected = arrow_json.get_record_batches().unwrap();
+    let mut decoder = StreamDecoder::new().with_ensure_native_endian(true);
+    let mut actual = Vec::with_capacity(expected.len());
+    for mut x in chunked_file(&format!("{prefix}.stream"), 123) {
+        while !x.is_empty() {
+            if let Some(x) = decoder.decode(&mut x).unwrap() {
+                actual.push(x);
+            }
+        }
+    }
+    decoder.finish().unwrap();
+    assert_eq!(expected, actual);
+}
+
 #[test]
 fn read_1_0_0_littleendian() {
     let testdata = arrow_test_data();
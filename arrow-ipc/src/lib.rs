@@ -21,6 +21,7 @@
 
 pub mod convert;
 pub mod reader;
+pub mod tensor;
 pub mod writer;
 
 mod compression;
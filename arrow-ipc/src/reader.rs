@@ -24,6 +24,9 @@ mod stream;
 
 pub use stream::*;
 
+#[cfg(feature = "async")]
+pub mod async_reader;
+
 use flatbuffers::{VectorIter, VerifierOptions};
 use std::collections::{HashMap, VecDeque};
 use std::fmt;
@@ -34,6 +37,7 @@ use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, BooleanBuffer, Buffer, MutableBuffer, ScalarBuffer};
 use arrow_data::ArrayData;
 use arrow_schema::*;
+use arrow_select::concat::concat;
 
 use crate::compression::CompressionCodec;
 use crate::{Block, FieldNode, Message, MetadataVersion, CONTINUATION_MARKER};
@@ -82,26 +86,34 @@ fn create_array(
 ) -> Result<ArrayRef, ArrowError> {
     let data_type = field.data_type();
     match data_type {
-        Utf8 | Binary | LargeBinary | LargeUtf8 => create_primitive_array(
-            reader.next_node(field)?,
-            data_type,
-            &[
-                reader.next_buffer()?,
-                reader.next_buffer()?,
-                reader.next_buffer()?,
-            ],
-            require_alignment,
-        ),
+        Utf8 | Binary | LargeBinary | LargeUtf8 => {
+            let offsets_type = match data_type {
+                LargeBinary | LargeUtf8 => &Int64,
+                _ => &Int32,
+            };
+            create_primitive_array(
+                reader.next_node(field)?,
+                data_type,
+                &[
+                    reader.next_buffer()?,
+                    reader.next_values_buffer(offsets_type)?,
+                    reader.next_buffer()?,
+                ],
+                require_alignment,
+            )
+        }
         BinaryView | Utf8View => {
             let count = variadic_counts
                 .pop_front()
                 .ok_or(ArrowError::IpcError(format!(
                     "Missing variadic count for {data_type} column"
                 )))?;
-            let count = count + 2; // view and null buffer.
-            let buffers = (0..count)
-                .map(|_| reader.next_buffer())
-                .collect::<Result<Vec<_>, _>>()?;
+            let mut buffers = Vec::with_capacity(count as usize + 2);
+            buffers.push(reader.next_buffer()?); // null buffer
+            buffers.push(reader.next_values_buffer(data_type)?); // view buffer
+            for _ in 0..count {
+                buffers.push(reader.next_buffer()?);
+            }
             create_primitive_array(
                 reader.next_node(field)?,
                 data_type,
@@ -117,7 +129,34 @@ fn create_array(
         ),
         List(ref list_field) | LargeList(ref list_field) | Map(ref list_field, _) => {
             let list_node = reader.next_node(field)?;
-            let list_buffers = [reader.next_buffer()?, reader.next_buffer()?];
+            let offsets_type = match data_type {
+                LargeList(_) => &Int64,
+                _ => &Int32,
+            };
+            let list_buffers = [
+                reader.next_buffer()?,
+                reader.next_values_buffer(offsets_type)?,
+            ];
+            let values = create_array(reader, list_field, variadic_counts, require_alignment)?;
+            create_list_array(
+                list_node,
+                data_type,
+                &list_buffers,
+                values,
+                require_alignment,
+            )
+        }
+        ListView(ref list_field) | LargeListView(ref list_field) => {
+            let list_node = reader.next_node(field)?;
+            let offsets_type = match data_type {
+                LargeListView(_) => &Int64,
+                _ => &Int32,
+            };
+            let list_buffers = [
+                reader.next_buffer()?,
+                reader.next_values_buffer(offsets_type)?,
+                reader.next_values_buffer(offsets_type)?,
+            ];
             let values = create_array(reader, list_field, variadic_counts, require_alignment)?;
             create_list_array(
                 list_node,
@@ -190,9 +229,9 @@ fn create_array(
             Ok(make_array(array_data))
         }
         // Create dictionary array from RecordBatch
-        Dictionary(_, _) => {
+        Dictionary(key_type, _) => {
             let index_node = reader.next_node(field)?;
-            let index_buffers = [reader.next_buffer()?, reader.next_buffer()?];
+            let index_buffers = [reader.next_buffer()?, reader.next_values_buffer(key_type)?];
 
             let dict_id = field.dict_id().ok_or_else(|| {
                 ArrowError::ParseError(format!("Field {field} does not have dict id"))
@@ -226,8 +265,10 @@ fn create_array(
 
             let value_offsets = match mode {
                 UnionMode::Dense => {
-                    let offsets: ScalarBuffer<i32> =
-                        reader.next_buffer()?.slice_with_length(0, len * 4).into();
+                    let offsets: ScalarBuffer<i32> = reader
+                        .next_values_buffer(&Int32)?
+                        .slice_with_length(0, len * 4)
+                        .into();
                     Some(offsets)
                 }
                 UnionMode::Sparse => None,
@@ -270,12 +311,66 @@ fn create_array(
         _ => create_primitive_array(
             reader.next_node(field)?,
             data_type,
-            &[reader.next_buffer()?, reader.next_buffer()?],
+            &[reader.next_buffer()?, reader.next_values_buffer(data_type)?],
             require_alignment,
         ),
     }
 }
 
+/// Converts a buffer of big or little endian values of `data_type` to the opposite
+/// endianness, by reversing the bytes of each value
+fn swap_endianness(buffer: Buffer, data_type: &DataType) -> Result<Buffer, ArrowError> {
+    // The widths of the fields in each value
+    let fields: &[usize] = match data_type {
+        Int8 | UInt8 | Boolean | FixedSizeBinary(_) | Null => return Ok(buffer),
+        BinaryView | Utf8View => return Ok(swap_view_endianness(buffer)),
+        Interval(IntervalUnit::DayTime) => &[4, 4],
+        Interval(IntervalUnit::MonthDayNano) => &[4, 4, 8],
+        _ => match data_type.primitive_width() {
+            Some(2) => &[2],
+            Some(4) => &[4],
+            Some(8) => &[8],
+            Some(16) => &[16],
+            Some(32) => &[32],
+            _ => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Converting the endianness of {data_type} is not supported"
+                )))
+            }
+        },
+    };
+
+    let width = fields.iter().sum::<usize>();
+    let mut out = MutableBuffer::from_len_zeroed(buffer.len());
+    out.copy_from_slice(&buffer);
+    for value in out.chunks_exact_mut(width) {
+        let mut offset = 0;
+        for field in fields {
+            value[offset..offset + field].reverse();
+            offset += field;
+        }
+    }
+    Ok(out.into())
+}
+
+/// Converts the endianness of a buffer of views, see [`swap_endianness`]
+///
+/// A view contains a 4 byte length, followed by either 12 bytes of inline data, or a 4 byte
+/// prefix, a 4 byte buffer index and a 4 byte offset
+fn swap_view_endianness(buffer: Buffer) -> Buffer {
+    let mut out = MutableBuffer::from_len_zeroed(buffer.len());
+    out.copy_from_slice(&buffer);
+    for view in out.chunks_exact_mut(16) {
+        view[0..4].reverse();
+        let len = u32::from_le_bytes(view[0..4].try_into().unwrap());
+        if len > 12 {
+            view[8..12].reverse();
+            view[12..16].reverse();
+        }
+    }
+    out.into()
+}
+
 /// Reads the correct number of buffers based on data type and null_count, and creates a
 /// primitive array ref
 fn create_primitive_array(
@@ -336,6 +431,13 @@ fn create_list_array(
             .add_child_data(child_data)
             .null_bit_buffer(null_buffer),
 
+        ListView(_) | LargeListView(_) => ArrayData::builder(data_type.clone())
+            .len(length)
+            .add_buffer(buffers[1].clone())
+            .add_buffer(buffers[2].clone())
+            .add_child_data(child_data)
+            .null_bit_buffer(null_buffer),
+
         FixedSizeList(_, _) => ArrayData::builder(data_type.clone())
             .len(length)
             .add_child_data(child_data)
@@ -396,6 +498,8 @@ struct ArrayReader<'a> {
     nodes: VectorIter<'a, FieldNode>,
     /// The buffers comprising this array
     buffers: VectorIter<'a, crate::Buffer>,
+    /// Whether the data has non-native endianness, and must be byte swapped
+    swap_endianness: bool,
 }
 
 impl<'a> ArrayReader<'a> {
@@ -403,6 +507,16 @@ impl<'a> ArrayReader<'a> {
         read_buffer(self.buffers.next().unwrap(), self.data, self.compression)
     }
 
+    /// Reads the next buffer, containing values of `data_type`, converting them
+    /// to native endianness if necessary
+    fn next_values_buffer(&mut self, data_type: &DataType) -> Result<Buffer, ArrowError> {
+        let buffer = self.next_buffer()?;
+        match self.swap_endianness {
+            true => swap_endianness(buffer, data_type),
+            false => Ok(buffer),
+        }
+    }
+
     fn skip_buffer(&mut self) {
         self.buffers.next().unwrap();
     }
@@ -450,6 +564,12 @@ impl<'a> ArrayReader<'a> {
                 self.skip_buffer();
                 self.skip_field(list_field, variadic_count)?;
             }
+            ListView(list_field) | LargeListView(list_field) => {
+                self.skip_buffer();
+                self.skip_buffer();
+                self.skip_buffer();
+                self.skip_field(list_field, variadic_count)?;
+            }
             FixedSizeList(list_field, _) => {
                 self.skip_buffer();
                 self.skip_field(list_field, variadic_count)?;
@@ -518,6 +638,7 @@ pub fn read_record_batch(
         projection,
         metadata,
         false,
+        false,
     )
 }
 
@@ -530,9 +651,22 @@ pub fn read_dictionary(
     dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
     metadata: &MetadataVersion,
 ) -> Result<(), ArrowError> {
-    read_dictionary_impl(buf, batch, schema, dictionaries_by_id, metadata, false)
+    read_dictionary_impl(
+        buf,
+        batch,
+        schema,
+        dictionaries_by_id,
+        metadata,
+        false,
+        false,
+    )
 }
 
+/// Creates a record batch, see [`read_record_batch`]
+///
+/// If `swap_endianness` is true, the data has non-native endianness and is converted
+/// to native endianness
+#[allow(clippy::too_many_arguments)]
 fn read_record_batch_impl(
     buf: &Buffer,
     batch: crate::RecordBatch,
@@ -541,6 +675,7 @@ fn read_record_batch_impl(
     projection: Option<&[usize]>,
     metadata: &MetadataVersion,
     require_alignment: bool,
+    swap_endianness: bool,
 ) -> Result<RecordBatch, ArrowError> {
     let buffers = batch.buffers().ok_or_else(|| {
         ArrowError::IpcError("Unable to get buffers from IPC RecordBatch".to_string())
@@ -564,6 +699,7 @@ fn read_record_batch_impl(
         data: buf,
         nodes: field_nodes.iter(),
         buffers: buffers.iter(),
+        swap_endianness,
     };
 
     let options = RecordBatchOptions::new().with_row_count(Some(batch.length() as usize));
@@ -607,13 +743,8 @@ fn read_dictionary_impl(
     dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
     metadata: &MetadataVersion,
     require_alignment: bool,
+    swap_endianness: bool,
 ) -> Result<(), ArrowError> {
-    if batch.isDelta() {
-        return Err(ArrowError::InvalidArgumentError(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
-
     let id = batch.id();
     let fields_using_this_dictionary = schema.fields_with_dict_id(id);
     let first_field = fields_using_this_dictionary.first().ok_or_else(|| {
@@ -637,6 +768,7 @@ fn read_dictionary_impl(
                 None,
                 metadata,
                 require_alignment,
+                swap_endianness,
             )?;
             Some(record_batch.column(0).clone())
         }
@@ -646,10 +778,24 @@ fn read_dictionary_impl(
         ArrowError::InvalidArgumentError(format!("dictionary id {id} not found in schema"))
     })?;
 
+    // A delta dictionary batch appends its values to the existing dictionary,
+    // otherwise it replaces any existing dictionary with the same id
+    let dictionary_values = match batch.isDelta() {
+        true => {
+            let existing = dictionaries_by_id.get(&id).ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "delta dictionary batch for dictionary id {id} with no existing dictionary"
+                ))
+            })?;
+            concat(&[existing.as_ref(), dictionary_values.as_ref()])?
+        }
+        false => dictionary_values,
+    };
+
     // We don't currently record the isOrdered field. This could be general
     // attributes of arrays.
     // Add (possibly multiple) array refs to the dictionaries array.
-    dictionaries_by_id.insert(id, dictionary_values.clone());
+    dictionaries_by_id.insert(id, dictionary_values);
 
     Ok(())
 }
@@ -762,6 +908,7 @@ pub struct FileDecoder {
     version: MetadataVersion,
     projection: Option<Vec<usize>>,
     require_alignment: bool,
+    swap_endianness: bool,
 }
 
 impl FileDecoder {
@@ -773,9 +920,19 @@ impl FileDecoder {
             dictionaries: Default::default(),
             projection: None,
             require_alignment: false,
+            swap_endianness: false,
         }
     }
 
+    /// Specify the endianness of the data, as recorded in the schema of the file
+    ///
+    /// If this differs from the endianness of the target system, the data will be
+    /// converted to native endianness as it is decoded. Defaults to native endianness
+    pub fn with_endianness(mut self, endianness: crate::Endianness) -> Self {
+        self.swap_endianness = !endianness.equals_to_target_endianness();
+        self
+    }
+
     /// Specify a projection
     pub fn with_projection(mut self, projection: Vec<usize>) -> Self {
         self.projection = Some(projection);
@@ -824,6 +981,7 @@ impl FileDecoder {
                     &mut self.dictionaries,
                     &message.version(),
                     self.require_alignment,
+                    self.swap_endianness,
                 )
             }
             t => Err(ArrowError::ParseError(format!(
@@ -838,6 +996,18 @@ impl FileDecoder {
         block: &Block,
         buf: &Buffer,
     ) -> Result<Option<RecordBatch>, ArrowError> {
+        Ok(self
+            .read_record_batch_with_metadata(block, buf)?
+            .map(|(batch, _)| batch))
+    }
+
+    /// Read the RecordBatch with the given block and data buffer, along with the
+    /// custom metadata of its message
+    pub fn read_record_batch_with_metadata(
+        &self,
+        block: &Block,
+        buf: &Buffer,
+    ) -> Result<Option<RecordBatchWithMetadata>, ArrowError> {
         let message = self.read_message(buf)?;
         match message.header_type() {
             crate::MessageHeader::Schema => Err(ArrowError::IpcError(
@@ -848,7 +1018,7 @@ impl FileDecoder {
                     ArrowError::IpcError("Unable to read IPC message as record batch".to_string())
                 })?;
                 // read the block that makes up the record batch into a buffer
-                read_record_batch_impl(
+                let batch = read_record_batch_impl(
                     &buf.slice(block.metaDataLength() as _),
                     batch,
                     self.schema.clone(),
@@ -856,8 +1026,9 @@ impl FileDecoder {
                     self.projection.as_deref(),
                     &message.version(),
                     self.require_alignment,
-                )
-                .map(Some)
+                    self.swap_endianness,
+                )?;
+                Ok(Some((batch, message_custom_metadata(&message))))
             }
             crate::MessageHeader::NONE => Ok(None),
             t => Err(ArrowError::InvalidArgumentError(format!(
@@ -867,6 +1038,23 @@ impl FileDecoder {
     }
 }
 
+/// A [`RecordBatch`] along with the custom metadata of the IPC message it was read from
+pub type RecordBatchWithMetadata = (RecordBatch, HashMap<String, String>);
+
+/// Returns the custom metadata of `message`
+pub(crate) fn message_custom_metadata(message: &crate::Message) -> HashMap<String, String> {
+    let mut custom_metadata = HashMap::new();
+    if let Some(fb_custom_metadata) = message.custom_metadata() {
+        for kv in fb_custom_metadata.into_iter() {
+            custom_metadata.insert(
+                kv.key().unwrap().to_string(),
+                kv.value().unwrap().to_string(),
+            );
+        }
+    }
+    custom_metadata
+}
+
 /// Build an Arrow [`FileReader`] with custom options.
 #[derive(Debug)]
 pub struct FileReaderBuilder {
@@ -876,6 +1064,8 @@ pub struct FileReaderBuilder {
     max_footer_fb_tables: usize,
     /// Passed through to construct [`VerifierOptions`]
     max_footer_fb_depth: usize,
+    /// Convert data with non-native endianness to native endianness
+    ensure_native_endian: bool,
 }
 
 impl Default for FileReaderBuilder {
@@ -885,6 +1075,7 @@ impl Default for FileReaderBuilder {
             max_footer_fb_tables: verifier_options.max_tables,
             max_footer_fb_depth: verifier_options.max_depth,
             projection: None,
+            ensure_native_endian: false,
         }
     }
 }
@@ -937,6 +1128,17 @@ impl FileReaderBuilder {
         self
     }
 
+    /// Specifies whether to read files with non-native endianness, such as a file written
+    /// on a big endian system read on a little endian system.
+    ///
+    /// If `ensure_native_endian` is true, the data of such files is byte swapped into
+    /// native endianness as it is read. If false (the default), reading such a file
+    /// returns an error
+    pub fn with_ensure_native_endian(mut self, ensure_native_endian: bool) -> Self {
+        self.ensure_native_endian = ensure_native_endian;
+        self
+    }
+
     /// Build [`FileReader`] with given reader.
     pub fn build<R: Read + Seek>(self, mut reader: R) -> Result<FileReader<R>, ArrowError> {
         // Space for ARROW_MAGIC (6 bytes) and length (4 bytes)
@@ -951,28 +1153,82 @@ impl FileReaderBuilder {
         reader.seek(SeekFrom::End(-10 - footer_len as i64))?;
         reader.read_exact(&mut footer_data)?;
 
-        let verifier_options = VerifierOptions {
+        let footer = FileFooter::try_new(&footer_data, &self.verifier_options())?;
+        check_endianness(footer.endianness, self.ensure_native_endian)?;
+        let total_blocks = footer.record_batches.len();
+
+        let mut decoder =
+            FileDecoder::new(footer.schema, footer.version).with_endianness(footer.endianness);
+        if let Some(projection) = self.projection {
+            decoder = decoder.with_projection(projection)
+        }
+
+        // Create an array of optional dictionary value arrays, one per field.
+        for block in &footer.dictionaries {
+            let buf = read_block(&mut reader, block)?;
+            decoder.read_dictionary(block, &buf)?;
+        }
+
+        Ok(FileReader {
+            reader,
+            blocks: footer.record_batches,
+            current_block: 0,
+            total_blocks,
+            decoder,
+            custom_metadata: footer.custom_metadata,
+        })
+    }
+
+    fn verifier_options(&self) -> VerifierOptions {
+        VerifierOptions {
             max_tables: self.max_footer_fb_tables,
             max_depth: self.max_footer_fb_depth,
             ..Default::default()
-        };
-        let footer = crate::root_as_footer_with_opts(&verifier_options, &footer_data[..]).map_err(
-            |err| ArrowError::ParseError(format!("Unable to get root as footer: {err:?}")),
-        )?;
+        }
+    }
+}
+
+/// Returns an error if data with `endianness` cannot be read, or whether it must be
+/// converted to native endianness
+pub(crate) fn check_endianness(
+    endianness: crate::Endianness,
+    ensure_native_endian: bool,
+) -> Result<bool, ArrowError> {
+    match (endianness.equals_to_target_endianness(), ensure_native_endian) {
+        (true, _) => Ok(false),
+        (false, true) => Ok(true),
+        (false, false) => Err(ArrowError::IpcError(
+            "the endianness of the source system does not match the endianness of the target system.".to_owned()
+        )),
+    }
+}
+
+/// The decoded footer of an IPC file
+pub(crate) struct FileFooter {
+    pub(crate) schema: SchemaRef,
+    pub(crate) version: MetadataVersion,
+    pub(crate) endianness: crate::Endianness,
+    pub(crate) record_batches: Vec<Block>,
+    pub(crate) dictionaries: Vec<Block>,
+    pub(crate) custom_metadata: HashMap<String, String>,
+}
+
+impl FileFooter {
+    /// Decode the footer flatbuffer in `footer_data`
+    pub(crate) fn try_new(
+        footer_data: &[u8],
+        verifier_options: &VerifierOptions,
+    ) -> Result<Self, ArrowError> {
+        let footer =
+            crate::root_as_footer_with_opts(verifier_options, footer_data).map_err(|err| {
+                ArrowError::ParseError(format!("Unable to get root as footer: {err:?}"))
+            })?;
 
         let blocks = footer.recordBatches().ok_or_else(|| {
             ArrowError::ParseError("Unable to get record batches from IPC Footer".to_string())
         })?;
 
-        let total_blocks = blocks.len();
-
         let ipc_schema = footer.schema().unwrap();
-        if !ipc_schema.endianness().equals_to_target_endianness() {
-            return Err(ArrowError::IpcError(
-                "the endianness of the source system does not match the endianness of the target system.".to_owned()
-            ));
-        }
-
         let schema = crate::convert::fb_to_schema(ipc_schema);
 
         let mut custom_metadata = HashMap::new();
@@ -985,25 +1241,15 @@ impl FileReaderBuilder {
             }
         }
 
-        let mut decoder = FileDecoder::new(Arc::new(schema), footer.version());
-        if let Some(projection) = self.projection {
-            decoder = decoder.with_projection(projection)
-        }
-
-        // Create an array of optional dictionary value arrays, one per field.
-        if let Some(dictionaries) = footer.dictionaries() {
-            for block in dictionaries {
-                let buf = read_block(&mut reader, block)?;
-                decoder.read_dictionary(block, &buf)?;
-            }
-        }
-
-        Ok(FileReader {
-            reader,
-            blocks: blocks.iter().copied().collect(),
-            current_block: 0,
-            total_blocks,
-            decoder,
+        Ok(Self {
+            schema: Arc::new(schema),
+            version: footer.version(),
+            endianness: ipc_schema.endianness(),
+            record_batches: blocks.iter().copied().collect(),
+            dictionaries: footer
+                .dictionaries()
+                .map(|d| d.iter().copied().collect())
+                .unwrap_or_default(),
             custom_metadata,
         })
     }
@@ -1101,13 +1347,26 @@ impl<R: Read + Seek> FileReader<R> {
         }
     }
 
-    fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+    /// Read the next record batch, along with the custom metadata of its message
+    ///
+    /// This metadata is distinct from the file-level [`Self::custom_metadata`], and is
+    /// empty if none was written for the batch
+    pub fn next_with_metadata(&mut self) -> Option<Result<RecordBatchWithMetadata, ArrowError>> {
+        // get current block
+        if self.current_block < self.total_blocks {
+            self.maybe_next().transpose()
+        } else {
+            None
+        }
+    }
+
+    fn maybe_next(&mut self) -> Result<Option<RecordBatchWithMetadata>, ArrowError> {
         let block = &self.blocks[self.current_block];
         self.current_block += 1;
 
         // read length
         let buffer = read_block(&mut self.reader, block)?;
-        self.decoder.read_record_batch(block, &buffer)
+        self.decoder.read_record_batch_with_metadata(block, &buffer)
     }
 
     /// Gets a reference to the underlying reader.
@@ -1129,12 +1388,7 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
     type Item = Result<RecordBatch, ArrowError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // get current block
-        if self.current_block < self.total_blocks {
-            self.maybe_next().transpose()
-        } else {
-            None
-        }
+        self.next_with_metadata().map(|r| r.map(|(batch, _)| batch))
     }
 }
 
@@ -1164,6 +1418,12 @@ pub struct StreamReader<R> {
 
     /// Optional projection
     projection: Option<(Vec<usize>, Schema)>,
+
+    /// The endianness of the stream, as recorded in its schema
+    endianness: crate::Endianness,
+
+    /// Convert data with non-native endianness to native endianness
+    ensure_native_endian: bool,
 }
 
 impl<R> fmt::Debug for StreamReader<R> {
@@ -1174,6 +1434,8 @@ impl<R> fmt::Debug for StreamReader<R> {
             .field("dictionaries_by_id", &self.dictionaries_by_id)
             .field("finished", &self.finished)
             .field("projection", &self.projection)
+            .field("endianness", &self.endianness)
+            .field("ensure_native_endian", &self.ensure_native_endian)
             .finish()
     }
 }
@@ -1243,9 +1505,22 @@ impl<R: Read> StreamReader<R> {
             finished: false,
             dictionaries_by_id,
             projection,
+            endianness: ipc_schema.endianness(),
+            ensure_native_endian: false,
         })
     }
 
+    /// Specifies whether to read streams with non-native endianness, such as a stream
+    /// written on a big endian system read on a little endian system.
+    ///
+    /// If `ensure_native_endian` is true, the data of such streams is byte swapped into
+    /// native endianness as it is read. If false (the default), reading the record
+    /// batches of such a stream returns an error
+    pub fn with_ensure_native_endian(mut self, ensure_native_endian: bool) -> Self {
+        self.ensure_native_endian = ensure_native_endian;
+        self
+    }
+
     /// Deprecated, use [`StreamReader::try_new`] instead.
     #[deprecated(since = "53.0.0", note = "use `try_new` instead")]
     pub fn try_new_unbuffered(
@@ -1265,7 +1540,14 @@ impl<R: Read> StreamReader<R> {
         self.finished
     }
 
-    fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+    /// Read the next record batch, along with the custom metadata of its message
+    ///
+    /// The metadata is empty if none was written for the batch
+    pub fn next_with_metadata(&mut self) -> Option<Result<RecordBatchWithMetadata, ArrowError>> {
+        self.maybe_next().transpose()
+    }
+
+    fn maybe_next(&mut self) -> Result<Option<RecordBatchWithMetadata>, ArrowError> {
         if self.finished {
             return Ok(None);
         }
@@ -1309,6 +1591,7 @@ impl<R: Read> StreamReader<R> {
         let message = crate::root_as_message(vecs).map_err(|err| {
             ArrowError::ParseError(format!("Unable to get root as message: {err:?}"))
         })?;
+        let swap_endianness = check_endianness(self.endianness, self.ensure_native_endian)?;
 
         match message.header_type() {
             crate::MessageHeader::Schema => Err(ArrowError::IpcError(
@@ -1322,7 +1605,7 @@ impl<R: Read> StreamReader<R> {
                 let mut buf = MutableBuffer::from_len_zeroed(message.bodyLength() as usize);
                 self.reader.read_exact(&mut buf)?;
 
-                read_record_batch_impl(
+                let batch = read_record_batch_impl(
                     &buf.into(),
                     batch,
                     self.schema(),
@@ -1330,8 +1613,9 @@ impl<R: Read> StreamReader<R> {
                     self.projection.as_ref().map(|x| x.0.as_ref()),
                     &message.version(),
                     false,
-                )
-                .map(Some)
+                    swap_endianness,
+                )?;
+                Ok(Some((batch, message_custom_metadata(&message))))
             }
             crate::MessageHeader::DictionaryBatch => {
                 let batch = message.header_as_dictionary_batch().ok_or_else(|| {
@@ -1350,6 +1634,7 @@ impl<R: Read> StreamReader<R> {
                     &mut self.dictionaries_by_id,
                     &message.version(),
                     false,
+                    swap_endianness,
                 )?;
 
                 // read the next message until we encounter a RecordBatch
@@ -1381,7 +1666,7 @@ impl<R: Read> Iterator for StreamReader<R> {
     type Item = Result<RecordBatch, ArrowError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.maybe_next().transpose()
+        self.next_with_metadata().map(|r| r.map(|(batch, _)| batch))
     }
 }
 
@@ -1393,12 +1678,17 @@ impl<R: Read> RecordBatchReader for StreamReader<R> {
 
 #[cfg(test)]
 mod tests {
-    use crate::writer::{unslice_run_array, DictionaryTracker, IpcDataGenerator};
+    use crate::writer::{
+        unslice_run_array, write_message, DictionaryTracker, EncodedData, IpcDataGenerator,
+        IpcWriteOptions,
+    };
 
     use super::*;
 
     use crate::root_as_message;
-    use arrow_array::builder::{PrimitiveRunBuilder, UnionBuilder};
+    use arrow_array::builder::{
+        Int32Builder, LargeListViewBuilder, PrimitiveRunBuilder, StringBuilder, UnionBuilder,
+    };
     use arrow_array::types::*;
     use arrow_buffer::NullBuffer;
     use arrow_data::ArrayDataBuilder;
@@ -1678,6 +1968,63 @@ mod tests {
         assert_eq!(reader.custom_metadata(), &test_metadata);
     }
 
+    #[test]
+    fn test_roundtrip_batch_custom_metadata() {
+        let batch =
+            RecordBatch::try_from_iter([("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as _)])
+                .unwrap();
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+
+        let mut buf = Vec::new();
+        let mut writer = crate::writer::FileWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        writer
+            .write_with_metadata(&batch, metadata.clone())
+            .unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut reader = FileReader::try_new(std::io::Cursor::new(buf), None).unwrap();
+        assert!(reader.custom_metadata().is_empty());
+        let (read, read_metadata) = reader.next_with_metadata().unwrap().unwrap();
+        assert_eq!(read, batch);
+        assert_eq!(read_metadata, metadata);
+        let (read, read_metadata) = reader.next_with_metadata().unwrap().unwrap();
+        assert_eq!(read, batch);
+        assert!(read_metadata.is_empty());
+        assert!(reader.next_with_metadata().is_none());
+
+        let mut buf = Vec::new();
+        let mut writer = crate::writer::StreamWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer
+            .write_with_metadata(&batch, metadata.clone())
+            .unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut reader = StreamReader::try_new(buf.as_slice(), None).unwrap();
+        let (read, read_metadata) = reader.next_with_metadata().unwrap().unwrap();
+        assert_eq!(read, batch);
+        assert!(read_metadata.is_empty());
+        let (read, read_metadata) = reader.next_with_metadata().unwrap().unwrap();
+        assert_eq!(read, batch);
+        assert_eq!(read_metadata, metadata);
+        assert!(reader.next_with_metadata().is_none());
+
+        let mut decoder = StreamDecoder::new();
+        let mut buffer = Buffer::from_vec(buf);
+        let mut decoded = vec![];
+        while let Some(x) = decoder.decode_with_metadata(&mut buffer).unwrap() {
+            decoded.push(x);
+        }
+        decoder.finish().unwrap();
+        assert_eq!(
+            decoded,
+            vec![(batch.clone(), HashMap::new()), (batch, metadata)]
+        );
+    }
+
     #[test]
     fn test_roundtrip_nested_dict() {
         let inner: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
@@ -1787,6 +2134,53 @@ mod tests {
         assert_eq!(run_array_1_unsliced, output_batch.column(0).into_data());
     }
 
+    #[test]
+    fn test_roundtrip_list_view() {
+        // [[1, 2], null, [], [2, 3], [3]]
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        let list_view = ListViewArray::new(
+            field,
+            ScalarBuffer::from(vec![0, 2, 2, 1, 2]),
+            ScalarBuffer::from(vec![2, 0, 0, 2, 1]),
+            Arc::new(Int32Array::from(vec![1, 2, 3])),
+            Some(NullBuffer::from(vec![true, false, true, true, true])),
+        );
+        let list_view_sliced = list_view.slice(1, 3);
+
+        let mut builder = LargeListViewBuilder::new(StringBuilder::new());
+        builder.append_value([Some("a"), None]);
+        builder.append_null();
+        builder.append_value([Some("b")]);
+        builder.append(true);
+        builder.append_value([Some("c"), Some("d")]);
+        let large_list_view = builder.finish();
+
+        let mut builder = LargeListViewBuilder::new(Int32Builder::new());
+        builder.append_value([Some(1), Some(2)]);
+        builder.append_value([None]);
+        builder.append_value([]);
+        let large_list_view_ints = builder.finish();
+
+        let batch = RecordBatch::try_from_iter(vec![
+            ("list_view", Arc::new(list_view) as ArrayRef),
+            ("large_list_view", Arc::new(large_list_view) as ArrayRef),
+        ])
+        .unwrap();
+        assert_eq!(batch, roundtrip_ipc(&batch));
+        assert_eq!(batch, roundtrip_ipc_stream(&batch));
+
+        let batch = RecordBatch::try_from_iter(vec![
+            ("list_view", Arc::new(list_view_sliced) as ArrayRef),
+            (
+                "large_list_view",
+                Arc::new(large_list_view_ints) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        assert_eq!(batch, roundtrip_ipc(&batch));
+        assert_eq!(batch, roundtrip_ipc_stream(&batch));
+    }
+
     #[test]
     fn test_roundtrip_stream_nested_dict() {
         let xs = vec!["AA", "BB", "AA", "CC", "BB"];
@@ -2136,6 +2530,7 @@ mod tests {
             None,
             &message.version(),
             false,
+            false,
         )
         .unwrap();
         assert_eq!(batch, roundtrip);
@@ -2173,6 +2568,7 @@ mod tests {
             None,
             &message.version(),
             true,
+            false,
         );
 
         let error = result.unwrap_err();
@@ -2235,4 +2631,272 @@ mod tests {
 
         assert_eq!(batch, roundtrip_batch);
     }
+
+    /// Re-encodes the record batch message of `values` as a dictionary batch message
+    fn dictionary_message(id: i64, values: ArrayRef, is_delta: bool) -> EncodedData {
+        let batch = RecordBatch::try_from_iter([("", values)]).unwrap();
+        let (_, encoded) = IpcDataGenerator::default()
+            .encoded_batch(
+                &batch,
+                &mut DictionaryTracker::new(false),
+                &IpcWriteOptions::default(),
+            )
+            .unwrap();
+        let message = root_as_message(&encoded.ipc_message).unwrap();
+        let record_batch = message.header_as_record_batch().unwrap();
+
+        let mut fbb = flatbuffers::FlatBufferBuilder::new();
+        let nodes = fbb.create_vector_from_iter(record_batch.nodes().unwrap().iter());
+        let buffers = fbb.create_vector_from_iter(record_batch.buffers().unwrap().iter());
+        let mut batch_builder = crate::RecordBatchBuilder::new(&mut fbb);
+        batch_builder.add_length(record_batch.length());
+        batch_builder.add_nodes(nodes);
+        batch_builder.add_buffers(buffers);
+        let data = batch_builder.finish();
+
+        let mut dictionary_builder = crate::DictionaryBatchBuilder::new(&mut fbb);
+        dictionary_builder.add_id(id);
+        dictionary_builder.add_data(data);
+        dictionary_builder.add_isDelta(is_delta);
+        let header = dictionary_builder.finish().as_union_value();
+
+        let mut message_builder = crate::MessageBuilder::new(&mut fbb);
+        message_builder.add_version(message.version());
+        message_builder.add_header_type(crate::MessageHeader::DictionaryBatch);
+        message_builder.add_bodyLength(message.bodyLength());
+        message_builder.add_header(header);
+        let root = message_builder.finish();
+        fbb.finish(root, None);
+
+        EncodedData {
+            ipc_message: fbb.finished_data().to_vec(),
+            arrow_data: encoded.arrow_data,
+        }
+    }
+
+    /// Writes a stream of `schema` containing `messages`
+    fn write_stream(schema: &Schema, messages: Vec<EncodedData>) -> Vec<u8> {
+        let options = IpcWriteOptions::default();
+        let mut buf = vec![];
+        let encoded = IpcDataGenerator::default().schema_to_bytes(schema, &options);
+        write_message(&mut buf, encoded, &options).unwrap();
+        for message in messages {
+            write_message(&mut buf, message, &options).unwrap();
+        }
+        // End of stream marker
+        buf.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0]);
+        buf
+    }
+
+    #[test]
+    fn test_read_delta_dictionary() {
+        let batch = |keys: Vec<i32>, values: Vec<&str>| {
+            let dict =
+                DictionaryArray::new(Int32Array::from(keys), Arc::new(StringArray::from(values)));
+            RecordBatch::try_from_iter([("dict", Arc::new(dict) as ArrayRef)]).unwrap()
+        };
+        let expected = [
+            batch(vec![0, 1], vec!["a", "b"]),
+            batch(vec![2, 0], vec!["a", "b", "c"]),
+            batch(vec![0], vec!["x"]),
+        ];
+        let record_batch_message = |batch: &RecordBatch| {
+            IpcDataGenerator::default()
+                .encoded_batch(
+                    batch,
+                    &mut DictionaryTracker::new(false),
+                    &IpcWriteOptions::default(),
+                )
+                .unwrap()
+                .1
+        };
+        let values = |v: Vec<&str>| Arc::new(StringArray::from(v)) as ArrayRef;
+
+        let data = write_stream(
+            expected[0].schema_ref(),
+            vec![
+                dictionary_message(0, values(vec!["a", "b"]), false),
+                record_batch_message(&expected[0]),
+                // Append "c" to the existing dictionary
+                dictionary_message(0, values(vec!["c"]), true),
+                record_batch_message(&expected[1]),
+                // Replace the dictionary
+                dictionary_message(0, values(vec!["x"]), false),
+                record_batch_message(&expected[2]),
+            ],
+        );
+
+        let reader = StreamReader::try_new(std::io::Cursor::new(&data), None).unwrap();
+        let actual = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(actual, expected);
+
+        for chunk_size in [1, 7, data.len()] {
+            let mut decoder = StreamDecoder::new();
+            let mut actual = vec![];
+            for chunk in data.chunks(chunk_size) {
+                let mut buffer = Buffer::from(chunk);
+                while !buffer.is_empty() {
+                    actual.extend(decoder.decode(&mut buffer).unwrap());
+                }
+            }
+            decoder.finish().unwrap();
+            assert_eq!(actual, expected);
+        }
+
+        // A delta dictionary batch requires an existing dictionary
+        let data = write_stream(
+            expected[0].schema_ref(),
+            vec![dictionary_message(0, values(vec!["c"]), true)],
+        );
+        let mut reader = StreamReader::try_new(std::io::Cursor::new(&data), None).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: delta dictionary batch for dictionary id 0 with no existing dictionary"
+        );
+    }
+
+    /// Encodes `batch` as a stream with big endian data, reversing the fields of the values
+    /// of each buffer of the batch, whose widths are given by `buffer_fields`
+    fn big_endian_stream(batch: &RecordBatch, buffer_fields: &[&[usize]]) -> Vec<u8> {
+        let options = IpcWriteOptions::default();
+        let (_, mut encoded) = IpcDataGenerator::default()
+            .encoded_batch(batch, &mut DictionaryTracker::new(false), &options)
+            .unwrap();
+        let message = root_as_message(&encoded.ipc_message).unwrap();
+        let buffers = message.header_as_record_batch().unwrap().buffers().unwrap();
+        assert_eq!(buffers.len(), buffer_fields.len());
+        for (buffer, fields) in buffers.iter().zip(buffer_fields) {
+            let start = buffer.offset() as usize;
+            let data = &mut encoded.arrow_data[start..start + buffer.length() as usize];
+            let width = fields.iter().sum::<usize>().max(1);
+            for value in data.chunks_exact_mut(width) {
+                let mut offset = 0;
+                for field in *fields {
+                    value[offset..offset + field].reverse();
+                    offset += field;
+                }
+            }
+        }
+
+        let mut fbb = flatbuffers::FlatBufferBuilder::new();
+        let fields: Vec<_> = batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| crate::convert::build_field(&mut fbb, f))
+            .collect();
+        let fields = fbb.create_vector(&fields);
+        let mut schema_builder = crate::SchemaBuilder::new(&mut fbb);
+        schema_builder.add_fields(fields);
+        schema_builder.add_endianness(crate::Endianness::Big);
+        let header = schema_builder.finish().as_union_value();
+        let mut message_builder = crate::MessageBuilder::new(&mut fbb);
+        message_builder.add_version(MetadataVersion::V5);
+        message_builder.add_header_type(crate::MessageHeader::Schema);
+        message_builder.add_bodyLength(0);
+        message_builder.add_header(header);
+        let root = message_builder.finish();
+        fbb.finish(root, None);
+        let schema = EncodedData {
+            ipc_message: fbb.finished_data().to_vec(),
+            arrow_data: vec![],
+        };
+
+        let mut buf = vec![];
+        write_message(&mut buf, schema, &options).unwrap();
+        write_message(&mut buf, encoded, &options).unwrap();
+        buf.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0]);
+        buf
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_read_big_endian() {
+        let list = LargeListArray::from_iter_primitive::<Int16Type, _, _>([
+            Some(vec![Some(1), Some(-2)]),
+            None,
+            Some(vec![Some(300)]),
+        ]);
+        let intervals = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNano::new(1, 2, 3),
+            IntervalMonthDayNano::new(-1, 20, 3_000_000_000),
+            IntervalMonthDayNano::new(0, 0, -1),
+        ]);
+        let views = StringViewArray::from(vec![
+            "a string longer than twelve bytes",
+            "another string that is not inlined",
+            "and one more string of some length",
+        ]);
+        let batch = RecordBatch::try_from_iter([
+            (
+                "i32",
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(-3)])) as ArrayRef,
+            ),
+            (
+                "f64",
+                Arc::new(Float64Array::from(vec![1.5, 2.25, -0.5])) as _,
+            ),
+            (
+                "decimal",
+                Arc::new(
+                    Decimal128Array::from(vec![123, -456, 789])
+                        .with_precision_and_scale(10, 2)
+                        .unwrap(),
+                ) as _,
+            ),
+            (
+                "utf8",
+                Arc::new(StringArray::from(vec!["hello", "", "world"])) as _,
+            ),
+            ("list", Arc::new(list) as _),
+            ("interval", Arc::new(intervals) as _),
+            ("view", Arc::new(views) as _),
+        ])
+        .unwrap();
+
+        let data = big_endian_stream(
+            &batch,
+            &[
+                &[],
+                &[4],
+                &[],
+                &[8],
+                &[],
+                &[16],
+                &[],
+                &[4],
+                &[],
+                &[],
+                &[8],
+                &[],
+                &[2],
+                &[],
+                &[4, 4, 8],
+                &[],
+                &[4, 1, 1, 1, 1, 4, 4],
+                &[],
+            ],
+        );
+
+        let reader = StreamReader::try_new(data.as_slice(), None)
+            .unwrap()
+            .with_ensure_native_endian(true);
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, vec![batch.clone()]);
+
+        let mut reader = StreamReader::try_new(data.as_slice(), None).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "Ipc error: the endianness of the source system does not match the endianness of the target system.");
+
+        let mut decoder = StreamDecoder::new().with_ensure_native_endian(true);
+        let mut buffer = Buffer::from_vec(data.clone());
+        assert_eq!(decoder.decode(&mut buffer).unwrap().unwrap(), batch);
+        decoder.decode(&mut buffer).unwrap();
+        decoder.finish().unwrap();
+
+        let mut decoder = StreamDecoder::new();
+        let err = decoder.decode(&mut Buffer::from_vec(data)).unwrap_err();
+        assert_eq!(err.to_string(), "Ipc error: the endianness of the source system does not match the endianness of the target system.");
+    }
 }
@@ -0,0 +1,700 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Asynchronous readers for the Arrow IPC stream and file formats
+//!
+//! * [`AsyncStreamReader`] reads the IPC stream format from an [`AsyncRead`], yielding a
+//!   [`Stream`] of [`RecordBatch`]
+//! * [`FileStreamBuilder`] reads the IPC file format from an [`AsyncFileReader`], fetching
+//!   the footer and then only the byte ranges of the requested record batches
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{Int32Array, RecordBatch};
+//! # use arrow_ipc::reader::async_reader::{AsyncStreamReader, FileStreamBuilder};
+//! # use arrow_ipc::writer::{FileWriter, StreamWriter};
+//! # use futures::TryStreamExt;
+//! # #[tokio::main(flavor="current_thread")]
+//! # async fn main() {
+//! let batch = RecordBatch::try_from_iter([
+//!     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as _),
+//! ]).unwrap();
+//!
+//! // Read an IPC stream
+//! let mut buf = vec![];
+//! let mut writer = StreamWriter::try_new(&mut buf, &batch.schema()).unwrap();
+//! writer.write(&batch).unwrap();
+//! writer.finish().unwrap();
+//!
+//! let reader = AsyncStreamReader::try_new(buf.as_slice()).await.unwrap();
+//! let batches: Vec<_> = reader.try_collect().await.unwrap();
+//! assert_eq!(batches, vec![batch.clone()]);
+//!
+//! // Read an IPC file
+//! let mut buf = vec![];
+//! let mut writer = FileWriter::try_new(&mut buf, &batch.schema()).unwrap();
+//! writer.write(&batch).unwrap();
+//! writer.finish().unwrap();
+//!
+//! let builder = FileStreamBuilder::new(std::io::Cursor::new(buf)).await.unwrap();
+//! assert_eq!(builder.num_batches(), 1);
+//! let batches: Vec<_> = builder.build().await.unwrap().try_collect().await.unwrap();
+//! assert_eq!(batches, vec![batch]);
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::Formatter;
+use std::io::SeekFrom;
+use std::ops::Range;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use arrow_array::RecordBatch;
+use arrow_buffer::Buffer;
+use arrow_schema::{ArrowError, SchemaRef};
+use bytes::Bytes;
+use flatbuffers::VerifierOptions;
+use futures::future::{poll_fn, BoxFuture, FutureExt};
+use futures::ready;
+use futures::stream::Stream;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, ReadBuf};
+
+use crate::reader::{check_endianness, read_footer_length, FileDecoder, FileFooter, StreamDecoder};
+use crate::Block;
+
+#[cfg(feature = "object_store")]
+mod store;
+
+#[cfg(feature = "object_store")]
+pub use store::IpcObjectReader;
+
+/// The default number of bytes [`AsyncStreamReader`] reads at a time
+const DEFAULT_READ_SIZE: usize = 64 * 1024;
+
+/// Reads the Arrow IPC stream format from an [`AsyncRead`]
+///
+/// This is the asynchronous equivalent of [`StreamReader`](crate::reader::StreamReader),
+/// and yields a [`Stream`] of [`RecordBatch`]
+pub struct AsyncStreamReader<R> {
+    reader: R,
+    decoder: StreamDecoder,
+    schema: SchemaRef,
+    /// Data read from `reader` not yet passed to `decoder`
+    buffer: Buffer,
+    /// Scratch space for reads from `reader`
+    scratch: Vec<u8>,
+    /// A batch decoded whilst reading the schema
+    pending: Option<RecordBatch>,
+    /// The number of bytes to read from `reader` at a time
+    read_size: usize,
+    finished: bool,
+}
+
+impl<R> std::fmt::Debug for AsyncStreamReader<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncStreamReader<R>")
+            .field("decoder", &self.decoder)
+            .field("schema", &self.schema)
+            .field("read_size", &self.read_size)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncStreamReader<R> {
+    /// Try to create a new [`AsyncStreamReader`], reading the schema from `reader`
+    pub async fn try_new(reader: R) -> Result<Self, ArrowError> {
+        Self::try_new_with_read_size(reader, DEFAULT_READ_SIZE).await
+    }
+
+    /// Try to create a new [`AsyncStreamReader`] that reads up to `read_size` bytes
+    /// from `reader` at a time
+    pub async fn try_new_with_read_size(reader: R, read_size: usize) -> Result<Self, ArrowError> {
+        let mut decoder = StreamDecoder::new();
+        let mut buffer = Buffer::from_vec(Vec::<u8>::new());
+        let mut scratch = vec![];
+        let mut reader = reader;
+        let read_size = read_size.max(1);
+
+        let mut pending = None;
+        let schema = loop {
+            if let Some(schema) = decoder.schema() {
+                break schema;
+            }
+            let read =
+                poll_fn(|cx| poll_read_buffer(&mut reader, cx, &mut scratch, read_size)).await?;
+            match read {
+                Some(read) => buffer = read,
+                None => {
+                    return Err(ArrowError::IpcError(
+                        "Unexpected end of stream before schema".to_string(),
+                    ))
+                }
+            }
+            // The first batch may be decoded alongside the schema
+            pending = decoder.decode(&mut buffer)?;
+        };
+
+        Ok(Self {
+            reader,
+            decoder,
+            schema,
+            buffer,
+            scratch,
+            pending,
+            read_size,
+            finished: false,
+        })
+    }
+
+    /// Returns the schema of the stream
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Gets a reference to the underlying reader
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Gets a mutable reference to the underlying reader
+    ///
+    /// It is inadvisable to directly read from the underlying reader
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    fn poll_next_batch(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<RecordBatch>, ArrowError>> {
+        if let Some(batch) = self.pending.take() {
+            return Poll::Ready(Ok(Some(batch)));
+        }
+        loop {
+            if self.decoder.is_finished() {
+                return Poll::Ready(Ok(None));
+            }
+            if !self.buffer.is_empty() {
+                if let Some(batch) = self.decoder.decode(&mut self.buffer)? {
+                    return Poll::Ready(Ok(Some(batch)));
+                }
+                continue;
+            }
+            let read = poll_read_buffer(&mut self.reader, cx, &mut self.scratch, self.read_size);
+            match ready!(read)? {
+                Some(read) => self.buffer = read,
+                None => {
+                    // A stream may end without an end of stream marker
+                    self.decoder.finish()?;
+                    return Poll::Ready(Ok(None));
+                }
+            }
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for AsyncStreamReader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.finished {
+            return Poll::Ready(None);
+        }
+        let result = ready!(self.poll_next_batch(cx));
+        if !matches!(result, Ok(Some(_))) {
+            self.finished = true;
+        }
+        Poll::Ready(result.transpose())
+    }
+}
+
+/// Reads up to `read_size` bytes from `reader`, returning `None` at the end of input
+fn poll_read_buffer<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    cx: &mut Context<'_>,
+    scratch: &mut Vec<u8>,
+    read_size: usize,
+) -> Poll<Result<Option<Buffer>, ArrowError>> {
+    scratch.resize(read_size, 0);
+    let mut read_buf = ReadBuf::new(scratch);
+    ready!(Pin::new(reader).poll_read(cx, &mut read_buf))?;
+    let read = read_buf.filled().len();
+    if read == 0 {
+        return Poll::Ready(Ok(None));
+    }
+    scratch.truncate(read);
+    Poll::Ready(Ok(Some(Buffer::from_vec(std::mem::take(scratch)))))
+}
+
+/// The asynchronous interface used by [`FileStreamBuilder`] to read IPC files
+///
+/// Notably this interface does not require the source to implement [`AsyncSeek`],
+/// allowing it to be implemented for sources such as object storage, see
+/// `IpcObjectReader` (requires the `object_store` feature)
+pub trait AsyncFileReader: Send {
+    /// Retrieve the bytes in `range`
+    fn get_bytes(&mut self, range: Range<usize>) -> BoxFuture<'_, Result<Bytes, ArrowError>>;
+
+    /// Retrieve multiple byte ranges. The default implementation will call `get_bytes` sequentially
+    fn get_byte_ranges(
+        &mut self,
+        ranges: Vec<Range<usize>>,
+    ) -> BoxFuture<'_, Result<Vec<Bytes>, ArrowError>> {
+        async move {
+            let mut result = Vec::with_capacity(ranges.len());
+
+            for range in ranges.into_iter() {
+                let data = self.get_bytes(range).await?;
+                result.push(data);
+            }
+
+            Ok(result)
+        }
+        .boxed()
+    }
+
+    /// Returns the total size of the file in bytes
+    fn get_file_size(&mut self) -> BoxFuture<'_, Result<usize, ArrowError>>;
+}
+
+impl AsyncFileReader for Box<dyn AsyncFileReader> {
+    fn get_bytes(&mut self, range: Range<usize>) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+        self.as_mut().get_bytes(range)
+    }
+
+    fn get_byte_ranges(
+        &mut self,
+        ranges: Vec<Range<usize>>,
+    ) -> BoxFuture<'_, Result<Vec<Bytes>, ArrowError>> {
+        self.as_mut().get_byte_ranges(ranges)
+    }
+
+    fn get_file_size(&mut self) -> BoxFuture<'_, Result<usize, ArrowError>> {
+        self.as_mut().get_file_size()
+    }
+}
+
+impl<T: AsyncRead + AsyncSeek + Unpin + Send> AsyncFileReader for T {
+    fn get_bytes(&mut self, range: Range<usize>) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+        async move {
+            self.seek(SeekFrom::Start(range.start as u64)).await?;
+
+            let to_read = range.end - range.start;
+            let mut buffer = Vec::with_capacity(to_read);
+            let read = self.take(to_read as u64).read_to_end(&mut buffer).await?;
+            if read != to_read {
+                return Err(ArrowError::IpcError(format!(
+                    "Expected to read {to_read} bytes, got {read}"
+                )));
+            }
+
+            Ok(buffer.into())
+        }
+        .boxed()
+    }
+
+    fn get_file_size(&mut self) -> BoxFuture<'_, Result<usize, ArrowError>> {
+        async move {
+            let size = self.seek(SeekFrom::End(0)).await?;
+            Ok(size as usize)
+        }
+        .boxed()
+    }
+}
+
+/// Builds a [`FileStream`] reading the Arrow IPC file format from an [`AsyncFileReader`]
+///
+/// [`FileStreamBuilder::new`] fetches the footer of the file, and [`FileStreamBuilder::build`]
+/// fetches its dictionaries. The returned [`FileStream`] then fetches only the byte ranges
+/// of the selected record batches
+pub struct FileStreamBuilder<T> {
+    input: T,
+    footer: FileFooter,
+    projection: Option<Vec<usize>>,
+    batches: Option<Vec<usize>>,
+    ensure_native_endian: bool,
+}
+
+impl<T> std::fmt::Debug for FileStreamBuilder<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileStreamBuilder<T>")
+            .field("schema", &self.footer.schema)
+            .field("num_batches", &self.footer.record_batches.len())
+            .field("projection", &self.projection)
+            .field("batches", &self.batches)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: AsyncFileReader + Unpin + 'static> FileStreamBuilder<T> {
+    /// Create a new [`FileStreamBuilder`], fetching the footer of the file from `input`
+    pub async fn new(input: T) -> Result<Self, ArrowError> {
+        Self::new_with_options(input, VerifierOptions::default()).await
+    }
+
+    /// Create a new [`FileStreamBuilder`] with the provided flatbuffer [`VerifierOptions`],
+    /// see [`FileReaderBuilder`](crate::reader::FileReaderBuilder) for details
+    pub async fn new_with_options(
+        mut input: T,
+        verifier_options: VerifierOptions,
+    ) -> Result<Self, ArrowError> {
+        let file_size = input.get_file_size().await?;
+        // Space for ARROW_MAGIC (6 bytes) and length (4 bytes)
+        if file_size < 10 {
+            return Err(ArrowError::ParseError(format!(
+                "Arrow file of {file_size} bytes is too small to contain a footer"
+            )));
+        }
+        let trailer = input.get_bytes(file_size - 10..file_size).await?;
+        let footer_len = read_footer_length(trailer.as_ref().try_into().unwrap())?;
+        let footer_start = file_size.checked_sub(10 + footer_len).ok_or_else(|| {
+            ArrowError::ParseError(format!(
+                "Footer length {footer_len} exceeds Arrow file of {file_size} bytes"
+            ))
+        })?;
+
+        let footer_data = input.get_bytes(footer_start..file_size - 10).await?;
+        let footer = FileFooter::try_new(&footer_data, &verifier_options)?;
+        Ok(Self {
+            input,
+            footer,
+            projection: None,
+            batches: None,
+            ensure_native_endian: false,
+        })
+    }
+
+    /// Returns the schema of the file
+    pub fn schema(&self) -> SchemaRef {
+        self.footer.schema.clone()
+    }
+
+    /// Returns the number of record batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.footer.record_batches.len()
+    }
+
+    /// Returns the user defined metadata of the file
+    pub fn custom_metadata(&self) -> &HashMap<String, String> {
+        &self.footer.custom_metadata
+    }
+
+    /// Only read the columns with the given (zero-based) indices
+    pub fn with_projection(mut self, projection: Vec<usize>) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Only read the record batches with the given (zero-based) indices, in the given
+    /// order. By default all record batches are read
+    pub fn with_batches(mut self, batches: Vec<usize>) -> Self {
+        self.batches = Some(batches);
+        self
+    }
+
+    /// Specifies whether to read files with non-native endianness, see
+    /// [`FileReaderBuilder::with_ensure_native_endian`](crate::reader::FileReaderBuilder::with_ensure_native_endian)
+    pub fn with_ensure_native_endian(mut self, ensure_native_endian: bool) -> Self {
+        self.ensure_native_endian = ensure_native_endian;
+        self
+    }
+
+    /// Fetch the dictionaries of the file, and build a [`FileStream`]
+    pub async fn build(mut self) -> Result<FileStream<T>, ArrowError> {
+        check_endianness(self.footer.endianness, self.ensure_native_endian)?;
+        let num_batches = self.num_batches();
+        let blocks = match self.batches {
+            Some(batches) => batches
+                .into_iter()
+                .map(|idx| match self.footer.record_batches.get(idx) {
+                    Some(block) => Ok(*block),
+                    None => Err(ArrowError::InvalidArgumentError(format!(
+                        "Cannot read batch at index {idx} from {num_batches} total batches"
+                    ))),
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            None => std::mem::take(&mut self.footer.record_batches),
+        };
+
+        let mut decoder = FileDecoder::new(self.footer.schema, self.footer.version)
+            .with_endianness(self.footer.endianness);
+        if let Some(projection) = self.projection {
+            decoder = decoder.with_projection(projection)
+        }
+
+        let dictionaries = &self.footer.dictionaries;
+        let ranges = dictionaries.iter().map(block_range).collect();
+        let data = self.input.get_byte_ranges(ranges).await?;
+        for (block, data) in dictionaries.iter().zip(data) {
+            decoder.read_dictionary(block, &Buffer::from_bytes(data.into()))?;
+        }
+
+        Ok(FileStream {
+            blocks: blocks.into_iter(),
+            decoder,
+            state: FileStreamState::Idle(self.input),
+        })
+    }
+}
+
+/// Returns the byte range of `block`
+fn block_range(block: &Block) -> Range<usize> {
+    let start = block.offset() as usize;
+    start..start + block.metaDataLength() as usize + block.bodyLength() as usize
+}
+
+enum FileStreamState<T> {
+    /// Waiting to fetch the next record batch
+    Idle(T),
+    /// Fetching a record batch
+    Reading(BoxFuture<'static, (T, Block, Result<Bytes, ArrowError>)>),
+    /// An error occurred, or all record batches have been read
+    Finished,
+}
+
+/// A [`Stream`] of the [`RecordBatch`] in an Arrow IPC file, created by [`FileStreamBuilder`]
+pub struct FileStream<T> {
+    blocks: std::vec::IntoIter<Block>,
+    decoder: FileDecoder,
+    state: FileStreamState<T>,
+}
+
+impl<T> std::fmt::Debug for FileStream<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileStream<T>")
+            .field("decoder", &self.decoder)
+            .field("remaining_batches", &self.blocks.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> FileStream<T> {
+    /// Returns the schema of the file
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema.clone()
+    }
+}
+
+impl<T: AsyncFileReader + Unpin + 'static> Stream for FileStream<T> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match std::mem::replace(&mut self.state, FileStreamState::Finished) {
+                FileStreamState::Idle(mut input) => {
+                    let block = match self.blocks.next() {
+                        Some(block) => block,
+                        None => {
+                            self.state = FileStreamState::Idle(input);
+                            return Poll::Ready(None);
+                        }
+                    };
+                    let fut = async move {
+                        let data = input.get_bytes(block_range(&block)).await;
+                        (input, block, data)
+                    }
+                    .boxed();
+                    self.state = FileStreamState::Reading(fut);
+                }
+                FileStreamState::Reading(mut fut) => {
+                    let (input, block, data) = match fut.poll_unpin(cx) {
+                        Poll::Ready(result) => result,
+                        Poll::Pending => {
+                            self.state = FileStreamState::Reading(fut);
+                            return Poll::Pending;
+                        }
+                    };
+                    let result = data.and_then(|data| {
+                        let data = Buffer::from_bytes(data.into());
+                        self.decoder.read_record_batch(&block, &data)
+                    });
+                    match result {
+                        Ok(Some(batch)) => {
+                            self.state = FileStreamState::Idle(input);
+                            return Poll::Ready(Some(Ok(batch)));
+                        }
+                        // Skip blocks that do not contain a record batch
+                        Ok(None) => self.state = FileStreamState::Idle(input),
+                        Err(e) => return Poll::Ready(Some(Err(e))),
+                    }
+                }
+                FileStreamState::Finished => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    use arrow_array::types::Int32Type;
+    use arrow_array::{DictionaryArray, Int32Array};
+    use futures::TryStreamExt;
+
+    use crate::writer::{FileWriter, StreamWriter};
+
+    fn test_batches() -> Vec<RecordBatch> {
+        (0..5)
+            .map(|i| {
+                let values = (0..100).map(|x| x * i).collect::<Int32Array>();
+                let strings = (0..100).map(|x| ["a", "b", "c", "d", "e", "f", "g"][x % 7]);
+                let dict = strings.collect::<DictionaryArray<Int32Type>>();
+                RecordBatch::try_from_iter([
+                    ("a", Arc::new(values) as _),
+                    ("b", Arc::new(dict) as _),
+                ])
+                .unwrap()
+            })
+            .collect()
+    }
+
+    fn write_stream(batches: &[RecordBatch]) -> Vec<u8> {
+        let mut buf = vec![];
+        let mut writer = StreamWriter::try_new(&mut buf, &batches[0].schema()).unwrap();
+        for batch in batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+        buf
+    }
+
+    fn write_file(batches: &[RecordBatch]) -> Vec<u8> {
+        let mut buf = vec![];
+        let mut writer = FileWriter::try_new(&mut buf, &batches[0].schema()).unwrap();
+        for batch in batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+        buf
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_reader() {
+        let batches = test_batches();
+        let buf = write_stream(&batches);
+
+        for read_size in [1, 7, 100, 1024 * 1024] {
+            let reader = AsyncStreamReader::try_new_with_read_size(buf.as_slice(), read_size)
+                .await
+                .unwrap();
+            assert_eq!(reader.schema(), batches[0].schema());
+            let read: Vec<_> = reader.try_collect().await.unwrap();
+            assert_eq!(read, batches);
+        }
+
+        // Truncated stream
+        let reader = AsyncStreamReader::try_new(&buf[..buf.len() - 100])
+            .await
+            .unwrap();
+        let err = reader.try_collect::<Vec<_>>().await.unwrap_err();
+        assert_eq!(err.to_string(), "Ipc error: Unexpected End of Stream");
+
+        let err = AsyncStreamReader::try_new(&buf[..0]).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Unexpected end of stream before schema"
+        );
+    }
+
+    /// An [`AsyncFileReader`] that records the ranges read
+    struct TestReader {
+        data: Bytes,
+        requests: Vec<Range<usize>>,
+    }
+
+    impl AsyncFileReader for TestReader {
+        fn get_bytes(&mut self, range: Range<usize>) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+            self.requests.push(range.clone());
+            futures::future::ready(Ok(self.data.slice(range))).boxed()
+        }
+
+        fn get_file_size(&mut self) -> BoxFuture<'_, Result<usize, ArrowError>> {
+            futures::future::ready(Ok(self.data.len())).boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_stream() {
+        let batches = test_batches();
+        let buf = write_file(&batches);
+
+        let builder = FileStreamBuilder::new(Cursor::new(buf.clone()))
+            .await
+            .unwrap();
+        assert_eq!(builder.num_batches(), 5);
+        assert_eq!(builder.schema(), batches[0].schema());
+        let read: Vec<_> = builder.build().await.unwrap().try_collect().await.unwrap();
+        assert_eq!(read, batches);
+
+        let input = TestReader {
+            data: buf.into(),
+            requests: vec![],
+        };
+        let builder = FileStreamBuilder::new(input).await.unwrap();
+        let blocks = builder.footer.record_batches.clone();
+        let dictionaries = builder.footer.dictionaries.clone();
+        let mut stream = builder
+            .with_batches(vec![3, 1])
+            .with_projection(vec![1])
+            .build()
+            .await
+            .unwrap();
+
+        let mut read = vec![];
+        while let Some(batch) = stream.try_next().await.unwrap() {
+            read.push(batch);
+        }
+        let expected = [&batches[3], &batches[1]].map(|b| b.project(&[1]).unwrap());
+        assert_eq!(read, expected);
+
+        // Only the trailer, footer, dictionaries and selected batches are fetched
+        let input = match stream.state {
+            FileStreamState::Idle(input) => input,
+            _ => unreachable!(),
+        };
+        let mut expected: Vec<_> = input.requests[..2].to_vec();
+        expected.extend(dictionaries.iter().map(block_range));
+        expected.push(block_range(&blocks[3]));
+        expected.push(block_range(&blocks[1]));
+        assert_eq!(input.requests, expected);
+
+        let builder = FileStreamBuilder::new(Cursor::new(write_file(&batches)))
+            .await
+            .unwrap();
+        let err = builder.with_batches(vec![5]).build().await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot read batch at index 5 from 5 total batches"
+        );
+
+        let err = FileStreamBuilder::new(Cursor::new(vec![0; 4]))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Arrow file of 4 bytes is too small to contain a footer"
+        );
+    }
+}
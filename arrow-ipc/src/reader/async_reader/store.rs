@@ -0,0 +1,125 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow_schema::ArrowError;
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use futures::{FutureExt, TryFutureExt};
+
+use object_store::{ObjectMeta, ObjectStore};
+
+use crate::reader::async_reader::AsyncFileReader;
+
+/// Reads Arrow IPC files in object storage using [`ObjectStore`]
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use arrow_ipc::reader::async_reader::{FileStreamBuilder, IpcObjectReader};
+/// # use futures::TryStreamExt;
+/// # use object_store::memory::InMemory;
+/// # use object_store::path::Path;
+/// # use object_store::ObjectStore;
+/// # async fn run() {
+/// # let store = Arc::new(InMemory::new());
+/// let location = Path::from("path/to/file.arrow");
+/// let meta = store.head(&location).await.unwrap();
+///
+/// let reader = IpcObjectReader::new(store, meta);
+/// let builder = FileStreamBuilder::new(reader).await.unwrap();
+/// let batches: Vec<_> = builder.build().await.unwrap().try_collect().await.unwrap();
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct IpcObjectReader {
+    store: Arc<dyn ObjectStore>,
+    meta: ObjectMeta,
+}
+
+impl IpcObjectReader {
+    /// Creates a new [`IpcObjectReader`] for the provided [`ObjectStore`] and [`ObjectMeta`]
+    ///
+    /// [`ObjectMeta`] can be obtained using [`ObjectStore::list`] or [`ObjectStore::head`]
+    pub fn new(store: Arc<dyn ObjectStore>, meta: ObjectMeta) -> Self {
+        Self { store, meta }
+    }
+}
+
+impl AsyncFileReader for IpcObjectReader {
+    fn get_bytes(&mut self, range: Range<usize>) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+        self.store
+            .get_range(&self.meta.location, range)
+            .map_err(|e| ArrowError::ExternalError(Box::new(e)))
+            .boxed()
+    }
+
+    fn get_byte_ranges(
+        &mut self,
+        ranges: Vec<Range<usize>>,
+    ) -> BoxFuture<'_, Result<Vec<Bytes>, ArrowError>> {
+        async move {
+            self.store
+                .get_ranges(&self.meta.location, &ranges)
+                .await
+                .map_err(|e| ArrowError::ExternalError(Box::new(e)))
+        }
+        .boxed()
+    }
+
+    fn get_file_size(&mut self) -> BoxFuture<'_, Result<usize, ArrowError>> {
+        futures::future::ready(Ok(self.meta.size)).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow_array::{Int32Array, RecordBatch};
+    use futures::TryStreamExt;
+    use object_store::memory::InMemory;
+    use object_store::path::Path;
+
+    use crate::reader::async_reader::FileStreamBuilder;
+    use crate::writer::FileWriter;
+
+    #[tokio::test]
+    async fn test_object_store() {
+        let batch =
+            RecordBatch::try_from_iter([("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as _)])
+                .unwrap();
+
+        let mut buf = vec![];
+        let mut writer = FileWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let store = Arc::new(InMemory::new());
+        let location = Path::from("test.arrow");
+        store.put(&location, buf.into()).await.unwrap();
+        let meta = store.head(&location).await.unwrap();
+
+        let reader = IpcObjectReader::new(store, meta);
+        let builder = FileStreamBuilder::new(reader).await.unwrap();
+        let read: Vec<_> = builder.build().await.unwrap().try_collect().await.unwrap();
+        assert_eq!(read, vec![batch.clone(), batch]);
+    }
+}
@@ -24,7 +24,10 @@ use arrow_buffer::{Buffer, MutableBuffer};
 use arrow_schema::{ArrowError, SchemaRef};
 
 use crate::convert::MessageBuffer;
-use crate::reader::{read_dictionary_impl, read_record_batch_impl};
+use crate::reader::{
+    check_endianness, message_custom_metadata, read_dictionary_impl, read_record_batch_impl,
+    RecordBatchWithMetadata,
+};
 use crate::{MessageHeader, CONTINUATION_MARKER};
 
 /// A low-level interface for reading [`RecordBatch`] data from a stream of bytes
@@ -42,6 +45,10 @@ pub struct StreamDecoder {
     buf: MutableBuffer,
     /// Whether or not array data in input buffers are required to be aligned
     require_alignment: bool,
+    /// Whether to convert data with non-native endianness to native endianness
+    ensure_native_endian: bool,
+    /// Whether the data of the stream must be converted to native endianness
+    swap_endianness: bool,
 }
 
 #[derive(Debug)]
@@ -102,6 +109,27 @@ impl StreamDecoder {
         self
     }
 
+    /// Specifies whether to read streams with non-native endianness, such as a stream
+    /// written on a big endian system read on a little endian system.
+    ///
+    /// If `ensure_native_endian` is true, the data of such streams is byte swapped into
+    /// native endianness as it is decoded. If false (the default), decoding the schema
+    /// of such a stream returns an error
+    pub fn with_ensure_native_endian(mut self, ensure_native_endian: bool) -> Self {
+        self.ensure_native_endian = ensure_native_endian;
+        self
+    }
+
+    /// Returns the schema of the stream, if it has been read
+    pub fn schema(&self) -> Option<SchemaRef> {
+        self.schema.clone()
+    }
+
+    /// Returns true if the end of stream marker has been read
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state, DecoderState::Finished)
+    }
+
     /// Try to read the next [`RecordBatch`] from the provided [`Buffer`]
     ///
     /// [`Buffer::advance`] will be called on `buffer` for any consumed bytes.
@@ -129,6 +157,18 @@ impl StreamDecoder {
     /// }
     /// ```
     pub fn decode(&mut self, buffer: &mut Buffer) -> Result<Option<RecordBatch>, ArrowError> {
+        Ok(self.decode_with_metadata(buffer)?.map(|(batch, _)| batch))
+    }
+
+    /// Try to read the next [`RecordBatch`] from the provided [`Buffer`], along with the
+    /// custom metadata of its message
+    ///
+    /// Behaves like [`Self::decode`], with the metadata empty if none was written for
+    /// the batch
+    pub fn decode_with_metadata(
+        &mut self,
+        buffer: &mut Buffer,
+    ) -> Result<Option<RecordBatchWithMetadata>, ArrowError> {
         while !buffer.is_empty() {
             match &mut self.state {
                 DecoderState::Header {
@@ -202,6 +242,10 @@ impl StreamDecoder {
                             }
 
                             let ipc_schema = message.header_as_schema().unwrap();
+                            self.swap_endianness = check_endianness(
+                                ipc_schema.endianness(),
+                                self.ensure_native_endian,
+                            )?;
                             let schema = crate::convert::fb_to_schema(ipc_schema);
                             self.state = DecoderState::default();
                             self.schema = Some(Arc::new(schema));
@@ -219,9 +263,11 @@ impl StreamDecoder {
                                 None,
                                 &version,
                                 self.require_alignment,
+                                self.swap_endianness,
                             )?;
+                            let metadata = message_custom_metadata(&message);
                             self.state = DecoderState::default();
-                            return Ok(Some(batch));
+                            return Ok(Some((batch, metadata)));
                         }
                         MessageHeader::DictionaryBatch => {
                             let dictionary = message.header_as_dictionary_batch().unwrap();
@@ -235,6 +281,7 @@ impl StreamDecoder {
                                 &mut self.dictionaries,
                                 &version,
                                 self.require_alignment,
+                                self.swap_endianness,
                             )?;
                             self.state = DecoderState::default();
                         }
@@ -0,0 +1,687 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Dense and sparse tensors, as encoded in IPC [Tensor] and [SparseTensor] messages
+//!
+//! Tensor messages are not part of an IPC stream or file, and are instead read and
+//! written individually with [`read_tensor`], [`read_sparse_tensor`] and
+//! [`IpcDataGenerator::tensor_to_bytes`]
+//!
+//! [Tensor]: https://github.com/apache/arrow/blob/main/format/Tensor.fbs
+//! [SparseTensor]: https://github.com/apache/arrow/blob/main/format/SparseTensor.fbs
+//! [`IpcDataGenerator::tensor_to_bytes`]: crate::writer::IpcDataGenerator::tensor_to_bytes
+
+use std::io::Read;
+
+use arrow_buffer::{Buffer, MutableBuffer};
+use arrow_schema::{ArrowError, DataType};
+use flatbuffers::{ForwardsUOffset, Vector};
+
+use crate::{Message, MessageHeader, CONTINUATION_MARKER};
+
+/// A dense tensor of fixed width values
+#[derive(Debug, Clone, PartialEq)]
+pub struct TensorData {
+    data_type: DataType,
+    shape: Vec<usize>,
+    strides: Option<Vec<usize>>,
+    names: Option<Vec<String>>,
+    data: Buffer,
+}
+
+impl TensorData {
+    /// Create a new [`TensorData`] from its values in `data`
+    ///
+    /// `strides` is the number of bytes between values in each dimension, if `None`
+    /// the values are in row major order. A tensor with an empty `shape` contains a
+    /// single value
+    pub fn try_new(
+        data_type: DataType,
+        data: Buffer,
+        shape: Vec<usize>,
+        strides: Option<Vec<usize>>,
+        names: Option<Vec<String>>,
+    ) -> Result<Self, ArrowError> {
+        let width = value_width(&data_type)?;
+        check_dimensions(&shape, names.as_deref())?;
+        let required = match &strides {
+            Some(strides) => {
+                if strides.len() != shape.len() {
+                    return Err(ArrowError::InvalidArgumentError(
+                        "shape and stride dimensions differ".to_string(),
+                    ));
+                }
+                // The byte offset of the last value, plus its width
+                match shape.contains(&0) {
+                    true => 0,
+                    false => {
+                        shape
+                            .iter()
+                            .zip(strides)
+                            .map(|(dim, stride)| (dim - 1) * stride)
+                            .sum::<usize>()
+                            + width
+                    }
+                }
+            }
+            None => shape.iter().product::<usize>() * width,
+        };
+        if data.len() < required {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Tensor requires {required} bytes of data, but only {} were provided",
+                data.len()
+            )));
+        }
+        Ok(Self {
+            data_type,
+            shape,
+            strides,
+            names,
+            data,
+        })
+    }
+
+    /// The data type of the values
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// The sizes of the dimensions
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// The number of bytes between values in each dimension, if `None` the
+    /// values are in row major order
+    pub fn strides(&self) -> Option<&[usize]> {
+        self.strides.as_deref()
+    }
+
+    /// The names of the dimensions
+    pub fn names(&self) -> Option<&[String]> {
+        self.names.as_deref()
+    }
+
+    /// The buffer containing the values
+    pub fn data(&self) -> &Buffer {
+        &self.data
+    }
+}
+
+/// The axis compressed by [`SparseIndex::Csx`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressedAxis {
+    /// Compressed sparse row (CSR)
+    Row,
+    /// Compressed sparse column (CSC)
+    Column,
+}
+
+/// The locations of the non-zero values of a [`SparseTensorData`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum SparseIndex {
+    /// Coordinate (COO) format
+    ///
+    /// `indices` is a row major matrix of `indices_type`, with a row containing
+    /// the coordinates of each non-zero value
+    Coo {
+        indices_type: DataType,
+        indices: Buffer,
+        /// Whether the coordinates are sorted in lexicographical order, and
+        /// contain no duplicates
+        is_canonical: bool,
+    },
+    /// Compressed sparse row (CSR) or column (CSC) format, for two-dimensional tensors
+    ///
+    /// The values of row (or column) `i` are at positions `indptr[i]..indptr[i + 1]`,
+    /// and `indices` contains the column (or row) of each value
+    Csx {
+        compressed_axis: CompressedAxis,
+        indptr_type: DataType,
+        indptr: Buffer,
+        indices_type: DataType,
+        indices: Buffer,
+    },
+}
+
+/// A sparse tensor of fixed width values
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseTensorData {
+    data_type: DataType,
+    shape: Vec<usize>,
+    names: Option<Vec<String>>,
+    non_zero_length: usize,
+    index: SparseIndex,
+    data: Buffer,
+}
+
+impl SparseTensorData {
+    /// Create a new [`SparseTensorData`] with `non_zero_length` values in `data`,
+    /// located by `index`
+    pub fn try_new(
+        data_type: DataType,
+        data: Buffer,
+        shape: Vec<usize>,
+        names: Option<Vec<String>>,
+        non_zero_length: usize,
+        index: SparseIndex,
+    ) -> Result<Self, ArrowError> {
+        let width = value_width(&data_type)?;
+        check_dimensions(&shape, names.as_deref())?;
+        check_length("data", &data, non_zero_length * width)?;
+        match &index {
+            SparseIndex::Coo {
+                indices_type,
+                indices,
+                ..
+            } => {
+                let width = index_width(indices_type)?;
+                check_length("indices", indices, non_zero_length * shape.len() * width)?;
+            }
+            SparseIndex::Csx {
+                compressed_axis,
+                indptr_type,
+                indptr,
+                indices_type,
+                indices,
+            } => {
+                if shape.len() != 2 {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Compressed sparse index requires a two-dimensional tensor, got {} dimensions",
+                        shape.len()
+                    )));
+                }
+                let compressed = match compressed_axis {
+                    CompressedAxis::Row => shape[0],
+                    CompressedAxis::Column => shape[1],
+                };
+                let width = index_width(indptr_type)?;
+                check_length("indptr", indptr, (compressed + 1) * width)?;
+                let width = index_width(indices_type)?;
+                check_length("indices", indices, non_zero_length * width)?;
+            }
+        }
+        Ok(Self {
+            data_type,
+            shape,
+            names,
+            non_zero_length,
+            index,
+            data,
+        })
+    }
+
+    /// The data type of the values
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// The sizes of the dimensions
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// The names of the dimensions
+    pub fn names(&self) -> Option<&[String]> {
+        self.names.as_deref()
+    }
+
+    /// The number of non-zero values
+    pub fn non_zero_length(&self) -> usize {
+        self.non_zero_length
+    }
+
+    /// The locations of the non-zero values
+    pub fn index(&self) -> &SparseIndex {
+        &self.index
+    }
+
+    /// The buffer containing the non-zero values
+    pub fn data(&self) -> &Buffer {
+        &self.data
+    }
+}
+
+/// Returns the byte width of the values of a tensor
+pub(crate) fn value_width(data_type: &DataType) -> Result<usize, ArrowError> {
+    match data_type {
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float16
+        | DataType::Float32
+        | DataType::Float64 => Ok(data_type.primitive_width().unwrap()),
+        d => Err(ArrowError::NotYetImplemented(format!(
+            "Tensors of {d} are not currently supported"
+        ))),
+    }
+}
+
+/// Returns the byte width of the values of a sparse index
+fn index_width(data_type: &DataType) -> Result<usize, ArrowError> {
+    match data_type {
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64 => Ok(data_type.primitive_width().unwrap()),
+        d => Err(ArrowError::InvalidArgumentError(format!(
+            "Sparse tensor indices must be integers, got {d}"
+        ))),
+    }
+}
+
+fn check_dimensions(shape: &[usize], names: Option<&[String]>) -> Result<(), ArrowError> {
+    match names {
+        Some(names) if names.len() != shape.len() => Err(ArrowError::InvalidArgumentError(
+            "number of dimensions and number of dimension names differ".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn check_length(name: &str, buffer: &Buffer, required: usize) -> Result<(), ArrowError> {
+    match buffer.len() < required {
+        true => Err(ArrowError::InvalidArgumentError(format!(
+            "Sparse tensor requires {required} bytes of {name}, but only {} were provided",
+            buffer.len()
+        ))),
+        false => Ok(()),
+    }
+}
+
+/// Reads a [`TensorData`] from an encapsulated IPC Tensor message
+pub fn read_tensor<R: Read>(reader: R) -> Result<TensorData, ArrowError> {
+    let (metadata, body) = read_message(reader)?;
+    let message = parse_message(&metadata)?;
+    let tensor = match message.header_type() {
+        MessageHeader::Tensor => message.header_as_tensor().unwrap(),
+        t => {
+            return Err(ArrowError::IpcError(format!(
+                "Expected a Tensor message, got {t:?}"
+            )))
+        }
+    };
+
+    let data_type = fb_to_data_type(tensor.type_type(), tensor.type_as_int(), || {
+        tensor.type_as_floating_point()
+    })?;
+    let (shape, names) = fb_to_shape(tensor.shape())?;
+    let strides = tensor
+        .strides()
+        .map(|s| s.iter().map(to_usize).collect::<Result<Vec<_>, _>>())
+        .transpose()?;
+    let data = body_buffer(&body, tensor.data())?;
+    TensorData::try_new(data_type, data, shape, strides, names)
+}
+
+/// Reads a [`SparseTensorData`] from an encapsulated IPC SparseTensor message
+pub fn read_sparse_tensor<R: Read>(reader: R) -> Result<SparseTensorData, ArrowError> {
+    let (metadata, body) = read_message(reader)?;
+    let message = parse_message(&metadata)?;
+    let tensor = match message.header_type() {
+        MessageHeader::SparseTensor => message.header_as_sparse_tensor().unwrap(),
+        t => {
+            return Err(ArrowError::IpcError(format!(
+                "Expected a SparseTensor message, got {t:?}"
+            )))
+        }
+    };
+
+    let data_type = fb_to_data_type(tensor.type_type(), tensor.type_as_int(), || {
+        tensor.type_as_floating_point()
+    })?;
+    let (shape, names) = fb_to_shape(tensor.shape())?;
+    let non_zero_length = to_usize(tensor.non_zero_length())?;
+
+    let index = match tensor.sparseIndex_type() {
+        crate::SparseTensorIndex::SparseTensorIndexCOO => {
+            let coo = tensor.sparseIndex_as_sparse_tensor_index_coo().unwrap();
+            let indices_type = fb_to_index_type(coo.indicesType())?;
+            let width = index_width(&indices_type)?;
+            let indices = body_buffer(&body, coo.indicesBuffer())?;
+            let indices = match coo.indicesStrides() {
+                Some(strides) => {
+                    let strides = strides
+                        .iter()
+                        .map(to_usize)
+                        .collect::<Result<Vec<_>, _>>()?;
+                    row_major_indices(indices, &strides, non_zero_length, shape.len(), width)?
+                }
+                None => indices,
+            };
+            SparseIndex::Coo {
+                indices_type,
+                indices,
+                is_canonical: coo.isCanonical(),
+            }
+        }
+        crate::SparseTensorIndex::SparseMatrixIndexCSX => {
+            let csx = tensor.sparseIndex_as_sparse_matrix_index_csx().unwrap();
+            let compressed_axis = match csx.compressedAxis() {
+                crate::SparseMatrixCompressedAxis::Row => CompressedAxis::Row,
+                crate::SparseMatrixCompressedAxis::Column => CompressedAxis::Column,
+                a => {
+                    return Err(ArrowError::IpcError(format!(
+                        "Unknown sparse matrix compressed axis {a:?}"
+                    )))
+                }
+            };
+            SparseIndex::Csx {
+                compressed_axis,
+                indptr_type: fb_to_index_type(csx.indptrType())?,
+                indptr: body_buffer(&body, csx.indptrBuffer())?,
+                indices_type: fb_to_index_type(csx.indicesType())?,
+                indices: body_buffer(&body, csx.indicesBuffer())?,
+            }
+        }
+        t => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Reading sparse tensors with index {t:?} is not currently supported"
+            )))
+        }
+    };
+
+    let data = body_buffer(&body, tensor.data())?;
+    SparseTensorData::try_new(data_type, data, shape, names, non_zero_length, index)
+}
+
+/// Reads an encapsulated message, returning its flatbuffer metadata and body
+fn read_message<R: Read>(mut reader: R) -> Result<(Vec<u8>, Buffer), ArrowError> {
+    let mut len = [0; 4];
+    reader.read_exact(&mut len)?;
+    if len == CONTINUATION_MARKER {
+        reader.read_exact(&mut len)?;
+    }
+    let len = i32::from_le_bytes(len);
+    let len = usize::try_from(len)
+        .map_err(|_| ArrowError::ParseError(format!("Invalid metadata length: {len}")))?;
+    let mut metadata = vec![0; len];
+    reader.read_exact(&mut metadata)?;
+
+    let body_len = to_usize(parse_message(&metadata)?.bodyLength())?;
+    let mut body = MutableBuffer::from_len_zeroed(body_len);
+    reader.read_exact(&mut body)?;
+    Ok((metadata, body.into()))
+}
+
+fn parse_message(metadata: &[u8]) -> Result<Message<'_>, ArrowError> {
+    crate::root_as_message(metadata)
+        .map_err(|err| ArrowError::ParseError(format!("Unable to get root as message: {err:?}")))
+}
+
+fn to_usize(v: i64) -> Result<usize, ArrowError> {
+    usize::try_from(v).map_err(|_| ArrowError::ParseError(format!("Invalid tensor length {v}")))
+}
+
+/// Returns the slice of `body` described by `buffer`
+fn body_buffer(body: &Buffer, buffer: &crate::Buffer) -> Result<Buffer, ArrowError> {
+    let offset = to_usize(buffer.offset())?;
+    let length = to_usize(buffer.length())?;
+    match offset.checked_add(length) {
+        Some(end) if end <= body.len() => Ok(body.slice_with_length(offset, length)),
+        _ => Err(ArrowError::ParseError(format!(
+            "Buffer at offset {offset} with length {length} exceeds message body of {} bytes",
+            body.len()
+        ))),
+    }
+}
+
+fn fb_to_shape(
+    shape: Vector<'_, ForwardsUOffset<crate::TensorDim<'_>>>,
+) -> Result<(Vec<usize>, Option<Vec<String>>), ArrowError> {
+    let sizes = shape
+        .iter()
+        .map(|d| to_usize(d.size_()))
+        .collect::<Result<_, _>>()?;
+    let names = shape.iter().any(|d| d.name().is_some()).then(|| {
+        shape
+            .iter()
+            .map(|d| d.name().unwrap_or_default().to_string())
+            .collect()
+    });
+    Ok((sizes, names))
+}
+
+fn fb_to_index_type(int: crate::Int<'_>) -> Result<DataType, ArrowError> {
+    fb_to_data_type(crate::Type::Int, Some(int), || None)
+}
+
+fn fb_to_data_type<'a>(
+    type_type: crate::Type,
+    int: Option<crate::Int<'a>>,
+    float: impl FnOnce() -> Option<crate::FloatingPoint<'a>>,
+) -> Result<DataType, ArrowError> {
+    match (type_type, int, float()) {
+        (crate::Type::Int, Some(int), _) => match (int.bitWidth(), int.is_signed()) {
+            (8, true) => Ok(DataType::Int8),
+            (8, false) => Ok(DataType::UInt8),
+            (16, true) => Ok(DataType::Int16),
+            (16, false) => Ok(DataType::UInt16),
+            (32, true) => Ok(DataType::Int32),
+            (32, false) => Ok(DataType::UInt32),
+            (64, true) => Ok(DataType::Int64),
+            (64, false) => Ok(DataType::UInt64),
+            (w, _) => Err(ArrowError::IpcError(format!(
+                "Int type with bit width of {w} not supported"
+            ))),
+        },
+        (crate::Type::FloatingPoint, _, Some(float)) => match float.precision() {
+            crate::Precision::HALF => Ok(DataType::Float16),
+            crate::Precision::SINGLE => Ok(DataType::Float32),
+            crate::Precision::DOUBLE => Ok(DataType::Float64),
+            p => Err(ArrowError::IpcError(format!(
+                "FloatingPoint type with precision of {p:?} not supported"
+            ))),
+        },
+        (t, _, _) => Err(ArrowError::NotYetImplemented(format!(
+            "Tensors of {t:?} are not currently supported"
+        ))),
+    }
+}
+
+/// Copies a `rows` by `columns` matrix of `width` byte values with byte `strides`
+/// into row major order
+fn row_major_indices(
+    indices: Buffer,
+    strides: &[usize],
+    rows: usize,
+    columns: usize,
+    width: usize,
+) -> Result<Buffer, ArrowError> {
+    if strides.len() != 2 {
+        return Err(ArrowError::ParseError(format!(
+            "Sparse tensor indices must have 2 strides, got {}",
+            strides.len()
+        )));
+    }
+    if strides[0] == columns * width && strides[1] == width {
+        return Ok(indices);
+    }
+    if rows > 0 && columns > 0 {
+        let last = (rows - 1) * strides[0] + (columns - 1) * strides[1] + width;
+        check_length("indices", &indices, last)?;
+    }
+
+    let mut out = MutableBuffer::new(rows * columns * width);
+    for row in 0..rows {
+        for column in 0..columns {
+            let start = row * strides[0] + column * strides[1];
+            out.extend_from_slice(&indices[start..start + width]);
+        }
+    }
+    Ok(out.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::writer::{write_message, IpcDataGenerator, IpcWriteOptions};
+
+    fn encode_tensor(tensor: &TensorData) -> Vec<u8> {
+        let options = IpcWriteOptions::default();
+        let encoded = IpcDataGenerator::default()
+            .tensor_to_bytes(tensor, &options)
+            .unwrap();
+        let mut bytes = vec![];
+        write_message(&mut bytes, encoded, &options).unwrap();
+        bytes
+    }
+
+    fn encode_sparse_tensor(tensor: &SparseTensorData) -> Vec<u8> {
+        let options = IpcWriteOptions::default();
+        let encoded = IpcDataGenerator::default()
+            .sparse_tensor_to_bytes(tensor, &options)
+            .unwrap();
+        let mut bytes = vec![];
+        write_message(&mut bytes, encoded, &options).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_tensor_roundtrip() {
+        let data = Buffer::from_slice_ref([1_i32, 2, 3, 4, 5, 6]);
+        let names = Some(vec!["a".to_string(), "b".to_string()]);
+        let tensor =
+            TensorData::try_new(DataType::Int32, data.clone(), vec![2, 3], None, names).unwrap();
+        let read = read_tensor(encode_tensor(&tensor).as_slice()).unwrap();
+        assert_eq!(read, tensor);
+
+        // Column major
+        let tensor =
+            TensorData::try_new(DataType::Int32, data, vec![2, 3], Some(vec![4, 8]), None).unwrap();
+        let read = read_tensor(encode_tensor(&tensor).as_slice()).unwrap();
+        assert_eq!(read, tensor);
+    }
+
+    #[test]
+    fn test_sparse_tensor_coo_roundtrip() {
+        // [[0, 1.5, 0], [0, 0, 2.5]]
+        let tensor = SparseTensorData::try_new(
+            DataType::Float32,
+            Buffer::from_slice_ref([1.5_f32, 2.5]),
+            vec![2, 3],
+            None,
+            2,
+            SparseIndex::Coo {
+                indices_type: DataType::Int64,
+                indices: Buffer::from_slice_ref([0_i64, 1, 1, 2]),
+                is_canonical: true,
+            },
+        )
+        .unwrap();
+        let bytes = encode_sparse_tensor(&tensor);
+        let read = read_sparse_tensor(bytes.as_slice()).unwrap();
+        assert_eq!(read, tensor);
+
+        let err = read_tensor(bytes.as_slice()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Expected a Tensor message, got SparseTensor"
+        );
+    }
+
+    #[test]
+    fn test_sparse_tensor_csx_roundtrip() {
+        // [[0, 1, 0], [2, 0, 3]]
+        for compressed_axis in [CompressedAxis::Row, CompressedAxis::Column] {
+            let indptr = match compressed_axis {
+                CompressedAxis::Row => Buffer::from_slice_ref([0_i32, 1, 3]),
+                CompressedAxis::Column => Buffer::from_slice_ref([0_i32, 1, 2, 3]),
+            };
+            let tensor = SparseTensorData::try_new(
+                DataType::UInt8,
+                Buffer::from_slice_ref([1_u8, 2, 3]),
+                vec![2, 3],
+                Some(vec!["row".to_string(), "column".to_string()]),
+                3,
+                SparseIndex::Csx {
+                    compressed_axis,
+                    indptr_type: DataType::Int32,
+                    indptr,
+                    indices_type: DataType::Int16,
+                    indices: Buffer::from_slice_ref([1_i16, 0, 2]),
+                },
+            )
+            .unwrap();
+            let read = read_sparse_tensor(encode_sparse_tensor(&tensor).as_slice()).unwrap();
+            assert_eq!(read, tensor);
+        }
+    }
+
+    #[test]
+    fn test_column_major_coo_indices() {
+        // Coordinates (0, 1), (1, 2) and (1, 0) stored column by column
+        let indices = Buffer::from_slice_ref([0_i32, 1, 1, 1, 2, 0]);
+        let out = row_major_indices(indices, &[4, 12], 3, 2, 4).unwrap();
+        assert_eq!(out.typed_data::<i32>(), &[0, 1, 1, 2, 1, 0]);
+
+        let indices = Buffer::from_slice_ref([0_i32, 1, 1]);
+        let err = row_major_indices(indices, &[4, 12], 3, 2, 4).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Sparse tensor requires 24 bytes of indices, but only 12 were provided"
+        );
+    }
+
+    #[test]
+    fn test_invalid_tensor() {
+        let data = Buffer::from_slice_ref([1_i32, 2, 3]);
+        let err =
+            TensorData::try_new(DataType::Int32, data.clone(), vec![2, 2], None, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Tensor requires 16 bytes of data, but only 12 were provided"
+        );
+
+        let err =
+            TensorData::try_new(DataType::Utf8, data.clone(), vec![3], None, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: Tensors of Utf8 are not currently supported"
+        );
+
+        let err = SparseTensorData::try_new(
+            DataType::Int32,
+            data,
+            vec![3, 3, 3],
+            None,
+            3,
+            SparseIndex::Csx {
+                compressed_axis: CompressedAxis::Row,
+                indptr_type: DataType::Int32,
+                indptr: Buffer::from_slice_ref([0_i32, 1, 2, 3]),
+                indices_type: DataType::Int32,
+                indices: Buffer::from_slice_ref([0_i32, 1, 2]),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Compressed sparse index requires a two-dimensional tensor, got 3 dimensions"
+        );
+    }
+}
@@ -22,10 +22,10 @@
 
 use std::cmp::min;
 use std::collections::HashMap;
-use std::io::{BufWriter, Write};
+use std::io::{BufWriter, ErrorKind, IoSlice, Write};
 use std::sync::Arc;
 
-use flatbuffers::FlatBufferBuilder;
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
 
 use arrow_array::builder::BufferBuilder;
 use arrow_array::cast::*;
@@ -37,6 +37,7 @@ use arrow_data::{layout, ArrayData, ArrayDataBuilder, BufferSpec};
 use arrow_schema::*;
 
 use crate::compression::CompressionCodec;
+use crate::tensor::{CompressedAxis, SparseIndex, SparseTensorData, TensorData};
 use crate::CONTINUATION_MARKER;
 
 /// IPC write options used to control the behaviour of the [`IpcDataGenerator`]
@@ -64,6 +65,8 @@ pub struct IpcWriteOptions {
     ///
     /// Defaults to `true`
     preserve_dict_id: bool,
+    /// How dictionaries that differ from those previously written are emitted
+    dictionary_handling: DictionaryHandling,
 }
 
 impl IpcWriteOptions {
@@ -112,6 +115,7 @@ impl IpcWriteOptions {
                 metadata_version,
                 batch_compression_type: None,
                 preserve_dict_id: true,
+                dictionary_handling: DictionaryHandling::default(),
             }),
             crate::MetadataVersion::V5 => {
                 if write_legacy_ipc_format {
@@ -125,6 +129,7 @@ impl IpcWriteOptions {
                         metadata_version,
                         batch_compression_type: None,
                         preserve_dict_id: true,
+                        dictionary_handling: DictionaryHandling::default(),
                     })
                 }
             }
@@ -149,6 +154,24 @@ impl IpcWriteOptions {
         self.preserve_dict_id = preserve_dict_id;
         self
     }
+
+    /// Returns the [`DictionaryHandling`] of these options
+    pub fn dictionary_handling(&self) -> DictionaryHandling {
+        self.dictionary_handling
+    }
+
+    /// Set how dictionaries that differ from those previously written are emitted
+    /// (defaults to [`DictionaryHandling::Resend`])
+    ///
+    /// With [`DictionaryHandling::Delta`], a [`StreamWriter`] writes only the values
+    /// appended to a dictionary since it was last written, falling back to replacing the
+    /// dictionary if it does not extend the previous one. As the file format does not
+    /// support dictionary replacement, a [`FileWriter`] writes the appended values, and
+    /// returns an error for any other change to a dictionary
+    pub fn with_dictionary_handling(mut self, dictionary_handling: DictionaryHandling) -> Self {
+        self.dictionary_handling = dictionary_handling;
+        self
+    }
 }
 
 impl Default for IpcWriteOptions {
@@ -159,10 +182,26 @@ impl Default for IpcWriteOptions {
             metadata_version: crate::MetadataVersion::V5,
             batch_compression_type: None,
             preserve_dict_id: true,
+            dictionary_handling: DictionaryHandling::default(),
         }
     }
 }
 
+/// Controls how the IPC writers emit a dictionary that differs from the dictionary
+/// previously written with the same dictionary id
+///
+/// See [`IpcWriteOptions::with_dictionary_handling`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DictionaryHandling {
+    /// Write the full dictionary whenever it changes
+    #[default]
+    Resend,
+    /// If the new dictionary starts with all the values of the previously written
+    /// dictionary, write a delta dictionary batch containing only the new values,
+    /// otherwise write the full dictionary
+    Delta,
+}
+
 #[derive(Debug, Default)]
 /// Handles low level details of encoding [`Array`] and [`Schema`] into the
 /// [Arrow IPC Format].
@@ -222,6 +261,129 @@ impl IpcDataGenerator {
         }
     }
 
+    /// Encodes a dense tensor as an IPC Tensor message, which can be read with
+    /// [`read_tensor`](crate::tensor::read_tensor)
+    pub fn tensor_to_bytes(
+        &self,
+        tensor: &TensorData,
+        write_options: &IpcWriteOptions,
+    ) -> Result<EncodedData, ArrowError> {
+        let mut fbb = FlatBufferBuilder::new();
+        let mut body = MessageBody::Contiguous(vec![]);
+        let data = tensor_body_buffer(tensor.data(), &mut body, write_options.alignment);
+
+        let field_type = crate::convert::get_fb_field_type(tensor.data_type(), &mut fbb);
+        let shape = tensor_shape(&mut fbb, tensor.shape(), tensor.names());
+        let strides = tensor.strides().map(|strides| {
+            let strides: Vec<i64> = strides.iter().map(|s| *s as i64).collect();
+            fbb.create_vector(&strides)
+        });
+
+        let mut builder = crate::TensorBuilder::new(&mut fbb);
+        builder.add_type_type(field_type.type_type);
+        builder.add_type_(field_type.type_);
+        builder.add_shape(shape);
+        if let Some(strides) = strides {
+            builder.add_strides(strides);
+        }
+        builder.add_data(&data);
+        let root = builder.finish().as_union_value();
+
+        Ok(tensor_message(
+            fbb,
+            crate::MessageHeader::Tensor,
+            root,
+            body,
+            write_options,
+        ))
+    }
+
+    /// Encodes a sparse tensor as an IPC SparseTensor message, which can be read with
+    /// [`read_sparse_tensor`](crate::tensor::read_sparse_tensor)
+    pub fn sparse_tensor_to_bytes(
+        &self,
+        tensor: &SparseTensorData,
+        write_options: &IpcWriteOptions,
+    ) -> Result<EncodedData, ArrowError> {
+        let mut fbb = FlatBufferBuilder::new();
+        let mut body = MessageBody::Contiguous(vec![]);
+        let alignment = write_options.alignment;
+
+        let (index_type, index) = match tensor.index() {
+            SparseIndex::Coo {
+                indices_type,
+                indices,
+                is_canonical,
+            } => {
+                let indices = tensor_body_buffer(indices, &mut body, alignment);
+                // The indices are a row major matrix with a row per non-zero value
+                let width = indices_type.primitive_width().unwrap() as i64;
+                let strides = [tensor.shape().len() as i64 * width, width];
+                let strides = fbb.create_vector(&strides);
+                let indices_type = tensor_index_type(&mut fbb, indices_type);
+
+                let mut builder = crate::SparseTensorIndexCOOBuilder::new(&mut fbb);
+                builder.add_indicesType(indices_type);
+                builder.add_indicesStrides(strides);
+                builder.add_indicesBuffer(&indices);
+                builder.add_isCanonical(*is_canonical);
+                (
+                    crate::SparseTensorIndex::SparseTensorIndexCOO,
+                    builder.finish().as_union_value(),
+                )
+            }
+            SparseIndex::Csx {
+                compressed_axis,
+                indptr_type,
+                indptr,
+                indices_type,
+                indices,
+            } => {
+                let indptr = tensor_body_buffer(indptr, &mut body, alignment);
+                let indices = tensor_body_buffer(indices, &mut body, alignment);
+                let indptr_type = tensor_index_type(&mut fbb, indptr_type);
+                let indices_type = tensor_index_type(&mut fbb, indices_type);
+                let compressed_axis = match compressed_axis {
+                    CompressedAxis::Row => crate::SparseMatrixCompressedAxis::Row,
+                    CompressedAxis::Column => crate::SparseMatrixCompressedAxis::Column,
+                };
+
+                let mut builder = crate::SparseMatrixIndexCSXBuilder::new(&mut fbb);
+                builder.add_compressedAxis(compressed_axis);
+                builder.add_indptrType(indptr_type);
+                builder.add_indptrBuffer(&indptr);
+                builder.add_indicesType(indices_type);
+                builder.add_indicesBuffer(&indices);
+                (
+                    crate::SparseTensorIndex::SparseMatrixIndexCSX,
+                    builder.finish().as_union_value(),
+                )
+            }
+        };
+        let data = tensor_body_buffer(tensor.data(), &mut body, alignment);
+
+        let field_type = crate::convert::get_fb_field_type(tensor.data_type(), &mut fbb);
+        let shape = tensor_shape(&mut fbb, tensor.shape(), tensor.names());
+
+        let mut builder = crate::SparseTensorBuilder::new(&mut fbb);
+        builder.add_type_type(field_type.type_type);
+        builder.add_type_(field_type.type_);
+        builder.add_shape(shape);
+        builder.add_non_zero_length(tensor.non_zero_length() as i64);
+        builder.add_sparseIndex_type(index_type);
+        builder.add_sparseIndex(index);
+        builder.add_data(&data);
+        let root = builder.finish().as_union_value();
+
+        Ok(tensor_message(
+            fbb,
+            crate::MessageHeader::SparseTensor,
+            root,
+            body,
+            write_options,
+        ))
+    }
+
     fn _encode_dictionaries<I: Iterator<Item = i64>>(
         &self,
         column: &ArrayRef,
@@ -286,6 +448,28 @@ impl IpcDataGenerator {
                     dict_id,
                 )?;
             }
+            DataType::ListView(field) => {
+                let list = column.as_list_view::<i32>();
+                self.encode_dictionaries(
+                    field,
+                    list.values(),
+                    encoded_dictionaries,
+                    dictionary_tracker,
+                    write_options,
+                    dict_id,
+                )?;
+            }
+            DataType::LargeListView(field) => {
+                let list = column.as_list_view::<i64>();
+                self.encode_dictionaries(
+                    field,
+                    list.values(),
+                    encoded_dictionaries,
+                    dictionary_tracker,
+                    write_options,
+                    dict_id,
+                )?;
+            }
             DataType::FixedSizeList(field, _) => {
                 let list = column
                     .as_any()
@@ -379,14 +563,30 @@ impl IpcDataGenerator {
                     dict_id_seq,
                 )?;
 
-                let emit = dictionary_tracker.insert(dict_id, column)?;
+                let update = dictionary_tracker.insert_column(
+                    dict_id,
+                    column,
+                    write_options.dictionary_handling,
+                )?;
 
-                if emit {
-                    encoded_dictionaries.push(self.dictionary_batch_to_bytes(
-                        dict_id,
-                        dict_values,
-                        write_options,
-                    )?);
+                match update {
+                    DictionaryUpdate::None => {}
+                    DictionaryUpdate::New | DictionaryUpdate::Replaced => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            dict_values,
+                            false,
+                            write_options,
+                        )?);
+                    }
+                    DictionaryUpdate::Delta(delta) => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            &delta,
+                            true,
+                            write_options,
+                        )?);
+                    }
                 }
             }
             _ => self._encode_dictionaries(
@@ -410,6 +610,104 @@ impl IpcDataGenerator {
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
     ) -> Result<(Vec<EncodedData>, EncodedData), ArrowError> {
+        let encoded_dictionaries =
+            self.encode_batch_dictionaries(batch, dictionary_tracker, write_options)?;
+        let encoded_message = self.record_batch_to_bytes(batch, write_options)?;
+        Ok((encoded_dictionaries, encoded_message))
+    }
+
+    /// Encodes a batch like [`Self::encoded_batch`], attaching the key-value pairs in
+    /// `metadata` to the custom metadata of the record batch message
+    pub fn encoded_batch_with_metadata(
+        &self,
+        batch: &RecordBatch,
+        metadata: &HashMap<String, String>,
+        dictionary_tracker: &mut DictionaryTracker,
+        write_options: &IpcWriteOptions,
+    ) -> Result<(Vec<EncodedData>, EncodedData), ArrowError> {
+        let encoded_dictionaries =
+            self.encode_batch_dictionaries(batch, dictionary_tracker, write_options)?;
+        let body = MessageBody::Contiguous(vec![]);
+        let (ipc_message, body) =
+            self.record_batch_message(batch, metadata, body, write_options)?;
+        let encoded_message = EncodedData {
+            ipc_message,
+            arrow_data: body.into_contiguous(),
+        };
+        Ok((encoded_dictionaries, encoded_message))
+    }
+
+    /// Encodes a batch like [`Self::encoded_batch`], but without copying the buffers of
+    /// the record batch into a contiguous body
+    ///
+    /// The body of the returned [`EncodedSegments`] instead references the buffers of the
+    /// arrays in `batch`, and can be written with [`write_message_segments`]. If compression
+    /// is enabled, the compressed body is returned as a single segment
+    pub fn encoded_batch_segments(
+        &self,
+        batch: &RecordBatch,
+        dictionary_tracker: &mut DictionaryTracker,
+        write_options: &IpcWriteOptions,
+    ) -> Result<(Vec<EncodedData>, EncodedSegments), ArrowError> {
+        let metadata = HashMap::new();
+        self.encoded_batch_segments_with_metadata(
+            batch,
+            &metadata,
+            dictionary_tracker,
+            write_options,
+        )
+    }
+
+    /// Encodes a batch like [`Self::encoded_batch_segments`], attaching the key-value
+    /// pairs in `metadata` to the custom metadata of the record batch message
+    fn encoded_batch_segments_with_metadata(
+        &self,
+        batch: &RecordBatch,
+        metadata: &HashMap<String, String>,
+        dictionary_tracker: &mut DictionaryTracker,
+        write_options: &IpcWriteOptions,
+    ) -> Result<(Vec<EncodedData>, EncodedSegments), ArrowError> {
+        let encoded_dictionaries =
+            self.encode_batch_dictionaries(batch, dictionary_tracker, write_options)?;
+
+        let body = match write_options.batch_compression_type {
+            Some(_) => MessageBody::Contiguous(vec![]),
+            None => MessageBody::segments(),
+        };
+        let (ipc_message, body) =
+            self.record_batch_message(batch, metadata, body, write_options)?;
+        let body = match body {
+            MessageBody::Contiguous(data) if data.is_empty() => vec![],
+            MessageBody::Contiguous(data) => vec![Buffer::from_vec(data)],
+            MessageBody::Segments { segments, .. } => segments,
+        };
+        Ok((encoded_dictionaries, EncodedSegments { ipc_message, body }))
+    }
+
+    /// Write a `RecordBatch` into two sets of bytes, one for the header (crate::Message) and the
+    /// other for the batch's data
+    fn record_batch_to_bytes(
+        &self,
+        batch: &RecordBatch,
+        write_options: &IpcWriteOptions,
+    ) -> Result<EncodedData, ArrowError> {
+        let body = MessageBody::Contiguous(vec![]);
+        let metadata = HashMap::new();
+        let (ipc_message, body) =
+            self.record_batch_message(batch, &metadata, body, write_options)?;
+        Ok(EncodedData {
+            ipc_message,
+            arrow_data: body.into_contiguous(),
+        })
+    }
+
+    /// Encodes the dictionaries of `batch` that have not yet been sent
+    fn encode_batch_dictionaries(
+        &self,
+        batch: &RecordBatch,
+        dictionary_tracker: &mut DictionaryTracker,
+        write_options: &IpcWriteOptions,
+    ) -> Result<Vec<EncodedData>, ArrowError> {
         let schema = batch.schema();
         let mut encoded_dictionaries = Vec::with_capacity(schema.flattened_fields().len());
 
@@ -426,23 +724,22 @@ impl IpcDataGenerator {
                 &mut dict_id,
             )?;
         }
-
-        let encoded_message = self.record_batch_to_bytes(batch, write_options)?;
-        Ok((encoded_dictionaries, encoded_message))
+        Ok(encoded_dictionaries)
     }
 
-    /// Write a `RecordBatch` into two sets of bytes, one for the header (crate::Message) and the
-    /// other for the batch's data
-    fn record_batch_to_bytes(
+    /// Encodes the header of a `RecordBatch` message, with the key-value pairs in `metadata`
+    /// as its custom metadata, appending the batch's data to `body`
+    fn record_batch_message(
         &self,
         batch: &RecordBatch,
+        metadata: &HashMap<String, String>,
+        mut body: MessageBody,
         write_options: &IpcWriteOptions,
-    ) -> Result<EncodedData, ArrowError> {
+    ) -> Result<(Vec<u8>, MessageBody), ArrowError> {
         let mut fbb = FlatBufferBuilder::new();
 
         let mut nodes: Vec<crate::FieldNode> = vec![];
         let mut buffers: Vec<crate::Buffer> = vec![];
-        let mut arrow_data: Vec<u8> = vec![];
         let mut offset = 0;
 
         // get the type of compression
@@ -465,7 +762,7 @@ impl IpcDataGenerator {
             offset = write_array_data(
                 &array_data,
                 &mut buffers,
-                &mut arrow_data,
+                &mut body,
                 &mut nodes,
                 offset,
                 array.len(),
@@ -477,9 +774,8 @@ impl IpcDataGenerator {
             append_variadic_buffer_counts(&mut variadic_buffer_counts, &array_data);
         }
         // pad the tail of body data
-        let len = arrow_data.len();
-        let pad_len = pad_to_alignment(write_options.alignment, len);
-        arrow_data.extend_from_slice(&PADDING[..pad_len]);
+        let pad_len = pad_to_alignment(write_options.alignment, body.len());
+        body.pad(pad_len);
 
         // write data
         let buffers = fbb.create_vector(&buffers);
@@ -505,35 +801,40 @@ impl IpcDataGenerator {
             let b = batch_builder.finish();
             b.as_union_value()
         };
+        let fb_custom_metadata =
+            (!metadata.is_empty()).then(|| crate::convert::metadata_to_fb(&mut fbb, metadata));
         // create an crate::Message
         let mut message = crate::MessageBuilder::new(&mut fbb);
         message.add_version(write_options.metadata_version);
         message.add_header_type(crate::MessageHeader::RecordBatch);
-        message.add_bodyLength(arrow_data.len() as i64);
+        message.add_bodyLength(body.len() as i64);
         message.add_header(root);
+        if let Some(fb_custom_metadata) = fb_custom_metadata {
+            message.add_custom_metadata(fb_custom_metadata);
+        }
         let root = message.finish();
         fbb.finish(root, None);
         let finished_data = fbb.finished_data();
 
-        Ok(EncodedData {
-            ipc_message: finished_data.to_vec(),
-            arrow_data,
-        })
+        Ok((finished_data.to_vec(), body))
     }
 
     /// Write dictionary values into two sets of bytes, one for the header (crate::Message) and the
     /// other for the data
+    ///
+    /// If `is_delta` is true, `array_data` contains values to append to the existing dictionary
     fn dictionary_batch_to_bytes(
         &self,
         dict_id: i64,
         array_data: &ArrayData,
+        is_delta: bool,
         write_options: &IpcWriteOptions,
     ) -> Result<EncodedData, ArrowError> {
         let mut fbb = FlatBufferBuilder::new();
 
         let mut nodes: Vec<crate::FieldNode> = vec![];
         let mut buffers: Vec<crate::Buffer> = vec![];
-        let mut arrow_data: Vec<u8> = vec![];
+        let mut body = MessageBody::Contiguous(vec![]);
 
         // get the type of compression
         let batch_compression_type = write_options.batch_compression_type;
@@ -552,7 +853,7 @@ impl IpcDataGenerator {
         write_array_data(
             array_data,
             &mut buffers,
-            &mut arrow_data,
+            &mut body,
             &mut nodes,
             0,
             array_data.len(),
@@ -565,9 +866,9 @@ impl IpcDataGenerator {
         append_variadic_buffer_counts(&mut variadic_buffer_counts, array_data);
 
         // pad the tail of body data
-        let len = arrow_data.len();
-        let pad_len = pad_to_alignment(write_options.alignment, len);
-        arrow_data.extend_from_slice(&PADDING[..pad_len]);
+        let pad_len = pad_to_alignment(write_options.alignment, body.len());
+        body.pad(pad_len);
+        let arrow_data = body.into_contiguous();
 
         // write data
         let buffers = fbb.create_vector(&buffers);
@@ -596,6 +897,7 @@ impl IpcDataGenerator {
             let mut batch_builder = crate::DictionaryBatchBuilder::new(&mut fbb);
             batch_builder.add_id(dict_id);
             batch_builder.add_data(root);
+            batch_builder.add_isDelta(is_delta);
             batch_builder.finish().as_union_value()
         };
 
@@ -791,35 +1093,88 @@ impl DictionaryTracker {
     ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
     ///   inserted.
     pub fn insert(&mut self, dict_id: i64, column: &ArrayRef) -> Result<bool, ArrowError> {
+        let update = self.insert_column(dict_id, column, DictionaryHandling::Resend)?;
+        Ok(!matches!(update, DictionaryUpdate::None))
+    }
+
+    /// Keep track of the dictionary with the given ID and values, returning the
+    /// [`DictionaryUpdate`] that must be written. Behavior:
+    ///
+    /// * If this ID has not been written before, return [`DictionaryUpdate::New`].
+    /// * If this ID has been written already and has the same data, return
+    ///   [`DictionaryUpdate::None`].
+    /// * If `dictionary_handling` is [`DictionaryHandling::Delta`] and the previously
+    ///   written values are a prefix of the new values, return [`DictionaryUpdate::Delta`]
+    ///   with the appended values.
+    /// * Otherwise, if this tracker is configured to return an error on replacement, return
+    ///   an error, else return [`DictionaryUpdate::Replaced`].
+    pub fn insert_column(
+        &mut self,
+        dict_id: i64,
+        column: &ArrayRef,
+        dictionary_handling: DictionaryHandling,
+    ) -> Result<DictionaryUpdate, ArrowError> {
         let dict_data = column.to_data();
         let dict_values = &dict_data.child_data()[0];
 
         // If a dictionary with this id was already emitted, check if it was the same.
-        if let Some(last) = self.written.get(&dict_id) {
-            if ArrayData::ptr_eq(&last.child_data()[0], dict_values) {
-                // Same dictionary values => no need to emit it again
-                return Ok(false);
+        let last = match self.written.get(&dict_id) {
+            Some(last) => &last.child_data()[0],
+            None => {
+                self.written.insert(dict_id, dict_data);
+                return Ok(DictionaryUpdate::New);
             }
-            if self.error_on_replacement {
-                // If error on replacement perform a logical comparison
-                if last.child_data()[0] == *dict_values {
-                    // Same dictionary values => no need to emit it again
-                    return Ok(false);
-                }
-                return Err(ArrowError::InvalidArgumentError(
-                    "Dictionary replacement detected when writing IPC file format. \
-                     Arrow IPC files only support a single dictionary for a given field \
-                     across all batches."
-                        .to_string(),
-                ));
+        };
+        if ArrayData::ptr_eq(last, dict_values) {
+            // Same dictionary values => no need to emit it again
+            return Ok(DictionaryUpdate::None);
+        }
+
+        if dictionary_handling == DictionaryHandling::Delta
+            && dict_values.len() >= last.len()
+            && dict_values.slice(0, last.len()) == *last
+        {
+            if dict_values.len() == last.len() {
+                return Ok(DictionaryUpdate::None);
+            }
+            let delta = dict_values.slice(last.len(), dict_values.len() - last.len());
+            self.written.insert(dict_id, dict_data);
+            return Ok(DictionaryUpdate::Delta(delta));
+        }
+
+        if self.error_on_replacement {
+            // If error on replacement perform a logical comparison
+            if *last == *dict_values {
+                // Same dictionary values => no need to emit it again
+                return Ok(DictionaryUpdate::None);
             }
+            return Err(ArrowError::InvalidArgumentError(
+                "Dictionary replacement detected when writing IPC file format. \
+                 Arrow IPC files only support a single dictionary for a given field \
+                 across all batches."
+                    .to_string(),
+            ));
         }
 
         self.written.insert(dict_id, dict_data);
-        Ok(true)
+        Ok(DictionaryUpdate::Replaced)
     }
 }
 
+/// Describes how a dictionary must be written, as returned by
+/// [`DictionaryTracker::insert_column`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum DictionaryUpdate {
+    /// The dictionary has already been written
+    None,
+    /// The dictionary has not been written before
+    New,
+    /// The dictionary replaces the previously written dictionary
+    Replaced,
+    /// The dictionary extends the previously written dictionary with these values
+    Delta(ArrayData),
+}
+
 /// Writer for an IPC file
 pub struct FileWriter<W> {
     /// The object to write to
@@ -911,17 +1266,32 @@ impl<W: Write> FileWriter<W> {
 
     /// Write a record batch to the file
     pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write_with_metadata(batch, HashMap::new())
+    }
+
+    /// Write a record batch to the file, with the key-value pairs in `metadata` as the
+    /// custom metadata of its record batch message
+    ///
+    /// Unlike [`Self::write_metadata`], this metadata applies only to `batch`, and can be
+    /// read with [`FileReader::next_with_metadata`](crate::reader::FileReader::next_with_metadata)
+    pub fn write_with_metadata(
+        &mut self,
+        batch: &RecordBatch,
+        metadata: HashMap<String, String>,
+    ) -> Result<(), ArrowError> {
         if self.finished {
             return Err(ArrowError::IpcError(
                 "Cannot write record batch to file writer as it is closed".to_string(),
             ));
         }
 
-        let (encoded_dictionaries, encoded_message) = self.data_gen.encoded_batch(
-            batch,
-            &mut self.dictionary_tracker,
-            &self.write_options,
-        )?;
+        let (encoded_dictionaries, encoded_message) =
+            self.data_gen.encoded_batch_segments_with_metadata(
+                batch,
+                &metadata,
+                &mut self.dictionary_tracker,
+                &self.write_options,
+            )?;
 
         for encoded_dictionary in encoded_dictionaries {
             let (meta, data) =
@@ -932,7 +1302,8 @@ impl<W: Write> FileWriter<W> {
             self.block_offsets += meta + data;
         }
 
-        let (meta, data) = write_message(&mut self.writer, encoded_message, &self.write_options)?;
+        let (meta, data) =
+            write_message_segments(&mut self.writer, encoded_message, &self.write_options)?;
         // add a record block for the footer
         let block = crate::Block::new(
             self.block_offsets as i64,
@@ -1102,6 +1473,19 @@ impl<W: Write> StreamWriter<W> {
 
     /// Write a record batch to the stream
     pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write_with_metadata(batch, HashMap::new())
+    }
+
+    /// Write a record batch to the stream, with the key-value pairs in `metadata` as the
+    /// custom metadata of its record batch message
+    ///
+    /// This can be read with [`StreamReader::next_with_metadata`](crate::reader::StreamReader::next_with_metadata)
+    /// or [`StreamDecoder::decode_with_metadata`](crate::reader::StreamDecoder::decode_with_metadata)
+    pub fn write_with_metadata(
+        &mut self,
+        batch: &RecordBatch,
+        metadata: HashMap<String, String>,
+    ) -> Result<(), ArrowError> {
         if self.finished {
             return Err(ArrowError::IpcError(
                 "Cannot write record batch to stream writer as it is closed".to_string(),
@@ -1110,14 +1494,19 @@ impl<W: Write> StreamWriter<W> {
 
         let (encoded_dictionaries, encoded_message) = self
             .data_gen
-            .encoded_batch(batch, &mut self.dictionary_tracker, &self.write_options)
+            .encoded_batch_segments_with_metadata(
+                batch,
+                &metadata,
+                &mut self.dictionary_tracker,
+                &self.write_options,
+            )
             .expect("StreamWriter is configured to not error on dictionary replacement");
 
         for encoded_dictionary in encoded_dictionaries {
             write_message(&mut self.writer, encoded_dictionary, &self.write_options)?;
         }
 
-        write_message(&mut self.writer, encoded_message, &self.write_options)?;
+        write_message_segments(&mut self.writer, encoded_message, &self.write_options)?;
         Ok(())
     }
 
@@ -1219,6 +1608,39 @@ pub struct EncodedData {
     /// Arrow buffers to be written, should be an empty vec for schema messages
     pub arrow_data: Vec<u8>,
 }
+
+/// Stores an encoded crate::Message, and its body as a sequence of [`Buffer`] segments
+/// that reference the encoded arrays, instead of a copy of them as in [`EncodedData`]
+///
+/// See [`IpcDataGenerator::encoded_batch_segments`]
+#[derive(Debug, Clone)]
+pub struct EncodedSegments {
+    /// An encoded crate::Message
+    pub ipc_message: Vec<u8>,
+    /// The body of the message, the buffers and padding to be written in order
+    pub body: Vec<Buffer>,
+}
+
+impl EncodedSegments {
+    /// Returns the total length of the message body
+    pub fn body_len(&self) -> usize {
+        self.body.iter().map(|b| b.len()).sum()
+    }
+}
+
+impl From<EncodedData> for EncodedSegments {
+    fn from(value: EncodedData) -> Self {
+        let body = match value.arrow_data.is_empty() {
+            true => vec![],
+            false => vec![Buffer::from_vec(value.arrow_data)],
+        };
+        Self {
+            ipc_message: value.ipc_message,
+            body,
+        }
+    }
+}
+
 /// Write a message's IPC data and buffers, returning metadata and buffer data lengths written
 pub fn write_message<W: Write>(
     mut writer: W,
@@ -1232,8 +1654,77 @@ pub fn write_message<W: Write>(
         ));
     }
 
+    let aligned_size = write_message_header(&mut writer, &encoded.ipc_message, write_options)?;
+
+    // write arrow data
+    let body_len = if arrow_data_len > 0 {
+        write_body_buffers(&mut writer, &encoded.arrow_data, write_options.alignment)?
+    } else {
+        0
+    };
+
+    Ok((aligned_size, body_len))
+}
+
+/// Write a message's IPC data, and the segments of its body using [`Write::write_vectored`],
+/// returning metadata and buffer data lengths written
+pub fn write_message_segments<W: Write>(
+    mut writer: W,
+    encoded: EncodedSegments,
+    write_options: &IpcWriteOptions,
+) -> Result<(usize, usize), ArrowError> {
+    let body_len = encoded.body_len();
+    if body_len % usize::from(write_options.alignment) != 0 {
+        return Err(ArrowError::MemoryError(
+            "Arrow data not aligned".to_string(),
+        ));
+    }
+
+    let aligned_size = write_message_header(&mut writer, &encoded.ipc_message, write_options)?;
+    write_all_vectored(&mut writer, &encoded.body)?;
+    writer.flush()?;
+
+    Ok((aligned_size, body_len))
+}
+
+/// Writes all of `segments` to `writer`, as the unstable `Write::write_all_vectored`
+fn write_all_vectored<W: Write>(mut writer: W, segments: &[Buffer]) -> std::io::Result<()> {
+    // The index of the first segment not yet fully written, and the bytes written of it
+    let mut idx = 0;
+    let mut offset = 0;
+    while idx < segments.len() {
+        let slices: Vec<_> = std::iter::once(IoSlice::new(&segments[idx][offset..]))
+            .chain(segments[idx + 1..].iter().map(|s| IoSlice::new(s)))
+            .collect();
+        let mut written = match writer.write_vectored(&slices) {
+            Ok(0) => {
+                return Err(std::io::Error::new(
+                    ErrorKind::WriteZero,
+                    "failed to write whole buffer",
+                ))
+            }
+            Ok(n) => n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        while idx < segments.len() && written >= segments[idx].len() - offset {
+            written -= segments[idx].len() - offset;
+            idx += 1;
+            offset = 0;
+        }
+        offset += written;
+    }
+    Ok(())
+}
+
+/// Write the continuation marker, length and flatbuffer of a message, returning the
+/// length written
+fn write_message_header<W: Write>(
+    mut writer: W,
+    buffer: &[u8],
+    write_options: &IpcWriteOptions,
+) -> Result<usize, ArrowError> {
     let a = usize::from(write_options.alignment - 1);
-    let buffer = encoded.ipc_message;
     let flatbuf_size = buffer.len();
     let prefix_size = if write_options.write_legacy_ipc_format {
         4
@@ -1251,19 +1742,12 @@ pub fn write_message<W: Write>(
 
     // write the flatbuf
     if flatbuf_size > 0 {
-        writer.write_all(&buffer)?;
+        writer.write_all(buffer)?;
     }
     // write padding
     writer.write_all(&PADDING[..padding_bytes])?;
 
-    // write arrow data
-    let body_len = if arrow_data_len > 0 {
-        write_body_buffers(&mut writer, &encoded.arrow_data, write_options.alignment)?
-    } else {
-        0
-    };
-
-    Ok((aligned_size, body_len))
+    Ok(aligned_size)
 }
 
 fn write_body_buffers<W: Write>(
@@ -1412,7 +1896,7 @@ fn get_list_array_buffers<O: OffsetSizeTrait>(data: &ArrayData) -> (Buffer, Arra
 fn write_array_data(
     array_data: &ArrayData,
     buffers: &mut Vec<crate::Buffer>,
-    arrow_data: &mut Vec<u8>,
+    body: &mut MessageBody,
     nodes: &mut Vec<crate::FieldNode>,
     offset: i64,
     num_rows: usize,
@@ -1442,9 +1926,9 @@ fn write_array_data(
         };
 
         offset = write_buffer(
-            null_buffer.as_slice(),
+            &null_buffer,
             buffers,
-            arrow_data,
+            body,
             offset,
             compression_codec,
             write_options.alignment,
@@ -1456,9 +1940,9 @@ fn write_array_data(
         let (offsets, values) = get_byte_array_buffers::<i32>(array_data);
         for buffer in [offsets, values] {
             offset = write_buffer(
-                buffer.as_slice(),
+                &buffer,
                 buffers,
-                arrow_data,
+                body,
                 offset,
                 compression_codec,
                 write_options.alignment,
@@ -1473,9 +1957,9 @@ fn write_array_data(
         // they should consider the gc API suggested in #5513
         for buffer in array_data.buffers() {
             offset = write_buffer(
-                buffer.as_slice(),
+                buffer,
                 buffers,
-                arrow_data,
+                body,
                 offset,
                 compression_codec,
                 write_options.alignment,
@@ -1485,9 +1969,9 @@ fn write_array_data(
         let (offsets, values) = get_byte_array_buffers::<i64>(array_data);
         for buffer in [offsets, values] {
             offset = write_buffer(
-                buffer.as_slice(),
+                &buffer,
                 buffers,
-                arrow_data,
+                body,
                 offset,
                 compression_codec,
                 write_options.alignment,
@@ -1512,14 +1996,14 @@ fn write_array_data(
         let buffer_slice = if buffer_need_truncate(array_data.offset(), buffer, spec, min_length) {
             let byte_offset = array_data.offset() * byte_width;
             let buffer_length = min(min_length, buffer.len() - byte_offset);
-            &buffer.as_slice()[byte_offset..(byte_offset + buffer_length)]
+            buffer.slice_with_length(byte_offset, buffer_length)
         } else {
-            buffer.as_slice()
+            buffer.clone()
         };
         offset = write_buffer(
-            buffer_slice,
+            &buffer_slice,
             buffers,
-            arrow_data,
+            body,
             offset,
             compression_codec,
             write_options.alignment,
@@ -1534,7 +2018,7 @@ fn write_array_data(
         offset = write_buffer(
             &buffer,
             buffers,
-            arrow_data,
+            body,
             offset,
             compression_codec,
             write_options.alignment,
@@ -1554,9 +2038,9 @@ fn write_array_data(
             _ => unreachable!(),
         };
         offset = write_buffer(
-            offsets.as_slice(),
+            &offsets,
             buffers,
-            arrow_data,
+            body,
             offset,
             compression_codec,
             write_options.alignment,
@@ -1564,7 +2048,7 @@ fn write_array_data(
         offset = write_array_data(
             &sliced_child_data,
             buffers,
-            arrow_data,
+            body,
             nodes,
             offset,
             sliced_child_data.len(),
@@ -1573,12 +2057,37 @@ fn write_array_data(
             write_options,
         )?;
         return Ok(offset);
+    } else if matches!(
+        data_type,
+        DataType::ListView(_) | DataType::LargeListView(_)
+    ) {
+        assert_eq!(array_data.buffers().len(), 2);
+        assert_eq!(array_data.child_data().len(), 1);
+
+        // Truncate the offsets and sizes, the child data is written in full as
+        // list views may reference any of its values
+        let byte_width = match data_type {
+            DataType::ListView(_) => std::mem::size_of::<i32>(),
+            _ => std::mem::size_of::<i64>(),
+        };
+        let byte_offset = array_data.offset() * byte_width;
+        let byte_len = array_data.len() * byte_width;
+        for buffer in array_data.buffers() {
+            offset = write_buffer(
+                &buffer.slice_with_length(byte_offset, byte_len),
+                buffers,
+                body,
+                offset,
+                compression_codec,
+                write_options.alignment,
+            )?;
+        }
     } else {
         for buffer in array_data.buffers() {
             offset = write_buffer(
                 buffer,
                 buffers,
-                arrow_data,
+                body,
                 offset,
                 compression_codec,
                 write_options.alignment,
@@ -1597,7 +2106,7 @@ fn write_array_data(
                 offset = write_array_data(
                     data_ref,
                     buffers,
-                    arrow_data,
+                    body,
                     nodes,
                     offset,
                     data_ref.len(),
@@ -1614,7 +2123,7 @@ fn write_array_data(
                 offset = write_array_data(
                     data_ref,
                     buffers,
-                    arrow_data,
+                    body,
                     nodes,
                     offset,
                     data_ref.len(),
@@ -1628,8 +2137,8 @@ fn write_array_data(
     Ok(offset)
 }
 
-/// Write a buffer into `arrow_data`, a vector of bytes, and adds its
-/// [`crate::Buffer`] to `buffers`. Returns the new offset in `arrow_data`
+/// Write a buffer into `body`, and adds its [`crate::Buffer`] to `buffers`.
+/// Returns the new offset in `body`
 ///
 ///
 /// From <https://github.com/apache/arrow/blob/6a936c4ff5007045e86f65f1a6b6c3c955ad5103/format/Message.fbs#L58>
@@ -1641,17 +2150,22 @@ fn write_array_data(
 /// follows is not compressed, which can be useful for cases where
 /// compression does not yield appreciable savings.
 fn write_buffer(
-    buffer: &[u8],                    // input
+    buffer: &Buffer,                  // input
     buffers: &mut Vec<crate::Buffer>, // output buffer descriptors
-    arrow_data: &mut Vec<u8>,         // output stream
+    body: &mut MessageBody,           // output stream
     offset: i64,                      // current output stream offset
     compression_codec: Option<CompressionCodec>,
     alignment: u8,
 ) -> Result<i64, ArrowError> {
-    let len: i64 = match compression_codec {
-        Some(compressor) => compressor.compress_to_vec(buffer, arrow_data)?,
-        None => {
-            arrow_data.extend_from_slice(buffer);
+    let len: i64 = match (compression_codec, &mut *body) {
+        (Some(compressor), MessageBody::Contiguous(data)) => {
+            compressor.compress_to_vec(buffer, data)?
+        }
+        (Some(_), MessageBody::Segments { .. }) => {
+            unreachable!("compressed bodies are written contiguously")
+        }
+        (None, _) => {
+            body.push(buffer);
             buffer.len()
         }
     }
@@ -1664,13 +2178,142 @@ fn write_buffer(
     buffers.push(crate::Buffer::new(offset, len));
     // padding and make offset aligned
     let pad_len = pad_to_alignment(alignment, len as usize);
-    arrow_data.extend_from_slice(&PADDING[..pad_len]);
+    body.pad(pad_len);
 
     Ok(offset + len + (pad_len as i64))
 }
 
 const PADDING: [u8; 64] = [0; 64];
 
+/// Appends a tensor buffer to `body`, returning its [`crate::Buffer`]
+fn tensor_body_buffer(buffer: &Buffer, body: &mut MessageBody, alignment: u8) -> crate::Buffer {
+    let offset = body.len();
+    body.push(buffer);
+    body.pad(pad_to_alignment(alignment, buffer.len()));
+    crate::Buffer::new(offset as i64, buffer.len() as i64)
+}
+
+fn tensor_shape<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    shape: &[usize],
+    names: Option<&[String]>,
+) -> WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<crate::TensorDim<'a>>>> {
+    let dims: Vec<_> = shape
+        .iter()
+        .enumerate()
+        .map(|(i, size)| {
+            let name = names.map(|names| fbb.create_string(&names[i]));
+            let mut builder = crate::TensorDimBuilder::new(fbb);
+            builder.add_size_(*size as i64);
+            if let Some(name) = name {
+                builder.add_name(name);
+            }
+            builder.finish()
+        })
+        .collect();
+    fbb.create_vector(&dims)
+}
+
+fn tensor_index_type<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    data_type: &DataType,
+) -> WIPOffset<crate::Int<'a>> {
+    let mut builder = crate::IntBuilder::new(fbb);
+    builder.add_bitWidth(data_type.primitive_width().unwrap() as i32 * 8);
+    builder.add_is_signed(data_type.is_signed_integer());
+    builder.finish()
+}
+
+fn tensor_message(
+    mut fbb: FlatBufferBuilder<'_>,
+    header_type: crate::MessageHeader,
+    header: WIPOffset<flatbuffers::UnionWIPOffset>,
+    body: MessageBody,
+    write_options: &IpcWriteOptions,
+) -> EncodedData {
+    let mut message = crate::MessageBuilder::new(&mut fbb);
+    message.add_version(write_options.metadata_version);
+    message.add_header_type(header_type);
+    message.add_bodyLength(body.len() as i64);
+    message.add_header(header);
+    let root = message.finish();
+    fbb.finish(root, None);
+    EncodedData {
+        ipc_message: fbb.finished_data().to_vec(),
+        arrow_data: body.into_contiguous(),
+    }
+}
+
+/// The body of an IPC message under construction
+enum MessageBody {
+    /// The buffers of the body copied into a contiguous allocation
+    Contiguous(Vec<u8>),
+    /// The buffers of the body, and the padding between them, without copying
+    Segments {
+        segments: Vec<Buffer>,
+        /// The total length of `segments`
+        len: usize,
+        /// A buffer of [`PADDING`] to slice padding segments from
+        padding: Buffer,
+    },
+}
+
+impl MessageBody {
+    fn segments() -> Self {
+        Self::Segments {
+            segments: vec![],
+            len: 0,
+            padding: Buffer::from_slice_ref(PADDING),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Contiguous(data) => data.len(),
+            Self::Segments { len, .. } => *len,
+        }
+    }
+
+    fn push(&mut self, buffer: &Buffer) {
+        match self {
+            Self::Contiguous(data) => data.extend_from_slice(buffer),
+            Self::Segments { segments, len, .. } => {
+                if !buffer.is_empty() {
+                    *len += buffer.len();
+                    segments.push(buffer.clone());
+                }
+            }
+        }
+    }
+
+    fn pad(&mut self, pad_len: usize) {
+        match self {
+            Self::Contiguous(data) => data.extend_from_slice(&PADDING[..pad_len]),
+            Self::Segments {
+                segments,
+                len,
+                padding,
+            } => {
+                if pad_len > 0 {
+                    *len += pad_len;
+                    segments.push(padding.slice_with_length(0, pad_len));
+                }
+            }
+        }
+    }
+
+    fn into_contiguous(self) -> Vec<u8> {
+        match self {
+            Self::Contiguous(data) => data,
+            Self::Segments { segments, len, .. } => {
+                let mut data = Vec::with_capacity(len);
+                segments.iter().for_each(|s| data.extend_from_slice(s));
+                data
+            }
+        }
+    }
+}
+
 /// Calculate an alignment boundary and return the number of bytes needed to pad to the alignment boundary
 #[inline]
 fn pad_to_alignment(alignment: u8, len: usize) -> usize {
@@ -2720,4 +3363,174 @@ mod tests {
         assert_eq!(stream_bytes_written_on_flush, expected_stream_flushed_bytes);
         assert_eq!(file_bytes_written_on_flush, expected_file_flushed_bytes);
     }
+
+    /// Returns the `(id, isDelta, length)` of the dictionary batches in an IPC stream
+    fn stream_dictionary_batches(mut data: &[u8]) -> Vec<(i64, bool, i64)> {
+        let mut out = vec![];
+        loop {
+            let len = i32::from_le_bytes(data[4..8].try_into().unwrap()) as usize;
+            if len == 0 {
+                return out;
+            }
+            let message = crate::root_as_message(&data[8..8 + len]).unwrap();
+            if let Some(batch) = message.header_as_dictionary_batch() {
+                let length = batch.data().unwrap().length();
+                out.push((batch.id(), batch.isDelta(), length));
+            }
+            data = &data[8 + len + message.bodyLength() as usize..];
+        }
+    }
+
+    fn dictionary_batches() -> Vec<RecordBatch> {
+        let batch = |keys: Vec<i32>, values: Vec<Option<&str>>| {
+            let dict =
+                DictionaryArray::new(Int32Array::from(keys), Arc::new(StringArray::from(values)));
+            RecordBatch::try_from_iter([("dict", Arc::new(dict) as ArrayRef)]).unwrap()
+        };
+        vec![
+            batch(vec![0, 1], vec![Some("a"), Some("b")]),
+            // Extends the dictionary
+            batch(vec![2, 3, 0], vec![Some("a"), Some("b"), None, Some("c")]),
+            // Same values as the previous dictionary
+            batch(vec![3], vec![Some("a"), Some("b"), None, Some("c")]),
+        ]
+    }
+
+    #[test]
+    fn test_stream_writer_delta_dictionary() {
+        let mut batches = dictionary_batches();
+        // Does not extend the dictionary
+        let values = StringArray::from(vec!["x", "a"]);
+        let dict = DictionaryArray::new(Int32Array::from(vec![0, 1]), Arc::new(values));
+        batches.push(RecordBatch::try_from_iter([("dict", Arc::new(dict) as ArrayRef)]).unwrap());
+
+        let write = |dictionary_handling| {
+            let options = IpcWriteOptions::default().with_dictionary_handling(dictionary_handling);
+            let mut writer =
+                StreamWriter::try_new_with_options(vec![], batches[0].schema_ref(), options)
+                    .unwrap();
+            for batch in &batches {
+                writer.write(batch).unwrap();
+            }
+            writer.into_inner().unwrap()
+        };
+
+        let data = write(DictionaryHandling::Delta);
+        assert_eq!(
+            stream_dictionary_batches(&data),
+            vec![(0, false, 2), (0, true, 2), (0, false, 2)]
+        );
+        let reader = StreamReader::try_new(Cursor::new(data), None).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+
+        let data = write(DictionaryHandling::Resend);
+        assert_eq!(
+            stream_dictionary_batches(&data),
+            vec![(0, false, 2), (0, false, 4), (0, false, 4), (0, false, 2)]
+        );
+    }
+
+    #[test]
+    fn test_file_writer_delta_dictionary() {
+        let batches = dictionary_batches();
+        let options =
+            IpcWriteOptions::default().with_dictionary_handling(DictionaryHandling::Delta);
+        let mut writer =
+            FileWriter::try_new_with_options(vec![], batches[0].schema_ref(), options).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        assert_eq!(writer.dictionary_blocks.len(), 2);
+
+        // The file format does not support replacing a dictionary
+        let values = StringArray::from(vec!["x", "a"]);
+        let dict = DictionaryArray::new(Int32Array::from(vec![0, 1]), Arc::new(values));
+        let batch = RecordBatch::try_from_iter([("dict", Arc::new(dict) as ArrayRef)]).unwrap();
+        let err = writer.write(&batch).unwrap_err();
+        assert!(err.to_string().contains("Dictionary replacement detected"));
+
+        writer.finish().unwrap();
+        let data = writer.into_inner().unwrap();
+        let reader = FileReader::try_new(Cursor::new(data), None).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read.len(), 3);
+        // Dictionary batches are applied before reading any record batch
+        let full = batches[2].column(0).as_dictionary::<Int32Type>().values();
+        for (read, expected) in read.iter().zip(&batches) {
+            let read = read.column(0).as_dictionary::<Int32Type>();
+            let expected = expected.column(0).as_dictionary::<Int32Type>();
+            assert_eq!(read.keys(), expected.keys());
+            assert_eq!(read.values(), full);
+        }
+    }
+
+    /// A writer that writes at most 5 bytes per call
+    struct ShortWriter(Vec<u8>);
+
+    impl Write for ShortWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let len = buf.len().min(5);
+            self.0.extend_from_slice(&buf[..len]);
+            Ok(len)
+        }
+
+        fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> std::io::Result<usize> {
+            match bufs.iter().find(|b| !b.is_empty()) {
+                Some(buf) => self.write(buf),
+                None => Ok(0),
+            }
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_encoded_batch_segments() {
+        let ints = Int32Array::from_iter((0..100).map(|x| (x % 3 != 0).then_some(x)));
+        let strings = StringArray::from_iter_values((0..100).map(|x| format!("value {x}")));
+        let mut list = GenericListBuilder::<i32, _>::new(UInt32Builder::new());
+        for i in 0..100 {
+            list.values().append_slice(&[i; 3][..(i % 4) as usize]);
+            list.append(i % 5 != 0);
+        }
+        let batch = RecordBatch::try_from_iter([
+            ("ints", Arc::new(ints) as ArrayRef),
+            ("strings", Arc::new(strings) as ArrayRef),
+            ("list", Arc::new(list.finish()) as ArrayRef),
+            (
+                "bools",
+                Arc::new(BooleanArray::from(vec![true; 100])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let data_gen = IpcDataGenerator::default();
+        let options = IpcWriteOptions::default();
+        for batch in [batch.clone(), batch.slice(13, 50)] {
+            let (_, contiguous) = data_gen
+                .encoded_batch(&batch, &mut DictionaryTracker::new(false), &options)
+                .unwrap();
+            let (_, segments) = data_gen
+                .encoded_batch_segments(&batch, &mut DictionaryTracker::new(false), &options)
+                .unwrap();
+            assert_eq!(contiguous.ipc_message, segments.ipc_message);
+            assert_eq!(contiguous.arrow_data.len(), segments.body_len());
+
+            // The values of the unsliced primitive column are not copied
+            if batch.num_rows() == 100 {
+                let values = batch.column(0).as_primitive::<Int32Type>().values();
+                let values_ptr = values.inner().as_ptr();
+                assert!(segments.body.iter().any(|s| s.as_ptr() == values_ptr));
+            }
+
+            let mut expected = vec![];
+            write_message(&mut expected, contiguous, &options).unwrap();
+            let mut writer = ShortWriter(vec![]);
+            write_message_segments(&mut writer, segments, &options).unwrap();
+            assert_eq!(writer.0, expected);
+        }
+    }
 }
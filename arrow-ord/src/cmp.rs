@@ -22,7 +22,31 @@
 //! `RUSTFLAGS="-C target-feature=+avx2"` for example.  See the documentation
 //! [here](https://doc.rust-lang.org/stable/core/arch/) for more information.
 //!
+//! # Nested Comparison
+//!
+//! `List`, `LargeList`, `FixedSizeList`, `Struct` and `Map` are supported, and are
+//! compared element-wise in the same manner as [`make_comparator`] with the default
+//! [`SortOptions`]. A null at the top-level is handled in the same way as for any other
+//! type, i.e. yielding a null result except for [`distinct`] and [`not_distinct`].
+//! However, nulls nested within a value compare equal to each other, and less than
+//! any non-null value. In particular
+//!
+//! ```text
+//! [1, null] == [1, null] => true
+//! [1, null] == [1, 2] => false
+//! [1, null] < [1, 2] => true
+//! [1, 2] < [1, 2, 3] => true
+//! {a: 1, b: null} == {a: 1, b: null} => true
+//! {a: 1, b: null} == null => null
+//! ```
+//!
+//! Lists are compared lexicographically, with a shorter list that is a prefix of a
+//! longer list ordering first, structs are compared field by field, and maps are compared
+//! as a list of key-value entries, in the order they are stored.
+//!
+//! [`make_comparator`]: crate::ord::make_comparator
 
+use crate::ord::{make_comparator, DynComparator};
 use arrow_array::cast::AsArray;
 use arrow_array::types::{ByteArrayType, ByteViewType};
 use arrow_array::{
@@ -31,7 +55,7 @@ use arrow_array::{
 };
 use arrow_buffer::bit_util::ceil;
 use arrow_buffer::{BooleanBuffer, MutableBuffer, NullBuffer};
-use arrow_schema::ArrowError;
+use arrow_schema::{ArrowError, SortOptions};
 use arrow_select::take::take;
 use std::ops::Not;
 
@@ -73,8 +97,8 @@ impl std::fmt::Display for Op {
 /// to treat them as equal, please normalize zeros before calling this kernel. See
 /// [`f32::total_cmp`] and [`f64::total_cmp`].
 ///
-/// Nested types, such as lists, are not supported as the null semantics are not well-defined.
-/// For comparisons involving nested types see [`crate::ord::make_comparator`]
+/// Nested types, such as lists and structs, are compared as described in
+/// [Nested Comparison](crate::cmp#nested-comparison)
 pub fn eq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
     compare_op(Op::Equal, lhs, rhs)
 }
@@ -90,8 +114,8 @@ pub fn eq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError>
 /// to treat them as equal, please normalize zeros before calling this kernel. See
 /// [`f32::total_cmp`] and [`f64::total_cmp`].
 ///
-/// Nested types, such as lists, are not supported as the null semantics are not well-defined.
-/// For comparisons involving nested types see [`crate::ord::make_comparator`]
+/// Nested types, such as lists and structs, are compared as described in
+/// [Nested Comparison](crate::cmp#nested-comparison)
 pub fn neq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
     compare_op(Op::NotEqual, lhs, rhs)
 }
@@ -107,8 +131,8 @@ pub fn neq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError>
 /// to treat them as equal, please normalize zeros before calling this kernel. See
 /// [`f32::total_cmp`] and [`f64::total_cmp`].
 ///
-/// Nested types, such as lists, are not supported as the null semantics are not well-defined.
-/// For comparisons involving nested types see [`crate::ord::make_comparator`]
+/// Nested types, such as lists and structs, are compared as described in
+/// [Nested Comparison](crate::cmp#nested-comparison)
 pub fn lt(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
     compare_op(Op::Less, lhs, rhs)
 }
@@ -124,8 +148,8 @@ pub fn lt(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError>
 /// to treat them as equal, please normalize zeros before calling this kernel. See
 /// [`f32::total_cmp`] and [`f64::total_cmp`].
 ///
-/// Nested types, such as lists, are not supported as the null semantics are not well-defined.
-/// For comparisons involving nested types see [`crate::ord::make_comparator`]
+/// Nested types, such as lists and structs, are compared as described in
+/// [Nested Comparison](crate::cmp#nested-comparison)
 pub fn lt_eq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
     compare_op(Op::LessEqual, lhs, rhs)
 }
@@ -141,8 +165,8 @@ pub fn lt_eq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowErro
 /// to treat them as equal, please normalize zeros before calling this kernel. See
 /// [`f32::total_cmp`] and [`f64::total_cmp`].
 ///
-/// Nested types, such as lists, are not supported as the null semantics are not well-defined.
-/// For comparisons involving nested types see [`crate::ord::make_comparator`]
+/// Nested types, such as lists and structs, are compared as described in
+/// [Nested Comparison](crate::cmp#nested-comparison)
 pub fn gt(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
     compare_op(Op::Greater, lhs, rhs)
 }
@@ -158,8 +182,8 @@ pub fn gt(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError>
 /// to treat them as equal, please normalize zeros before calling this kernel. See
 /// [`f32::total_cmp`] and [`f64::total_cmp`].
 ///
-/// Nested types, such as lists, are not supported as the null semantics are not well-defined.
-/// For comparisons involving nested types see [`crate::ord::make_comparator`]
+/// Nested types, such as lists and structs, are compared as described in
+/// [Nested Comparison](crate::cmp#nested-comparison)
 pub fn gt_eq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
     compare_op(Op::GreaterEqual, lhs, rhs)
 }
@@ -176,8 +200,8 @@ pub fn gt_eq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowErro
 /// to treat them as equal, please normalize zeros before calling this kernel. See
 /// [`f32::total_cmp`] and [`f64::total_cmp`].
 ///
-/// Nested types, such as lists, are not supported as the null semantics are not well-defined.
-/// For comparisons involving nested types see [`crate::ord::make_comparator`]
+/// Nested types, such as lists and structs, are compared as described in
+/// [Nested Comparison](crate::cmp#nested-comparison)
 pub fn distinct(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
     compare_op(Op::Distinct, lhs, rhs)
 }
@@ -194,8 +218,8 @@ pub fn distinct(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowE
 /// to treat them as equal, please normalize zeros before calling this kernel. See
 /// [`f32::total_cmp`] and [`f64::total_cmp`].
 ///
-/// Nested types, such as lists, are not supported as the null semantics are not well-defined.
-/// For comparisons involving nested types see [`crate::ord::make_comparator`]
+/// Nested types, such as lists and structs, are compared as described in
+/// [Nested Comparison](crate::cmp#nested-comparison)
 pub fn not_distinct(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
     compare_op(Op::NotDistinct, lhs, rhs)
 }
@@ -232,18 +256,31 @@ fn compare_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray,
     let r = r_v.map(|x| x.values().as_ref()).unwrap_or(r);
     let r_t = r.data_type();
 
-    if r_t.is_nested() || l_t.is_nested() {
-        return Err(ArrowError::InvalidArgumentError(format!(
-            "Nested comparison: {l_t} {op} {r_t} (hint: use make_comparator instead)"
-        )));
-    } else if l_t != r_t {
+    let nested = l_t.is_nested() || r_t.is_nested();
+    let valid = match nested {
+        true => l_t.equals_datatype(r_t),
+        false => l_t == r_t,
+    };
+    if !valid {
         return Err(ArrowError::InvalidArgumentError(format!(
             "Invalid comparison operation: {l_t} {op} {r_t}"
         )));
     }
 
+    // Nested types are compared element-wise using a comparator
+    let nested_cmp = match nested && !l.is_empty() && !r.is_empty() {
+        true => Some(make_comparator(l, r, SortOptions::default())?),
+        false => None,
+    };
+
     // Defer computation as may not be necessary
     let values = || -> BooleanBuffer {
+        if nested {
+            return match &nested_cmp {
+                Some(cmp) => apply_nested(op, cmp, len, l_s, l_v, r_s, r_v),
+                None => BooleanBuffer::new_unset(len), // Handle empty arrays
+            };
+        }
         let d = downcast_primitive_array! {
             (l, r) => apply(op, l.values().as_ref(), l_s, l_v, r.values().as_ref(), r_s, r_v),
             (Boolean, Boolean) => apply(op, l.as_boolean(), l_s, l_v, r.as_boolean(), r_s, r_v),
@@ -381,6 +418,36 @@ fn apply<T: ArrayOrd>(
     }
 }
 
+/// Perform `op` on the provided nested arrays using the comparator `cmp`
+fn apply_nested(
+    op: Op,
+    cmp: &DynComparator,
+    len: usize,
+    l_s: bool,
+    l_v: Option<&dyn AnyDictionaryArray>,
+    r_s: bool,
+    r_v: Option<&dyn AnyDictionaryArray>,
+) -> BooleanBuffer {
+    let l_keys = l_v.map(|x| x.normalized_keys());
+    let r_keys = r_v.map(|x| x.normalized_keys());
+
+    // Map a logical index to an index into the (dictionary) values
+    let index = |keys: &Option<Vec<usize>>, is_scalar: bool, idx: usize| {
+        let idx = if is_scalar { 0 } else { idx };
+        keys.as_ref().map(|k| k[idx]).unwrap_or(idx)
+    };
+    let c = |i: usize| cmp(index(&l_keys, l_s, i), index(&r_keys, r_s, i));
+
+    match op {
+        Op::Equal | Op::NotDistinct => collect_bool(len, false, |i| c(i).is_eq()),
+        Op::NotEqual | Op::Distinct => collect_bool(len, true, |i| c(i).is_eq()),
+        Op::Less => collect_bool(len, false, |i| c(i).is_lt()),
+        Op::LessEqual => collect_bool(len, false, |i| c(i).is_le()),
+        Op::Greater => collect_bool(len, false, |i| c(i).is_gt()),
+        Op::GreaterEqual => collect_bool(len, false, |i| c(i).is_ge()),
+    }
+}
+
 /// Perform a take operation on `buffer` with the given dictionary
 fn take_bits(v: &dyn AnyDictionaryArray, buffer: BooleanBuffer) -> BooleanBuffer {
     let array = take(&BooleanArray::new(buffer, None), v.keys(), None).unwrap();
@@ -694,7 +761,13 @@ pub unsafe fn compare_byte_view_unchecked<T: ByteViewType>(
 mod tests {
     use std::sync::Arc;
 
-    use arrow_array::{DictionaryArray, Int32Array, Scalar, StringArray};
+    use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+    use arrow_array::types::Int32Type;
+    use arrow_array::{
+        DictionaryArray, FixedSizeListArray, Int32Array, LargeListArray, ListArray, Scalar,
+        StringArray, StructArray,
+    };
+    use arrow_schema::{DataType, Field, Fields};
 
     use super::*;
 
@@ -852,4 +925,250 @@ mod tests {
 
         neq(&col.slice(0, col.len() - 1), &col.slice(1, col.len() - 1)).unwrap();
     }
+
+    fn list_array(values: Vec<Option<Vec<Option<i32>>>>) -> ListArray {
+        ListArray::from_iter_primitive::<Int32Type, _, _>(values)
+    }
+
+    #[test]
+    fn test_nested_list() {
+        let a = list_array(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1), None]),
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(1), Some(2)]),
+        ]);
+        let b = list_array(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1), None]),
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1)]),
+            Some(vec![Some(1)]),
+            Some(vec![Some(1), Some(2), Some(3)]),
+        ]);
+
+        let r = eq(&a, &b).unwrap();
+        let expected = BooleanArray::from(vec![
+            Some(true),
+            Some(true),
+            Some(false),
+            None,
+            Some(false),
+            Some(false),
+        ]);
+        assert_eq!(r, expected);
+
+        let r = neq(&a, &b).unwrap();
+        let expected = BooleanArray::from(vec![
+            Some(false),
+            Some(false),
+            Some(true),
+            None,
+            Some(true),
+            Some(true),
+        ]);
+        assert_eq!(r, expected);
+
+        // Nested nulls are less than any value
+        let r = lt(&a, &b).unwrap();
+        let expected = BooleanArray::from(vec![
+            Some(false),
+            Some(false),
+            Some(true),
+            None,
+            Some(true),
+            Some(true),
+        ]);
+        assert_eq!(r, expected);
+
+        let r = gt_eq(&a, &b).unwrap();
+        let expected = BooleanArray::from(vec![
+            Some(true),
+            Some(true),
+            Some(false),
+            None,
+            Some(false),
+            Some(false),
+        ]);
+        assert_eq!(r, expected);
+
+        let r = distinct(&a, &b).unwrap();
+        let expected = BooleanArray::from(vec![false, false, true, true, true, true]);
+        assert_eq!(r, expected);
+
+        let r = not_distinct(&a, &a).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true; 6]));
+
+        let large =
+            LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1)]), None]);
+        let r = lt_eq(&large, &large.slice(0, 1)).unwrap_err();
+        assert!(r.to_string().contains("different lengths"), "{r}");
+        let r = gt(&large, &large).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![Some(false), None]));
+    }
+
+    #[test]
+    fn test_nested_scalar() {
+        let a = list_array(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![Some(3)]),
+        ]);
+        let s = Scalar::new(list_array(vec![Some(vec![Some(1), Some(2)])]));
+
+        let r = eq(&a, &s).unwrap();
+        let expected = BooleanArray::from(vec![Some(true), Some(false), None, Some(false)]);
+        assert_eq!(r, expected);
+
+        let r = lt(&s, &a).unwrap();
+        let expected = BooleanArray::from(vec![Some(false), Some(false), None, Some(true)]);
+        assert_eq!(r, expected);
+
+        let r = distinct(&a, &s).unwrap();
+        let expected = BooleanArray::from(vec![false, true, true, true]);
+        assert_eq!(r, expected);
+
+        let null = Scalar::new(list_array(vec![None]));
+        let r = eq(&a, &null).unwrap();
+        assert_eq!(r.null_count(), 4);
+        let r = not_distinct(&a, &null).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, false, true, false]));
+
+        let r = eq(&list_array(vec![]), &s).unwrap();
+        assert_eq!(r.len(), 0);
+    }
+
+    #[test]
+    fn test_nested_fixed_size_list() {
+        let a = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+            vec![
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![Some(1), None]),
+                None,
+            ],
+            2,
+        );
+        let b = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+            vec![
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![Some(0), Some(5)]),
+                Some(vec![Some(1), Some(2)]),
+            ],
+            2,
+        );
+
+        let r = eq(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![Some(true), Some(false), None]));
+        let r = gt(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![Some(false), Some(true), None]));
+
+        let c = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+            vec![Some(vec![Some(1)]), None, None],
+            1,
+        );
+        let err = eq(&a, &c).unwrap_err();
+        assert!(
+            err.to_string().contains("Invalid comparison operation"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_nested_struct() {
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let a = StructArray::new(
+            fields.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), Some(1), None, Some(2)])),
+                Arc::new(StringArray::from(vec![Some("a"), None, None, Some("c")])),
+            ],
+            Some(NullBuffer::from(vec![true, true, true, false])),
+        );
+        let b = StructArray::new(
+            fields,
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), Some(1), None, Some(2)])),
+                Arc::new(StringArray::from(vec![
+                    Some("b"),
+                    None,
+                    Some("a"),
+                    Some("c"),
+                ])),
+            ],
+            None,
+        );
+
+        let r = eq(&a, &b).unwrap();
+        let expected = BooleanArray::from(vec![Some(false), Some(true), Some(false), None]);
+        assert_eq!(r, expected);
+
+        let r = lt(&a, &b).unwrap();
+        let expected = BooleanArray::from(vec![Some(true), Some(false), Some(true), None]);
+        assert_eq!(r, expected);
+
+        let r = not_distinct(&a, &b).unwrap();
+        let expected = BooleanArray::from(vec![false, true, false, false]);
+        assert_eq!(r, expected);
+    }
+
+    #[test]
+    fn test_nested_map() {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        let mut append = |entries: &[(&str, Option<i32>)]| {
+            for (k, v) in entries {
+                builder.keys().append_value(k);
+                builder.values().append_option(*v);
+            }
+            builder.append(true).unwrap();
+        };
+        append(&[("a", Some(1)), ("b", Some(2))]);
+        append(&[("a", Some(1))]);
+        append(&[("a", None)]);
+        let a = builder.finish();
+
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        let mut append = |entries: &[(&str, Option<i32>)]| {
+            for (k, v) in entries {
+                builder.keys().append_value(k);
+                builder.values().append_option(*v);
+            }
+            builder.append(true).unwrap();
+        };
+        append(&[("a", Some(1)), ("b", Some(2))]);
+        append(&[("a", Some(2))]);
+        append(&[("a", None)]);
+        let b = builder.finish();
+
+        let r = eq(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, false, true]));
+        let r = lt(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, true, false]));
+    }
+
+    #[test]
+    fn test_nested_dictionary() {
+        let values = list_array(vec![Some(vec![Some(1)]), Some(vec![Some(2), None])]);
+        let keys = Int32Array::from(vec![Some(0), Some(1), None, Some(1)]);
+        let a = DictionaryArray::new(keys, Arc::new(values));
+        let b = list_array(vec![
+            Some(vec![Some(1)]),
+            Some(vec![Some(2)]),
+            Some(vec![Some(2)]),
+            Some(vec![Some(2), None]),
+        ]);
+
+        let r = eq(&a, &b).unwrap();
+        let expected = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+        assert_eq!(r, expected);
+
+        let r = gt(&a, &b).unwrap();
+        let expected = BooleanArray::from(vec![Some(false), Some(true), None, Some(false)]);
+        assert_eq!(r, expected);
+    }
 }
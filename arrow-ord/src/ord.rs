@@ -202,6 +202,37 @@ fn compare_list<O: OffsetSizeTrait>(
     Ok(f)
 }
 
+fn compare_map(
+    left: &dyn Array,
+    right: &dyn Array,
+    opts: SortOptions,
+) -> Result<DynComparator, ArrowError> {
+    let left = left.as_map();
+    let right = right.as_map();
+
+    let c_opts = child_opts(opts);
+    let cmp = make_comparator(left.entries(), right.entries(), c_opts)?;
+
+    let l_o = left.offsets().clone();
+    let r_o = right.offsets().clone();
+    let f = compare(left, right, opts, move |i, j| {
+        let l_end = l_o[i + 1].as_usize();
+        let l_start = l_o[i].as_usize();
+
+        let r_end = r_o[j + 1].as_usize();
+        let r_start = r_o[j].as_usize();
+
+        for (i, j) in (l_start..l_end).zip(r_start..r_end) {
+            match cmp(i, j) {
+                Ordering::Equal => continue,
+                r => return r,
+            }
+        }
+        (l_end - l_start).cmp(&(r_end - r_start))
+    });
+    Ok(f)
+}
+
 fn compare_fixed_list(
     left: &dyn Array,
     right: &dyn Array,
@@ -375,6 +406,7 @@ pub fn make_comparator(
         (LargeList(_), LargeList(_)) => compare_list::<i64>(left, right, opts),
         (FixedSizeList(_, _), FixedSizeList(_, _)) => compare_fixed_list(left, right, opts),
         (Struct(_), Struct(_)) => compare_struct(left, right, opts),
+        (Map(_, _), Map(_, _)) => compare_map(left, right, opts),
         (Dictionary(l_key, _), Dictionary(r_key, _)) => {
              macro_rules! dict_helper {
                 ($t:ty, $left:expr, $right:expr, $opts: expr) => {
@@ -396,7 +428,7 @@ pub fn make_comparator(
 #[cfg(test)]
 pub mod tests {
     use super::*;
-    use arrow_array::builder::{Int32Builder, ListBuilder};
+    use arrow_array::builder::{Int32Builder, ListBuilder, MapBuilder, StringBuilder};
     use arrow_buffer::{i256, IntervalDayTime, OffsetBuffer};
     use arrow_schema::{DataType, Field, Fields};
     use half::f16;
@@ -921,4 +953,42 @@ pub mod tests {
         assert_eq!(cmp(2, 0), Ordering::Equal); // (None, None) cmp (None, None)
         assert_eq!(cmp(3, 0), Ordering::Greater); // None cmp (None, None)
     }
+
+    #[test]
+    fn test_map() {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        // {a: 1, b: 2}
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.keys().append_value("b");
+        builder.values().append_value(2);
+        builder.append(true).unwrap();
+        // {a: 1}
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.append(true).unwrap();
+        // null
+        builder.append(false).unwrap();
+        // {a: null}
+        builder.keys().append_value("a");
+        builder.values().append_null();
+        builder.append(true).unwrap();
+        let map = builder.finish();
+
+        let cmp = make_comparator(&map, &map, SortOptions::default()).unwrap();
+        assert_eq!(cmp(0, 0), Ordering::Equal);
+        assert_eq!(cmp(0, 1), Ordering::Greater); // {a: 1, b: 2} cmp {a: 1}
+        assert_eq!(cmp(1, 3), Ordering::Greater); // {a: 1} cmp {a: null}
+        assert_eq!(cmp(2, 3), Ordering::Less); // null cmp {a: null}
+        assert_eq!(cmp(2, 2), Ordering::Equal); // null cmp null
+
+        let opts = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let cmp = make_comparator(&map, &map, opts).unwrap();
+        assert_eq!(cmp(0, 1), Ordering::Less); // {a: 1, b: 2} cmp {a: 1}
+        assert_eq!(cmp(1, 3), Ordering::Less); // {a: 1} cmp {a: null}
+        assert_eq!(cmp(2, 3), Ordering::Greater); // null cmp {a: null}
+    }
 }
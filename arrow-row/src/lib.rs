@@ -130,7 +130,7 @@ use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use arrow_array::cast::*;
-use arrow_array::types::ArrowDictionaryKeyType;
+use arrow_array::types::{ArrowDictionaryKeyType, Int16Type, Int32Type, Int64Type};
 use arrow_array::*;
 use arrow_buffer::ArrowNativeType;
 use arrow_data::ArrayDataBuilder;
@@ -142,6 +142,8 @@ use crate::variable::{decode_binary, decode_string};
 
 mod fixed;
 mod list;
+mod run;
+mod union;
 mod variable;
 
 /// Converts [`ArrayRef`] columns into a [row-oriented](self) format.
@@ -334,6 +336,43 @@ mod variable;
 ///
 /// With `[]` represented by an empty byte array, and `null` a null byte array.
 ///
+/// ## Fixed Size List Encoding
+///
+/// As every list contains the same number of elements, fixed size lists are encoded
+/// in a similar fashion to structs.
+///
+/// A null is encoded as a `0_u8`, followed by the row encoding of a null child element
+/// for each of the list's elements.
+///
+/// A valid value is encoded as `1_u8` followed by the row encoding of each child element.
+///
+/// ## Union Encoding
+///
+/// A union value is encoded as its type id as a `u8`, followed by the row encoding of
+/// the corresponding child value. Values are therefore ordered first by type id and then
+/// by value. Unions have no top-level nulls, with nulls instead encoded by the child.
+///
+/// ## Run End Encoding
+///
+/// Run end encoded arrays are hydrated to their logical values, in the same manner as
+/// dictionaries. When converting back, consecutive rows with the same encoding are
+/// combined into a single run.
+///
+/// ## Compact Encoding
+///
+/// A [`RowConverter`] created with [`RowConverter::new_compact`] uses a more compact
+/// encoding that supports equality comparison, but does not preserve ordering.
+/// [`SortOptions`] are ignored, and variable length data is length-prefixed instead of
+/// being split into padded blocks. Lengths are encoded as unsigned [LEB128] varints.
+///
+/// A variable length byte array is encoded as a `0_u8` if null, otherwise as a `1_u8`
+/// followed by the length of the byte array, and then the bytes themselves.
+///
+/// A list is encoded as a `0_u8` if null, otherwise as a `1_u8` followed by the number
+/// of elements, and then each encoded child element prefixed by its length in bytes.
+///
+/// All other types are encoded as described above.
+///
 /// # Ordering
 ///
 /// ## Float Ordering
@@ -355,12 +394,15 @@ mod variable;
 /// The order of a given column can be reversed by negating the encoded bytes of non-null values
 ///
 /// [COBS]: https://en.wikipedia.org/wiki/Consistent_Overhead_Byte_Stuffing
+/// [LEB128]: https://en.wikipedia.org/wiki/LEB128
 /// [byte stuffing]: https://en.wikipedia.org/wiki/High-Level_Data_Link_Control#Asynchronous_framing
 #[derive(Debug)]
 pub struct RowConverter {
     fields: Arc<[SortField]>,
     /// State for codecs
     codecs: Vec<Codec>,
+    /// Whether to use the compact, non-order-preserving encoding
+    compact: bool,
 }
 
 #[derive(Debug)]
@@ -375,16 +417,24 @@ enum Codec {
     Struct(RowConverter, OwnedRow),
     /// A row converter for the child field
     List(RowConverter),
+    /// A row converter for the child field
+    /// and the encoding of a row containing a single null child
+    FixedSizeList(RowConverter, OwnedRow),
+    /// A row converter for each child field
+    /// and the encoding of a row containing only nulls for each child field
+    Union(Vec<RowConverter>, Vec<OwnedRow>),
+    /// A row converter for the values field
+    RunEndEncoded(RowConverter),
 }
 
 impl Codec {
-    fn new(sort_field: &SortField) -> Result<Self, ArrowError> {
+    fn new(sort_field: &SortField, compact: bool) -> Result<Self, ArrowError> {
         match &sort_field.data_type {
             DataType::Dictionary(_, values) => {
                 let sort_field =
                     SortField::new_with_options(values.as_ref().clone(), sort_field.options);
 
-                let converter = RowConverter::new(vec![sort_field])?;
+                let converter = RowConverter::try_new(vec![sort_field], compact)?;
                 let null_array = new_null_array(values.as_ref(), 1);
                 let nulls = converter.convert_columns(&[null_array])?;
 
@@ -394,6 +444,12 @@ impl Codec {
                 };
                 Ok(Self::Dictionary(converter, owned))
             }
+            DataType::RunEndEncoded(_, values) => {
+                let sort_field =
+                    SortField::new_with_options(values.data_type().clone(), sort_field.options);
+                let converter = RowConverter::try_new(vec![sort_field], compact)?;
+                Ok(Self::RunEndEncoded(converter))
+            }
             d if !d.is_nested() => Ok(Self::Stateless),
             DataType::List(f) | DataType::LargeList(f) => {
                 // The encoded contents will be inverted if descending is set to true
@@ -405,7 +461,7 @@ impl Codec {
                 };
 
                 let field = SortField::new_with_options(f.data_type().clone(), options);
-                let converter = RowConverter::new(vec![field])?;
+                let converter = RowConverter::try_new(vec![field], compact)?;
                 Ok(Self::List(converter))
             }
             DataType::Struct(f) => {
@@ -414,7 +470,7 @@ impl Codec {
                     .map(|x| SortField::new_with_options(x.data_type().clone(), sort_field.options))
                     .collect();
 
-                let converter = RowConverter::new(sort_fields)?;
+                let converter = RowConverter::try_new(sort_fields, compact)?;
                 let nulls: Vec<_> = f.iter().map(|x| new_null_array(x.data_type(), 1)).collect();
 
                 let nulls = converter.convert_columns(&nulls)?;
@@ -425,6 +481,34 @@ impl Codec {
 
                 Ok(Self::Struct(converter, owned))
             }
+            DataType::FixedSizeList(f, _) => {
+                let field = SortField::new_with_options(f.data_type().clone(), sort_field.options);
+                let converter = RowConverter::try_new(vec![field], compact)?;
+                let nulls = converter.convert_columns(&[new_null_array(f.data_type(), 1)])?;
+
+                let owned = OwnedRow {
+                    data: nulls.buffer.into(),
+                    config: nulls.config,
+                };
+                Ok(Self::FixedSizeList(converter, owned))
+            }
+            DataType::Union(f, _) => {
+                let mut converters = Vec::with_capacity(f.len());
+                let mut nulls = Vec::with_capacity(f.len());
+                for (_, field) in f.iter() {
+                    let sort_field =
+                        SortField::new_with_options(field.data_type().clone(), sort_field.options);
+                    let converter = RowConverter::try_new(vec![sort_field], compact)?;
+                    let null =
+                        converter.convert_columns(&[new_null_array(field.data_type(), 1)])?;
+                    nulls.push(OwnedRow {
+                        data: null.buffer.into(),
+                        config: null.config,
+                    });
+                    converters.push(converter);
+                }
+                Ok(Self::Union(converters, nulls))
+            }
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "not yet implemented: {:?}",
                 sort_field.data_type
@@ -454,6 +538,32 @@ impl Codec {
                 let rows = converter.convert_columns(&[values.clone()])?;
                 Ok(Encoder::List(rows))
             }
+            Codec::FixedSizeList(converter, null) => {
+                let values = array.as_fixed_size_list().values();
+                let rows = converter.convert_columns(std::slice::from_ref(values))?;
+                Ok(Encoder::FixedSizeList(rows, null.row()))
+            }
+            Codec::Union(converters, _) => {
+                let array = as_union_array(array);
+                let fields = match array.data_type() {
+                    DataType::Union(fields, _) => fields,
+                    _ => unreachable!(),
+                };
+                let mut rows: Vec<Option<Rows>> = (0..=i8::MAX).map(|_| None).collect();
+                for ((type_id, _), converter) in fields.iter().zip(converters) {
+                    let child = std::slice::from_ref(array.child(type_id));
+                    rows[type_id as usize] = Some(converter.convert_columns(child)?);
+                }
+                Ok(Encoder::Union(rows))
+            }
+            Codec::RunEndEncoded(converter) => {
+                let values = downcast_run_array! {
+                    array => array.values(),
+                    _ => unreachable!()
+                };
+                let rows = converter.convert_columns(std::slice::from_ref(values))?;
+                Ok(Encoder::RunEndEncoded(rows))
+            }
         }
     }
 
@@ -463,6 +573,13 @@ impl Codec {
             Codec::Dictionary(converter, nulls) => converter.size() + nulls.data.len(),
             Codec::Struct(converter, nulls) => converter.size() + nulls.data.len(),
             Codec::List(converter) => converter.size(),
+            Codec::FixedSizeList(converter, nulls) => converter.size() + nulls.data.len(),
+            Codec::Union(converters, nulls) => converters
+                .iter()
+                .zip(nulls)
+                .map(|(converter, nulls)| converter.size() + nulls.data.len())
+                .sum(),
+            Codec::RunEndEncoded(converter) => converter.size(),
         }
     }
 }
@@ -481,6 +598,12 @@ enum Encoder<'a> {
     Struct(Rows, Row<'a>),
     /// The row encoding of the child array
     List(Rows),
+    /// The row encoding of the child array and the encoding of a null child
+    FixedSizeList(Rows, Row<'a>),
+    /// The row encoding of each child array, indexed by type id
+    Union(Vec<Option<Rows>>),
+    /// The row encoding of the values array
+    RunEndEncoded(Rows),
 }
 
 /// Configure the data type and sort order for a given column
@@ -514,16 +637,62 @@ impl SortField {
 impl RowConverter {
     /// Create a new [`RowConverter`] with the provided schema
     pub fn new(fields: Vec<SortField>) -> Result<Self, ArrowError> {
+        Self::try_new(fields, false)
+    }
+
+    /// Create a new [`RowConverter`] with the provided schema, that uses a compact
+    /// encoding that does not preserve ordering
+    ///
+    /// The resulting [`Rows`] are smaller and faster to produce, and can be compared for
+    /// equality, hashed and converted back to arrays with [`RowConverter::convert_rows`].
+    /// However, the [`Ord`] implementation of the resulting [`Row`] does not correspond to
+    /// any meaningful ordering of the underlying values, and the [`SortOptions`] of the
+    /// provided fields are ignored.
+    ///
+    /// This makes it well suited to hash joins and grouping, where only equality is required.
+    ///
+    /// See the [`RowConverter`] documentation for details of the encoding
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, StringArray};
+    /// # use arrow_row::{RowConverter, SortField};
+    /// # use arrow_schema::DataType;
+    /// #
+    /// let converter = RowConverter::new_compact(vec![SortField::new(DataType::Utf8)]).unwrap();
+    /// let array = Arc::new(StringArray::from(vec!["hello", "world", "hello"])) as ArrayRef;
+    ///
+    /// let rows = converter.convert_columns(&[Arc::clone(&array)]).unwrap();
+    /// assert_eq!(rows.row(0), rows.row(2));
+    /// assert_ne!(rows.row(0), rows.row(1));
+    ///
+    /// let back = converter.convert_rows(&rows).unwrap();
+    /// assert_eq!(&back[0], &array);
+    /// ```
+    pub fn new_compact(fields: Vec<SortField>) -> Result<Self, ArrowError> {
+        // The sort options have no bearing on equality and so are ignored
+        let fields = fields
+            .into_iter()
+            .map(|f| SortField::new(f.data_type))
+            .collect();
+        Self::try_new(fields, true)
+    }
+
+    fn try_new(fields: Vec<SortField>, compact: bool) -> Result<Self, ArrowError> {
         if !Self::supports_fields(&fields) {
             return Err(ArrowError::NotYetImplemented(format!(
                 "Row format support not yet implemented for: {fields:?}"
             )));
         }
 
-        let codecs = fields.iter().map(Codec::new).collect::<Result<_, _>>()?;
+        let codecs = fields
+            .iter()
+            .map(|f| Codec::new(f, compact))
+            .collect::<Result<_, _>>()?;
         Ok(Self {
             fields: fields.into(),
             codecs,
+            compact,
         })
     }
 
@@ -534,11 +703,16 @@ impl RowConverter {
 
     fn supports_datatype(d: &DataType) -> bool {
         match d {
+            DataType::RunEndEncoded(_, v) => Self::supports_datatype(v.data_type()),
             _ if !d.is_nested() => true,
-            DataType::List(f) | DataType::LargeList(f) | DataType::Map(f, _) => {
-                Self::supports_datatype(f.data_type())
-            }
+            DataType::List(f)
+            | DataType::LargeList(f)
+            | DataType::FixedSizeList(f, _)
+            | DataType::Map(f, _) => Self::supports_datatype(f.data_type()),
             DataType::Struct(f) => f.iter().all(|x| Self::supports_datatype(x.data_type())),
+            DataType::Union(f, _) => f
+                .iter()
+                .all(|(_, x)| Self::supports_datatype(x.data_type())),
             _ => false,
         }
     }
@@ -618,7 +792,7 @@ impl RowConverter {
             .collect::<Result<Vec<_>, _>>()?;
 
         let write_offset = rows.num_rows();
-        let lengths = row_lengths(columns, &encoders);
+        let lengths = row_lengths(columns, &encoders, self.compact);
 
         // We initialize the offsets shifted down by one row index.
         //
@@ -655,6 +829,7 @@ impl RowConverter {
                 column.as_ref(),
                 field.options,
                 &encoder,
+                self.compact,
             )
         }
 
@@ -751,7 +926,7 @@ impl RowConverter {
         self.fields
             .iter()
             .zip(&self.codecs)
-            .map(|(field, codec)| decode_column(field, rows, codec, validate_utf8))
+            .map(|(field, codec)| decode_column(field, rows, codec, validate_utf8, self.compact))
             .collect()
     }
 
@@ -1059,7 +1234,7 @@ fn null_sentinel(options: SortOptions) -> u8 {
 }
 
 /// Computes the length of each encoded [`Rows`] and returns an empty [`Rows`]
-fn row_lengths(cols: &[ArrayRef], encoders: &[Encoder]) -> Vec<usize> {
+fn row_lengths(cols: &[ArrayRef], encoders: &[Encoder], compact: bool) -> Vec<usize> {
     use fixed::FixedLengthEncoding;
 
     let num_rows = cols.first().map(|x| x.len()).unwrap_or(0);
@@ -1075,28 +1250,28 @@ fn row_lengths(cols: &[ArrayRef], encoders: &[Encoder]) -> Vec<usize> {
                     DataType::Binary => as_generic_binary_array::<i32>(array)
                         .iter()
                         .zip(lengths.iter_mut())
-                        .for_each(|(slice, length)| *length += variable::encoded_len(slice)),
+                        .for_each(|(slice, length)| *length += variable::encoded_len(slice, compact)),
                     DataType::LargeBinary => as_generic_binary_array::<i64>(array)
                         .iter()
                         .zip(lengths.iter_mut())
-                        .for_each(|(slice, length)| *length += variable::encoded_len(slice)),
+                        .for_each(|(slice, length)| *length += variable::encoded_len(slice, compact)),
                     DataType::BinaryView => array.as_binary_view().iter().zip(lengths.iter_mut()).for_each(|(slice, length)| {
-                            *length += variable::encoded_len(slice)
+                            *length += variable::encoded_len(slice, compact)
                         }),
                     DataType::Utf8 => array.as_string::<i32>()
                         .iter()
                         .zip(lengths.iter_mut())
                         .for_each(|(slice, length)| {
-                            *length += variable::encoded_len(slice.map(|x| x.as_bytes()))
+                            *length += variable::encoded_len(slice.map(|x| x.as_bytes()), compact)
                         }),
                     DataType::LargeUtf8 => array.as_string::<i64>()
                         .iter()
                         .zip(lengths.iter_mut())
                         .for_each(|(slice, length)| {
-                            *length += variable::encoded_len(slice.map(|x| x.as_bytes()))
+                            *length += variable::encoded_len(slice.map(|x| x.as_bytes()), compact)
                         }),
                     DataType::Utf8View => array.as_string_view().iter().zip(lengths.iter_mut()).for_each(|(slice, length)| {
-                        *length += variable::encoded_len(slice.map(|x| x.as_bytes()))
+                        *length += variable::encoded_len(slice.map(|x| x.as_bytes()), compact)
                     }),
                     DataType::FixedSizeBinary(len) => {
                         let len = len.to_usize().unwrap();
@@ -1129,13 +1304,26 @@ fn row_lengths(cols: &[ArrayRef], encoders: &[Encoder]) -> Vec<usize> {
             }
             Encoder::List(rows) => match array.data_type() {
                 DataType::List(_) => {
-                    list::compute_lengths(&mut lengths, rows, as_list_array(array))
+                    list::compute_lengths(&mut lengths, rows, as_list_array(array), compact)
                 }
                 DataType::LargeList(_) => {
-                    list::compute_lengths(&mut lengths, rows, as_large_list_array(array))
+                    list::compute_lengths(&mut lengths, rows, as_large_list_array(array), compact)
                 }
                 _ => unreachable!(),
             },
+            Encoder::FixedSizeList(rows, null) => list::compute_lengths_fixed_size_list(
+                &mut lengths,
+                rows,
+                null.as_ref(),
+                array.as_fixed_size_list(),
+            ),
+            Encoder::Union(rows) => {
+                union::compute_lengths(&mut lengths, rows, as_union_array(array))
+            }
+            Encoder::RunEndEncoded(rows) => downcast_run_array! {
+                array => run::compute_lengths(&mut lengths, rows, array),
+                _ => unreachable!()
+            },
         }
     }
 
@@ -1149,6 +1337,7 @@ fn encode_column(
     column: &dyn Array,
     opts: SortOptions,
     encoder: &Encoder<'_>,
+    compact: bool,
 ) {
     match encoder {
         Encoder::Stateless => {
@@ -1169,18 +1358,19 @@ fn encode_column(
                     }
                 }
                 DataType::Binary => {
-                    variable::encode(data, offsets, as_generic_binary_array::<i32>(column).iter(), opts)
+                    variable::encode(data, offsets, as_generic_binary_array::<i32>(column).iter(), opts, compact)
                 }
                 DataType::BinaryView => {
-                    variable::encode(data, offsets, column.as_binary_view().iter(), opts)
+                    variable::encode(data, offsets, column.as_binary_view().iter(), opts, compact)
                 }
                 DataType::LargeBinary => {
-                    variable::encode(data, offsets, as_generic_binary_array::<i64>(column).iter(), opts)
+                    variable::encode(data, offsets, as_generic_binary_array::<i64>(column).iter(), opts, compact)
                 }
                 DataType::Utf8 => variable::encode(
                     data, offsets,
                     column.as_string::<i32>().iter().map(|x| x.map(|x| x.as_bytes())),
                     opts,
+                    compact,
                 ),
                 DataType::LargeUtf8 => variable::encode(
                     data, offsets,
@@ -1188,11 +1378,13 @@ fn encode_column(
                         .iter()
                         .map(|x| x.map(|x| x.as_bytes())),
                     opts,
+                    compact,
                 ),
                 DataType::Utf8View => variable::encode(
                     data, offsets,
                     column.as_string_view().iter().map(|x| x.map(|x| x.as_bytes())),
                     opts,
+                    compact,
                 ),
                 DataType::FixedSizeBinary(_) => {
                     let array = column.as_any().downcast_ref().unwrap();
@@ -1226,12 +1418,32 @@ fn encode_column(
                 })
         }
         Encoder::List(rows) => match column.data_type() {
-            DataType::List(_) => list::encode(data, offsets, rows, opts, as_list_array(column)),
-            DataType::LargeList(_) => {
-                list::encode(data, offsets, rows, opts, as_large_list_array(column))
+            DataType::List(_) => {
+                list::encode(data, offsets, rows, opts, as_list_array(column), compact)
             }
+            DataType::LargeList(_) => list::encode(
+                data,
+                offsets,
+                rows,
+                opts,
+                as_large_list_array(column),
+                compact,
+            ),
             _ => unreachable!(),
         },
+        Encoder::FixedSizeList(rows, null) => list::encode_fixed_size_list(
+            data,
+            offsets,
+            rows,
+            null.as_ref(),
+            opts,
+            column.as_fixed_size_list(),
+        ),
+        Encoder::Union(rows) => union::encode(data, offsets, rows, opts, as_union_array(column)),
+        Encoder::RunEndEncoded(rows) => downcast_run_array! {
+            column => run::encode(data, offsets, rows, column),
+            _ => unreachable!()
+        },
     }
 }
 
@@ -1270,6 +1482,7 @@ unsafe fn decode_column(
     rows: &mut [&[u8]],
     codec: &Codec,
     validate_utf8: bool,
+    compact: bool,
 ) -> Result<ArrayRef, ArrowError> {
     let options = field.options;
 
@@ -1280,13 +1493,13 @@ unsafe fn decode_column(
                 data_type => (decode_primitive_helper, rows, data_type, options),
                 DataType::Null => Arc::new(NullArray::new(rows.len())),
                 DataType::Boolean => Arc::new(decode_bool(rows, options)),
-                DataType::Binary => Arc::new(decode_binary::<i32>(rows, options)),
-                DataType::LargeBinary => Arc::new(decode_binary::<i64>(rows, options)),
-                DataType::BinaryView => Arc::new(decode_binary_view(rows, options)),
+                DataType::Binary => Arc::new(decode_binary::<i32>(rows, options, compact)),
+                DataType::LargeBinary => Arc::new(decode_binary::<i64>(rows, options, compact)),
+                DataType::BinaryView => Arc::new(decode_binary_view(rows, options, compact)),
                 DataType::FixedSizeBinary(size) => Arc::new(decode_fixed_size_binary(rows, size, options)),
-                DataType::Utf8 => Arc::new(decode_string::<i32>(rows, options, validate_utf8)),
-                DataType::LargeUtf8 => Arc::new(decode_string::<i64>(rows, options, validate_utf8)),
-                DataType::Utf8View => Arc::new(decode_string_view(rows, options, validate_utf8)),
+                DataType::Utf8 => Arc::new(decode_string::<i32>(rows, options, compact, validate_utf8)),
+                DataType::LargeUtf8 => Arc::new(decode_string::<i64>(rows, options, compact, validate_utf8)),
+                DataType::Utf8View => Arc::new(decode_string_view(rows, options, compact, validate_utf8)),
                 _ => return Err(ArrowError::NotYetImplemented(format!("unsupported data type: {}", data_type)))
             }
         }
@@ -1309,12 +1522,60 @@ unsafe fn decode_column(
             Arc::new(StructArray::from(builder.build_unchecked()))
         }
         Codec::List(converter) => match &field.data_type {
-            DataType::List(_) => {
-                Arc::new(list::decode::<i32>(converter, rows, field, validate_utf8)?)
-            }
-            DataType::LargeList(_) => {
-                Arc::new(list::decode::<i64>(converter, rows, field, validate_utf8)?)
-            }
+            DataType::List(_) => Arc::new(list::decode::<i32>(
+                converter,
+                rows,
+                field,
+                validate_utf8,
+                compact,
+            )?),
+            DataType::LargeList(_) => Arc::new(list::decode::<i64>(
+                converter,
+                rows,
+                field,
+                validate_utf8,
+                compact,
+            )?),
+            _ => unreachable!(),
+        },
+        Codec::FixedSizeList(converter, _) => Arc::new(list::decode_fixed_size_list(
+            converter,
+            rows,
+            field,
+            validate_utf8,
+        )?),
+        Codec::Union(converters, nulls) => {
+            let nulls: Vec<_> = nulls.iter().map(|x| x.data.as_ref()).collect();
+            Arc::new(union::decode(
+                converters,
+                &nulls,
+                rows,
+                field,
+                validate_utf8,
+            )?)
+        }
+        Codec::RunEndEncoded(converter) => match &field.data_type {
+            DataType::RunEndEncoded(r, _) => match r.data_type() {
+                DataType::Int16 => Arc::new(run::decode::<Int16Type>(
+                    converter,
+                    rows,
+                    field,
+                    validate_utf8,
+                )?),
+                DataType::Int32 => Arc::new(run::decode::<Int32Type>(
+                    converter,
+                    rows,
+                    field,
+                    validate_utf8,
+                )?),
+                DataType::Int64 => Arc::new(run::decode::<Int64Type>(
+                    converter,
+                    rows,
+                    field,
+                    validate_utf8,
+                )?),
+                _ => unreachable!(),
+            },
             _ => unreachable!(),
         },
     };
@@ -2048,6 +2309,236 @@ mod tests {
         test_nested_list::<i64>();
     }
 
+    #[test]
+    fn test_fixed_size_list() {
+        let list = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+            vec![
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![Some(1), Some(3)]),
+                None,
+                Some(vec![Some(1), None]),
+                Some(vec![Some(0), Some(5)]),
+            ],
+            2,
+        );
+        let list = Arc::new(list) as ArrayRef;
+
+        let converter = RowConverter::new(vec![SortField::new(list.data_type().clone())]).unwrap();
+        let rows = converter.convert_columns(&[Arc::clone(&list)]).unwrap();
+
+        // null < [0, 5] < [1, null] < [1, 2] < [1, 3]
+        assert!(rows.row(2) < rows.row(4));
+        assert!(rows.row(4) < rows.row(3));
+        assert!(rows.row(3) < rows.row(0));
+        assert!(rows.row(0) < rows.row(1));
+
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(back.len(), 1);
+        back[0].to_data().validate_full().unwrap();
+        assert_eq!(&back[0], &list);
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let field = SortField::new_with_options(list.data_type().clone(), options);
+        let converter = RowConverter::new(vec![field]).unwrap();
+        let rows = converter.convert_columns(&[Arc::clone(&list)]).unwrap();
+
+        // [1, 3] < [1, 2] < [1, null] < [0, 5] < null
+        assert!(rows.row(1) < rows.row(0));
+        assert!(rows.row(0) < rows.row(3));
+        assert!(rows.row(3) < rows.row(4));
+        assert!(rows.row(4) < rows.row(2));
+
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(&back[0], &list);
+
+        let sliced = list.slice(1, 3);
+        let rows = converter.convert_columns(&[Arc::clone(&sliced)]).unwrap();
+        let back = converter.convert_rows(&rows).unwrap();
+        back[0].to_data().validate_full().unwrap();
+        assert_eq!(&back[0], &sliced);
+
+        // Lists of size 0 only encode the null sentinel
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        let values = new_empty_array(&DataType::Int32);
+        let nulls = NullBuffer::from(vec![true, false, true]);
+        let empty = FixedSizeListArray::new(field, 0, values, Some(nulls));
+        let empty = Arc::new(empty) as ArrayRef;
+
+        let converter = RowConverter::new(vec![SortField::new(empty.data_type().clone())]).unwrap();
+        let rows = converter.convert_columns(&[Arc::clone(&empty)]).unwrap();
+        assert_eq!(rows.row(0), rows.row(2));
+        assert!(rows.row(1) < rows.row(0));
+
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(&back[0], &empty);
+    }
+
+    #[test]
+    fn test_dense_union() {
+        let fields = UnionFields::new(
+            vec![0, 1],
+            vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, false),
+            ],
+        );
+        let type_ids = vec![0, 1, 0, 1, 0].into();
+        let offsets = vec![0, 0, 1, 1, 2].into();
+        let children = vec![
+            Arc::new(Int32Array::from(vec![Some(5), None, Some(1)])) as ArrayRef,
+            Arc::new(StringArray::from(vec!["x", "a"])) as ArrayRef,
+        ];
+        let union = UnionArray::try_new(fields, type_ids, Some(offsets), children).unwrap();
+        let union = Arc::new(union) as ArrayRef;
+
+        let converter = RowConverter::new(vec![SortField::new(union.data_type().clone())]).unwrap();
+        let rows = converter.convert_columns(&[Arc::clone(&union)]).unwrap();
+
+        // Ordered first by type id and then by value
+        assert!(rows.row(2) < rows.row(4));
+        assert!(rows.row(4) < rows.row(0));
+        assert!(rows.row(0) < rows.row(3));
+        assert!(rows.row(3) < rows.row(1));
+
+        let back = converter.convert_rows(&rows).unwrap();
+        back[0].to_data().validate_full().unwrap();
+        assert_eq!(&back[0], &union);
+
+        let sliced = union.slice(1, 3);
+        let rows = converter.convert_columns(&[Arc::clone(&sliced)]).unwrap();
+        let back = converter.convert_rows(&rows).unwrap();
+        back[0].to_data().validate_full().unwrap();
+        assert_eq!(&back[0], &sliced);
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let field = SortField::new_with_options(union.data_type().clone(), options);
+        let converter = RowConverter::new(vec![field]).unwrap();
+        let rows = converter.convert_columns(&[Arc::clone(&union)]).unwrap();
+
+        assert!(rows.row(1) < rows.row(3));
+        assert!(rows.row(3) < rows.row(2));
+        assert!(rows.row(2) < rows.row(0));
+        assert!(rows.row(0) < rows.row(4));
+
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(&back[0], &union);
+    }
+
+    #[test]
+    fn test_sparse_union() {
+        let fields = UnionFields::new(
+            vec![1, 3],
+            vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+            ],
+        );
+        let type_ids = vec![1, 3, 1, 3, 1].into();
+        let children = vec![
+            Arc::new(Int32Array::from(vec![Some(5), None, None, None, Some(1)])) as ArrayRef,
+            Arc::new(StringArray::from(vec![
+                None,
+                Some("x"),
+                None,
+                Some("a"),
+                None,
+            ])) as ArrayRef,
+        ];
+        let union = UnionArray::try_new(fields, type_ids, None, children).unwrap();
+        let union = Arc::new(union) as ArrayRef;
+
+        let converter = RowConverter::new(vec![SortField::new(union.data_type().clone())]).unwrap();
+        let rows = converter.convert_columns(&[Arc::clone(&union)]).unwrap();
+
+        assert!(rows.row(2) < rows.row(4));
+        assert!(rows.row(4) < rows.row(0));
+        assert!(rows.row(0) < rows.row(3));
+        assert!(rows.row(3) < rows.row(1));
+
+        let back = converter.convert_rows(&rows).unwrap();
+        back[0].to_data().validate_full().unwrap();
+        assert_eq!(&back[0], &union);
+
+        let sliced = union.slice(1, 3);
+        let rows = converter.convert_columns(&[Arc::clone(&sliced)]).unwrap();
+        let back = converter.convert_rows(&rows).unwrap();
+        back[0].to_data().validate_full().unwrap();
+        assert_eq!(&back[0], &sliced);
+    }
+
+    #[test]
+    fn test_run_end_encoded() {
+        let run_ends = Int32Array::from(vec![2, 3, 6, 7]);
+        let values = StringArray::from(vec![Some("b"), None, Some("a"), Some("b")]);
+        let array = RunArray::try_new(&run_ends, &values).unwrap();
+        let array = Arc::new(array) as ArrayRef;
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let converter = RowConverter::new(vec![SortField::new_with_options(
+            array.data_type().clone(),
+            options,
+        )])
+        .unwrap();
+        let rows = converter.convert_columns(&[Arc::clone(&array)]).unwrap();
+
+        // Run end encoded arrays are encoded the same as their logical values
+        let logical = StringArray::from(vec![
+            Some("b"),
+            Some("b"),
+            None,
+            Some("a"),
+            Some("a"),
+            Some("a"),
+            Some("b"),
+        ]);
+        let logical_converter =
+            RowConverter::new(vec![SortField::new_with_options(DataType::Utf8, options)]).unwrap();
+        let logical_rows = logical_converter
+            .convert_columns(&[Arc::new(logical) as ArrayRef])
+            .unwrap();
+        assert_eq!(rows.num_rows(), logical_rows.num_rows());
+        for (a, b) in rows.iter().zip(logical_rows.iter()) {
+            assert_eq!(a.as_ref(), b.as_ref());
+        }
+
+        let back = converter.convert_rows(&rows).unwrap();
+        back[0].to_data().validate_full().unwrap();
+        assert_eq!(&back[0], &array);
+
+        let sliced = array.slice(1, 4);
+        let rows = converter.convert_columns(&[sliced]).unwrap();
+        let back = converter.convert_rows(&rows).unwrap();
+        let back = as_run_array::<Int32Type>(back[0].as_ref());
+        assert_eq!(back.run_ends().values(), &[1, 2, 4]);
+        let values = back.values().as_string::<i32>();
+        assert_eq!(values, &StringArray::from(vec![Some("b"), None, Some("a")]));
+
+        // Adjacent runs with the same value are merged
+        let run_ends = Int16Array::from(vec![2, 4, 5]);
+        let values = Int64Array::from(vec![1, 1, 2]);
+        let array = RunArray::try_new(&run_ends, &values).unwrap();
+        let array = Arc::new(array) as ArrayRef;
+
+        let converter = RowConverter::new(vec![SortField::new(array.data_type().clone())]).unwrap();
+        let rows = converter.convert_columns(&[array]).unwrap();
+        assert_eq!(rows.row(0), rows.row(3));
+        assert!(rows.row(3) < rows.row(4));
+
+        let back = converter.convert_rows(&rows).unwrap();
+        let back = as_run_array::<Int16Type>(back[0].as_ref());
+        assert_eq!(back.run_ends().values(), &[4, 5]);
+        assert_eq!(back.values().as_primitive::<Int64Type>().values(), &[1, 2]);
+    }
+
     fn generate_primitive_array<K>(len: usize, valid_percent: f64) -> PrimitiveArray<K>
     where
         K: ArrowPrimitiveType,
@@ -2179,9 +2670,21 @@ mod tests {
         ListArray::new(field, offsets, values, Some(nulls))
     }
 
+    fn generate_fixed_size_list<F>(len: usize, valid_percent: f64, values: F) -> FixedSizeListArray
+    where
+        F: FnOnce(usize) -> ArrayRef,
+    {
+        let mut rng = thread_rng();
+        let size = rng.gen_range(0..5);
+        let values = values(len * size as usize);
+        let nulls = NullBuffer::from_iter((0..len).map(|_| rng.gen_bool(valid_percent)));
+        let field = Arc::new(Field::new("item", values.data_type().clone(), true));
+        FixedSizeListArray::new(field, size, values, Some(nulls))
+    }
+
     fn generate_column(len: usize) -> ArrayRef {
         let mut rng = thread_rng();
-        match rng.gen_range(0..16) {
+        match rng.gen_range(0..17) {
             0 => Arc::new(generate_primitive_array::<Int32Type>(len, 0.8)),
             1 => Arc::new(generate_primitive_array::<UInt32Type>(len, 0.8)),
             2 => Arc::new(generate_primitive_array::<Int64Type>(len, 0.8)),
@@ -2217,6 +2720,9 @@ mod tests {
             })),
             14 => Arc::new(generate_string_view(len, 0.8)),
             15 => Arc::new(generate_byte_view(len, 0.8)),
+            16 => Arc::new(generate_fixed_size_list(len, 0.8, |values_len| {
+                Arc::new(generate_primitive_array::<Int32Type>(values_len, 0.8))
+            })),
             _ => unreachable!(),
         }
     }
@@ -2304,9 +2810,112 @@ mod tests {
                 actual.to_data().validate_full().unwrap();
                 dictionary_eq(actual, expected)
             }
+
+            // The compact encoding must agree with the sortable encoding on equality
+            let fields = arrays
+                .iter()
+                .map(|a| SortField::new(a.data_type().clone()))
+                .collect();
+            let compact_converter = RowConverter::new_compact(fields).unwrap();
+            let compact = compact_converter.convert_columns(&arrays).unwrap();
+            for i in 0..len {
+                for j in 0..len {
+                    assert_eq!(
+                        rows.row(i) == rows.row(j),
+                        compact.row(i) == compact.row(j),
+                        "({:?} vs {:?}) for types {}",
+                        print_row(&sort_columns, i),
+                        print_row(&sort_columns, j),
+                        print_col_types(&sort_columns)
+                    );
+                }
+            }
+
+            let back = compact_converter.convert_rows(&compact).unwrap();
+            for (actual, expected) in back.iter().zip(&arrays) {
+                actual.to_data().validate_full().unwrap();
+                dictionary_eq(actual, expected)
+            }
         }
     }
 
+    #[test]
+    fn test_compact() {
+        let strings = Arc::new(StringArray::from(vec![
+            Some("hello"),
+            None,
+            Some(""),
+            Some("a very long string that would otherwise span multiple blocks"),
+            Some("hello"),
+        ])) as ArrayRef;
+
+        let mut builder = ListBuilder::new(StringBuilder::new());
+        builder.append_value([Some("a"), None]);
+        builder.append_null();
+        builder.append_value([None::<&str>; 0]);
+        builder.append_value([Some("a"), Some("b"), Some("c")]);
+        builder.append_value([Some("a"), None]);
+        let lists = Arc::new(builder.finish()) as ArrayRef;
+
+        let ints = Arc::new(Int32Array::from(vec![
+            Some(1),
+            Some(2),
+            None,
+            Some(-4),
+            Some(1),
+        ])) as ArrayRef;
+
+        let columns = vec![strings, lists, ints];
+        let fields: Vec<_> = columns
+            .iter()
+            .map(|c| {
+                let options = SortOptions {
+                    descending: true,
+                    nulls_first: false,
+                };
+                SortField::new_with_options(c.data_type().clone(), options)
+            })
+            .collect();
+
+        let sortable = RowConverter::new(fields.clone()).unwrap();
+        let sortable = sortable.convert_columns(&columns).unwrap();
+
+        let converter = RowConverter::new_compact(fields).unwrap();
+        let rows = converter.convert_columns(&columns).unwrap();
+        assert_eq!(rows.num_rows(), 5);
+        assert!(rows.size() < sortable.size());
+
+        assert_eq!(rows.row(0), rows.row(4));
+        for i in 0..4 {
+            for j in (i + 1)..4 {
+                assert_ne!(rows.row(i), rows.row(j));
+            }
+        }
+
+        // The first column is the string "hello" prefixed with its length
+        assert_eq!(
+            &rows.row(0).as_ref()[..7],
+            &[1, 5, b'h', b'e', b'l', b'l', b'o']
+        );
+
+        let back = converter.convert_rows(&rows).unwrap();
+        for (actual, expected) in back.iter().zip(&columns) {
+            actual.to_data().validate_full().unwrap();
+            assert_eq!(actual, expected);
+        }
+
+        // Lengths of 128 or more require multiple bytes
+        let long = "a".repeat(300);
+        let array = Arc::new(StringArray::from(vec![long.as_str()])) as ArrayRef;
+        let converter = RowConverter::new_compact(vec![SortField::new(DataType::Utf8)]).unwrap();
+        let rows = converter.convert_columns(&[Arc::clone(&array)]).unwrap();
+        assert_eq!(&rows.row(0).as_ref()[..3], &[1, 0xAC, 0x02]);
+        assert_eq!(rows.row(0).as_ref().len(), 303);
+
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(&back[0], &array);
+    }
+
     #[test]
     fn test_clear() {
         let converter = RowConverter::new(vec![SortField::new(DataType::Int32)]).unwrap();
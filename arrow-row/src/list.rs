@@ -15,17 +15,19 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::{null_sentinel, RowConverter, Rows, SortField};
-use arrow_array::{Array, GenericListArray, OffsetSizeTrait};
+use crate::{fixed, null_sentinel, RowConverter, Rows, SortField};
+use arrow_array::{new_empty_array, Array, FixedSizeListArray, GenericListArray, OffsetSizeTrait};
 use arrow_buffer::{Buffer, MutableBuffer};
+use arrow_data::transform::MutableArrayData;
 use arrow_data::ArrayDataBuilder;
-use arrow_schema::{ArrowError, SortOptions};
+use arrow_schema::{ArrowError, DataType, SortOptions};
 use std::ops::Range;
 
 pub fn compute_lengths<O: OffsetSizeTrait>(
     lengths: &mut [usize],
     rows: &Rows,
     array: &GenericListArray<O>,
+    compact: bool,
 ) {
     let offsets = array.value_offsets().windows(2);
     lengths
@@ -36,13 +38,22 @@ pub fn compute_lengths<O: OffsetSizeTrait>(
             let start = offsets[0].as_usize();
             let end = offsets[1].as_usize();
             let range = array.is_valid(idx).then_some(start..end);
-            *length += encoded_len(rows, range);
+            *length += encoded_len(rows, range, compact);
         });
 }
 
-fn encoded_len(rows: &Rows, range: Option<Range<usize>>) -> usize {
+fn encoded_len(rows: &Rows, range: Option<Range<usize>>, compact: bool) -> usize {
     match range {
         None => 1,
+        Some(range) if compact => {
+            1 + super::variable::varint_len(range.len())
+                + range
+                    .map(|i| {
+                        let len = rows.row(i).as_ref().len();
+                        super::variable::varint_len(len) + len
+                    })
+                    .sum::<usize>()
+        }
         Some(range) => {
             1 + range
                 .map(|i| super::variable::padded_length(Some(rows.row(i).as_ref().len())))
@@ -54,12 +65,16 @@ fn encoded_len(rows: &Rows, range: Option<Range<usize>>) -> usize {
 /// Encodes the provided `GenericListArray` to `out` with the provided `SortOptions`
 ///
 /// `rows` should contain the encoded child elements
+///
+/// If `compact` is true, a valid list is instead encoded as `1_u8`, followed by the number
+/// of elements as a varint, and then each child element prefixed by its length as a varint
 pub fn encode<O: OffsetSizeTrait>(
     data: &mut [u8],
     offsets: &mut [usize],
     rows: &Rows,
     opts: SortOptions,
     array: &GenericListArray<O>,
+    compact: bool,
 ) {
     offsets
         .iter_mut()
@@ -71,10 +86,36 @@ pub fn encode<O: OffsetSizeTrait>(
             let end = offsets[1].as_usize();
             let range = array.is_valid(idx).then_some(start..end);
             let out = &mut data[*offset..];
-            *offset += encode_one(out, rows, range, opts)
+            *offset += match compact {
+                true => encode_one_compact(out, rows, range, opts),
+                false => encode_one(out, rows, range, opts),
+            }
         });
 }
 
+#[inline]
+fn encode_one_compact(
+    out: &mut [u8],
+    rows: &Rows,
+    range: Option<Range<usize>>,
+    opts: SortOptions,
+) -> usize {
+    match range {
+        None => super::variable::encode_null(out, opts),
+        Some(range) => {
+            out[0] = 1;
+            let mut offset = 1 + super::variable::encode_varint(&mut out[1..], range.len());
+            for i in range {
+                let row = rows.row(i).data;
+                offset += super::variable::encode_varint(&mut out[offset..], row.len());
+                out[offset..offset + row.len()].copy_from_slice(row);
+                offset += row.len();
+            }
+            offset
+        }
+    }
+}
+
 #[inline]
 fn encode_one(
     out: &mut [u8],
@@ -107,7 +148,12 @@ pub unsafe fn decode<O: OffsetSizeTrait>(
     rows: &mut [&[u8]],
     field: &SortField,
     validate_utf8: bool,
+    compact: bool,
 ) -> Result<GenericListArray<O>, ArrowError> {
+    if compact {
+        return decode_compact(converter, rows, field, validate_utf8);
+    }
+
     let opts = field.options;
 
     let mut values_bytes = 0;
@@ -184,3 +230,180 @@ pub unsafe fn decode<O: OffsetSizeTrait>(
 
     Ok(GenericListArray::from(unsafe { builder.build_unchecked() }))
 }
+
+/// Decodes a list array from `rows` encoded with the compact encoding
+///
+/// # Safety
+///
+/// `rows` must contain valid data for the provided `converter`
+unsafe fn decode_compact<O: OffsetSizeTrait>(
+    converter: &RowConverter,
+    rows: &mut [&[u8]],
+    field: &SortField,
+    validate_utf8: bool,
+) -> Result<GenericListArray<O>, ArrowError> {
+    let null_sentinel = null_sentinel(field.options);
+
+    let mut null_count = 0;
+    let nulls = MutableBuffer::collect_bool(rows.len(), |x| {
+        let valid = rows[x][0] != null_sentinel;
+        null_count += !valid as usize;
+        valid
+    });
+
+    let mut offsets = Vec::with_capacity(rows.len() + 1);
+    offsets.push(O::usize_as(0));
+
+    let mut child_rows = Vec::new();
+    for row in rows.iter_mut() {
+        let data = *row;
+        let mut row_offset = 1;
+        if data[0] != null_sentinel {
+            let (count, len) = super::variable::decode_varint(&data[1..]);
+            row_offset += len;
+            for _ in 0..count {
+                let (len, varint_len) = super::variable::decode_varint(&data[row_offset..]);
+                row_offset += varint_len;
+                child_rows.push(&data[row_offset..row_offset + len]);
+                row_offset += len;
+            }
+        }
+        offsets.push(O::from_usize(child_rows.len()).expect("overflow"));
+        *row = &data[row_offset..];
+    }
+
+    let child = converter.convert_raw(&mut child_rows, validate_utf8)?;
+    assert_eq!(child.len(), 1);
+
+    let builder = ArrayDataBuilder::new(field.data_type.clone())
+        .len(rows.len())
+        .null_count(null_count)
+        .null_bit_buffer(Some(nulls.into()))
+        .add_buffer(Buffer::from_vec(offsets))
+        .add_child_data(child[0].to_data());
+
+    Ok(GenericListArray::from(unsafe { builder.build_unchecked() }))
+}
+
+/// Computes the encoded length of each element of a `FixedSizeListArray`
+///
+/// `rows` should contain the encoded child elements, and `null` the encoding
+/// of a single null child element
+pub fn compute_lengths_fixed_size_list(
+    lengths: &mut [usize],
+    rows: &Rows,
+    null: &[u8],
+    array: &FixedSizeListArray,
+) {
+    let size = array.value_length() as usize;
+    lengths.iter_mut().enumerate().for_each(|(idx, length)| {
+        *length += 1;
+        match array.is_valid(idx) {
+            true => {
+                let start = array.value_offset(idx) as usize;
+                *length += (start..start + size)
+                    .map(|i| rows.row(i).as_ref().len())
+                    .sum::<usize>()
+            }
+            false => *length += size * null.len(),
+        }
+    });
+}
+
+/// Encodes the provided `FixedSizeListArray` to `out` with the provided `SortOptions`
+///
+/// As every list contains the same number of elements, there is no need for the
+/// length-prefixed encoding used for variable length lists. Instead a list is
+/// encoded as a null sentinel, followed by the row encoding of each child element
+///
+/// `rows` should contain the encoded child elements, and `null` the encoding
+/// of a single null child element
+pub fn encode_fixed_size_list(
+    data: &mut [u8],
+    offsets: &mut [usize],
+    rows: &Rows,
+    null: &[u8],
+    opts: SortOptions,
+    array: &FixedSizeListArray,
+) {
+    let size = array.value_length() as usize;
+    let null_sentinel = null_sentinel(opts);
+    offsets
+        .iter_mut()
+        .skip(1)
+        .enumerate()
+        .for_each(|(idx, offset)| match array.is_valid(idx) {
+            true => {
+                data[*offset] = 0x01;
+                *offset += 1;
+                let start = array.value_offset(idx) as usize;
+                for i in start..start + size {
+                    let row = rows.row(i);
+                    let end_offset = *offset + row.as_ref().len();
+                    data[*offset..end_offset].copy_from_slice(row.as_ref());
+                    *offset = end_offset;
+                }
+            }
+            false => {
+                data[*offset] = null_sentinel;
+                *offset += 1;
+                for _ in 0..size {
+                    let end_offset = *offset + null.len();
+                    data[*offset..end_offset].copy_from_slice(null);
+                    *offset = end_offset;
+                }
+            }
+        });
+}
+
+/// Decodes a `FixedSizeListArray` from `rows` with the provided `options`
+///
+/// # Safety
+///
+/// `rows` must contain valid data for the provided `converter`
+pub unsafe fn decode_fixed_size_list(
+    converter: &RowConverter,
+    rows: &mut [&[u8]],
+    field: &SortField,
+    validate_utf8: bool,
+) -> Result<FixedSizeListArray, ArrowError> {
+    let (child_field, size) = match &field.data_type {
+        DataType::FixedSizeList(f, size) => (f, *size as usize),
+        _ => unreachable!(),
+    };
+
+    let (null_count, nulls) = fixed::decode_nulls(rows);
+    rows.iter_mut().for_each(|row| *row = &row[1..]);
+
+    // Each call to `convert_raw` decodes the next element of every list
+    let mut elements = Vec::with_capacity(size);
+    for _ in 0..size {
+        let mut decoded = converter.convert_raw(rows, validate_utf8)?;
+        elements.push(decoded.pop().unwrap().to_data());
+    }
+
+    let child_data = match size {
+        0 => new_empty_array(child_field.data_type()).to_data(),
+        _ => {
+            // Interleave the decoded elements to reconstruct the child array
+            let mut mutable =
+                MutableArrayData::new(elements.iter().collect(), false, rows.len() * size);
+            for row in 0..rows.len() {
+                for element in 0..size {
+                    mutable.extend(element, row, row + 1);
+                }
+            }
+            mutable.freeze()
+        }
+    };
+
+    let builder = ArrayDataBuilder::new(field.data_type.clone())
+        .len(rows.len())
+        .null_count(null_count)
+        .null_bit_buffer(Some(nulls))
+        .add_child_data(child_data);
+
+    Ok(FixedSizeListArray::from(unsafe {
+        builder.build_unchecked()
+    }))
+}
@@ -0,0 +1,135 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::{RowConverter, Rows, SortField};
+use arrow_array::types::RunEndIndexType;
+use arrow_array::{Array, PrimitiveArray, RunArray};
+use arrow_buffer::ArrowNativeType;
+use arrow_data::transform::MutableArrayData;
+use arrow_data::ArrayDataBuilder;
+use arrow_schema::ArrowError;
+
+/// Returns an iterator over the physical index of each logical element of `array`
+fn physical_indices<R: RunEndIndexType>(array: &RunArray<R>) -> impl Iterator<Item = usize> + '_ {
+    let run_ends = array.run_ends();
+    let offset = run_ends.offset();
+    let mut physical = run_ends.get_start_physical_index();
+    (offset..offset + run_ends.len()).map(move |logical| {
+        while run_ends.values()[physical].as_usize() <= logical {
+            physical += 1;
+        }
+        physical
+    })
+}
+
+/// Computes the encoded length of each element of a `RunArray`
+///
+/// `rows` should contain the encoded values of the array
+pub fn compute_lengths<R: RunEndIndexType>(
+    lengths: &mut [usize],
+    rows: &Rows,
+    array: &RunArray<R>,
+) {
+    lengths
+        .iter_mut()
+        .zip(physical_indices(array))
+        .for_each(|(length, physical)| *length += rows.row(physical).as_ref().len());
+}
+
+/// Encodes the provided `RunArray` to `out`
+///
+/// Each logical element is encoded as the row encoding of its value, and so
+/// a `RunArray` is encoded identically to its logical values
+///
+/// `rows` should contain the encoded values of the array
+pub fn encode<R: RunEndIndexType>(
+    data: &mut [u8],
+    offsets: &mut [usize],
+    rows: &Rows,
+    array: &RunArray<R>,
+) {
+    offsets
+        .iter_mut()
+        .skip(1)
+        .zip(physical_indices(array))
+        .for_each(|(offset, physical)| {
+            let row = rows.row(physical);
+            let end_offset = *offset + row.as_ref().len();
+            data[*offset..end_offset].copy_from_slice(row.as_ref());
+            *offset = end_offset;
+        });
+}
+
+/// Decodes a `RunArray` from `rows` with the provided `options`
+///
+/// Consecutive rows with identical encodings are coalesced into a single run
+///
+/// # Safety
+///
+/// `rows` must contain valid data for the provided `converter`
+pub unsafe fn decode<R: RunEndIndexType>(
+    converter: &RowConverter,
+    rows: &mut [&[u8]],
+    field: &SortField,
+    validate_utf8: bool,
+) -> Result<RunArray<R>, ArrowError> {
+    let encoded: Vec<&[u8]> = rows.to_vec();
+    let values = converter.convert_raw(rows, validate_utf8)?.pop().unwrap();
+
+    // Determine the bytes consumed by each row
+    let encoded: Vec<&[u8]> = encoded
+        .iter()
+        .zip(rows.iter())
+        .map(|(encoded, remaining)| &encoded[..encoded.len() - remaining.len()])
+        .collect();
+
+    let values_data = values.to_data();
+    let mut run_values = MutableArrayData::new(vec![&values_data], false, 0);
+    let mut run_ends = Vec::new();
+    for (idx, row) in encoded.iter().enumerate() {
+        if idx == 0 || encoded[idx - 1] != *row {
+            if idx != 0 {
+                run_ends.push(idx);
+            }
+            run_values.extend(0, idx, idx + 1);
+        }
+    }
+    if !encoded.is_empty() {
+        run_ends.push(encoded.len());
+    }
+
+    let run_ends = run_ends
+        .into_iter()
+        .map(|end| {
+            R::Native::from_usize(end).ok_or_else(|| {
+                ArrowError::ComputeError(format!(
+                    "Cannot decode {} rows to RunArray with run ends of type {}",
+                    encoded.len(),
+                    R::DATA_TYPE
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let run_ends = PrimitiveArray::<R>::from_iter_values(run_ends);
+
+    let builder = ArrayDataBuilder::new(field.data_type.clone())
+        .len(encoded.len())
+        .add_child_data(run_ends.into_data())
+        .add_child_data(run_values.freeze());
+
+    Ok(RunArray::from(unsafe { builder.build_unchecked() }))
+}
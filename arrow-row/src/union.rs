@@ -0,0 +1,155 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::{RowConverter, Rows, SortField};
+use arrow_array::{ArrayRef, UnionArray};
+use arrow_buffer::ScalarBuffer;
+use arrow_schema::{ArrowError, DataType, SortOptions, UnionMode};
+
+/// Encodes the type id of a union element, inverting it if `descending`
+#[inline]
+fn encode_type_id(type_id: i8, opts: SortOptions) -> u8 {
+    match opts.descending {
+        true => !(type_id as u8),
+        false => type_id as u8,
+    }
+}
+
+/// Computes the encoded length of each element of a `UnionArray`
+///
+/// `rows` should contain the encoded child arrays, indexed by type id
+pub fn compute_lengths(lengths: &mut [usize], rows: &[Option<Rows>], array: &UnionArray) {
+    lengths
+        .iter_mut()
+        .zip(array.type_ids())
+        .enumerate()
+        .for_each(|(idx, (length, type_id))| {
+            let child = rows[*type_id as usize].as_ref().unwrap();
+            *length += 1 + child.row(array.value_offset(idx)).as_ref().len();
+        });
+}
+
+/// Encodes the provided `UnionArray` to `out` with the provided `SortOptions`
+///
+/// Each element is encoded as its type id, followed by the row encoding of the
+/// corresponding child element. Elements are therefore ordered first by type id,
+/// and then by value
+///
+/// `rows` should contain the encoded child arrays, indexed by type id
+pub fn encode(
+    data: &mut [u8],
+    offsets: &mut [usize],
+    rows: &[Option<Rows>],
+    opts: SortOptions,
+    array: &UnionArray,
+) {
+    offsets
+        .iter_mut()
+        .skip(1)
+        .zip(array.type_ids())
+        .enumerate()
+        .for_each(|(idx, (offset, type_id))| {
+            let child = rows[*type_id as usize].as_ref().unwrap();
+            let row = child.row(array.value_offset(idx));
+            data[*offset] = encode_type_id(*type_id, opts);
+            let end_offset = *offset + 1 + row.as_ref().len();
+            data[*offset + 1..end_offset].copy_from_slice(row.as_ref());
+            *offset = end_offset;
+        });
+}
+
+/// Decodes a `UnionArray` from `rows` with the provided `options`
+///
+/// `converters` and `nulls` contain the [`RowConverter`] and the encoding of
+/// a null row for each child, in the order of the union's fields
+///
+/// # Safety
+///
+/// `rows` must contain valid data for the provided `converters`
+pub unsafe fn decode(
+    converters: &[RowConverter],
+    nulls: &[&[u8]],
+    rows: &mut [&[u8]],
+    field: &SortField,
+    validate_utf8: bool,
+) -> Result<UnionArray, ArrowError> {
+    let (fields, mode) = match &field.data_type {
+        DataType::Union(fields, mode) => (fields, *mode),
+        _ => unreachable!(),
+    };
+
+    let type_ids: ScalarBuffer<i8> = rows
+        .iter_mut()
+        .map(|row| {
+            let type_id = encode_type_id(row[0] as i8, field.options) as i8;
+            *row = &row[1..];
+            type_id
+        })
+        .collect();
+
+    let mut offsets = match mode {
+        UnionMode::Dense => Some(vec![0_i32; rows.len()]),
+        UnionMode::Sparse => None,
+    };
+
+    let children = fields
+        .iter()
+        .zip(converters.iter().zip(nulls))
+        .map(|((type_id, _), (converter, null))| {
+            let selected: Vec<usize> = type_ids
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, t)| (*t == type_id).then_some(idx))
+                .collect();
+
+            // A sparse child must contain a slot for every element, and so
+            // the elements of other children are decoded from a null row
+            let mut child_rows: Vec<&[u8]> = match &mut offsets {
+                Some(offsets) => selected
+                    .iter()
+                    .enumerate()
+                    .map(|(child_idx, idx)| {
+                        offsets[*idx] = child_idx as i32;
+                        rows[*idx]
+                    })
+                    .collect(),
+                None => type_ids
+                    .iter()
+                    .zip(rows.iter())
+                    .map(|(t, row)| if *t == type_id { *row } else { *null })
+                    .collect(),
+            };
+
+            let child = converter.convert_raw(&mut child_rows, validate_utf8)?;
+
+            // Advance the rows past the decoded child elements
+            selected.iter().enumerate().for_each(|(child_idx, idx)| {
+                let remaining = match mode {
+                    UnionMode::Dense => child_rows[child_idx].len(),
+                    UnionMode::Sparse => child_rows[*idx].len(),
+                };
+                let row = rows[*idx];
+                rows[*idx] = &row[row.len() - remaining..];
+            });
+
+            Ok(child.into_iter().next().unwrap())
+        })
+        .collect::<Result<Vec<ArrayRef>, ArrowError>>()?;
+
+    let offsets = offsets.map(ScalarBuffer::from);
+    UnionArray::try_new(fields.clone(), type_ids, offsets, children)
+}
@@ -46,8 +46,57 @@ pub const NON_EMPTY_SENTINEL: u8 = 2;
 
 /// Returns the length of the encoded representation of a byte array, including the null byte
 #[inline]
-pub fn encoded_len(a: Option<&[u8]>) -> usize {
-    padded_length(a.map(|x| x.len()))
+pub fn encoded_len(a: Option<&[u8]>, compact: bool) -> usize {
+    match compact {
+        true => compact_length(a.map(|x| x.len())),
+        false => padded_length(a.map(|x| x.len())),
+    }
+}
+
+/// Returns the length of the compact encoding of the given length, including the null byte
+#[inline]
+pub fn compact_length(a: Option<usize>) -> usize {
+    match a {
+        Some(a) => 1 + varint_len(a) + a,
+        None => 1,
+    }
+}
+
+/// Returns the number of bytes needed to encode `v` as an unsigned LEB128 varint
+#[inline]
+pub fn varint_len(mut v: usize) -> usize {
+    let mut len = 1;
+    while v >= 0x80 {
+        v >>= 7;
+        len += 1;
+    }
+    len
+}
+
+/// Writes `v` to `out` as an unsigned LEB128 varint, returning the number of bytes written
+#[inline]
+pub fn encode_varint(out: &mut [u8], mut v: usize) -> usize {
+    let mut idx = 0;
+    while v >= 0x80 {
+        out[idx] = (v as u8) | 0x80;
+        v >>= 7;
+        idx += 1;
+    }
+    out[idx] = v as u8;
+    idx + 1
+}
+
+/// Reads an unsigned LEB128 varint from `row`, returning the value and the number of bytes read
+#[inline]
+pub fn decode_varint(row: &[u8]) -> (usize, usize) {
+    let mut v = 0;
+    for (idx, b) in row.iter().enumerate() {
+        v |= ((b & 0x7F) as usize) << (7 * idx);
+        if b & 0x80 == 0 {
+            return (v, idx + 1);
+        }
+    }
+    unreachable!("truncated varint")
 }
 
 /// Returns the padded length of the encoded length of the given length
@@ -73,14 +122,37 @@ pub fn padded_length(a: Option<usize>) -> usize {
 /// - [`BLOCK_SIZE`] bytes of string data, padded with 0s
 /// - `0xFF_u8` if this is not the last block for this string
 /// - otherwise the length of the block as a `u8`
+///
+/// If `compact` is true, values are instead encoded as
+///
+/// - single `0_u8` if null
+/// - `1_u8` if not null, followed by the length as a varint and then the value
 pub fn encode<'a, I: Iterator<Item = Option<&'a [u8]>>>(
     data: &mut [u8],
     offsets: &mut [usize],
     i: I,
     opts: SortOptions,
+    compact: bool,
 ) {
     for (offset, maybe_val) in offsets.iter_mut().skip(1).zip(i) {
-        *offset += encode_one(&mut data[*offset..], maybe_val, opts);
+        let out = &mut data[*offset..];
+        *offset += match compact {
+            true => encode_one_compact(out, maybe_val, opts),
+            false => encode_one(out, maybe_val, opts),
+        };
+    }
+}
+
+fn encode_one_compact(out: &mut [u8], val: Option<&[u8]>, opts: SortOptions) -> usize {
+    match val {
+        None => encode_null(out, opts),
+        Some(val) => {
+            out[0] = 1;
+            let start = 1 + encode_varint(&mut out[1..], val.len());
+            let end = start + val.len();
+            out[start..end].copy_from_slice(val);
+            end
+        }
     }
 }
 
@@ -194,10 +266,31 @@ pub fn decode_blocks(row: &[u8], options: SortOptions, mut f: impl FnMut(&[u8]))
     }
 }
 
+/// Decodes a single compact encoded value
+/// The `f` function accepts a slice of the decoded data, it is not called for nulls
+fn decode_compact(row: &[u8], options: SortOptions, mut f: impl FnMut(&[u8])) -> usize {
+    if row[0] == null_sentinel(options) {
+        return 1;
+    }
+    let (len, varint_len) = decode_varint(&row[1..]);
+    let start = 1 + varint_len;
+    f(&row[start..start + len]);
+    start + len
+}
+
+/// Decodes a single value, returning the number of bytes consumed
+#[inline]
+fn decode_value(row: &[u8], options: SortOptions, compact: bool, f: impl FnMut(&[u8])) -> usize {
+    match compact {
+        true => decode_compact(row, options, f),
+        false => decode_blocks(row, options, f),
+    }
+}
+
 /// Returns the number of bytes of encoded data
-fn decoded_len(row: &[u8], options: SortOptions) -> usize {
+fn decoded_len(row: &[u8], options: SortOptions, compact: bool) -> usize {
     let mut len = 0;
-    decode_blocks(row, options, |block| len += block.len());
+    decode_value(row, options, compact, |block| len += block.len());
     len
 }
 
@@ -205,6 +298,7 @@ fn decoded_len(row: &[u8], options: SortOptions) -> usize {
 pub fn decode_binary<I: OffsetSizeTrait>(
     rows: &mut [&[u8]],
     options: SortOptions,
+    compact: bool,
 ) -> GenericBinaryArray<I> {
     let len = rows.len();
     let mut null_count = 0;
@@ -214,13 +308,16 @@ pub fn decode_binary<I: OffsetSizeTrait>(
         valid
     });
 
-    let values_capacity = rows.iter().map(|row| decoded_len(row, options)).sum();
+    let values_capacity = rows
+        .iter()
+        .map(|row| decoded_len(row, options, compact))
+        .sum();
     let mut offsets = BufferBuilder::<I>::new(len + 1);
     offsets.append(I::zero());
     let mut values = MutableBuffer::new(values_capacity);
 
     for row in rows {
-        let offset = decode_blocks(row, options, |b| values.extend_from_slice(b));
+        let offset = decode_value(row, options, compact, |b| values.extend_from_slice(b));
         *row = &row[offset..];
         offsets.append(I::from_usize(values.len()).expect("offset overflow"))
     }
@@ -249,6 +346,7 @@ pub fn decode_binary<I: OffsetSizeTrait>(
 fn decode_binary_view_inner(
     rows: &mut [&[u8]],
     options: SortOptions,
+    compact: bool,
     check_utf8: bool,
 ) -> BinaryViewArray {
     let len = rows.len();
@@ -261,13 +359,16 @@ fn decode_binary_view_inner(
         valid
     });
 
-    let values_capacity: usize = rows.iter().map(|row| decoded_len(row, options)).sum();
+    let values_capacity: usize = rows
+        .iter()
+        .map(|row| decoded_len(row, options, compact))
+        .sum();
     let mut values = MutableBuffer::new(values_capacity);
     let mut views = BufferBuilder::<u128>::new(len);
 
     for row in rows {
         let start_offset = values.len();
-        let offset = decode_blocks(row, options, |b| values.extend_from_slice(b));
+        let offset = decode_value(row, options, compact, |b| values.extend_from_slice(b));
         if row[0] == null_sentinel(options) {
             debug_assert_eq!(offset, 1);
             debug_assert_eq!(start_offset, values.len());
@@ -305,8 +406,12 @@ fn decode_binary_view_inner(
 }
 
 /// Decodes a binary view array from `rows` with the provided `options`
-pub fn decode_binary_view(rows: &mut [&[u8]], options: SortOptions) -> BinaryViewArray {
-    decode_binary_view_inner(rows, options, false)
+pub fn decode_binary_view(
+    rows: &mut [&[u8]],
+    options: SortOptions,
+    compact: bool,
+) -> BinaryViewArray {
+    decode_binary_view_inner(rows, options, compact, false)
 }
 
 /// Decodes a string array from `rows` with the provided `options`
@@ -317,9 +422,10 @@ pub fn decode_binary_view(rows: &mut [&[u8]], options: SortOptions) -> BinaryVie
 pub unsafe fn decode_string<I: OffsetSizeTrait>(
     rows: &mut [&[u8]],
     options: SortOptions,
+    compact: bool,
     validate_utf8: bool,
 ) -> GenericStringArray<I> {
-    let decoded = decode_binary::<I>(rows, options);
+    let decoded = decode_binary::<I>(rows, options, compact);
 
     if validate_utf8 {
         return GenericStringArray::from(decoded);
@@ -343,8 +449,9 @@ pub unsafe fn decode_string<I: OffsetSizeTrait>(
 pub unsafe fn decode_string_view(
     rows: &mut [&[u8]],
     options: SortOptions,
+    compact: bool,
     validate_utf8: bool,
 ) -> StringViewArray {
-    let view = decode_binary_view_inner(rows, options, validate_utf8);
+    let view = decode_binary_view_inner(rows, options, compact, validate_utf8);
     view.to_string_view_unchecked()
 }
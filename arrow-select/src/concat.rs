@@ -438,6 +438,29 @@ mod tests {
         assert_eq!(array_result.as_ref(), &array_expected as &dyn Array);
     }
 
+    #[test]
+    fn test_concat_run_arrays() {
+        let a = RunArray::<Int16Type>::try_new(
+            &Int16Array::from(vec![2, 3]),
+            &StringArray::from(vec!["a", "b"]),
+        )
+        .unwrap();
+        let b = RunArray::<Int16Type>::try_new(
+            &Int16Array::from(vec![1, 4]),
+            &StringArray::from(vec![Some("b"), None]),
+        )
+        .unwrap();
+
+        let arr = concat(&[&a, &b]).unwrap();
+        let arr = arr.as_any().downcast_ref::<RunArray<Int16Type>>().unwrap();
+        assert_eq!(arr.run_ends().values(), &[2, 3, 4, 7]);
+        let values = arr.values().as_string::<i32>();
+        assert_eq!(
+            values.iter().collect::<Vec<_>>(),
+            vec![Some("a"), Some("b"), Some("b"), None]
+        );
+    }
+
     #[test]
     fn test_concat_struct_arrays() {
         let field = Arc::new(Field::new("field", DataType::Int64, true));
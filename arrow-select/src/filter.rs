@@ -17,7 +17,6 @@
 
 //! Defines filter kernels
 
-use std::ops::AddAssign;
 use std::sync::Arc;
 
 use arrow_array::builder::BooleanBufferBuilder;
@@ -348,12 +347,12 @@ fn filter_array(values: &dyn Array, predicate: &FilterPredicate) -> Result<Array
             DataType::RunEndEncoded(_, _) => {
                 downcast_run_array!{
                     values => Ok(Arc::new(filter_run_end_array(values, predicate)?)),
-                    t => unimplemented!("Filter not supported for RunEndEncoded type {:?}", t)
+                    t => Err(ArrowError::InvalidArgumentError(format!("Filter not supported for RunEndEncoded type {t:?}")))
                 }
             }
             DataType::Dictionary(_, _) => downcast_dictionary_array! {
                 values => Ok(Arc::new(filter_dict(values, predicate))),
-                t => unimplemented!("Filter not supported for dictionary type {:?}", t)
+                t => Err(ArrowError::InvalidArgumentError(format!("Filter not supported for dictionary type {t:?}")))
             }
             _ => {
                 let data = values.to_data();
@@ -387,44 +386,36 @@ fn filter_array(values: &dyn Array, predicate: &FilterPredicate) -> Result<Array
 fn filter_run_end_array<R: RunEndIndexType>(
     re_arr: &RunArray<R>,
     pred: &FilterPredicate,
-) -> Result<RunArray<R>, ArrowError>
-where
-    R::Native: Into<i64> + From<bool>,
-    R::Native: AddAssign,
-{
+) -> Result<RunArray<R>, ArrowError> {
     let run_ends: &RunEndBuffer<R::Native> = re_arr.run_ends();
-    let mut values_filter = BooleanBufferBuilder::new(run_ends.len());
-    let mut new_run_ends = vec![R::default_value(); run_ends.len()];
+    let offset = run_ends.offset();
+    let start_physical = run_ends.get_start_physical_index();
+    let end_physical = run_ends.get_end_physical_index();
+    let physical_len = match run_ends.is_empty() {
+        true => 0,
+        false => end_physical + 1 - start_physical,
+    };
 
-    let mut start = 0i64;
-    let mut i = 0;
+    let mut values_filter = BooleanBufferBuilder::new(physical_len);
+    let mut new_run_ends = Vec::with_capacity(physical_len);
+
+    // the predicate may be shorter than the array, in which case any
+    // remaining runs are not selected
     let filter_values = pred.filter.values();
-    let mut count = R::default_value();
-
-    for end in run_ends.inner().into_iter().map(|i| (*i).into()) {
-        let mut keep = false;
-        // in filter_array the predicate array is checked to have the same len as the run end array
-        // this means the largest value in the run_ends is == to pred.len()
-        // so we're always within bounds when calling value_unchecked
-        for pred in (start..end).map(|i| unsafe { filter_values.value_unchecked(i as usize) }) {
-            count += R::Native::from(pred);
-            keep |= pred
+    let mut start = 0;
+    let mut count = 0;
+    for end in &run_ends.values()[start_physical..start_physical + physical_len] {
+        let end = (end.as_usize() - offset).min(filter_values.len());
+        let selected = filter_values.slice(start, end - start).count_set_bits();
+        if selected != 0 {
+            count += selected;
+            new_run_ends.push(R::Native::usize_as(count));
         }
-        // this is to avoid branching
-        new_run_ends[i] = count;
-        i += keep as usize;
-
-        values_filter.append(keep);
+        values_filter.append(selected != 0);
         start = end;
     }
 
-    new_run_ends.truncate(i);
-
-    if values_filter.is_empty() {
-        new_run_ends.clear();
-    }
-
-    let values = re_arr.values();
+    let values = re_arr.values().slice(start_physical, physical_len);
     let pred = BooleanArray::new(values_filter.finish(), None);
     let values = filter(&values, &pred)?;
 
@@ -1072,6 +1063,32 @@ mod tests {
         assert_eq!(0, actual.len());
     }
 
+    #[test]
+    fn test_filter_run_end_encoding_array_sliced() {
+        let run_ends = Int16Array::from(vec![2, 3, 8, 10]);
+        let values = StringArray::from(vec![Some("a"), None, Some("b"), Some("c")]);
+        let a = RunArray::try_new(&run_ends, &values).unwrap();
+        // [a, N, b, b, b, b, b, c]
+        let a = a.slice(1, 8);
+
+        let b = BooleanArray::from(vec![true, true, false, true, false, false, false, true]);
+        let c = filter(&a, &b).unwrap();
+        let actual: &RunArray<Int16Type> = as_run_array(&c);
+        assert_eq!(4, actual.len());
+        assert_eq!(actual.run_ends().values(), &[1, 2, 3, 4]);
+        let values: Vec<_> = actual.values().as_string::<i32>().iter().collect();
+        assert_eq!(values, [Some("a"), None, Some("b"), Some("c")]);
+
+        // predicate shorter than the array
+        let b = BooleanArray::from(vec![false, false, true, false, true]);
+        let c = filter(&a, &b).unwrap();
+        let actual: &RunArray<Int16Type> = as_run_array(&c);
+        assert_eq!(2, actual.len());
+        assert_eq!(actual.run_ends().values(), &[2]);
+        let values: Vec<_> = actual.values().as_string::<i32>().iter().collect();
+        assert_eq!(values, [Some("b")]);
+    }
+
     #[test]
     fn test_filter_dictionary_array() {
         let values = [Some("hello"), None, Some("world"), Some("!")];
@@ -195,13 +195,19 @@ fn take_impl<IndexType: ArrowPrimitiveType>(
 
             Ok(Arc::new(StructArray::from((fields, is_valid))) as ArrayRef)
         }
+        DataType::ListView(_) => {
+            Ok(Arc::new(take_list_view(values.as_list_view::<i32>(), indices)?))
+        }
+        DataType::LargeListView(_) => {
+            Ok(Arc::new(take_list_view(values.as_list_view::<i64>(), indices)?))
+        }
         DataType::Dictionary(_, _) => downcast_dictionary_array! {
             values => Ok(Arc::new(take_dict(values, indices)?)),
-            t => unimplemented!("Take not supported for dictionary type {:?}", t)
+            t => Err(ArrowError::InvalidArgumentError(format!("Take not supported for dictionary type {t:?}")))
         }
         DataType::RunEndEncoded(_, _) => downcast_run_array! {
             values => Ok(Arc::new(take_run(values, indices)?)),
-            t => unimplemented!("Take not supported for run type {:?}", t)
+            t => Err(ArrowError::InvalidArgumentError(format!("Take not supported for run type {t:?}")))
         }
         DataType::Binary => {
             Ok(Arc::new(take_bytes(values.as_binary::<i32>(), indices)?))
@@ -280,7 +286,7 @@ fn take_impl<IndexType: ArrowPrimitiveType>(
 
             Ok(Arc::new(array))
         }
-        t => unimplemented!("Take not supported for data type {:?}", t)
+        t => Err(ArrowError::NotYetImplemented(format!("Take not supported for data type {t:?}")))
     }
 }
 
@@ -528,6 +534,30 @@ where
     Ok(GenericListArray::<OffsetType::Native>::from(list_data))
 }
 
+/// `take` implementation for list view arrays
+///
+/// As list views may reference any range of their values, this only takes the
+/// offsets and sizes, sharing the values of `values`
+fn take_list_view<O: OffsetSizeTrait, IndexType: ArrowPrimitiveType>(
+    values: &GenericListViewArray<O>,
+    indices: &PrimitiveArray<IndexType>,
+) -> Result<GenericListViewArray<O>, ArrowError> {
+    let offsets = take_native(values.value_offsets(), indices);
+    let sizes = take_native(values.value_sizes(), indices);
+    let nulls = take_nulls(values.nulls(), indices);
+
+    let list_data = ArrayDataBuilder::new(values.data_type().clone())
+        .len(indices.len())
+        .nulls(nulls)
+        .add_buffer(offsets.into_inner())
+        .add_buffer(sizes.into_inner())
+        .add_child_data(values.values().to_data());
+
+    // Safety: the offsets and sizes are taken from a valid list view array
+    let list_data = unsafe { list_data.build_unchecked() };
+    Ok(GenericListViewArray::from(list_data))
+}
+
 /// `take` implementation for `FixedSizeListArray`
 ///
 /// Calculates the index and indexed offset for the inner array,
@@ -610,62 +640,58 @@ fn take_dict<T: ArrowDictionaryKeyType, I: ArrowPrimitiveType>(
 /// For e.g. an input `RunArray{ run_ends = [2,4,6,8], values=[1,2,1,2] }` and `logical_indices=[2,3,6,7]`
 /// would be converted to `physical_indices=[1,1,3,3]` which will be used to build
 /// output `RunArray{ run_ends=[2,4], values=[2,2] }`.
+///
+/// Consecutive null indices are encoded as a single run with a null value.
 fn take_run<T: RunEndIndexType, I: ArrowPrimitiveType>(
     run_array: &RunArray<T>,
     logical_indices: &PrimitiveArray<I>,
 ) -> Result<RunArray<T>, ArrowError> {
-    // get physical indices for the input logical indices
-    let physical_indices = run_array.get_physical_indices(logical_indices.values())?;
+    // get physical indices for the input logical indices, `None` for null indices
+    let physical_indices: Vec<Option<usize>> =
+        match logical_indices.nulls().filter(|n| n.null_count() > 0) {
+            Some(nulls) => {
+                let values = logical_indices.values();
+                let valid: Vec<_> = nulls.valid_indices().map(|i| values[i]).collect();
+                let mut physical = run_array.get_physical_indices(&valid)?.into_iter();
+                nulls
+                    .iter()
+                    .map(|is_valid| is_valid.then(|| physical.next().unwrap()))
+                    .collect()
+            }
+            None => run_array
+                .get_physical_indices(logical_indices.values())?
+                .into_iter()
+                .map(Some)
+                .collect(),
+        };
 
-    // Run encode the physical indices into new_run_ends_builder
+    // Run encode the physical indices into new_run_ends
     // Keep track of the physical indices to take in take_value_indices
-    // `unwrap` is used in this function because the unwrapped values are bounded by the corresponding `::Native`.
-    let mut new_run_ends_builder = BufferBuilder::<T::Native>::new(1);
-    let mut take_value_indices = BufferBuilder::<I::Native>::new(1);
-    let mut new_physical_len = 1;
+    let run_end = |end: usize| {
+        T::Native::from_usize(end).ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Cannot take {end} values from run array with run ends of type {}",
+                T::DATA_TYPE
+            ))
+        })
+    };
+    let mut new_run_ends = BufferBuilder::<T::Native>::new(1);
+    let mut take_value_indices = Vec::with_capacity(1);
     for ix in 1..physical_indices.len() {
         if physical_indices[ix] != physical_indices[ix - 1] {
-            take_value_indices.append(I::Native::from_usize(physical_indices[ix - 1]).unwrap());
-            new_run_ends_builder.append(T::Native::from_usize(ix).unwrap());
-            new_physical_len += 1;
+            take_value_indices.push(physical_indices[ix - 1].map(|x| x as u64));
+            new_run_ends.append(run_end(ix)?);
         }
     }
-    take_value_indices
-        .append(I::Native::from_usize(physical_indices[physical_indices.len() - 1]).unwrap());
-    new_run_ends_builder.append(T::Native::from_usize(physical_indices.len()).unwrap());
-    let new_run_ends = unsafe {
-        // Safety:
-        // The function builds a valid run_ends array and hence need not be validated.
-        ArrayDataBuilder::new(T::DATA_TYPE)
-            .len(new_physical_len)
-            .null_count(0)
-            .add_buffer(new_run_ends_builder.finish())
-            .build_unchecked()
-    };
-
-    let take_value_indices: PrimitiveArray<I> = unsafe {
-        // Safety:
-        // The function builds a valid take_value_indices array and hence need not be validated.
-        ArrayDataBuilder::new(I::DATA_TYPE)
-            .len(new_physical_len)
-            .null_count(0)
-            .add_buffer(take_value_indices.finish())
-            .build_unchecked()
-            .into()
-    };
+    if let Some(last) = physical_indices.last() {
+        take_value_indices.push(last.map(|x| x as u64));
+        new_run_ends.append(run_end(physical_indices.len())?);
+    }
 
+    let new_run_ends = PrimitiveArray::<T>::new(new_run_ends.finish().into(), None);
+    let take_value_indices = UInt64Array::from(take_value_indices);
     let new_values = take(run_array.values(), &take_value_indices, None)?;
-
-    let builder = ArrayDataBuilder::new(run_array.data_type().clone())
-        .len(physical_indices.len())
-        .add_child_data(new_run_ends)
-        .add_child_data(new_values.into_data());
-    let array_data = unsafe {
-        // Safety:
-        //  This function builds a valid run array and hence can skip validation.
-        builder.build_unchecked()
-    };
-    Ok(array_data.into())
+    RunArray::try_new(&new_run_ends, &new_values)
 }
 
 /// Takes/filters a list array's inner data using the offsets of the list array.
@@ -2113,6 +2139,85 @@ mod tests {
         assert_eq!(take_out_values.values(), &[2, 2, 2, 2, 1]);
     }
 
+    #[test]
+    fn test_take_runs_null_indices() {
+        let run_ends = Int16Array::from(vec![2, 5, 6]);
+        let values = StringArray::from(vec![Some("a"), None, Some("b")]);
+        let run_array = RunArray::try_new(&run_ends, &values).unwrap();
+        let run_array = run_array.slice(1, 5);
+
+        let indices = UInt64Array::from(vec![None, None, Some(0), Some(4), Some(1), None, Some(3)]);
+        let taken = take(&run_array, &indices, None).unwrap();
+        let taken = taken
+            .as_any()
+            .downcast_ref::<RunArray<Int16Type>>()
+            .unwrap();
+
+        assert_eq!(taken.len(), 7);
+        assert_eq!(taken.run_ends().values(), &[2, 3, 4, 5, 6, 7]);
+        let values = taken.values().as_string::<i32>();
+        let values: Vec<_> = values.iter().collect();
+        assert_eq!(values, [None, Some("a"), Some("b"), None, None, None]);
+
+        // empty indices
+        let indices = UInt64Array::from(Vec::<u64>::new());
+        let taken = take(&run_array, &indices, None).unwrap();
+        assert_eq!(taken.len(), 0);
+        assert_eq!(taken.data_type(), run_array.data_type());
+
+        // out of bounds
+        let indices = UInt64Array::from(vec![5]);
+        take(&run_array, &indices, None).unwrap_err();
+    }
+
+    #[test]
+    fn test_take_runs_all_run_end_types() {
+        fn test<R: RunEndIndexType>() {
+            let mut builder = PrimitiveRunBuilder::<R, Int32Type>::new();
+            builder.extend([Some(1), Some(1), None, Some(2), Some(2)]);
+            let run_array = builder.finish();
+
+            let indices = Int8Array::from(vec![4, 3, 2, 0]);
+            let taken = take(&run_array, &indices, None).unwrap();
+            let taken = taken.as_any().downcast_ref::<RunArray<R>>().unwrap();
+            let run_ends: Vec<_> = taken
+                .run_ends()
+                .values()
+                .iter()
+                .map(|x| x.as_usize())
+                .collect();
+            assert_eq!(run_ends, [2, 3, 4]);
+            let values = taken.values().as_primitive::<Int32Type>();
+            assert_eq!(values, &Int32Array::from(vec![Some(2), None, Some(1)]));
+        }
+        test::<Int16Type>();
+        test::<Int32Type>();
+        test::<Int64Type>();
+    }
+
+    #[test]
+    fn test_take_list_view() {
+        // [[1, 2], null, [2, 3], []]
+        let list_view = ListViewArray::new(
+            Arc::new(Field::new("item", DataType::Int32, true)),
+            ScalarBuffer::from(vec![0, 3, 1, 3]),
+            ScalarBuffer::from(vec![2, 0, 2, 0]),
+            Arc::new(Int32Array::from(vec![1, 2, 3])),
+            Some(NullBuffer::from(vec![true, false, true, true])),
+        );
+        let indices = UInt32Array::from(vec![Some(2), None, Some(1), Some(0), Some(2)]);
+        let taken = take(&list_view, &indices, None).unwrap();
+        let taken = taken.as_list_view::<i32>();
+
+        let mut builder = ListViewBuilder::new(Int32Builder::new());
+        builder.append_value([Some(2), Some(3)]);
+        builder.append_null();
+        builder.append_null();
+        builder.append_value([Some(1), Some(2)]);
+        builder.append_value([Some(2), Some(3)]);
+        assert_eq!(taken, &builder.finish());
+    }
+
     #[test]
     fn test_take_value_index_from_fixed_list() {
         let list = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
@@ -303,6 +303,53 @@ impl<'a, T: ArrowPrimitiveType> Tensor<'a, T> {
     }
 }
 
+#[cfg(feature = "ipc")]
+impl<'a, T: ArrowPrimitiveType> Tensor<'a, T> {
+    /// Converts this `Tensor` to a [`TensorData`](crate::ipc::tensor::TensorData), which
+    /// can be encoded as an IPC Tensor message
+    pub fn to_ipc(&self) -> Result<crate::ipc::tensor::TensorData> {
+        crate::ipc::tensor::TensorData::try_new(
+            self.data_type.clone(),
+            self.buffer.clone(),
+            self.shape.clone().unwrap_or_default(),
+            self.strides.clone(),
+            self.names
+                .as_ref()
+                .map(|names| names.iter().map(|n| n.to_string()).collect()),
+        )
+    }
+
+    /// Creates a `Tensor` from a [`TensorData`](crate::ipc::tensor::TensorData), such
+    /// as one read from an IPC Tensor message
+    ///
+    /// A tensor with no dimensions is returned as a `Tensor` with no shape
+    pub fn try_from_ipc(tensor: &'a crate::ipc::tensor::TensorData) -> Result<Self> {
+        if tensor.data_type() != &T::DATA_TYPE {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Expected tensor of {}, got {}",
+                T::DATA_TYPE,
+                tensor.data_type()
+            )));
+        }
+        let shape = tensor.shape();
+        let len = shape.iter().product::<usize>() * mem::size_of::<T::Native>();
+        let buffer = tensor.data().slice_with_length(0, len);
+        let names = tensor
+            .names()
+            .map(|names| names.iter().map(|n| n.as_str()).collect());
+
+        match shape.is_empty() {
+            true => Self::try_new(buffer, None, None, names),
+            false => Self::try_new(
+                buffer,
+                Some(shape.to_vec()),
+                tensor.strides().map(|s| s.to_vec()),
+                names,
+            ),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -499,4 +546,42 @@ mod tests {
             panic!("the input stride does not match the selected shape")
         }
     }
+
+    #[test]
+    #[cfg(feature = "ipc")]
+    fn test_ipc_roundtrip() {
+        use crate::ipc::tensor::read_tensor;
+        use crate::ipc::writer::{IpcDataGenerator, IpcWriteOptions};
+
+        let mut builder = Float64BufferBuilder::new(6);
+        for i in 0..6 {
+            builder.append(i as f64);
+        }
+        let buf = builder.finish();
+
+        let tensors = [
+            Float64Tensor::new_row_major(buf.clone(), Some(vec![2, 3]), Some(vec!["x", "y"])),
+            Float64Tensor::new_column_major(buf.clone(), Some(vec![3, 2]), None),
+            Float64Tensor::try_new(buf.slice_with_length(8, 8), None, None, None),
+        ];
+
+        let gen = IpcDataGenerator::default();
+        let options = IpcWriteOptions::default();
+        for tensor in tensors {
+            let tensor = tensor.unwrap();
+            let encoded = gen
+                .tensor_to_bytes(&tensor.to_ipc().unwrap(), &options)
+                .unwrap();
+
+            let mut bytes = vec![];
+            crate::ipc::writer::write_message(&mut bytes, encoded, &options).unwrap();
+            let data = read_tensor(bytes.as_slice()).unwrap();
+            let read = Float64Tensor::try_from_ipc(&data).unwrap();
+
+            assert_eq!(read.shape(), tensor.shape());
+            assert_eq!(read.strides(), tensor.strides());
+            assert_eq!(read.names(), tensor.names());
+            assert_eq!(read.data(), tensor.data());
+        }
+    }
 }
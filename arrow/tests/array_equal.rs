@@ -23,10 +23,13 @@ use arrow::array::{
 };
 use arrow::datatypes::{Int16Type, Int32Type};
 use arrow_array::builder::{StringBuilder, StringViewBuilder, StructBuilder};
-use arrow_array::{DictionaryArray, FixedSizeListArray, StringViewArray};
-use arrow_buffer::{Buffer, ToByteSlice};
+use arrow_array::{
+    DictionaryArray, FixedSizeListArray, Int64Array, ListViewArray, RunArray, StringViewArray,
+    UnionArray,
+};
+use arrow_buffer::{Buffer, NullBuffer, OffsetBuffer, ScalarBuffer, ToByteSlice};
 use arrow_data::{ArrayData, ArrayDataBuilder};
-use arrow_schema::{DataType, Field, Fields};
+use arrow_schema::{DataType, Field, Fields, UnionFields};
 use std::sync::Arc;
 
 #[test]
@@ -513,6 +516,57 @@ fn test_list_null() {
     test_equal(&c, &d, true);
 }
 
+fn create_list_view_array(
+    offsets: Vec<i32>,
+    sizes: Vec<i32>,
+    values: Vec<i32>,
+    nulls: Option<Vec<bool>>,
+) -> ListViewArray {
+    ListViewArray::new(
+        Arc::new(Field::new("item", DataType::Int32, true)),
+        ScalarBuffer::from(offsets),
+        ScalarBuffer::from(sizes),
+        Arc::new(Int32Array::from(values)),
+        nulls.map(NullBuffer::from),
+    )
+}
+
+#[test]
+fn test_list_view_equal() {
+    // [[1, 2], [3], null, []]
+    let a = create_list_view_array(
+        vec![0, 2, 3, 3],
+        vec![2, 1, 0, 0],
+        vec![1, 2, 3],
+        Some(vec![true, true, false, true]),
+    );
+    // the same lists, out of order and sharing values, with the null referencing values
+    let b = create_list_view_array(
+        vec![1, 0, 0, 2],
+        vec![2, 1, 3, 0],
+        vec![3, 1, 2],
+        Some(vec![true, true, false, true]),
+    );
+    test_equal(&a, &b, true);
+
+    // [[1, 2], [3], [], []]
+    let b = create_list_view_array(vec![0, 2, 3, 3], vec![2, 1, 0, 0], vec![1, 2, 3], None);
+    test_equal(&a, &b, false);
+
+    // [[1, 2], [2], null, []]
+    let b = create_list_view_array(
+        vec![0, 1, 3, 3],
+        vec![2, 1, 0, 0],
+        vec![1, 2, 3],
+        Some(vec![true, true, false, true]),
+    );
+    test_equal(&a, &b, false);
+
+    // slices
+    test_equal(&a.slice(1, 3), &b.slice(1, 3), false);
+    test_equal(&a.slice(2, 2), &b.slice(2, 2), true);
+}
+
 // Test the case where offset != 0
 #[test]
 fn test_list_offsets() {
@@ -1153,6 +1207,135 @@ fn test_union_equal_sparse_slice() {
     test_equal(&a1.slice(1, 2), &a2, true)
 }
 
+#[test]
+fn test_union_equal_sparse_unselected() {
+    let fields = UnionFields::new(
+        vec![0, 1],
+        vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ],
+    );
+    let type_ids: ScalarBuffer<i8> = vec![0, 1, 0].into();
+
+    // Values in slots not selected by the type ids are ignored
+    let a1 = UnionArray::try_new(
+        fields.clone(),
+        type_ids.clone(),
+        None,
+        vec![
+            Arc::new(Int32Array::from(vec![Some(1), Some(5), None])),
+            Arc::new(StringArray::from(vec![Some("x"), Some("b"), Some("y")])),
+        ],
+    )
+    .unwrap();
+    let a2 = UnionArray::try_new(
+        fields.clone(),
+        type_ids.clone(),
+        None,
+        vec![
+            Arc::new(Int32Array::from(vec![Some(1), None, None])),
+            Arc::new(StringArray::from(vec![None, Some("b"), None])),
+        ],
+    )
+    .unwrap();
+    test_equal(&a1, &a2, true);
+
+    let a3 = UnionArray::try_new(
+        fields,
+        type_ids,
+        None,
+        vec![
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])),
+            Arc::new(StringArray::from(vec![None, Some("b"), None])),
+        ],
+    )
+    .unwrap();
+    test_equal(&a1, &a3, false);
+    test_equal(&a1.slice(0, 2), &a3.slice(0, 2), true);
+}
+
+#[test]
+fn test_union_equal_dense_offsets() {
+    let fields = UnionFields::new(
+        vec![0, 1],
+        vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ],
+    );
+    let type_ids: ScalarBuffer<i8> = vec![0, 1, 0, 0].into();
+
+    let a1 = UnionArray::try_new(
+        fields.clone(),
+        type_ids.clone(),
+        Some(vec![0, 0, 1, 2].into()),
+        vec![
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])),
+            Arc::new(StringArray::from(vec!["b"])),
+        ],
+    )
+    .unwrap();
+
+    // The same logical values, stored at different offsets
+    let a2 = UnionArray::try_new(
+        fields,
+        type_ids,
+        Some(vec![3, 1, 2, 0].into()),
+        vec![
+            Arc::new(Int32Array::from(vec![Some(3), Some(10), None, Some(1)])),
+            Arc::new(StringArray::from(vec!["c", "b"])),
+        ],
+    )
+    .unwrap();
+    test_equal(&a1, &a2, true);
+    test_equal(&a1.slice(1, 3), &a2.slice(1, 3), true);
+    test_equal(&a1.slice(0, 2), &a2.slice(2, 2), false);
+}
+
+#[test]
+fn test_run_equal() {
+    // [1, 1, 1, 2, 2, null, 3]
+    let a = RunArray::try_new(
+        &Int32Array::from(vec![3, 5, 6, 7]),
+        &Int64Array::from(vec![Some(1), Some(2), None, Some(3)]),
+    )
+    .unwrap();
+
+    // The same logical values with different run boundaries
+    let b = RunArray::try_new(
+        &Int32Array::from(vec![1, 3, 4, 5, 6, 7]),
+        &Int64Array::from(vec![Some(1), Some(1), Some(2), Some(2), None, Some(3)]),
+    )
+    .unwrap();
+    test_equal(&a, &b, true);
+
+    // [1, 1, 1, 2, 2, 2, 3]
+    let c = RunArray::try_new(
+        &Int32Array::from(vec![3, 6, 7]),
+        &Int64Array::from(vec![1, 2, 3]),
+    )
+    .unwrap();
+    test_equal(&a, &c, false);
+
+    // Sliced arrays with arbitrary offsets
+    test_equal(&a.slice(1, 4), &b.slice(1, 4), true);
+    test_equal(&a.slice(1, 4), &c.slice(1, 4), true);
+    test_equal(&a.slice(2, 4), &c.slice(2, 4), false);
+    test_equal(&a.slice(3, 2), &c.slice(4, 2), true);
+    test_equal(&a.slice(0, 0), &c.slice(7, 0), true);
+
+    // Run end encoded arrays nested within a list
+    let offsets = OffsetBuffer::new(vec![0, 2, 7].into());
+    let field = Arc::new(Field::new("item", a.data_type().clone(), true));
+    let l1 = ListArray::new(field.clone(), offsets.clone(), Arc::new(a), None);
+    let l2 = ListArray::new(field.clone(), offsets.clone(), Arc::new(b), None);
+    let l3 = ListArray::new(field, offsets, Arc::new(c), None);
+    test_equal(&l1, &l2, true);
+    test_equal(&l1, &l3, false);
+    test_equal(&l1.slice(0, 1), &l3.slice(0, 1), true);
+}
+
 #[test]
 fn test_boolean_slice() {
     let array = BooleanArray::from(vec![true; 32]);
@@ -21,8 +21,10 @@ use arrow::array::{
     MapBuilder, NullArray, StringArray, StringBuilder, StringDictionaryBuilder, StructArray,
     UInt16Array, UInt16Builder, UInt8Array, UnionArray,
 };
-use arrow::datatypes::Int16Type;
-use arrow_array::StringViewArray;
+use arrow::datatypes::{Int16Type, Int32Type};
+use arrow_array::builder::ListViewBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::{ListViewArray, RunArray, StringViewArray};
 use arrow_buffer::{Buffer, ScalarBuffer};
 use arrow_data::transform::MutableArrayData;
 use arrow_data::ArrayData;
@@ -611,6 +613,57 @@ fn test_list_append() {
     assert_eq!(finished, expected_list_data);
 }
 
+#[test]
+fn test_list_view_append() {
+    // [[1, 2], null, [3], [1, 2, 3]]
+    let a = ListViewArray::new(
+        Arc::new(Field::new("item", DataType::Int64, true)),
+        ScalarBuffer::from(vec![0, 3, 2, 0]),
+        ScalarBuffer::from(vec![2, 0, 1, 3]),
+        Arc::new(Int64Array::from(vec![1, 2, 3])),
+        Some(vec![true, false, true, true].into()),
+    );
+    let mut builder = ListViewBuilder::new(Int64Builder::new());
+    builder.append_value([Some(4), None]);
+    builder.append_value([]);
+    builder.append_value([Some(5)]);
+    let b = builder.finish();
+    let a = a.into_data();
+    let b = b.into_data();
+    let c = b.slice(1, 2);
+
+    let mut mutable = MutableArrayData::new(vec![&a, &b, &c], false, 10);
+    mutable.extend(0, 0, a.len());
+    mutable.extend_nulls(1);
+    mutable.extend(1, 0, b.len());
+    mutable.extend(2, 0, c.len());
+    mutable.extend(0, 2, 3);
+    let result = ListViewArray::from(mutable.freeze());
+
+    let mut builder = ListViewBuilder::new(Int64Builder::new());
+    builder.append_value([Some(1), Some(2)]);
+    builder.append_null();
+    builder.append_value([Some(3)]);
+    builder.append_value([Some(1), Some(2), Some(3)]);
+    builder.append_null();
+    builder.append_value([Some(4), None]);
+    builder.append_value([]);
+    builder.append_value([Some(5)]);
+    builder.append_value([]);
+    builder.append_value([Some(5)]);
+    builder.append_value([Some(3)]);
+    let expected = builder.finish();
+    assert_eq!(result, expected);
+
+    let values = result.value(3);
+    assert_eq!(
+        values
+            .as_primitive::<arrow::datatypes::Int64Type>()
+            .values(),
+        &[1, 2, 3]
+    );
+}
+
 #[test]
 fn test_list_nulls_append() {
     let mut builder = ListBuilder::<Int64Builder>::new(Int64Builder::with_capacity(32));
@@ -1028,6 +1081,47 @@ fn test_extend_nulls_panic() {
     mutable.extend_nulls(2);
 }
 
+#[test]
+fn test_run_end_encoded() {
+    let run_ends = Int32Array::from(vec![2, 5, 6]);
+    let values = StringArray::from(vec![Some("a"), None, Some("b")]);
+    let a = RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap();
+    let b = a.slice(3, 3);
+    let (a, b) = (a.into_data(), b.into_data());
+
+    let mut mutable = MutableArrayData::new(vec![&a, &b], true, 0);
+    mutable.extend(0, 1, 3);
+    // continues the last run, so is merged with it
+    mutable.extend(0, 3, 4);
+    mutable.extend(1, 0, 3);
+    mutable.extend_nulls(2);
+    mutable.extend_nulls(1);
+    mutable.extend(0, 0, 0);
+    mutable.extend(0, 0, 1);
+
+    let data = mutable.freeze();
+    data.validate_full().unwrap();
+    let out = RunArray::<Int32Type>::from(data);
+    assert_eq!(out.run_ends().values(), &[1, 3, 5, 6, 9, 10]);
+    let values = out.values().as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!(
+        values.iter().collect::<Vec<_>>(),
+        vec![Some("a"), None, None, Some("b"), None, Some("a")]
+    );
+}
+
+#[test]
+#[should_panic(expected = "MutableArrayData not nullable")]
+fn test_run_end_encoded_extend_nulls_panic() {
+    let run_ends = Int32Array::from(vec![2]);
+    let values = Int32Array::from(vec![1]);
+    let a = RunArray::<Int32Type>::try_new(&run_ends, &values)
+        .unwrap()
+        .into_data();
+    let mut mutable = MutableArrayData::new(vec![&a], false, 0);
+    mutable.extend_nulls(1);
+}
+
 #[test]
 fn test_string_view() {
     let a1 =
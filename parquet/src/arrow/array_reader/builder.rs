@@ -17,7 +17,7 @@
 
 use std::sync::Arc;
 
-use arrow_schema::{DataType, Fields, SchemaBuilder};
+use arrow_schema::{DataType, Fields, SchemaBuilder, UnionFields};
 
 use crate::arrow::array_reader::byte_view_array::make_byte_view_array_reader;
 use crate::arrow::array_reader::empty_array::make_empty_array_reader;
@@ -25,9 +25,10 @@ use crate::arrow::array_reader::fixed_len_byte_array::make_fixed_len_byte_array_
 use crate::arrow::array_reader::{
     make_byte_array_dictionary_reader, make_byte_array_reader, ArrayReader,
     FixedSizeListArrayReader, ListArrayReader, MapArrayReader, NullArrayReader,
-    PrimitiveArrayReader, RowGroups, StructArrayReader,
+    PrimitiveArrayReader, RowGroups, RunEndEncodedArrayReader, StructArrayReader,
+    UnionArrayReader,
 };
-use crate::arrow::schema::{ParquetField, ParquetFieldType};
+use crate::arrow::schema::{union_struct_fields, ParquetField, ParquetFieldType};
 use crate::arrow::ProjectionMask;
 use crate::basic::Type as PhysicalType;
 use crate::data_type::{BoolType, DoubleType, FloatType, Int32Type, Int64Type, Int96Type};
@@ -58,6 +59,7 @@ fn build_reader(
         ParquetFieldType::Group { .. } => match &field.arrow_type {
             DataType::Map(_, _) => build_map_reader(field, mask, row_groups),
             DataType::Struct(_) => build_struct_reader(field, mask, row_groups),
+            DataType::Union(_, _) => build_union_reader(field, mask, row_groups),
             DataType::List(_) => build_list_reader(field, mask, false, row_groups),
             DataType::LargeList(_) => build_list_reader(field, mask, true, row_groups),
             DataType::FixedSizeList(_, _) => build_fixed_size_list_reader(field, mask, row_groups),
@@ -207,6 +209,18 @@ fn build_primitive_reader(
     mask: &ProjectionMask,
     row_groups: &dyn RowGroups,
 ) -> Result<Option<Box<dyn ArrayReader>>> {
+    if let DataType::RunEndEncoded(_, values) = &field.arrow_type {
+        // Read the logical values, and run-end encode them on output
+        let values_field = ParquetField {
+            arrow_type: values.data_type().clone(),
+            ..field.clone()
+        };
+        let reader = build_primitive_reader(&values_field, mask, row_groups)?;
+        return Ok(reader.map(|reader| {
+            Box::new(RunEndEncodedArrayReader::new(reader, field.arrow_type.clone())) as _
+        }));
+    }
+
     let (col_idx, primitive_type) = match &field.field_type {
         ParquetFieldType::Primitive {
             col_idx,
@@ -333,6 +347,66 @@ fn build_struct_reader(
     ))))
 }
 
+/// Build array reader for union type.
+fn build_union_reader(
+    field: &ParquetField,
+    mask: &ProjectionMask,
+    row_groups: &dyn RowGroups,
+) -> Result<Option<Box<dyn ArrayReader>>> {
+    let (arrow_fields, mode) = match &field.arrow_type {
+        DataType::Union(fields, mode) => (fields, *mode),
+        _ => unreachable!(),
+    };
+    let children = field.children().unwrap();
+    assert_eq!(arrow_fields.len() + 1, children.len());
+
+    let struct_fields = union_struct_fields(arrow_fields);
+    let mut readers = Vec::with_capacity(children.len());
+    let mut builder = SchemaBuilder::with_capacity(children.len());
+
+    for (arrow, parquet) in struct_fields.iter().zip(children) {
+        if let Some(reader) = build_reader(parquet, mask, row_groups)? {
+            // Need to retrieve underlying data type to handle projection
+            let child_type = reader.get_data_type().clone();
+            builder.push(arrow.as_ref().clone().with_data_type(child_type));
+            readers.push(reader);
+        }
+    }
+
+    if readers.is_empty() {
+        return Ok(None);
+    }
+
+    let struct_fields = builder.finish().fields;
+    if readers.len() != children.len() {
+        // The union can only be reconstructed if the type ids and all children are projected
+        return Ok(Some(Box::new(StructArrayReader::new(
+            DataType::Struct(struct_fields),
+            readers,
+            field.def_level,
+            field.rep_level,
+            field.nullable,
+        ))));
+    }
+
+    let fields = arrow_fields
+        .iter()
+        .zip(&struct_fields[1..])
+        .map(|((type_id, f), child)| {
+            let child_type = child.data_type().clone();
+            (type_id, Arc::new(f.as_ref().clone().with_data_type(child_type)))
+        })
+        .collect::<UnionFields>();
+
+    Ok(Some(Box::new(UnionArrayReader::new(
+        readers,
+        DataType::Union(fields, mode),
+        field.def_level,
+        field.rep_level,
+        field.nullable,
+    ))))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,7 +40,9 @@ mod list_array;
 mod map_array;
 mod null_array;
 mod primitive_array;
+mod run_end_encoded_array;
 mod struct_array;
+mod union_array;
 
 #[cfg(test)]
 mod test_util;
@@ -57,7 +59,9 @@ pub use list_array::ListArrayReader;
 pub use map_array::MapArrayReader;
 pub use null_array::NullArrayReader;
 pub use primitive_array::PrimitiveArrayReader;
+pub use run_end_encoded_array::RunEndEncodedArrayReader;
 pub use struct_array::StructArrayReader;
+pub use union_array::UnionArrayReader;
 
 /// Array reader reads parquet data into arrow array.
 pub trait ArrayReader: Send {
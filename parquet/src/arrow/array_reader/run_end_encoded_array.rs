@@ -0,0 +1,110 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::arrow::array_reader::ArrayReader;
+use crate::errors::{ParquetError, Result};
+use arrow_array::types::{Int16Type, Int32Type, Int64Type, RunEndIndexType};
+use arrow_array::{make_array, Array, ArrayRef, PrimitiveArray, RunArray, UInt32Array};
+use arrow_buffer::ArrowNativeType;
+use arrow_data::ArrayData;
+use arrow_ord::partition::partition;
+use arrow_schema::DataType as ArrowType;
+use arrow_select::take::take;
+use std::any::Any;
+use std::ops::Range;
+
+/// Implementation of a run-end encoded array reader.
+///
+/// Run-end encoded columns are stored in parquet as their logical values, this reads
+/// the values with `reader` and encodes runs of equal values on output
+pub struct RunEndEncodedArrayReader {
+    data_type: ArrowType,
+    reader: Box<dyn ArrayReader>,
+}
+
+impl RunEndEncodedArrayReader {
+    /// Creates a new [`RunEndEncodedArrayReader`] of `data_type`, reading the values
+    /// with `reader`
+    pub fn new(reader: Box<dyn ArrayReader>, data_type: ArrowType) -> Self {
+        Self { data_type, reader }
+    }
+}
+
+impl ArrayReader for RunEndEncodedArrayReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        self.reader.read_records(batch_size)
+    }
+
+    fn consume_batch(&mut self) -> Result<ArrayRef> {
+        let values = self.reader.consume_batch()?;
+        let runs = partition(std::slice::from_ref(&values))?.ranges();
+
+        let starts = UInt32Array::from_iter_values(runs.iter().map(|r| r.start as u32));
+        let values = take(&values, &starts, None)?;
+
+        let array = match &self.data_type {
+            ArrowType::RunEndEncoded(run_ends, _) => match run_ends.data_type() {
+                ArrowType::Int16 => run_array::<Int16Type>(&runs, &values)?,
+                ArrowType::Int32 => run_array::<Int32Type>(&runs, &values)?,
+                ArrowType::Int64 => run_array::<Int64Type>(&runs, &values)?,
+                d => unreachable!("invalid run end type {d}"),
+            },
+            _ => unreachable!("expected run-end encoded type"),
+        };
+
+        // Preserve the field names and nullability of the requested type
+        let data = array.into_builder().data_type(self.data_type.clone());
+        Ok(make_array(data.build()?))
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        self.reader.skip_records(num_records)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.reader.get_def_levels()
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.reader.get_rep_levels()
+    }
+}
+
+/// Creates a [`RunArray`] with a run for each of `runs` with the corresponding `values`
+fn run_array<R: RunEndIndexType>(
+    runs: &[Range<usize>],
+    values: &dyn Array,
+) -> Result<ArrayData> {
+    let run_ends = runs
+        .iter()
+        .map(|r| {
+            R::Native::from_usize(r.end)
+                .ok_or_else(|| general_err!("run end {} overflows {}", r.end, R::DATA_TYPE))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let run_ends = PrimitiveArray::<R>::new(run_ends.into(), None);
+    Ok(RunArray::try_new(&run_ends, values)?.into_data())
+}
@@ -0,0 +1,126 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::arrow::array_reader::{ArrayReader, StructArrayReader};
+use crate::arrow::schema::union_struct_fields;
+use crate::errors::{ParquetError, Result};
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int8Type;
+use arrow_array::{Array, ArrayRef, UInt32Array, UnionArray};
+use arrow_schema::{DataType as ArrowType, UnionMode};
+use arrow_select::take::take;
+use std::any::Any;
+use std::sync::Arc;
+
+/// Implementation of a union array reader.
+///
+/// Unions are stored in parquet as a struct of the type ids followed by a nullable
+/// field for each child, see [`union_struct_fields`]
+pub struct UnionArrayReader {
+    data_type: ArrowType,
+    reader: StructArrayReader,
+}
+
+impl UnionArrayReader {
+    /// Creates a new [`UnionArrayReader`] with a `def_level`, `rep_level` and `nullable`
+    /// as defined on [`ParquetField`][crate::arrow::schema::ParquetField]
+    ///
+    /// `children` must contain a reader for the type ids, followed by a reader for each
+    /// child of the union
+    pub fn new(
+        children: Vec<Box<dyn ArrayReader>>,
+        data_type: ArrowType,
+        def_level: i16,
+        rep_level: i16,
+        nullable: bool,
+    ) -> Self {
+        let struct_type = match &data_type {
+            ArrowType::Union(fields, _) => ArrowType::Struct(union_struct_fields(fields)),
+            _ => unreachable!("expected union type"),
+        };
+        let reader = StructArrayReader::new(struct_type, children, def_level, rep_level, nullable);
+        Self { data_type, reader }
+    }
+}
+
+impl ArrayReader for UnionArrayReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        self.reader.read_records(batch_size)
+    }
+
+    fn consume_batch(&mut self) -> Result<ArrayRef> {
+        let (fields, mode) = match &self.data_type {
+            ArrowType::Union(fields, mode) => (fields, *mode),
+            _ => unreachable!(),
+        };
+
+        let array = self.reader.consume_batch()?;
+        let array = array.as_struct();
+        if array.null_count() != 0 {
+            return Err(general_err!("UnionArray cannot contain nulls"));
+        }
+
+        let type_ids = array.column(0).as_primitive::<Int8Type>().values().clone();
+        let columns = &array.columns()[1..];
+
+        let union = match mode {
+            UnionMode::Sparse => UnionArray::try_new(fields.clone(), type_ids, None, columns.to_vec())?,
+            UnionMode::Dense => {
+                // Compute the rows of each child, and the offset of each row within its child
+                let mut indices = vec![vec![]; columns.len()];
+                let mut offsets = Vec::with_capacity(type_ids.len());
+                for (row, type_id) in type_ids.iter().enumerate() {
+                    let child = fields
+                        .iter()
+                        .position(|(id, _)| id == *type_id)
+                        .ok_or_else(|| general_err!("invalid union type id {}", type_id))?;
+                    offsets.push(indices[child].len() as i32);
+                    indices[child].push(row as u32);
+                }
+
+                let children = columns
+                    .iter()
+                    .zip(indices)
+                    .map(|(column, indices)| take(column, &UInt32Array::from(indices), None))
+                    .collect::<Result<_, _>>()?;
+
+                UnionArray::try_new(fields.clone(), type_ids, Some(offsets.into()), children)?
+            }
+        };
+        Ok(Arc::new(union))
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        self.reader.skip_records(num_records)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.reader.get_def_levels()
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.reader.get_rep_levels()
+    }
+}
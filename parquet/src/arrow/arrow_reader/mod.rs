@@ -22,25 +22,32 @@ use std::sync::Arc;
 
 use arrow_array::cast::AsArray;
 use arrow_array::Array;
-use arrow_array::{RecordBatch, RecordBatchReader};
+use arrow_array::{BooleanArray, RecordBatch, RecordBatchReader};
 use arrow_schema::{ArrowError, DataType as ArrowType, Schema, SchemaRef};
 use arrow_select::filter::prep_null_mask_filter;
 pub use filter::{ArrowPredicate, ArrowPredicateFn, RowFilter};
+pub(crate) use predicate::BoundPredicate;
+pub use predicate::{CompareOp, Predicate};
 pub use selection::{RowSelection, RowSelector};
 
 pub use crate::arrow::array_reader::RowGroups;
 use crate::arrow::array_reader::{build_array_reader, ArrayReader};
 use crate::arrow::schema::{parquet_to_arrow_schema_and_fields, ParquetField};
 use crate::arrow::{parquet_to_arrow_field_levels, FieldLevels, ProjectionMask};
+use crate::bloom_filter::Sbbf;
 use crate::column::page::{PageIterator, PageReader};
+#[cfg(feature = "encryption")]
+use crate::encryption::decrypt::{CryptoContext, FileDecryptionProperties};
 use crate::errors::{ParquetError, Result};
 use crate::file::footer;
-use crate::file::metadata::ParquetMetaData;
+use crate::file::metadata::{ColumnChunkMetaData, ParquetMetaData};
 use crate::file::page_index::index_reader;
+use crate::file::properties::{ReaderProperties, ReaderPropertiesPtr};
 use crate::file::reader::{ChunkReader, SerializedPageReader};
 use crate::schema::types::SchemaDescriptor;
 
 mod filter;
+mod predicate;
 mod selection;
 pub mod statistics;
 
@@ -74,6 +81,10 @@ pub struct ArrowReaderBuilder<T> {
     pub(crate) limit: Option<usize>,
 
     pub(crate) offset: Option<usize>,
+
+    pub(crate) predicate: Option<Predicate>,
+
+    pub(crate) verify_page_checksum: bool,
 }
 
 impl<T> ArrowReaderBuilder<T> {
@@ -90,6 +101,8 @@ impl<T> ArrowReaderBuilder<T> {
             selection: None,
             limit: None,
             offset: None,
+            predicate: None,
+            verify_page_checksum: metadata.verify_page_checksum,
         }
     }
 
@@ -213,6 +226,22 @@ impl<T> ArrowReaderBuilder<T> {
         }
     }
 
+    /// Provide a [`Predicate`] used to skip row groups, pages and rows
+    ///
+    /// Row groups are skipped based on their statistics and bloom filters, and pages
+    /// based on the page index if it was loaded (see [`ArrowReaderOptions::with_page_index`]).
+    /// The predicate is then evaluated against the remaining rows before any
+    /// [`RowFilter`] provided with [`Self::with_row_filter`].
+    ///
+    /// Any [`RowSelection`] provided with [`Self::with_row_selection`] must describe
+    /// the row groups prior to pruning, as with [`Self::with_row_groups`].
+    pub fn with_predicate(self, predicate: Predicate) -> Self {
+        Self {
+            predicate: Some(predicate),
+            ..self
+        }
+    }
+
     /// Provide a limit to the number of rows to be read
     ///
     /// The limit will be applied after any [`Self::with_row_selection`] and [`Self::with_row_filter`]
@@ -240,6 +269,97 @@ impl<T> ArrowReaderBuilder<T> {
             ..self
         }
     }
+
+    /// Applies the [`Predicate`] provided by [`Self::with_predicate`], if any, removing
+    /// pruned row groups, restricting the [`RowSelection`] to the pages that may match
+    /// and prepending a predicate to the [`RowFilter`]
+    ///
+    /// `load_bloom_filter` is used to read the bloom filters of the remaining row groups.
+    /// Returns the bound predicate so that readers unable to load bloom filters here
+    /// can check them before reading each row group.
+    pub(crate) fn apply_predicate<F>(
+        &mut self,
+        mut load_bloom_filter: F,
+    ) -> Result<Option<BoundPredicate>>
+    where
+        F: FnMut(&T, &ColumnChunkMetaData) -> Result<Option<Sbbf>>,
+    {
+        let Some(predicate) = self.predicate.take() else {
+            return Ok(None);
+        };
+        let metadata = self.metadata.as_ref();
+        let parquet_schema = metadata.file_metadata().schema_descr();
+        let bound = predicate.bind(&self.schema, parquet_schema)?;
+
+        let num_row_groups = metadata.num_row_groups();
+        let row_groups = match self.row_groups.take() {
+            Some(row_groups) => {
+                if let Some(col) = row_groups.iter().find(|x| **x >= num_row_groups) {
+                    return Err(general_err!(
+                        "row group {} out of bounds 0..{}",
+                        col,
+                        num_row_groups
+                    ));
+                }
+                row_groups
+            }
+            None => (0..num_row_groups).collect(),
+        };
+
+        let mut keep: Vec<bool> = bound
+            .prune_row_groups(&self.schema, metadata, &row_groups)?
+            .iter()
+            .collect();
+        for (keep, row_group) in keep.iter_mut().zip(&row_groups) {
+            if *keep {
+                let row_group = metadata.row_group(*row_group);
+                *keep = bound.may_match_bloom_filters(row_group, |column| {
+                    load_bloom_filter(&self.input, row_group.column(column))
+                })?;
+            }
+        }
+
+        // Split the selection into row groups, restricting those that are kept
+        // to the pages that may contain matching rows
+        let mut selection = self.selection.take();
+        let mut restrict_selection = selection.is_some();
+        let mut selectors = vec![];
+        let mut kept_row_groups = vec![];
+        for (row_group, keep) in row_groups.into_iter().zip(keep) {
+            let num_rows = metadata.row_group(row_group).num_rows() as usize;
+            let row_group_selection = selection.as_mut().map(|s| s.split_off(num_rows));
+            if !keep {
+                continue;
+            }
+            kept_row_groups.push(row_group);
+
+            let row_group_selection = match bound.prune_pages(&self.schema, metadata, row_group)? {
+                Some(mask) if mask.count_set_bits() != num_rows => {
+                    restrict_selection = true;
+                    let pages = RowSelection::from_filters(&[BooleanArray::new(mask, None)]);
+                    match row_group_selection {
+                        Some(s) => s.intersection(&pages),
+                        None => pages,
+                    }
+                }
+                _ => row_group_selection
+                    .unwrap_or_else(|| RowSelection::from(vec![RowSelector::select(num_rows)])),
+            };
+            selectors.extend(row_group_selection.iter().cloned());
+        }
+
+        if restrict_selection {
+            self.selection = Some(RowSelection::from(selectors));
+        }
+        self.row_groups = Some(kept_row_groups);
+
+        let filter = bound.row_filter(parquet_schema);
+        match self.filter.as_mut() {
+            Some(f) => f.predicates.insert(0, filter),
+            None => self.filter = Some(RowFilter::new(vec![filter])),
+        }
+        Ok(Some(bound))
+    }
 }
 
 /// Options that control how metadata is read for a parquet file
@@ -254,6 +374,11 @@ pub struct ArrowReaderOptions {
     supplied_schema: Option<SchemaRef>,
     /// If true, attempt to read `OffsetIndex` and `ColumnIndex`
     pub(crate) page_index: bool,
+    /// If true, verify the CRC32 checksum of pages that have one
+    pub(crate) verify_page_checksum: bool,
+    /// If encrypted, the properties used to decrypt the file
+    #[cfg(feature = "encryption")]
+    pub(crate) file_decryption_properties: Option<FileDecryptionProperties>,
 }
 
 impl ArrowReaderOptions {
@@ -344,6 +469,35 @@ impl ArrowReaderOptions {
     pub fn with_page_index(self, page_index: bool) -> Self {
         Self { page_index, ..self }
     }
+
+    /// Verify the CRC32 checksum of pages when reading (defaults to `false`)
+    ///
+    /// Pages without a checksum are read as normal. If a checksum does not
+    /// match the page data, reading fails with [`ParquetError::ChecksumMismatch`].
+    ///
+    /// See [`WriterPropertiesBuilder::set_write_page_checksum`] to write checksums.
+    ///
+    /// [`WriterPropertiesBuilder::set_write_page_checksum`]: crate::file::properties::WriterPropertiesBuilder::set_write_page_checksum
+    pub fn with_verify_page_checksum(self, verify_page_checksum: bool) -> Self {
+        Self {
+            verify_page_checksum,
+            ..self
+        }
+    }
+
+    /// Provide the properties used to decrypt an encrypted file
+    ///
+    /// See [`FileDecryptionProperties`] for details.
+    #[cfg(feature = "encryption")]
+    pub fn with_file_decryption_properties(
+        self,
+        file_decryption_properties: FileDecryptionProperties,
+    ) -> Self {
+        Self {
+            file_decryption_properties: Some(file_decryption_properties),
+            ..self
+        }
+    }
 }
 
 /// The metadata necessary to construct a [`ArrowReaderBuilder`]
@@ -368,6 +522,8 @@ pub struct ArrowReaderMetadata {
     pub(crate) schema: SchemaRef,
 
     pub(crate) fields: Option<Arc<ParquetField>>,
+
+    pub(crate) verify_page_checksum: bool,
 }
 
 impl ArrowReaderMetadata {
@@ -382,21 +538,22 @@ impl ArrowReaderMetadata {
     /// `Self::metadata` is missing the page index, this function will attempt
     /// to load the page index by making an object store request.
     pub fn load<T: ChunkReader>(reader: &T, options: ArrowReaderOptions) -> Result<Self> {
+        #[cfg(feature = "encryption")]
+        let mut metadata = footer::parse_metadata_with_decryption(
+            reader,
+            options.file_decryption_properties.as_ref(),
+        )?;
+        #[cfg(not(feature = "encryption"))]
         let mut metadata = footer::parse_metadata(reader)?;
-        if options.page_index {
-            let column_index = metadata
-                .row_groups()
-                .iter()
-                .map(|rg| index_reader::read_columns_indexes(reader, rg.columns()))
-                .collect::<Result<Vec<_>>>()?;
-            metadata.set_column_index(Some(column_index));
 
-            let offset_index = metadata
-                .row_groups()
-                .iter()
-                .map(|rg| index_reader::read_offset_indexes(reader, rg.columns()))
-                .collect::<Result<Vec<_>>>()?;
+        if options.page_index {
+            let (column_index, offset_index) = (0..metadata.num_row_groups())
+                .map(|i| index_reader::read_row_group_page_indexes(reader, &metadata, i))
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .unzip();
 
+            metadata.set_column_index(Some(column_index));
             metadata.set_offset_index(Some(offset_index))
         }
         Self::try_new(Arc::new(metadata), options)
@@ -409,8 +566,15 @@ impl ArrowReaderMetadata {
     /// This function does not attempt to load the PageIndex if not present in the metadata.
     /// See [`Self::load`] for more details.
     pub fn try_new(metadata: Arc<ParquetMetaData>, options: ArrowReaderOptions) -> Result<Self> {
+        let verify_page_checksum = options.verify_page_checksum;
         match options.supplied_schema {
-            Some(supplied_schema) => Self::with_supplied_schema(metadata, supplied_schema.clone()),
+            Some(supplied_schema) => {
+                let metadata = Self::with_supplied_schema(metadata, supplied_schema.clone())?;
+                Ok(Self {
+                    verify_page_checksum,
+                    ..metadata
+                })
+            }
             None => {
                 let kv_metadata = match options.skip_arrow_metadata {
                     true => None,
@@ -427,6 +591,7 @@ impl ArrowReaderMetadata {
                     metadata,
                     schema: Arc::new(schema),
                     fields: fields.map(Arc::new),
+                    verify_page_checksum,
                 })
             }
         }
@@ -477,6 +642,7 @@ impl ArrowReaderMetadata {
                     metadata,
                     schema: supplied_schema,
                     fields: field_levels.levels.map(Arc::new),
+                    verify_page_checksum: false,
                 })
             }
         }
@@ -591,7 +757,9 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
     /// Build a [`ParquetRecordBatchReader`]
     ///
     /// Note: this will eagerly evaluate any `RowFilter` before returning
-    pub fn build(self) -> Result<ParquetRecordBatchReader> {
+    pub fn build(mut self) -> Result<ParquetRecordBatchReader> {
+        self.apply_predicate(|input, column| Sbbf::read_from_column_chunk(column, &input.0))?;
+
         // Try to avoid allocate large buffer
         let batch_size = self
             .batch_size
@@ -601,10 +769,15 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             .row_groups
             .unwrap_or_else(|| (0..self.metadata.num_row_groups()).collect());
 
+        let props = ReaderProperties::builder()
+            .set_verify_page_checksum(self.verify_page_checksum)
+            .build();
+
         let reader = ReaderRowGroups {
             reader: Arc::new(self.input.0),
             metadata: self.metadata,
             row_groups,
+            props: Arc::new(props),
         };
 
         let mut filter = self.filter;
@@ -649,6 +822,8 @@ struct ReaderRowGroups<T: ChunkReader> {
     metadata: Arc<ParquetMetaData>,
     /// Optional list of row group indices to scan
     row_groups: Vec<usize>,
+
+    props: ReaderPropertiesPtr,
 }
 
 impl<T: ChunkReader + 'static> RowGroups for ReaderRowGroups<T> {
@@ -666,6 +841,7 @@ impl<T: ChunkReader + 'static> RowGroups for ReaderRowGroups<T> {
             reader: self.reader.clone(),
             metadata: self.metadata.clone(),
             row_groups: self.row_groups.clone().into_iter(),
+            props: self.props.clone(),
         }))
     }
 }
@@ -675,6 +851,7 @@ struct ReaderPageIterator<T: ChunkReader> {
     column_idx: usize,
     row_groups: std::vec::IntoIter<usize>,
     metadata: Arc<ParquetMetaData>,
+    props: ReaderPropertiesPtr,
 }
 
 impl<T: ChunkReader + 'static> Iterator for ReaderPageIterator<T> {
@@ -692,8 +869,30 @@ impl<T: ChunkReader + 'static> Iterator for ReaderPageIterator<T> {
             .map(|i| i[rg_idx][self.column_idx].page_locations.clone());
         let total_rows = rg.num_rows() as usize;
         let reader = self.reader.clone();
+        let props = self.props.clone();
+
+        #[cfg(feature = "encryption")]
+        let ret =
+            CryptoContext::for_column_chunk(self.metadata.file_decryptor(), rg, self.column_idx)
+                .and_then(|crypto_context| {
+                    SerializedPageReader::new_with_crypto_context(
+                        reader,
+                        meta,
+                        total_rows,
+                        page_locations,
+                        props,
+                        crypto_context,
+                    )
+                });
+        #[cfg(not(feature = "encryption"))]
+        let ret = SerializedPageReader::new_with_properties(
+            reader,
+            meta,
+            total_rows,
+            page_locations,
+            props,
+        );
 
-        let ret = SerializedPageReader::new(reader, meta, total_rows, page_locations);
         Some(ret.map(|x| Box::new(x) as _))
     }
 }
@@ -3358,6 +3557,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_verify_page_checksum() {
+        let array = Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([("a", array)]).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_write_page_checksum(true)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let read = |data: &[u8], options: ArrowReaderOptions| {
+            let data = Bytes::copy_from_slice(data);
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data, options)
+                .unwrap()
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        for page_index in [false, true] {
+            let options = ArrowReaderOptions::new()
+                .with_page_index(page_index)
+                .with_verify_page_checksum(true);
+            assert_eq!(read(&buf, options).unwrap(), vec![batch.clone()]);
+        }
+
+        // Corrupt the last byte of the data page
+        let metadata = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf.clone()))
+            .unwrap()
+            .metadata()
+            .clone();
+        let (start, len) = metadata.row_group(0).column(0).byte_range();
+        buf[(start + len - 1) as usize] ^= 0xFF;
+
+        for page_index in [false, true] {
+            let options = ArrowReaderOptions::new().with_page_index(page_index);
+            let batches = read(&buf, options.clone()).unwrap();
+            assert_ne!(batches, vec![batch.clone()]);
+
+            let err = read(&buf, options.with_verify_page_checksum(true)).unwrap_err();
+            let expected = format!("Checksum mismatch: page 0 of column chunk a at offset {start}");
+            assert!(err.to_string().contains(&expected), "{err}");
+        }
+    }
+
     #[test]
     fn test_raw_repetition() {
         const MESSAGE_TYPE: &str = "
@@ -0,0 +1,1175 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`Predicate`] expressions and their evaluation against parquet statistics,
+//! bloom filters and decoded [`RecordBatch`]es
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    Date32Type, Float16Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type,
+    UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+};
+use arrow_array::{Array, ArrayRef, BooleanArray, Datum, RecordBatch, Scalar, UInt64Array};
+use arrow_buffer::{BooleanBuffer, BooleanBufferBuilder};
+use arrow_cast::cast;
+use arrow_ord::cmp;
+use arrow_schema::{ArrowError, DataType, Schema};
+
+use crate::arrow::arrow_reader::statistics::StatisticsConverter;
+use crate::arrow::arrow_reader::ArrowPredicate;
+use crate::arrow::{parquet_column, ProjectionMask};
+use crate::basic::Type as PhysicalType;
+use crate::bloom_filter::Sbbf;
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::{ParquetMetaData, RowGroupMetaData};
+use crate::file::serialized_reader::is_encrypted;
+use crate::schema::types::SchemaDescriptor;
+
+/// A comparison operator used by [`Predicate::Compare`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    /// `column = value`
+    Eq,
+    /// `column != value`
+    NotEq,
+    /// `column < value`
+    Lt,
+    /// `column <= value`
+    LtEq,
+    /// `column > value`
+    Gt,
+    /// `column >= value`
+    GtEq,
+}
+
+impl CompareOp {
+    /// Returns the operator matching exactly the rows for which `self` is false
+    fn negate(self) -> Self {
+        match self {
+            Self::Eq => Self::NotEq,
+            Self::NotEq => Self::Eq,
+            Self::Lt => Self::GtEq,
+            Self::LtEq => Self::Gt,
+            Self::Gt => Self::LtEq,
+            Self::GtEq => Self::Lt,
+        }
+    }
+
+    fn evaluate(self, l: &dyn Datum, r: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+        match self {
+            Self::Eq => cmp::eq(l, r),
+            Self::NotEq => cmp::neq(l, r),
+            Self::Lt => cmp::lt(l, r),
+            Self::LtEq => cmp::lt_eq(l, r),
+            Self::Gt => cmp::gt(l, r),
+            Self::GtEq => cmp::gt_eq(l, r),
+        }
+    }
+}
+
+/// A predicate over the top-level columns of a parquet file
+///
+/// A [`Predicate`] passed to [`ArrowReaderBuilder::with_predicate`] is used to
+///
+/// 1. Skip row groups whose statistics or bloom filters show they cannot contain
+///    any matching rows
+/// 2. Skip pages whose statistics show they cannot contain any matching rows, if
+///    the page index was loaded (see [`ArrowReaderOptions::with_page_index`])
+/// 3. Filter the remaining rows with a [`RowFilter`], evaluated before any
+///    user provided [`ArrowPredicate`]
+///
+/// Predicates follow SQL semantics: comparisons against null evaluate to null, and
+/// rows for which the predicate is null are not returned.
+///
+/// Only primitive columns at the root of the schema can be referenced. Values are
+/// cast to the type of the column they are compared against, and an error is
+/// returned when the reader is built if this cannot be done without loss.
+///
+/// # Example
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Int32Array, RecordBatch, StringArray};
+/// # use arrow_schema::{DataType, Field, Schema};
+/// # use bytes::Bytes;
+/// # use parquet::arrow::arrow_reader::{ParquetRecordBatchReaderBuilder, Predicate};
+/// # use parquet::arrow::ArrowWriter;
+/// # let schema = Arc::new(Schema::new(vec![
+/// #     Field::new("id", DataType::Int32, false),
+/// #     Field::new("name", DataType::Utf8, true),
+/// # ]));
+/// # let batch = RecordBatch::try_new(schema.clone(), vec![
+/// #     Arc::new(Int32Array::from(vec![1, 2, 3])),
+/// #     Arc::new(StringArray::from(vec![Some("a"), None, Some("c")])),
+/// # ]).unwrap();
+/// # let mut file = Vec::new();
+/// # let mut writer = ArrowWriter::try_new(&mut file, schema, None).unwrap();
+/// # writer.write(&batch).unwrap();
+/// # writer.close().unwrap();
+/// # let file = Bytes::from(file);
+/// // id > 1 AND name IS NOT NULL
+/// let predicate = Predicate::gt("id", Int32Array::new_scalar(1))
+///     .and(Predicate::is_not_null("name"));
+///
+/// let reader = ParquetRecordBatchReaderBuilder::try_new(file)
+///     .unwrap()
+///     .with_predicate(predicate)
+///     .build()
+///     .unwrap();
+///
+/// let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(batches[0].num_rows(), 1);
+/// ```
+///
+/// [`ArrowReaderBuilder::with_predicate`]: crate::arrow::arrow_reader::ArrowReaderBuilder::with_predicate
+/// [`ArrowReaderOptions::with_page_index`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_page_index
+/// [`RowFilter`]: crate::arrow::arrow_reader::RowFilter
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate {
+    /// `column <op> value`, where `value` is an array containing a single value
+    Compare {
+        /// The name of the column
+        column: String,
+        /// The comparison operator
+        op: CompareOp,
+        /// The value to compare against
+        value: ArrayRef,
+    },
+    /// `column IN (values)`
+    InList {
+        /// The name of the column
+        column: String,
+        /// The values to compare against
+        values: ArrayRef,
+    },
+    /// `column IS NULL`
+    IsNull(String),
+    /// `column IS NOT NULL`
+    IsNotNull(String),
+    /// Conjunction of predicates, true for an empty list
+    And(Vec<Predicate>),
+    /// Disjunction of predicates, false for an empty list
+    Or(Vec<Predicate>),
+    /// Negation of a predicate
+    Not(Box<Predicate>),
+}
+
+impl Predicate {
+    /// Create a predicate comparing `column` with `value` using `op`
+    pub fn compare<T: Array + 'static>(
+        column: impl Into<String>,
+        op: CompareOp,
+        value: Scalar<T>,
+    ) -> Self {
+        Self::Compare {
+            column: column.into(),
+            op,
+            value: Arc::new(value.into_inner()),
+        }
+    }
+
+    /// Create a `column = value` predicate
+    pub fn eq<T: Array + 'static>(column: impl Into<String>, value: Scalar<T>) -> Self {
+        Self::compare(column, CompareOp::Eq, value)
+    }
+
+    /// Create a `column != value` predicate
+    pub fn not_eq<T: Array + 'static>(column: impl Into<String>, value: Scalar<T>) -> Self {
+        Self::compare(column, CompareOp::NotEq, value)
+    }
+
+    /// Create a `column < value` predicate
+    pub fn lt<T: Array + 'static>(column: impl Into<String>, value: Scalar<T>) -> Self {
+        Self::compare(column, CompareOp::Lt, value)
+    }
+
+    /// Create a `column <= value` predicate
+    pub fn lt_eq<T: Array + 'static>(column: impl Into<String>, value: Scalar<T>) -> Self {
+        Self::compare(column, CompareOp::LtEq, value)
+    }
+
+    /// Create a `column > value` predicate
+    pub fn gt<T: Array + 'static>(column: impl Into<String>, value: Scalar<T>) -> Self {
+        Self::compare(column, CompareOp::Gt, value)
+    }
+
+    /// Create a `column >= value` predicate
+    pub fn gt_eq<T: Array + 'static>(column: impl Into<String>, value: Scalar<T>) -> Self {
+        Self::compare(column, CompareOp::GtEq, value)
+    }
+
+    /// Create a `column IN (values)` predicate
+    pub fn in_list(column: impl Into<String>, values: impl Array + 'static) -> Self {
+        Self::InList {
+            column: column.into(),
+            values: Arc::new(values),
+        }
+    }
+
+    /// Create a `column IS NULL` predicate
+    pub fn is_null(column: impl Into<String>) -> Self {
+        Self::IsNull(column.into())
+    }
+
+    /// Create a `column IS NOT NULL` predicate
+    pub fn is_not_null(column: impl Into<String>) -> Self {
+        Self::IsNotNull(column.into())
+    }
+
+    /// Returns the conjunction of `self` and `other`
+    pub fn and(self, other: Predicate) -> Self {
+        match self {
+            Self::And(mut predicates) => {
+                predicates.push(other);
+                Self::And(predicates)
+            }
+            predicate => Self::And(vec![predicate, other]),
+        }
+    }
+
+    /// Returns the disjunction of `self` and `other`
+    pub fn or(self, other: Predicate) -> Self {
+        match self {
+            Self::Or(mut predicates) => {
+                predicates.push(other);
+                Self::Or(predicates)
+            }
+            predicate => Self::Or(vec![predicate, other]),
+        }
+    }
+
+    /// Resolve the columns referenced by this predicate against the provided schemas
+    ///
+    /// Negations are pushed down to the leaves, so the returned [`BoundPredicate`]
+    /// only contains comparisons combined with `AND` and `OR`
+    pub(crate) fn bind(
+        &self,
+        schema: &Schema,
+        parquet_schema: &SchemaDescriptor,
+    ) -> Result<BoundPredicate> {
+        self.bind_impl(schema, parquet_schema, false)
+    }
+
+    fn bind_impl(
+        &self,
+        schema: &Schema,
+        parquet_schema: &SchemaDescriptor,
+        negated: bool,
+    ) -> Result<BoundPredicate> {
+        let bind_all = |predicates: &[Predicate]| {
+            predicates
+                .iter()
+                .map(|p| p.bind_impl(schema, parquet_schema, negated))
+                .collect::<Result<Vec<_>>>()
+        };
+
+        Ok(match self {
+            Self::Compare { column, op, value } => {
+                if value.len() != 1 {
+                    return Err(general_err!(
+                        "Predicate value for column '{}' must contain a single value, got {}",
+                        column,
+                        value.len()
+                    ));
+                }
+                let column = BoundColumn::try_new(column, schema, parquet_schema)?;
+                let value = column.cast_values(value)?;
+                let op = if negated { op.negate() } else { *op };
+                // Surface unsupported comparisons when building the reader
+                op.evaluate(&value, &Scalar::new(value.clone()))?;
+                BoundPredicate::Compare { column, op, value }
+            }
+            Self::InList { column, values } => {
+                let column = BoundColumn::try_new(column, schema, parquet_schema)?;
+                let values = column.cast_values(values)?;
+                cmp::eq(&values, &values)?;
+                BoundPredicate::InList {
+                    column,
+                    values,
+                    negated,
+                }
+            }
+            Self::IsNull(column) => BoundPredicate::IsNull {
+                column: BoundColumn::try_new(column, schema, parquet_schema)?,
+                negated,
+            },
+            Self::IsNotNull(column) => BoundPredicate::IsNull {
+                column: BoundColumn::try_new(column, schema, parquet_schema)?,
+                negated: !negated,
+            },
+            Self::And(predicates) => match negated {
+                false => BoundPredicate::And(bind_all(predicates)?),
+                true => BoundPredicate::Or(bind_all(predicates)?),
+            },
+            Self::Or(predicates) => match negated {
+                false => BoundPredicate::Or(bind_all(predicates)?),
+                true => BoundPredicate::And(bind_all(predicates)?),
+            },
+            Self::Not(predicate) => predicate.bind_impl(schema, parquet_schema, !negated)?,
+        })
+    }
+}
+
+impl std::ops::Not for Predicate {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self::Not(Box::new(self))
+    }
+}
+
+/// A column referenced by a [`BoundPredicate`]
+#[derive(Debug, Clone)]
+pub(crate) struct BoundColumn {
+    /// The name of the column in the arrow schema
+    name: String,
+    /// The index of the leaf column in the parquet schema
+    parquet_index: usize,
+    /// The type of the column values, i.e. the value type of dictionaries
+    data_type: DataType,
+    /// The physical type of the column in the parquet schema
+    physical_type: PhysicalType,
+}
+
+impl BoundColumn {
+    fn try_new(name: &str, schema: &Schema, parquet_schema: &SchemaDescriptor) -> Result<Self> {
+        let (parquet_index, field) =
+            parquet_column(parquet_schema, schema, name).ok_or_else(|| {
+                general_err!(
+                    "Predicate column '{}' is not a primitive column in the parquet file",
+                    name
+                )
+            })?;
+
+        let data_type = match field.data_type() {
+            DataType::Dictionary(_, value_type) => value_type.as_ref().clone(),
+            data_type => data_type.clone(),
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            parquet_index,
+            data_type,
+            physical_type: parquet_schema.column(parquet_index).physical_type(),
+        })
+    }
+
+    /// Cast `values` to the type of this column, returning an error if this loses information
+    fn cast_values(&self, values: &ArrayRef) -> Result<ArrayRef> {
+        let lossy = || {
+            general_err!(
+                "Cannot cast predicate value of type {} to {} for column '{}' without loss",
+                values.data_type(),
+                self.data_type,
+                self.name
+            )
+        };
+
+        let cast_values = cast(values, &self.data_type).map_err(|_| lossy())?;
+        if cast_values.null_count() != values.null_count() {
+            return Err(lossy());
+        }
+
+        // Parsing strings is checked by the null count above, whereas numeric values
+        // may be silently truncated and must round trip
+        let is_string = matches!(
+            values.data_type(),
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+        );
+        if !is_string {
+            let round_trip = cast(&cast_values, values.data_type()).map_err(|_| lossy())?;
+            if round_trip.as_ref() != values.as_ref() {
+                return Err(lossy());
+            }
+        }
+        Ok(cast_values)
+    }
+
+    fn is_float(&self) -> bool {
+        matches!(
+            self.data_type,
+            DataType::Float16 | DataType::Float32 | DataType::Float64
+        )
+    }
+
+    /// Returns true if the bloom filter for this column can be probed with its values
+    fn supports_bloom_filter(&self) -> bool {
+        match self.data_type {
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::Date32 => self.physical_type == PhysicalType::INT32,
+            DataType::Int64 | DataType::UInt64 => self.physical_type == PhysicalType::INT64,
+            DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Utf8View
+            | DataType::Binary
+            | DataType::LargeBinary
+            | DataType::BinaryView => self.physical_type == PhysicalType::BYTE_ARRAY,
+            _ => false,
+        }
+    }
+
+    /// Returns false if the value at `idx` of `values` is definitely not in `sbbf`
+    ///
+    /// Values are hashed the same way the writer encodes them, i.e. integers narrower
+    /// than 32 bits are widened and unsigned integers are reinterpreted as signed
+    fn bloom_filter_may_contain(&self, sbbf: &Sbbf, values: &dyn Array, idx: usize) -> bool {
+        if values.is_null(idx) {
+            return false;
+        }
+        match values.data_type() {
+            DataType::Int8 => sbbf.check(&(values.as_primitive::<Int8Type>().value(idx) as i32)),
+            DataType::Int16 => sbbf.check(&(values.as_primitive::<Int16Type>().value(idx) as i32)),
+            DataType::Int32 => sbbf.check(&values.as_primitive::<Int32Type>().value(idx)),
+            DataType::UInt8 => sbbf.check(&(values.as_primitive::<UInt8Type>().value(idx) as i32)),
+            DataType::UInt16 => {
+                sbbf.check(&(values.as_primitive::<UInt16Type>().value(idx) as i32))
+            }
+            DataType::UInt32 => {
+                sbbf.check(&(values.as_primitive::<UInt32Type>().value(idx) as i32))
+            }
+            DataType::Date32 => sbbf.check(&values.as_primitive::<Date32Type>().value(idx)),
+            DataType::Int64 => sbbf.check(&values.as_primitive::<Int64Type>().value(idx)),
+            DataType::UInt64 => {
+                sbbf.check(&(values.as_primitive::<UInt64Type>().value(idx) as i64))
+            }
+            DataType::Utf8 => sbbf.check(values.as_string::<i32>().value(idx)),
+            DataType::LargeUtf8 => sbbf.check(values.as_string::<i64>().value(idx)),
+            DataType::Utf8View => sbbf.check(values.as_string_view().value(idx)),
+            DataType::Binary => sbbf.check(values.as_binary::<i32>().value(idx)),
+            DataType::LargeBinary => sbbf.check(values.as_binary::<i64>().value(idx)),
+            DataType::BinaryView => sbbf.check(values.as_binary_view().value(idx)),
+            _ => true,
+        }
+    }
+}
+
+/// Statistics for a column, with one entry per row group or page
+struct ColumnStatistics {
+    mins: ArrayRef,
+    maxes: ArrayRef,
+    null_counts: UInt64Array,
+    row_counts: UInt64Array,
+}
+
+impl ColumnStatistics {
+    fn len(&self) -> usize {
+        self.row_counts.len()
+    }
+
+    /// Returns a mask of the entries that may contain null values
+    fn may_contain_nulls(&self) -> BooleanBuffer {
+        let counts = &self.null_counts;
+        BooleanBuffer::collect_bool(self.len(), |i| counts.is_null(i) || counts.value(i) > 0)
+    }
+
+    /// Returns a mask of the entries that may contain non-null values
+    fn may_contain_values(&self) -> BooleanBuffer {
+        let (counts, rows) = (&self.null_counts, &self.row_counts);
+        BooleanBuffer::collect_bool(self.len(), |i| {
+            counts.is_null(i) || rows.is_null(i) || counts.value(i) < rows.value(i)
+        })
+    }
+}
+
+/// A [`Predicate`] resolved against the schema of a parquet file
+#[derive(Debug, Clone)]
+pub(crate) enum BoundPredicate {
+    Compare {
+        column: BoundColumn,
+        op: CompareOp,
+        value: ArrayRef,
+    },
+    InList {
+        column: BoundColumn,
+        values: ArrayRef,
+        negated: bool,
+    },
+    IsNull {
+        column: BoundColumn,
+        negated: bool,
+    },
+    And(Vec<BoundPredicate>),
+    Or(Vec<BoundPredicate>),
+}
+
+impl BoundPredicate {
+    /// Evaluates this predicate producing a mask of `len` values, calling `leaf` for
+    /// each comparison and combining the results
+    ///
+    /// As negations have been pushed down, treating null results of `leaf` as false
+    /// (or true) yields the same as evaluating with null propagation and then
+    /// treating null as false (or true)
+    fn evaluate<E>(
+        &self,
+        len: usize,
+        leaf: &mut dyn FnMut(&BoundPredicate) -> Result<BooleanBuffer, E>,
+    ) -> Result<BooleanBuffer, E> {
+        match self {
+            Self::And(predicates) => {
+                let mut result = BooleanBuffer::new_set(len);
+                for predicate in predicates {
+                    result = &result & &predicate.evaluate(len, leaf)?;
+                }
+                Ok(result)
+            }
+            Self::Or(predicates) => {
+                let mut result = BooleanBuffer::new_unset(len);
+                for predicate in predicates {
+                    result = &result | &predicate.evaluate(len, leaf)?;
+                }
+                Ok(result)
+            }
+            _ => leaf(self),
+        }
+    }
+
+    /// Returns the column referenced by a leaf predicate
+    fn column(&self) -> &BoundColumn {
+        match self {
+            Self::Compare { column, .. }
+            | Self::InList { column, .. }
+            | Self::IsNull { column, .. } => column,
+            Self::And(_) | Self::Or(_) => unreachable!("not a leaf predicate"),
+        }
+    }
+
+    /// Calls `f` for each leaf predicate
+    fn for_each_leaf(&self, f: &mut dyn FnMut(&BoundPredicate)) {
+        match self {
+            Self::And(predicates) | Self::Or(predicates) => {
+                predicates.iter().for_each(|p| p.for_each_leaf(f))
+            }
+            leaf => f(leaf),
+        }
+    }
+
+    /// Returns a mask of the containers described by `stats` that may contain
+    /// rows matching this leaf predicate
+    fn statistics_may_match(&self, stats: &ColumnStatistics) -> Result<BooleanBuffer> {
+        match self {
+            Self::Compare { column, op, value } => compare_may_match(column, *op, value, stats),
+            Self::InList {
+                column,
+                values,
+                negated: false,
+            } => {
+                let mut result = BooleanBuffer::new_unset(stats.len());
+                for idx in 0..values.len() {
+                    let value = values.slice(idx, 1);
+                    result = &result | &compare_may_match(column, CompareOp::Eq, &value, stats)?;
+                }
+                Ok(result)
+            }
+            Self::InList {
+                column,
+                values,
+                negated: true,
+            } => {
+                let mut result = BooleanBuffer::new_set(stats.len());
+                for idx in 0..values.len() {
+                    let value = values.slice(idx, 1);
+                    result = &result & &compare_may_match(column, CompareOp::NotEq, &value, stats)?;
+                }
+                Ok(result)
+            }
+            Self::IsNull { negated: false, .. } => Ok(stats.may_contain_nulls()),
+            Self::IsNull { negated: true, .. } => Ok(stats.may_contain_values()),
+            Self::And(_) | Self::Or(_) => unreachable!("not a leaf predicate"),
+        }
+    }
+
+    /// Returns a mask of the rows in `batch` matching this leaf predicate
+    fn batch_matches(&self, batch: &RecordBatch) -> Result<BooleanBuffer, ArrowError> {
+        let column = self.column();
+        let array = batch.column_by_name(&column.name).ok_or_else(|| {
+            ArrowError::SchemaError(format!("Predicate column '{}' not found", column.name))
+        })?;
+
+        match self {
+            Self::Compare { op, value, .. } => {
+                let result = op.evaluate(array, &Scalar::new(value.clone()))?;
+                Ok(null_as_false(&result))
+            }
+            Self::InList {
+                values, negated, ..
+            } => {
+                let (op, mut result) = match negated {
+                    false => (CompareOp::Eq, BooleanBuffer::new_unset(array.len())),
+                    true => (CompareOp::NotEq, BooleanBuffer::new_set(array.len())),
+                };
+                for idx in 0..values.len() {
+                    let value = Scalar::new(values.slice(idx, 1));
+                    let matches = null_as_false(&op.evaluate(array, &value)?);
+                    result = match negated {
+                        false => &result | &matches,
+                        true => &result & &matches,
+                    };
+                }
+                Ok(result)
+            }
+            Self::IsNull { negated, .. } => Ok(match (array.logical_nulls(), negated) {
+                (Some(nulls), false) => !nulls.inner(),
+                (Some(nulls), true) => nulls.into_inner(),
+                (None, false) => BooleanBuffer::new_unset(array.len()),
+                (None, true) => BooleanBuffer::new_set(array.len()),
+            }),
+            Self::And(_) | Self::Or(_) => unreachable!("not a leaf predicate"),
+        }
+    }
+
+    /// Returns a mask of the row groups in `row_groups` whose statistics show they
+    /// may contain rows matching this predicate
+    pub(crate) fn prune_row_groups(
+        &self,
+        schema: &Schema,
+        metadata: &ParquetMetaData,
+        row_groups: &[usize],
+    ) -> Result<BooleanBuffer> {
+        let parquet_schema = metadata.file_metadata().schema_descr();
+        let metadatas: Vec<&RowGroupMetaData> =
+            row_groups.iter().map(|x| metadata.row_group(*x)).collect();
+
+        self.evaluate(row_groups.len(), &mut |leaf| {
+            let converter =
+                StatisticsConverter::try_new(&leaf.column().name, schema, parquet_schema)?;
+            let Some(row_counts) = converter.row_group_row_counts(metadatas.iter().copied())?
+            else {
+                return Ok(BooleanBuffer::new_set(row_groups.len()));
+            };
+            let stats = ColumnStatistics {
+                mins: converter.row_group_mins(metadatas.iter().copied())?,
+                maxes: converter.row_group_maxes(metadatas.iter().copied())?,
+                null_counts: converter.row_group_null_counts(metadatas.iter().copied())?,
+                row_counts,
+            };
+            leaf.statistics_may_match(&stats)
+        })
+    }
+
+    /// Returns a mask of the rows in `row_group` contained in pages whose statistics
+    /// show they may contain rows matching this predicate
+    ///
+    /// Returns `None` if the page index was not loaded for `row_group`
+    pub(crate) fn prune_pages(
+        &self,
+        schema: &Schema,
+        metadata: &ParquetMetaData,
+        row_group: usize,
+    ) -> Result<Option<BooleanBuffer>> {
+        let (Some(column_index), Some(offset_index)) =
+            (metadata.column_index(), metadata.offset_index())
+        else {
+            return Ok(None);
+        };
+        if column_index[row_group].is_empty() || offset_index[row_group].is_empty() {
+            return Ok(None);
+        }
+
+        let parquet_schema = metadata.file_metadata().schema_descr();
+        let num_rows = metadata.row_group(row_group).num_rows() as usize;
+        let row_groups = [row_group];
+
+        let mask = self.evaluate::<ParquetError>(num_rows, &mut |leaf| {
+            let converter =
+                StatisticsConverter::try_new(&leaf.column().name, schema, parquet_schema)?;
+            let Some(row_counts) =
+                converter.data_page_row_counts(offset_index, metadata.row_groups(), &row_groups)?
+            else {
+                return Ok(BooleanBuffer::new_set(num_rows));
+            };
+            let stats = ColumnStatistics {
+                mins: converter.data_page_mins(column_index, offset_index, &row_groups)?,
+                maxes: converter.data_page_maxes(column_index, offset_index, &row_groups)?,
+                null_counts: converter.data_page_null_counts(
+                    column_index,
+                    offset_index,
+                    &row_groups,
+                )?,
+                row_counts,
+            };
+            let pages = leaf.statistics_may_match(&stats)?;
+
+            let mut rows = BooleanBufferBuilder::new(num_rows);
+            for (keep, count) in pages.iter().zip(stats.row_counts.values()) {
+                rows.append_n(*count as usize, keep);
+            }
+            Ok(rows.finish())
+        })?;
+        Ok(Some(mask))
+    }
+
+    /// Returns the column and values of a leaf predicate that can be checked
+    /// against the bloom filters of `row_group`
+    fn bloom_filter_leaf(&self, row_group: &RowGroupMetaData) -> Option<(&BoundColumn, &ArrayRef)> {
+        let (column, values) = match self {
+            Self::Compare {
+                column,
+                op: CompareOp::Eq,
+                value,
+            } => (column, value),
+            Self::InList {
+                column,
+                values,
+                negated: false,
+            } => (column, values),
+            _ => return None,
+        };
+        let supported =
+            column.supports_bloom_filter() && !is_encrypted(row_group.column(column.parquet_index));
+        supported.then_some((column, values))
+    }
+
+    /// Returns the parquet leaf indices of the columns of `row_group` whose bloom
+    /// filters are used by [`Self::may_match_bloom_filters`]
+    #[cfg(feature = "async")]
+    pub(crate) fn bloom_filter_columns(&self, row_group: &RowGroupMetaData) -> Vec<usize> {
+        let mut columns = vec![];
+        self.for_each_leaf(&mut |leaf| {
+            if let Some((column, _)) = leaf.bloom_filter_leaf(row_group) {
+                if !columns.contains(&column.parquet_index) {
+                    columns.push(column.parquet_index);
+                }
+            }
+        });
+        columns
+    }
+
+    /// Returns false if the bloom filters of `row_group` show it contains no rows
+    /// matching this predicate
+    ///
+    /// `load_bloom_filter` is called with the parquet leaf index of each column
+    /// returned by [`Self::bloom_filter_columns`], at most once per column
+    pub(crate) fn may_match_bloom_filters<F>(
+        &self,
+        row_group: &RowGroupMetaData,
+        mut load_bloom_filter: F,
+    ) -> Result<bool>
+    where
+        F: FnMut(usize) -> Result<Option<Sbbf>>,
+    {
+        let mut filters: HashMap<usize, Option<Sbbf>> = HashMap::new();
+        let result = self.evaluate::<ParquetError>(1, &mut |leaf| {
+            let Some((column, values)) = leaf.bloom_filter_leaf(row_group) else {
+                return Ok(BooleanBuffer::new_set(1));
+            };
+
+            let sbbf = match filters.entry(column.parquet_index) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(load_bloom_filter(column.parquet_index)?),
+            };
+
+            let may_match = match sbbf {
+                Some(sbbf) => (0..values.len())
+                    .any(|idx| column.bloom_filter_may_contain(sbbf, values.as_ref(), idx)),
+                None => true,
+            };
+            Ok(BooleanBuffer::collect_bool(1, |_| may_match))
+        })?;
+        Ok(result.value(0))
+    }
+
+    /// Returns an [`ArrowPredicate`] filtering the rows that do not match this predicate
+    pub(crate) fn row_filter(&self, parquet_schema: &SchemaDescriptor) -> Box<dyn ArrowPredicate> {
+        let mut columns = vec![];
+        self.for_each_leaf(&mut |leaf| columns.push(leaf.column().parquet_index));
+        Box::new(PredicateFilter {
+            projection: ProjectionMask::leaves(parquet_schema, columns),
+            predicate: self.clone(),
+        })
+    }
+}
+
+/// Returns a mask of the containers described by `stats` that may contain rows
+/// for which `column <op> value` is true
+fn compare_may_match(
+    column: &BoundColumn,
+    op: CompareOp,
+    value: &ArrayRef,
+    stats: &ColumnStatistics,
+) -> Result<BooleanBuffer> {
+    // Floating point statistics exclude NaN, which compares greater than all other values
+    let skip = value.is_null(0)
+        || column.is_float()
+            && (matches!(op, CompareOp::NotEq | CompareOp::Gt | CompareOp::GtEq) || is_nan(value));
+    if skip {
+        return Ok(BooleanBuffer::new_set(stats.len()));
+    }
+
+    let value = Scalar::new(value.clone());
+    let (mins, maxes) = (&stats.mins, &stats.maxes);
+    let result = match op {
+        CompareOp::Eq => {
+            &null_as_true(&cmp::lt_eq(mins, &value)?) & &null_as_true(&cmp::gt_eq(maxes, &value)?)
+        }
+        CompareOp::NotEq => {
+            // Only prune if all non-null values are equal to `value`
+            let all_eq =
+                &null_as_false(&cmp::eq(mins, &value)?) & &null_as_false(&cmp::eq(maxes, &value)?);
+            !&all_eq
+        }
+        CompareOp::Lt => null_as_true(&cmp::lt(mins, &value)?),
+        CompareOp::LtEq => null_as_true(&cmp::lt_eq(mins, &value)?),
+        CompareOp::Gt => null_as_true(&cmp::gt(maxes, &value)?),
+        CompareOp::GtEq => null_as_true(&cmp::gt_eq(maxes, &value)?),
+    };
+    // Comparisons are never true for null values
+    Ok(&result & &stats.may_contain_values())
+}
+
+fn is_nan(value: &dyn Array) -> bool {
+    match value.data_type() {
+        DataType::Float16 => value.as_primitive::<Float16Type>().value(0).is_nan(),
+        DataType::Float32 => value.as_primitive::<Float32Type>().value(0).is_nan(),
+        DataType::Float64 => value.as_primitive::<Float64Type>().value(0).is_nan(),
+        _ => false,
+    }
+}
+
+fn null_as_false(array: &BooleanArray) -> BooleanBuffer {
+    match array.nulls() {
+        Some(nulls) => array.values() & nulls.inner(),
+        None => array.values().clone(),
+    }
+}
+
+fn null_as_true(array: &BooleanArray) -> BooleanBuffer {
+    match array.nulls() {
+        Some(nulls) => array.values() | &!nulls.inner(),
+        None => array.values().clone(),
+    }
+}
+
+/// The [`ArrowPredicate`] evaluating a [`BoundPredicate`]
+struct PredicateFilter {
+    projection: ProjectionMask,
+    predicate: BoundPredicate,
+}
+
+impl ArrowPredicate for PredicateFilter {
+    fn projection(&self) -> &ProjectionMask {
+        &self.projection
+    }
+
+    fn evaluate(&mut self, batch: RecordBatch) -> Result<BooleanArray, ArrowError> {
+        let mask = self
+            .predicate
+            .evaluate(batch.num_rows(), &mut |leaf| leaf.batch_matches(&batch))?;
+        Ok(BooleanArray::new(mask, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Float64Array, Int32Array, Int64Array, StringArray};
+    use arrow_schema::Field;
+    use bytes::Bytes;
+
+    use crate::arrow::arrow_reader::{
+        ArrowReaderOptions, ParquetRecordBatchReaderBuilder, RowSelection, RowSelector,
+    };
+    use crate::arrow::ArrowWriter;
+    use crate::file::properties::WriterProperties;
+
+    /// Writes 4 row groups of 100 rows, with pages of 10 rows and bloom filters
+    ///
+    /// * `id`: 0..400
+    /// * `name`: `v{id:03}`, null for multiples of 10
+    /// * `value`: `id` as a float, NaN for id 42
+    fn test_file() -> Bytes {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, true),
+            Field::new("value", DataType::Float64, false),
+        ]));
+        let id = Int32Array::from_iter_values(0..400);
+        let name =
+            StringArray::from_iter((0..400).map(|i| (i % 10 != 0).then(|| format!("v{i:03}"))));
+        let value =
+            Float64Array::from_iter_values(
+                (0..400).map(|i| if i == 42 { f64::NAN } else { i as f64 }),
+            );
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(id), Arc::new(name), Arc::new(value)],
+        )
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(100)
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .set_bloom_filter_enabled(true)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, schema, Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        Bytes::from(buf)
+    }
+
+    /// The result of reading a file with a [`Predicate`]
+    struct PrunedRead {
+        row_groups: Vec<usize>,
+        selection: Option<RowSelection>,
+        ids: Vec<i32>,
+    }
+
+    fn prune_and_read(
+        file: &Bytes,
+        page_index: bool,
+        configure: impl FnOnce(
+            ParquetRecordBatchReaderBuilder<Bytes>,
+        ) -> ParquetRecordBatchReaderBuilder<Bytes>,
+    ) -> Result<PrunedRead> {
+        let options = ArrowReaderOptions::new().with_page_index(page_index);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(file.clone(), options)?;
+        let mut builder = configure(builder);
+        builder.apply_predicate(|input, column| Sbbf::read_from_column_chunk(column, &input.0))?;
+
+        let row_groups = builder.row_groups.clone().unwrap();
+        let selection = builder.selection.clone();
+        let mut ids = vec![];
+        for batch in builder.build()? {
+            let batch = batch?;
+            let id = batch
+                .column_by_name("id")
+                .unwrap()
+                .as_primitive::<Int32Type>();
+            ids.extend(id.values().iter().copied());
+        }
+        Ok(PrunedRead {
+            row_groups,
+            selection,
+            ids,
+        })
+    }
+
+    fn read_with_predicate(file: &Bytes, page_index: bool, predicate: Predicate) -> PrunedRead {
+        prune_and_read(file, page_index, |b| b.with_predicate(predicate)).unwrap()
+    }
+
+    #[test]
+    fn test_prune_row_groups() {
+        let file = test_file();
+
+        let read = read_with_predicate(
+            &file,
+            false,
+            Predicate::gt("id", Int32Array::new_scalar(250)),
+        );
+        assert_eq!(read.row_groups, vec![2, 3]);
+        assert!(read.selection.is_none());
+        assert_eq!(read.ids, (251..400).collect::<Vec<_>>());
+
+        let predicate = Predicate::eq("id", Int32Array::new_scalar(150))
+            .or(Predicate::lt_eq("id", Int32Array::new_scalar(2)));
+        let read = read_with_predicate(&file, false, predicate);
+        assert_eq!(read.row_groups, vec![0, 1]);
+        assert_eq!(read.ids, vec![0, 1, 2, 150]);
+
+        let read =
+            read_with_predicate(&file, false, Predicate::lt("id", Int32Array::new_scalar(0)));
+        assert!(read.row_groups.is_empty());
+        assert!(read.ids.is_empty());
+    }
+
+    #[test]
+    fn test_prune_pages() {
+        let file = test_file();
+        let predicate = Predicate::gt_eq("id", Int32Array::new_scalar(105))
+            .and(Predicate::lt("id", Int32Array::new_scalar(112)));
+
+        let read = read_with_predicate(&file, true, predicate.clone());
+        assert_eq!(read.row_groups, vec![1]);
+        let expected = RowSelection::from(vec![RowSelector::select(20), RowSelector::skip(80)]);
+        assert_eq!(read.selection, Some(expected));
+        assert_eq!(read.ids, (105..112).collect::<Vec<_>>());
+
+        // Without the page index only row groups are pruned
+        let read = read_with_predicate(&file, false, predicate);
+        assert_eq!(read.row_groups, vec![1]);
+        assert!(read.selection.is_none());
+        assert_eq!(read.ids, (105..112).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_prune_bloom_filters() {
+        let file = test_file();
+
+        // Within the min/max range of row group 1, but not present
+        let predicate = Predicate::eq("name", StringArray::new_scalar("v150x"));
+        let read = read_with_predicate(&file, false, predicate);
+        assert!(read.row_groups.is_empty());
+
+        let predicate = Predicate::in_list("name", StringArray::from(vec!["v150x", "v251"]));
+        let read = read_with_predicate(&file, false, predicate);
+        assert_eq!(read.row_groups, vec![2]);
+        assert_eq!(read.ids, vec![251]);
+
+        // Values are hashed as their physical type
+        let predicate = Predicate::in_list("id", Int32Array::from(vec![7, 307]));
+        let read = read_with_predicate(&file, false, predicate);
+        assert_eq!(read.row_groups, vec![0, 3]);
+        assert_eq!(read.ids, vec![7, 307]);
+    }
+
+    #[test]
+    fn test_not_and_nulls() {
+        let file = test_file();
+
+        let predicate = !Predicate::lt("id", Int32Array::new_scalar(100))
+            .or(Predicate::gt_eq("id", Int32Array::new_scalar(200)));
+        let read = read_with_predicate(&file, true, predicate);
+        assert_eq!(read.row_groups, vec![1]);
+        assert_eq!(read.ids, (100..200).collect::<Vec<_>>());
+
+        let predicate =
+            Predicate::is_null("name").and(Predicate::lt("id", Int32Array::new_scalar(50)));
+        let read = read_with_predicate(&file, false, predicate);
+        assert_eq!(read.row_groups, vec![0]);
+        assert_eq!(read.ids, vec![0, 10, 20, 30, 40]);
+
+        // Null values never match comparisons, or their negation
+        let predicate = Predicate::gt_eq("name", StringArray::new_scalar("v395"));
+        let read = read_with_predicate(&file, false, predicate.clone());
+        assert_eq!(read.ids, vec![395, 396, 397, 398, 399]);
+        let predicate = !predicate;
+        let read = read_with_predicate(&file, false, predicate);
+        assert_eq!(read.ids.len(), 400 - 40 - 5);
+        assert!(!read.ids.contains(&390));
+
+        let predicate = !Predicate::in_list("id", Int32Array::from(vec![1, 2]));
+        let read = read_with_predicate(&file, false, predicate);
+        assert_eq!(read.ids.len(), 398);
+    }
+
+    #[test]
+    fn test_float_nan() {
+        let file = test_file();
+
+        // Statistics exclude NaN, which compares greater than all other values
+        let predicate = Predicate::gt("value", Float64Array::new_scalar(397.));
+        let read = read_with_predicate(&file, true, predicate);
+        assert_eq!(read.row_groups, vec![0, 1, 2, 3]);
+        assert_eq!(read.ids, vec![42, 398, 399]);
+
+        let predicate = Predicate::lt("value", Float64Array::new_scalar(3.));
+        let read = read_with_predicate(&file, true, predicate);
+        assert_eq!(read.row_groups, vec![0]);
+        assert_eq!(read.ids, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_with_row_selection() {
+        let file = test_file();
+
+        let read = prune_and_read(&file, true, |b| {
+            b.with_row_selection(RowSelection::from(vec![
+                RowSelector::skip(150),
+                RowSelector::select(100),
+                RowSelector::skip(150),
+            ]))
+            .with_predicate(Predicate::lt("id", Int32Array::new_scalar(210)))
+        })
+        .unwrap();
+        assert_eq!(read.row_groups, vec![0, 1, 2]);
+        assert_eq!(read.ids, (150..210).collect::<Vec<_>>());
+
+        let read = prune_and_read(&file, false, |b| {
+            b.with_row_groups(vec![3, 1])
+                .with_row_selection(RowSelection::from(vec![
+                    RowSelector::select(10),
+                    RowSelector::skip(90),
+                    RowSelector::skip(10),
+                    RowSelector::select(10),
+                    RowSelector::skip(80),
+                ]))
+                .with_predicate(Predicate::lt("id", Int32Array::new_scalar(150)))
+        })
+        .unwrap();
+        assert_eq!(read.row_groups, vec![1]);
+        let expected = RowSelection::from(vec![
+            RowSelector::skip(10),
+            RowSelector::select(10),
+            RowSelector::skip(80),
+        ]);
+        assert_eq!(read.selection, Some(expected));
+        assert_eq!(read.ids, (110..120).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_cast_values() {
+        let file = test_file();
+
+        let predicate = Predicate::eq("id", Int64Array::new_scalar(150));
+        assert_eq!(read_with_predicate(&file, false, predicate).ids, vec![150]);
+
+        let predicate = Predicate::eq("id", StringArray::new_scalar("150"));
+        assert_eq!(read_with_predicate(&file, false, predicate).ids, vec![150]);
+
+        let predicate = Predicate::lt("value", Int32Array::new_scalar(2));
+        assert_eq!(read_with_predicate(&file, false, predicate).ids, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_bind_errors() {
+        let file = test_file();
+        let err = |predicate: Predicate| {
+            prune_and_read(&file, false, |b| b.with_predicate(predicate))
+                .err()
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(
+            err(Predicate::is_null("missing")),
+            "Parquet error: Predicate column 'missing' is not a primitive column in the parquet file"
+        );
+        assert_eq!(
+            err(Predicate::eq("id", Int64Array::new_scalar(1 << 40))),
+            "Parquet error: Cannot cast predicate value of type Int64 to Int32 for column 'id' without loss"
+        );
+        assert_eq!(
+            err(Predicate::eq("id", Float64Array::new_scalar(1.5))),
+            "Parquet error: Cannot cast predicate value of type Float64 to Int32 for column 'id' without loss"
+        );
+        assert_eq!(
+            err(Predicate::eq("id", StringArray::new_scalar("a"))),
+            "Parquet error: Cannot cast predicate value of type Utf8 to Int32 for column 'id' without loss"
+        );
+        assert_eq!(
+            err(Predicate::Compare {
+                column: "id".to_string(),
+                op: CompareOp::Eq,
+                value: Arc::new(Int32Array::from(vec![1, 2])),
+            }),
+            "Parquet error: Predicate value for column 'id' must contain a single value, got 2"
+        );
+    }
+}
@@ -21,19 +21,21 @@ use crate::arrow::buffer::bit_util::sign_extend_be;
 use crate::arrow::parquet_column;
 use crate::data_type::{ByteArray, FixedLenByteArray};
 use crate::errors::{ParquetError, Result};
-use crate::file::metadata::{ParquetColumnIndex, ParquetOffsetIndex, RowGroupMetaData};
+use crate::file::metadata::{
+    ColumnChunkMetaData, LevelHistogram, ParquetColumnIndex, ParquetOffsetIndex, RowGroupMetaData,
+};
 use crate::file::page_index::index::{Index, PageIndex};
 use crate::file::statistics::Statistics as ParquetStatistics;
 use crate::schema::types::SchemaDescriptor;
 use arrow_array::builder::{
-    BinaryViewBuilder, BooleanBuilder, FixedSizeBinaryBuilder, LargeStringBuilder, StringBuilder,
-    StringViewBuilder,
+    BinaryViewBuilder, BooleanBuilder, FixedSizeBinaryBuilder, Int64Builder, LargeStringBuilder,
+    ListBuilder, StringBuilder, StringViewBuilder,
 };
 use arrow_array::{
     new_empty_array, new_null_array, ArrayRef, BinaryArray, BooleanArray, Date32Array, Date64Array,
     Decimal128Array, Decimal256Array, Float16Array, Float32Array, Float64Array, Int16Array,
-    Int32Array, Int64Array, Int8Array, LargeBinaryArray, Time32MillisecondArray, Time32SecondArray,
-    Time64MicrosecondArray, Time64NanosecondArray, TimestampMicrosecondArray,
+    Int32Array, Int64Array, Int8Array, LargeBinaryArray, ListArray, Time32MillisecondArray,
+    Time32SecondArray, Time64MicrosecondArray, Time64NanosecondArray, TimestampMicrosecondArray,
     TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt16Array,
     UInt32Array, UInt64Array, UInt8Array,
 };
@@ -1125,6 +1127,102 @@ where
     Ok(UInt64Array::from_iter(iter))
 }
 
+/// The kind of level a [`LevelHistogram`] counts
+#[derive(Debug, Clone, Copy)]
+enum LevelKind {
+    Repetition,
+    Definition,
+}
+
+impl LevelKind {
+    /// Returns the histogram of this kind of level for a data page
+    fn page_histogram<T>(self, page: &PageIndex<T>) -> Option<&LevelHistogram> {
+        match self {
+            Self::Repetition => page.repetition_level_histogram(),
+            Self::Definition => page.definition_level_histogram(),
+        }
+    }
+
+    /// Returns the histogram of this kind of level for a column chunk
+    fn chunk_histogram(self, column: &ColumnChunkMetaData) -> Option<&LevelHistogram> {
+        match self {
+            Self::Repetition => column.repetition_level_histogram(),
+            Self::Definition => column.definition_level_histogram(),
+        }
+    }
+}
+
+/// Builds a [`ListArray`] of histograms, with a null for each missing histogram
+fn level_histograms_array<'a, I>(histograms: I) -> ListArray
+where
+    I: IntoIterator<Item = Option<&'a LevelHistogram>>,
+{
+    let mut builder = ListBuilder::new(Int64Builder::new());
+    for histogram in histograms {
+        match histogram {
+            Some(histogram) => {
+                builder.values().append_slice(histogram.values());
+                builder.append(true);
+            }
+            None => builder.append_null(),
+        }
+    }
+    builder.finish()
+}
+
+/// Extracts the level histograms of `kind` for each data page from an
+/// iterator of (number of pages, [`Index`])
+fn level_histograms_page_statistics<'a, I>(iterator: I, kind: LevelKind) -> ListArray
+where
+    I: Iterator<Item = (usize, &'a Index)>,
+{
+    let iter = iterator.flat_map(|(len, index)| match index {
+        Index::NONE => vec![None; len],
+        Index::BOOLEAN(native_index) => native_index
+            .indexes
+            .iter()
+            .map(|x| kind.page_histogram(x))
+            .collect::<Vec<_>>(),
+        Index::INT32(native_index) => native_index
+            .indexes
+            .iter()
+            .map(|x| kind.page_histogram(x))
+            .collect::<Vec<_>>(),
+        Index::INT64(native_index) => native_index
+            .indexes
+            .iter()
+            .map(|x| kind.page_histogram(x))
+            .collect::<Vec<_>>(),
+        Index::INT96(native_index) => native_index
+            .indexes
+            .iter()
+            .map(|x| kind.page_histogram(x))
+            .collect::<Vec<_>>(),
+        Index::FLOAT(native_index) => native_index
+            .indexes
+            .iter()
+            .map(|x| kind.page_histogram(x))
+            .collect::<Vec<_>>(),
+        Index::DOUBLE(native_index) => native_index
+            .indexes
+            .iter()
+            .map(|x| kind.page_histogram(x))
+            .collect::<Vec<_>>(),
+        Index::FIXED_LEN_BYTE_ARRAY(native_index) => native_index
+            .indexes
+            .iter()
+            .map(|x| kind.page_histogram(x))
+            .collect::<Vec<_>>(),
+        Index::BYTE_ARRAY(native_index) => native_index
+            .indexes
+            .iter()
+            .map(|x| kind.page_histogram(x))
+            .collect::<Vec<_>>(),
+    });
+
+    level_histograms_array(iter)
+}
+
 /// Extracts Parquet statistics as Arrow arrays
 ///
 /// This is used to convert Parquet statistics to Arrow [`ArrayRef`], with
@@ -1361,6 +1459,77 @@ impl<'a> StatisticsConverter<'a> {
         Ok(UInt64Array::from_iter(null_counts))
     }
 
+    /// Extract the total unencoded size of `BYTE_ARRAY` values, in bytes, from the
+    /// size statistics in [`RowGroupMetaData`]
+    ///
+    /// This is the total length of the values, excluding the length prefixes, and
+    /// so can be used to estimate the memory required to decode the column.
+    ///
+    /// The returned array contains 1 value for each row group, which is null if the
+    /// size is unknown, for example if the column is not a `BYTE_ARRAY` column or
+    /// the writer did not write size statistics.
+    ///
+    /// See docs on [`Self::row_group_mins`] for details
+    pub fn row_group_unencoded_byte_array_data_bytes<I>(&self, metadatas: I) -> Result<Int64Array>
+    where
+        I: IntoIterator<Item = &'a RowGroupMetaData>,
+    {
+        let Some(parquet_index) = self.parquet_column_index else {
+            let num_row_groups = metadatas.into_iter().count();
+            return Ok(Int64Array::new_null(num_row_groups));
+        };
+
+        let sizes = metadatas
+            .into_iter()
+            .map(|x| x.column(parquet_index).unencoded_byte_array_data_bytes());
+        Ok(Int64Array::from_iter(sizes))
+    }
+
+    /// Extract the repetition level histograms from the size statistics in
+    /// [`RowGroupMetaData`]
+    ///
+    /// The returned array contains 1 list of [`Int64Array`] for each row group,
+    /// where the value at index `i` is the number of values with repetition level `i`.
+    /// It is null if the histogram is unknown, for example if the column is not
+    /// repeated or the writer did not write size statistics.
+    ///
+    /// See docs on [`Self::row_group_mins`] for details
+    pub fn row_group_repetition_level_histograms<I>(&self, metadatas: I) -> Result<ListArray>
+    where
+        I: IntoIterator<Item = &'a RowGroupMetaData>,
+    {
+        Ok(self.row_group_level_histograms(metadatas, LevelKind::Repetition))
+    }
+
+    /// Extract the definition level histograms from the size statistics in
+    /// [`RowGroupMetaData`]
+    ///
+    /// The returned array contains 1 list of [`Int64Array`] for each row group,
+    /// where the value at index `i` is the number of values with definition level `i`.
+    /// It is null if the histogram is unknown, for example if the column is
+    /// required or the writer did not write size statistics.
+    ///
+    /// See docs on [`Self::row_group_mins`] for details
+    pub fn row_group_definition_level_histograms<I>(&self, metadatas: I) -> Result<ListArray>
+    where
+        I: IntoIterator<Item = &'a RowGroupMetaData>,
+    {
+        Ok(self.row_group_level_histograms(metadatas, LevelKind::Definition))
+    }
+
+    fn row_group_level_histograms<I>(&self, metadatas: I, kind: LevelKind) -> ListArray
+    where
+        I: IntoIterator<Item = &'a RowGroupMetaData>,
+    {
+        let metadatas = metadatas.into_iter();
+        match self.parquet_column_index {
+            Some(parquet_index) => level_histograms_array(
+                metadatas.map(|x| kind.chunk_histogram(x.column(parquet_index))),
+            ),
+            None => level_histograms_array(metadatas.map(|_| None)),
+        }
+    }
+
     /// Extract the minimum values from Data Page statistics.
     ///
     /// In Parquet files, in addition to the Column Chunk level statistics
@@ -1558,6 +1727,119 @@ impl<'a> StatisticsConverter<'a> {
         Ok(Some(UInt64Array::from_iter(row_count_total)))
     }
 
+    /// Returns an [`Int64Array`] with the total unencoded size of `BYTE_ARRAY`
+    /// values, in bytes, for each data page.
+    ///
+    /// The sizes are read from the offset index, and are null if unknown, for
+    /// example if the column is not a `BYTE_ARRAY` column or the writer did not
+    /// write size statistics.
+    ///
+    /// # Parameters:
+    ///
+    /// * `column_offset_index`: The parquet column offset indices, read from
+    ///   `ParquetMetaData` offset_index
+    ///
+    /// * `row_group_indices`: The indices of the row groups, that are used to
+    ///   extract the column offset index on a per row group per column basis.
+    ///
+    /// See docs on [`Self::data_page_mins`] for details.
+    pub fn data_page_unencoded_byte_array_data_bytes<I>(
+        &self,
+        column_offset_index: &ParquetOffsetIndex,
+        row_group_indices: I,
+    ) -> Result<Int64Array>
+    where
+        I: IntoIterator<Item = &'a usize>,
+    {
+        let Some(parquet_index) = self.parquet_column_index else {
+            let num_row_groups = row_group_indices.into_iter().count();
+            return Ok(Int64Array::new_null(num_row_groups));
+        };
+
+        let mut builder = Int64Array::builder(10);
+        for rg_idx in row_group_indices {
+            let offset_index = &column_offset_index[*rg_idx][parquet_index];
+            match offset_index.unencoded_byte_array_data_bytes() {
+                Some(sizes) => builder.append_slice(sizes),
+                None => builder.append_nulls(offset_index.page_locations().len()),
+            }
+        }
+        Ok(builder.finish())
+    }
+
+    /// Returns a [`ListArray`] with the repetition level histogram for each data page.
+    ///
+    /// The histograms are read from the column index, and are null if unknown.
+    /// See [`Self::row_group_repetition_level_histograms`] for the format of the histograms.
+    ///
+    /// See docs on [`Self::data_page_mins`] for details.
+    pub fn data_page_repetition_level_histograms<I>(
+        &self,
+        column_page_index: &ParquetColumnIndex,
+        column_offset_index: &ParquetOffsetIndex,
+        row_group_indices: I,
+    ) -> Result<ListArray>
+    where
+        I: IntoIterator<Item = &'a usize>,
+    {
+        Ok(self.data_page_level_histograms(
+            column_page_index,
+            column_offset_index,
+            row_group_indices,
+            LevelKind::Repetition,
+        ))
+    }
+
+    /// Returns a [`ListArray`] with the definition level histogram for each data page.
+    ///
+    /// The histograms are read from the column index, and are null if unknown.
+    /// See [`Self::row_group_definition_level_histograms`] for the format of the histograms.
+    ///
+    /// See docs on [`Self::data_page_mins`] for details.
+    pub fn data_page_definition_level_histograms<I>(
+        &self,
+        column_page_index: &ParquetColumnIndex,
+        column_offset_index: &ParquetOffsetIndex,
+        row_group_indices: I,
+    ) -> Result<ListArray>
+    where
+        I: IntoIterator<Item = &'a usize>,
+    {
+        Ok(self.data_page_level_histograms(
+            column_page_index,
+            column_offset_index,
+            row_group_indices,
+            LevelKind::Definition,
+        ))
+    }
+
+    fn data_page_level_histograms<I>(
+        &self,
+        column_page_index: &ParquetColumnIndex,
+        column_offset_index: &ParquetOffsetIndex,
+        row_group_indices: I,
+        kind: LevelKind,
+    ) -> ListArray
+    where
+        I: IntoIterator<Item = &'a usize>,
+    {
+        let Some(parquet_index) = self.parquet_column_index else {
+            let num_row_groups = row_group_indices.into_iter().count();
+            return level_histograms_array(std::iter::repeat(None).take(num_row_groups));
+        };
+
+        let iter = row_group_indices.into_iter().map(|rg_index| {
+            let column_page_index_per_row_group_per_column =
+                &column_page_index[*rg_index][parquet_index];
+            let num_data_pages = &column_offset_index[*rg_index][parquet_index]
+                .page_locations()
+                .len();
+
+            (*num_data_pages, column_page_index_per_row_group_per_column)
+        });
+        level_histograms_page_statistics(iter, kind)
+    }
+
     /// Returns a null array of data_type with one element per row group
     fn make_null_array<I, A>(&self, data_type: &DataType, metadatas: I) -> ArrayRef
     where
@@ -1572,12 +1854,12 @@ impl<'a> StatisticsConverter<'a> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::arrow::arrow_reader::ArrowReaderBuilder;
+    use crate::arrow::arrow_reader::{ArrowReaderBuilder, ArrowReaderOptions};
     use crate::arrow::arrow_writer::ArrowWriter;
     use crate::file::metadata::{ParquetMetaData, RowGroupMetaData};
     use crate::file::properties::{EnabledStatistics, WriterProperties};
     use arrow::compute::kernels::cast_utils::Parser;
-    use arrow::datatypes::{i256, Date32Type, Date64Type};
+    use arrow::datatypes::{i256, Date32Type, Date64Type, Int64Type};
     use arrow::util::test_util::parquet_test_data;
     use arrow_array::{
         new_empty_array, new_null_array, Array, ArrayRef, BinaryArray, BinaryViewArray,
@@ -2251,6 +2533,113 @@ mod test {
         );
     }
 
+    #[test]
+    fn size_statistics() {
+        let string_col: ArrayRef =
+            Arc::new(StringArray::from(vec![Some("a"), None, Some("bbb"), None]));
+        let int_col: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4]));
+        let input_batch =
+            RecordBatch::try_from_iter([("string_col", string_col), ("int_col", int_col)]).unwrap();
+        let schema = input_batch.schema();
+
+        // write two data pages of two rows each
+        let props = WriterProperties::builder()
+            .set_write_batch_size(2)
+            .set_data_page_row_count_limit(2)
+            .build();
+        let mut buffer = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buffer, schema.clone(), Some(props)).unwrap();
+        writer.write(&input_batch).unwrap();
+        writer.close().unwrap();
+
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let reader =
+            ArrowReaderBuilder::try_new_with_options(Bytes::from(buffer), options).unwrap();
+        let metadata = reader.metadata();
+        let parquet_schema = metadata.file_metadata().schema_descr();
+        let row_groups = metadata.row_groups();
+        let column_index = metadata.column_index().unwrap();
+        let offset_index = metadata.offset_index().unwrap();
+        let row_group_indices = [0];
+
+        let histograms = |values: Vec<Option<Vec<i64>>>| {
+            ListArray::from_iter_primitive::<Int64Type, _, _>(
+                values
+                    .into_iter()
+                    .map(|x| x.map(|x| x.into_iter().map(Some))),
+            )
+        };
+
+        let converter =
+            StatisticsConverter::try_new("string_col", &schema, parquet_schema).unwrap();
+        assert_eq!(
+            converter
+                .row_group_unencoded_byte_array_data_bytes(row_groups)
+                .unwrap(),
+            Int64Array::from(vec![4])
+        );
+        assert_eq!(
+            converter
+                .row_group_definition_level_histograms(row_groups)
+                .unwrap(),
+            histograms(vec![Some(vec![2, 2])])
+        );
+        // the column is not repeated
+        assert_eq!(
+            converter
+                .row_group_repetition_level_histograms(row_groups)
+                .unwrap(),
+            histograms(vec![None])
+        );
+        assert_eq!(
+            converter
+                .data_page_unencoded_byte_array_data_bytes(offset_index, &row_group_indices)
+                .unwrap(),
+            Int64Array::from(vec![1, 3])
+        );
+        assert_eq!(
+            converter
+                .data_page_definition_level_histograms(
+                    column_index,
+                    offset_index,
+                    &row_group_indices
+                )
+                .unwrap(),
+            histograms(vec![Some(vec![1, 1]), Some(vec![1, 1])])
+        );
+        assert_eq!(
+            converter
+                .data_page_repetition_level_histograms(
+                    column_index,
+                    offset_index,
+                    &row_group_indices
+                )
+                .unwrap(),
+            histograms(vec![None, None])
+        );
+
+        // the unencoded size is only recorded for BYTE_ARRAY columns
+        let converter = StatisticsConverter::try_new("int_col", &schema, parquet_schema).unwrap();
+        assert_eq!(
+            converter
+                .row_group_unencoded_byte_array_data_bytes(row_groups)
+                .unwrap(),
+            Int64Array::from(vec![None])
+        );
+        assert_eq!(
+            converter
+                .data_page_unencoded_byte_array_data_bytes(offset_index, &row_group_indices)
+                .unwrap(),
+            Int64Array::from(vec![None, None])
+        );
+        assert_eq!(
+            converter
+                .row_group_definition_level_histograms(row_groups)
+                .unwrap(),
+            histograms(vec![None])
+        );
+    }
+
     #[test]
     fn nan_in_stats() {
         // /parquet-testing/data/nan_in_stats.parquet
@@ -40,11 +40,19 @@
 //!
 //! \[1\] [parquet-format#nested-encoding](https://github.com/apache/parquet-format#nested-encoding)
 
+use crate::arrow::schema::union_struct_fields;
 use crate::errors::{ParquetError, Result};
-use arrow_array::cast::AsArray;
-use arrow_array::{Array, ArrayRef, OffsetSizeTrait};
-use arrow_buffer::{NullBuffer, OffsetBuffer};
-use arrow_schema::{DataType, Field};
+use arrow_array::cast::{as_run_array, as_union_array, AsArray};
+use arrow_array::types::{
+    ArrowDictionaryKeyType, Int16Type, Int32Type, Int64Type, RunEndIndexType,
+};
+use arrow_array::{
+    Array, ArrayRef, DictionaryArray, Int8Array, OffsetSizeTrait, PrimitiveArray, RunArray,
+    StructArray, UInt32Array, UnionArray,
+};
+use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer};
+use arrow_schema::{DataType, Field, UnionFields};
+use arrow_select::take::take;
 use std::ops::Range;
 use std::sync::Arc;
 
@@ -219,6 +227,21 @@ impl LevelInfoBuilder {
                     _ => unreachable!(),
                 })
             }
+            DataType::RunEndEncoded(r, v) if is_leaf(v.data_type()) => {
+                let array = match r.data_type() {
+                    DataType::Int16 => run_array_values(as_run_array::<Int16Type>(array))?,
+                    DataType::Int32 => run_array_values(as_run_array::<Int32Type>(array))?,
+                    DataType::Int64 => run_array_values(as_run_array::<Int64Type>(array))?,
+                    d => unreachable!("invalid run end type {d}"),
+                };
+                let levels = ArrayLevels::new(parent_ctx, is_nullable, array);
+                Ok(Self::Primitive(levels))
+            }
+            DataType::Union(fields, _) => {
+                let array = union_to_struct(as_union_array(array), fields)?;
+                let field = Field::new(field.name(), array.data_type().clone(), is_nullable);
+                Self::try_new(&field, parent_ctx, &(Arc::new(array) as ArrayRef))
+            }
             d => Err(nyi_err!("Datatype {} is not yet supported", d)),
         }
     }
@@ -580,6 +603,59 @@ impl PartialEq for ArrayLevels {
 }
 impl Eq for ArrayLevels {}
 
+/// Returns the logical values of a [`RunArray`]
+///
+/// Byte array values are returned as a [`DictionaryArray`] keyed by the physical index of
+/// each row, avoiding copying the values, other values are expanded with [`take`]
+fn run_array_values<R>(array: &RunArray<R>) -> Result<ArrayRef>
+where
+    R: RunEndIndexType + ArrowDictionaryKeyType,
+{
+    let run_ends = array.run_ends();
+    let mut keys = Vec::with_capacity(run_ends.len());
+    if !run_ends.is_empty() {
+        let start = array.get_start_physical_index();
+        let end = array.get_end_physical_index();
+        for (physical, run_end) in run_ends.values()[start..=end].iter().enumerate() {
+            let run_end = (run_end.as_usize() - run_ends.offset()).min(run_ends.len());
+            let key = R::Native::usize_as(start + physical);
+            keys.resize(run_end, key);
+        }
+    }
+    let keys = PrimitiveArray::<R>::new(keys.into(), None);
+
+    Ok(match array.values().data_type() {
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Binary | DataType::LargeBinary => {
+            Arc::new(DictionaryArray::new(keys, array.values().clone()))
+        }
+        _ => take(array.values(), &keys, None)?,
+    })
+}
+
+/// Converts a [`UnionArray`] to a [`StructArray`] with [`union_struct_fields`]
+///
+/// Each child is null for the rows of the union that have a different type
+fn union_to_struct(array: &UnionArray, fields: &UnionFields) -> Result<StructArray> {
+    let type_ids = array.type_ids();
+    let mut columns = Vec::with_capacity(fields.len() + 1);
+    columns.push(Arc::new(Int8Array::new(type_ids.clone(), None)) as ArrayRef);
+
+    for (type_id, _) in fields.iter() {
+        let indices: UInt32Array = type_ids
+            .iter()
+            .enumerate()
+            .map(|(idx, t)| (*t == type_id).then(|| array.value_offset(idx) as u32))
+            .collect();
+        columns.push(take(array.child(type_id), &indices, None)?);
+    }
+
+    Ok(StructArray::try_new(
+        union_struct_fields(fields),
+        columns,
+        None,
+    )?)
+}
+
 impl ArrayLevels {
     fn new(ctx: LevelContext, is_nullable: bool, array: ArrayRef) -> Self {
         let max_rep_level = ctx.rep_level;
@@ -32,7 +32,7 @@ use arrow_schema::{ArrowError, DataType as ArrowDataType, Field, IntervalUnit, S
 
 use super::schema::{
     add_encoded_arrow_schema_to_metadata, arrow_to_parquet_schema,
-    arrow_to_parquet_schema_with_root, decimal_length_from_precision,
+    arrow_to_parquet_schema_with_root, decimal_length_from_precision, union_struct_fields,
 };
 
 use crate::arrow::arrow_writer::byte_array::ByteArrayEncoder;
@@ -42,11 +42,14 @@ use crate::column::writer::{
     get_column_writer, ColumnCloseResult, ColumnWriter, GenericColumnWriter,
 };
 use crate::data_type::{ByteArray, FixedLenByteArray};
+#[cfg(feature = "encryption")]
+use crate::encryption::encrypt::{FileEncryptor, PageEncryptor};
 use crate::errors::{ParquetError, Result};
 use crate::file::metadata::{KeyValue, RowGroupMetaData};
 use crate::file::properties::{WriterProperties, WriterPropertiesPtr};
 use crate::file::reader::{ChunkReader, Length};
 use crate::file::writer::{SerializedFileWriter, SerializedRowGroupWriter};
+use crate::format::PageHeader;
 use crate::schema::types::{ColumnDescPtr, SchemaDescriptor};
 use crate::thrift::TSerializable;
 use levels::{calculate_array_levels, ArrayLevels};
@@ -160,7 +163,7 @@ impl<W: Write + Send> ArrowWriter<W> {
     ///
     /// The writer will fail if:
     ///  * a `SerializedFileWriter` cannot be created from the ParquetWriter
-    ///  * the Arrow schema contains unsupported datatypes such as Unions
+    ///  * the Arrow schema contains unsupported datatypes such as run-end encoded nested types
     pub fn try_new(
         writer: W,
         arrow_schema: SchemaRef,
@@ -174,7 +177,7 @@ impl<W: Write + Send> ArrowWriter<W> {
     ///
     /// The writer will fail if:
     ///  * a `SerializedFileWriter` cannot be created from the ParquetWriter
-    ///  * the Arrow schema contains unsupported datatypes such as Unions
+    ///  * the Arrow schema contains unsupported datatypes such as run-end encoded nested types
     pub fn try_new_with_options(
         writer: W,
         arrow_schema: SchemaRef,
@@ -263,11 +266,20 @@ impl<W: Write + Send> ArrowWriter<W> {
 
         let in_progress = match &mut self.in_progress {
             Some(in_progress) => in_progress,
-            x => x.insert(ArrowRowGroupWriter::new(
-                self.writer.schema_descr(),
-                self.writer.properties(),
-                &self.arrow_schema,
-            )?),
+            x => {
+                let factory = ArrowColumnWriterFactory::new();
+                #[cfg(feature = "encryption")]
+                let factory = factory.with_file_encryptor(
+                    self.writer.flushed_row_groups().len(),
+                    self.writer.file_encryptor(),
+                );
+                x.insert(ArrowRowGroupWriter::new(
+                    self.writer.schema_descr(),
+                    self.writer.properties(),
+                    &self.arrow_schema,
+                    &factory,
+                )?)
+            }
         };
 
         // If would exceed max_row_group_size, split batch
@@ -458,19 +470,51 @@ type SharedColumnChunk = Arc<Mutex<ArrowColumnChunkData>>;
 #[derive(Default)]
 struct ArrowPageWriter {
     buffer: SharedColumnChunk,
+    write_checksum: bool,
+    #[cfg(feature = "encryption")]
+    page_encryptor: Option<PageEncryptor>,
+}
+
+impl ArrowPageWriter {
+    /// Sets whether to write a CRC32 checksum of each page into its header
+    fn with_page_checksum(mut self, write_checksum: bool) -> Self {
+        self.write_checksum = write_checksum;
+        self
+    }
+
+    /// Sets the [`PageEncryptor`] used to encrypt the pages and page headers
+    #[cfg(feature = "encryption")]
+    fn with_page_encryptor(mut self, page_encryptor: Option<PageEncryptor>) -> Self {
+        self.page_encryptor = page_encryptor;
+        self
+    }
+
+    /// Serializes, and if necessary encrypts, the page header
+    fn serialize_page_header(&mut self, page_header: &PageHeader) -> Result<Bytes> {
+        #[cfg(feature = "encryption")]
+        if let Some(page_encryptor) = self.page_encryptor.as_mut() {
+            let header = page_encryptor.encrypt_page_header(page_header)?;
+            return Ok(Bytes::from(header));
+        }
+        let mut header = Vec::with_capacity(1024);
+        let mut protocol = TCompactOutputProtocol::new(&mut header);
+        page_header.write_to_out_protocol(&mut protocol)?;
+        Ok(Bytes::from(header))
+    }
 }
 
 impl PageWriter for ArrowPageWriter {
     fn write_page(&mut self, page: CompressedPage) -> Result<PageWriteSpec> {
-        let mut buf = self.buffer.try_lock().unwrap();
-        let page_header = page.to_thrift_header();
-        let header = {
-            let mut header = Vec::with_capacity(1024);
-            let mut protocol = TCompactOutputProtocol::new(&mut header);
-            page_header.write_to_out_protocol(&mut protocol)?;
-            Bytes::from(header)
+        #[cfg(feature = "encryption")]
+        let page = match self.page_encryptor.as_ref() {
+            Some(page_encryptor) => page_encryptor.encrypt_page(page)?,
+            None => page,
         };
 
+        let page_header = page.to_thrift_header(self.write_checksum);
+        let header = self.serialize_page_header(&page_header)?;
+        let mut buf = self.buffer.try_lock().unwrap();
+
         let data = page.compressed_page().buffer().clone();
         let compressed_size = data.len() + header.len();
 
@@ -689,8 +733,9 @@ impl ArrowRowGroupWriter {
         parquet: &SchemaDescriptor,
         props: &WriterPropertiesPtr,
         arrow: &SchemaRef,
+        factory: &ArrowColumnWriterFactory,
     ) -> Result<Self> {
-        let writers = get_column_writers(parquet, props, arrow)?;
+        let writers = get_column_writers_with_factory(parquet, props, arrow, factory)?;
         Ok(Self {
             writers,
             schema: arrow.clone(),
@@ -717,16 +762,99 @@ impl ArrowRowGroupWriter {
     }
 }
 
+/// Creates the [`ArrowPageWriter`] of each column of a row group
+struct ArrowColumnWriterFactory {
+    #[cfg(feature = "encryption")]
+    row_group_index: usize,
+    #[cfg(feature = "encryption")]
+    file_encryptor: Option<Arc<FileEncryptor>>,
+}
+
+impl ArrowColumnWriterFactory {
+    fn new() -> Self {
+        Self {
+            #[cfg(feature = "encryption")]
+            row_group_index: 0,
+            #[cfg(feature = "encryption")]
+            file_encryptor: None,
+        }
+    }
+
+    /// Encrypts the pages of the encrypted columns of the row group at `row_group_index`
+    #[cfg(feature = "encryption")]
+    fn with_file_encryptor(
+        mut self,
+        row_group_index: usize,
+        file_encryptor: Option<Arc<FileEncryptor>>,
+    ) -> Self {
+        self.row_group_index = row_group_index;
+        self.file_encryptor = file_encryptor;
+        self
+    }
+
+    /// Creates the [`ArrowPageWriter`] of the column at `column_index`
+    #[cfg(feature = "encryption")]
+    fn create_page_writer(
+        &self,
+        column: &ColumnDescPtr,
+        props: &WriterPropertiesPtr,
+        column_index: usize,
+    ) -> Result<Box<ArrowPageWriter>> {
+        let page_encryptor = match &self.file_encryptor {
+            Some(file_encryptor) => file_encryptor
+                .column_encryptor(column.path(), self.row_group_index, column_index)?
+                .map(PageEncryptor::new),
+            None => None,
+        };
+        Ok(Box::new(
+            ArrowPageWriter::default()
+                .with_page_checksum(props.write_page_checksum())
+                .with_page_encryptor(page_encryptor),
+        ))
+    }
+
+    /// Creates the [`ArrowPageWriter`] of the column at `column_index`
+    #[cfg(not(feature = "encryption"))]
+    fn create_page_writer(
+        &self,
+        _column: &ColumnDescPtr,
+        props: &WriterPropertiesPtr,
+        _column_index: usize,
+    ) -> Result<Box<ArrowPageWriter>> {
+        Ok(Box::new(
+            ArrowPageWriter::default().with_page_checksum(props.write_page_checksum()),
+        ))
+    }
+}
+
 /// Returns the [`ArrowColumnWriter`] for a given schema
+///
+/// Returns an error if the properties enable encryption, as the columns
+/// can then only be encoded by [`ArrowWriter`]
 pub fn get_column_writers(
     parquet: &SchemaDescriptor,
     props: &WriterPropertiesPtr,
     arrow: &SchemaRef,
+) -> Result<Vec<ArrowColumnWriter>> {
+    #[cfg(feature = "encryption")]
+    if props.file_encryption_properties().is_some() {
+        return Err(general_err!(
+            "get_column_writers does not support writing encrypted files, use ArrowWriter instead"
+        ));
+    }
+    get_column_writers_with_factory(parquet, props, arrow, &ArrowColumnWriterFactory::new())
+}
+
+fn get_column_writers_with_factory(
+    parquet: &SchemaDescriptor,
+    props: &WriterPropertiesPtr,
+    arrow: &SchemaRef,
+    factory: &ArrowColumnWriterFactory,
 ) -> Result<Vec<ArrowColumnWriter>> {
     let mut writers = Vec::with_capacity(arrow.fields.len());
     let mut leaves = parquet.columns().iter();
     for field in &arrow.fields {
-        get_arrow_column_writer(field.data_type(), props, &mut leaves, &mut writers)?;
+        get_arrow_column_writer(field.data_type(), props, &mut leaves, &mut writers, factory)?;
     }
     Ok(writers)
 }
@@ -737,64 +865,75 @@ fn get_arrow_column_writer(
     props: &WriterPropertiesPtr,
     leaves: &mut Iter<'_, ColumnDescPtr>,
     out: &mut Vec<ArrowColumnWriter>,
+    factory: &ArrowColumnWriterFactory,
 ) -> Result<()> {
-    let col = |desc: &ColumnDescPtr| {
-        let page_writer = Box::<ArrowPageWriter>::default();
+    // The leaves are visited in order, and so the column index is the number of writers
+    let col = |desc: &ColumnDescPtr, column_index: usize| -> Result<ArrowColumnWriter> {
+        let page_writer = factory.create_page_writer(desc, props, column_index)?;
         let chunk = page_writer.buffer.clone();
         let writer = get_column_writer(desc.clone(), props.clone(), page_writer);
-        ArrowColumnWriter {
+        Ok(ArrowColumnWriter {
             chunk,
             writer: ArrowColumnWriterImpl::Column(writer),
-        }
+        })
     };
 
-    let bytes = |desc: &ColumnDescPtr| {
-        let page_writer = Box::<ArrowPageWriter>::default();
+    let bytes = |desc: &ColumnDescPtr, column_index: usize| -> Result<ArrowColumnWriter> {
+        let page_writer = factory.create_page_writer(desc, props, column_index)?;
         let chunk = page_writer.buffer.clone();
         let writer = GenericColumnWriter::new(desc.clone(), props.clone(), page_writer);
-        ArrowColumnWriter {
+        Ok(ArrowColumnWriter {
             chunk,
             writer: ArrowColumnWriterImpl::ByteArray(writer),
-        }
+        })
     };
 
     match data_type {
-        _ if data_type.is_primitive() => out.push(col(leaves.next().unwrap())),
-        ArrowDataType::FixedSizeBinary(_) | ArrowDataType::Boolean | ArrowDataType::Null => out.push(col(leaves.next().unwrap())),
+        _ if data_type.is_primitive() => out.push(col(leaves.next().unwrap(), out.len())?),
+        ArrowDataType::FixedSizeBinary(_) | ArrowDataType::Boolean | ArrowDataType::Null => out.push(col(leaves.next().unwrap(), out.len())?),
         ArrowDataType::LargeBinary
         | ArrowDataType::Binary
         | ArrowDataType::Utf8
         | ArrowDataType::LargeUtf8
         | ArrowDataType::BinaryView
         | ArrowDataType::Utf8View => {
-            out.push(bytes(leaves.next().unwrap()))
+            out.push(bytes(leaves.next().unwrap(), out.len())?)
         }
         ArrowDataType::List(f)
         | ArrowDataType::LargeList(f)
         | ArrowDataType::FixedSizeList(f, _) => {
-            get_arrow_column_writer(f.data_type(), props, leaves, out)?
+            get_arrow_column_writer(f.data_type(), props, leaves, out, factory)?
         }
         ArrowDataType::Struct(fields) => {
             for field in fields {
-                get_arrow_column_writer(field.data_type(), props, leaves, out)?
+                get_arrow_column_writer(field.data_type(), props, leaves, out, factory)?
             }
         }
+        ArrowDataType::Union(fields, _) => {
+            let fields = union_struct_fields(fields);
+            for field in &fields {
+                get_arrow_column_writer(field.data_type(), props, leaves, out, factory)?
+            }
+        }
+        ArrowDataType::RunEndEncoded(_, values) => {
+            get_arrow_column_writer(values.data_type(), props, leaves, out, factory)?
+        }
         ArrowDataType::Map(f, _) => match f.data_type() {
             ArrowDataType::Struct(f) => {
-                get_arrow_column_writer(f[0].data_type(), props, leaves, out)?;
-                get_arrow_column_writer(f[1].data_type(), props, leaves, out)?
+                get_arrow_column_writer(f[0].data_type(), props, leaves, out, factory)?;
+                get_arrow_column_writer(f[1].data_type(), props, leaves, out, factory)?
             }
             _ => unreachable!("invalid map type"),
         }
         ArrowDataType::Dictionary(_, value_type) => match value_type.as_ref() {
             ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 | ArrowDataType::Binary | ArrowDataType::LargeBinary => {
-                out.push(bytes(leaves.next().unwrap()))
+                out.push(bytes(leaves.next().unwrap(), out.len())?)
             }
             ArrowDataType::Utf8View | ArrowDataType::BinaryView => {
-                out.push(bytes(leaves.next().unwrap()))
+                out.push(bytes(leaves.next().unwrap(), out.len())?)
             }
             _ => {
-                out.push(col(leaves.next().unwrap()))
+                out.push(col(leaves.next().unwrap(), out.len())?)
             }
         }
        _ => return Err(ParquetError::NYI(
@@ -1078,14 +1217,14 @@ mod tests {
     use std::fs::File;
 
     use crate::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
-    use crate::arrow::ARROW_SCHEMA_META_KEY;
+    use crate::arrow::{ProjectionMask, ARROW_SCHEMA_META_KEY};
     use arrow::datatypes::ToByteSlice;
     use arrow::datatypes::{DataType, Schema};
     use arrow::error::Result as ArrowResult;
     use arrow::util::pretty::pretty_format_batches;
     use arrow::{array::*, buffer::Buffer};
-    use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano, NullBuffer};
-    use arrow_schema::Fields;
+    use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano, NullBuffer, OffsetBuffer};
+    use arrow_schema::{Fields, UnionFields};
 
     use crate::basic::Encoding;
     use crate::data_type::AsBytes;
@@ -2101,29 +2240,183 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Converting Duration to parquet not supported")]
     fn duration_second_single_column() {
         required_and_optional::<DurationSecondArray, _>(0..SMALL_SIZE as i64);
     }
 
     #[test]
-    #[should_panic(expected = "Converting Duration to parquet not supported")]
     fn duration_millisecond_single_column() {
         required_and_optional::<DurationMillisecondArray, _>(0..SMALL_SIZE as i64);
     }
 
     #[test]
-    #[should_panic(expected = "Converting Duration to parquet not supported")]
     fn duration_microsecond_single_column() {
         required_and_optional::<DurationMicrosecondArray, _>(0..SMALL_SIZE as i64);
     }
 
     #[test]
-    #[should_panic(expected = "Converting Duration to parquet not supported")]
     fn duration_nanosecond_single_column() {
         required_and_optional::<DurationNanosecondArray, _>(0..SMALL_SIZE as i64);
     }
 
+    #[test]
+    fn run_end_encoded_single_column() {
+        let values = StringArray::from(vec![Some("foo"), None, Some("bar"), Some("foo")]);
+        let run_ends = Int32Array::from(vec![3, 100, 101, 200]);
+        let array = RunArray::try_new(&run_ends, &values).unwrap();
+        one_column_roundtrip(Arc::new(array.clone()), true);
+        one_column_roundtrip(Arc::new(array.slice(2, 150)), true);
+
+        let values = Int64Array::from_iter_values(0..20);
+        let run_ends = Int16Array::from_iter_values((1..=20).map(|x| x * 10));
+        let array = RunArray::try_new(&run_ends, &values).unwrap();
+        one_column_roundtrip(Arc::new(array), false);
+    }
+
+    #[test]
+    fn run_end_encoded_nested_values() {
+        let values = Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef;
+        let values = StructArray::from(vec![(
+            Arc::new(Field::new("a", DataType::Int32, false)),
+            values,
+        )]);
+        let array = RunArray::try_new(&Int32Array::from(vec![3, 4]), &values).unwrap();
+        let schema = Schema::new(vec![Field::new("col", array.data_type().clone(), false)]);
+
+        let err = ArrowWriter::try_new(Vec::new(), Arc::new(schema), None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Converting RunEndEncodedType with nested values to parquet not supported"
+        );
+    }
+
+    #[test]
+    fn union_single_column() {
+        let fields = UnionFields::new(
+            vec![0, 3],
+            vec![
+                Field::new("int", DataType::Int32, false),
+                Field::new("str", DataType::Utf8, true),
+            ],
+        );
+        let type_ids: Vec<i8> = (0..200).map(|x| if x % 3 == 0 { 3 } else { 0 }).collect();
+
+        let ints = Int32Array::from_iter_values(0..200);
+        let strings: StringArray = (0..200)
+            .map(|x| (x % 2 == 0).then(|| x.to_string()))
+            .collect();
+        let sparse = UnionArray::try_new(
+            fields.clone(),
+            type_ids.clone().into(),
+            None,
+            vec![Arc::new(ints), Arc::new(strings)],
+        )
+        .unwrap();
+        one_column_roundtrip(Arc::new(sparse.clone()), false);
+        one_column_roundtrip(Arc::new(sparse.slice(7, 100)), false);
+
+        let mut counts = [0; 4];
+        let offsets: Vec<i32> = type_ids
+            .iter()
+            .map(|t| {
+                counts[*t as usize] += 1;
+                counts[*t as usize] - 1
+            })
+            .collect();
+        let ints = Int32Array::from_iter_values(0..counts[0]);
+        let strings: StringArray = (0..counts[3])
+            .map(|x| (x % 2 == 0).then(|| x.to_string()))
+            .collect();
+        let dense = UnionArray::try_new(
+            fields,
+            type_ids.into(),
+            Some(offsets.into()),
+            vec![Arc::new(ints), Arc::new(strings)],
+        )
+        .unwrap();
+        one_column_roundtrip(Arc::new(dense), false);
+    }
+
+    #[test]
+    fn union_nested() {
+        let fields = UnionFields::new(
+            vec![0, 1],
+            vec![
+                Field::new_list("list", Field::new("item", DataType::Int32, true), true),
+                Field::new("bool", DataType::Boolean, false),
+            ],
+        );
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4)]),
+        ]);
+        let union = UnionArray::try_new(
+            fields.clone(),
+            vec![0, 1, 0, 1].into(),
+            None,
+            vec![
+                Arc::new(list),
+                Arc::new(BooleanArray::from(vec![true, true, false, false])),
+            ],
+        )
+        .unwrap();
+        let list = ListArray::new(
+            Arc::new(Field::new("item", union.data_type().clone(), false)),
+            OffsetBuffer::from_lengths([1, 0, 3]),
+            Arc::new(union),
+            Some(NullBuffer::from(vec![true, false, true])),
+        );
+
+        let batch = RecordBatch::try_from_iter([("col", Arc::new(list) as ArrayRef)]).unwrap();
+        roundtrip(batch, None);
+    }
+
+    #[test]
+    fn union_projection() {
+        let fields = UnionFields::new(
+            vec![0, 1],
+            vec![
+                Field::new("a", DataType::Int32, false),
+                Field::new("b", DataType::Float64, false),
+            ],
+        );
+        let union = UnionArray::try_new(
+            fields,
+            vec![0, 1, 1].into(),
+            None,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(Float64Array::from(vec![1., 2., 3.])),
+            ],
+        )
+        .unwrap();
+        let batch = RecordBatch::try_from_iter([("col", Arc::new(union) as ArrayRef)]).unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        // Projecting a subset of the union children reads the stored struct
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap();
+        let mask = ProjectionMask::leaves(builder.parquet_schema(), [0, 2]);
+        let batch = builder
+            .with_projection(mask)
+            .build()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        let s = batch.column(0).as_struct();
+        assert_eq!(s.column_names(), &["type_id", "b"]);
+        assert_eq!(s.column(0).as_primitive::<Int8Type>().values(), &[0, 1, 1]);
+        let b = s.column(1).as_primitive::<Float64Type>();
+        assert_eq!(b, &Float64Array::from(vec![None, Some(2.), Some(3.)]));
+    }
+
     #[test]
     fn interval_year_month_single_column() {
         required_and_optional::<IntervalYearMonthArray, _>(0..SMALL_SIZE as i32);
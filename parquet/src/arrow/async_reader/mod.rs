@@ -75,7 +75,7 @@
 //! # }
 //! ```
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Formatter;
 use std::io::SeekFrom;
 use std::ops::Range;
@@ -95,7 +95,7 @@ use arrow_schema::{DataType, Fields, Schema, SchemaRef};
 use crate::arrow::array_reader::{build_array_reader, RowGroups};
 use crate::arrow::arrow_reader::{
     apply_range, evaluate_predicate, selects_any, ArrowReaderBuilder, ArrowReaderMetadata,
-    ArrowReaderOptions, ParquetRecordBatchReader, RowFilter, RowSelection,
+    ArrowReaderOptions, BoundPredicate, ParquetRecordBatchReader, RowFilter, RowSelection,
 };
 use crate::arrow::ProjectionMask;
 
@@ -105,8 +105,9 @@ use crate::bloom_filter::{
 use crate::column::page::{PageIterator, PageReader};
 use crate::errors::{ParquetError, Result};
 use crate::file::footer::{decode_footer, decode_metadata};
-use crate::file::metadata::{ParquetMetaData, RowGroupMetaData};
+use crate::file::metadata::{ColumnChunkMetaData, ParquetMetaData, RowGroupMetaData};
 use crate::file::page_index::offset_index::OffsetIndexMetaData;
+use crate::file::properties::{ReaderProperties, ReaderPropertiesPtr};
 use crate::file::reader::{ChunkReader, Length, SerializedPageReader};
 use crate::file::FOOTER_SIZE;
 use crate::format::{BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash};
@@ -348,60 +349,14 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
     ) -> Result<Option<Sbbf>> {
         let metadata = self.metadata.row_group(row_group_idx);
         let column_metadata = metadata.column(column_idx);
-
-        let offset: usize = if let Some(offset) = column_metadata.bloom_filter_offset() {
-            offset
-                .try_into()
-                .map_err(|_| ParquetError::General("Bloom filter offset is invalid".to_string()))?
-        } else {
-            return Ok(None);
-        };
-
-        let buffer = match column_metadata.bloom_filter_length() {
-            Some(length) => self.input.0.get_bytes(offset..offset + length as usize),
-            None => self
-                .input
-                .0
-                .get_bytes(offset..offset + SBBF_HEADER_SIZE_ESTIMATE),
-        }
-        .await?;
-
-        let (header, bitset_offset) =
-            chunk_read_bloom_filter_header_and_offset(offset as u64, buffer.clone())?;
-
-        match header.algorithm {
-            BloomFilterAlgorithm::BLOCK(_) => {
-                // this match exists to future proof the singleton algorithm enum
-            }
-        }
-        match header.compression {
-            BloomFilterCompression::UNCOMPRESSED(_) => {
-                // this match exists to future proof the singleton compression enum
-            }
-        }
-        match header.hash {
-            BloomFilterHash::XXHASH(_) => {
-                // this match exists to future proof the singleton hash enum
-            }
-        }
-
-        let bitset = match column_metadata.bloom_filter_length() {
-            Some(_) => buffer.slice((bitset_offset as usize - offset)..),
-            None => {
-                let bitset_length: usize = header.num_bytes.try_into().map_err(|_| {
-                    ParquetError::General("Bloom filter length is invalid".to_string())
-                })?;
-                self.input
-                    .0
-                    .get_bytes(bitset_offset as usize..bitset_offset as usize + bitset_length)
-                    .await?
-            }
-        };
-        Ok(Some(Sbbf::new(&bitset)))
+        read_bloom_filter(&mut self.input.0, column_metadata).await
     }
 
     /// Build a new [`ParquetRecordBatchStream`]
-    pub fn build(self) -> Result<ParquetRecordBatchStream<T>> {
+    pub fn build(mut self) -> Result<ParquetRecordBatchStream<T>> {
+        // Bloom filters are checked by ReaderFactory before reading each row group
+        let predicate = self.apply_predicate(|_, _| Ok(None))?;
+
         let num_row_groups = self.metadata.row_groups().len();
 
         let row_groups = match self.row_groups {
@@ -429,6 +384,12 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
             fields: self.fields,
             limit: self.limit,
             offset: self.offset,
+            predicate,
+            props: Arc::new(
+                ReaderProperties::builder()
+                    .set_verify_page_checksum(self.verify_page_checksum)
+                    .build(),
+            ),
         };
 
         // Ensure schema of ParquetRecordBatchStream respects projection, and does
@@ -455,6 +416,60 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
     }
 }
 
+/// Read the bloom filter of the column chunk described by `column_metadata`
+/// Returns `None` if the column does not have a bloom filter
+async fn read_bloom_filter<T: AsyncFileReader>(
+    input: &mut T,
+    column_metadata: &ColumnChunkMetaData,
+) -> Result<Option<Sbbf>> {
+    let offset: usize = if let Some(offset) = column_metadata.bloom_filter_offset() {
+        offset
+            .try_into()
+            .map_err(|_| ParquetError::General("Bloom filter offset is invalid".to_string()))?
+    } else {
+        return Ok(None);
+    };
+
+    let buffer = match column_metadata.bloom_filter_length() {
+        Some(length) => input.get_bytes(offset..offset + length as usize),
+        None => input.get_bytes(offset..offset + SBBF_HEADER_SIZE_ESTIMATE),
+    }
+    .await?;
+
+    let (header, bitset_offset) =
+        chunk_read_bloom_filter_header_and_offset(offset as u64, buffer.clone())?;
+
+    match header.algorithm {
+        BloomFilterAlgorithm::BLOCK(_) => {
+            // this match exists to future proof the singleton algorithm enum
+        }
+    }
+    match header.compression {
+        BloomFilterCompression::UNCOMPRESSED(_) => {
+            // this match exists to future proof the singleton compression enum
+        }
+    }
+    match header.hash {
+        BloomFilterHash::XXHASH(_) => {
+            // this match exists to future proof the singleton hash enum
+        }
+    }
+
+    let bitset = match column_metadata.bloom_filter_length() {
+        Some(_) => buffer.slice((bitset_offset as usize - offset)..),
+        None => {
+            let bitset_length: usize = header
+                .num_bytes
+                .try_into()
+                .map_err(|_| ParquetError::General("Bloom filter length is invalid".to_string()))?;
+            input
+                .get_bytes(bitset_offset as usize..bitset_offset as usize + bitset_length)
+                .await?
+        }
+    };
+    Ok(Some(Sbbf::new(&bitset)))
+}
+
 type ReadResult<T> = Result<(ReaderFactory<T>, Option<ParquetRecordBatchReader>)>;
 
 /// [`ReaderFactory`] is used by [`ParquetRecordBatchStream`] to create
@@ -471,6 +486,10 @@ struct ReaderFactory<T> {
     limit: Option<usize>,
 
     offset: Option<usize>,
+
+    predicate: Option<BoundPredicate>,
+
+    props: ReaderPropertiesPtr,
 }
 
 impl<T> ReaderFactory<T>
@@ -490,6 +509,20 @@ where
         // TODO: calling build_array multiple times is wasteful
 
         let meta = self.metadata.row_group(row_group_idx);
+
+        if let Some(predicate) = self.predicate.as_ref() {
+            let mut bloom_filters = HashMap::new();
+            for column in predicate.bloom_filter_columns(meta) {
+                let sbbf = read_bloom_filter(&mut self.input, meta.column(column)).await?;
+                bloom_filters.insert(column, sbbf);
+            }
+            let may_match = predicate.may_match_bloom_filters(meta, |column| {
+                Ok(bloom_filters.remove(&column).flatten())
+            })?;
+            if !may_match {
+                return Ok((self, None));
+            }
+        }
         let offset_index = self
             .metadata
             .offset_index()
@@ -501,6 +534,7 @@ where
             row_count: meta.num_rows() as usize,
             column_chunks: vec![None; meta.columns().len()],
             offset_index,
+            props: self.props.clone(),
         };
 
         if let Some(filter) = self.filter.as_mut() {
@@ -707,6 +741,7 @@ struct InMemoryRowGroup<'a> {
     offset_index: Option<&'a [OffsetIndexMetaData]>,
     column_chunks: Vec<Option<Arc<ColumnChunkData>>>,
     row_count: usize,
+    props: ReaderPropertiesPtr,
 }
 
 impl<'a> InMemoryRowGroup<'a> {
@@ -816,12 +851,14 @@ impl<'a> RowGroups for InMemoryRowGroup<'a> {
                 let page_locations = self
                     .offset_index
                     .map(|index| index[i].page_locations.clone());
-                let page_reader: Box<dyn PageReader> = Box::new(SerializedPageReader::new(
-                    data.clone(),
-                    self.metadata.column(i),
-                    self.row_count,
-                    page_locations,
-                )?);
+                let page_reader: Box<dyn PageReader> =
+                    Box::new(SerializedPageReader::new_with_properties(
+                        data.clone(),
+                        self.metadata.column(i),
+                        self.row_count,
+                        page_locations,
+                        self.props.clone(),
+                    )?);
 
                 Ok(Box::new(ColumnChunkIterator {
                     reader: Some(Ok(page_reader)),
@@ -905,7 +942,7 @@ impl PageIterator for ColumnChunkIterator {}
 mod tests {
     use super::*;
     use crate::arrow::arrow_reader::{
-        ArrowPredicateFn, ParquetRecordBatchReaderBuilder, RowSelector,
+        ArrowPredicateFn, ParquetRecordBatchReaderBuilder, Predicate, RowSelector,
     };
     use crate::arrow::schema::parquet_to_arrow_schema_and_fields;
     use crate::arrow::ArrowWriter;
@@ -1473,6 +1510,71 @@ mod tests {
         assert_eq!(col2.values(), &[4, 5]);
     }
 
+    #[tokio::test]
+    async fn test_predicate() {
+        let a = StringArray::from_iter_values((0..200).map(|i| format!("v{i:03}")));
+        let b = Int32Array::from_iter_values(0..200);
+        let data = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(100)
+            .set_bloom_filter_enabled(true)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+
+        let data: Bytes = buf.into();
+        let metadata = Arc::new(parse_metadata(&data).unwrap());
+
+        let cases = [
+            // Row group 0 is pruned by statistics
+            (
+                Predicate::gt("b", Int32Array::new_scalar(150)),
+                (151..200).collect::<Vec<_>>(),
+            ),
+            // Row group 1 is pruned by statistics, and row group 0 by its bloom filter
+            (Predicate::eq("a", StringArray::new_scalar("v050x")), vec![]),
+            (
+                Predicate::in_list("a", StringArray::from(vec!["v050x", "v150"])),
+                vec![150],
+            ),
+        ];
+
+        for (predicate, expected) in cases {
+            let test = TestReader {
+                data: data.clone(),
+                metadata: metadata.clone(),
+                requests: Default::default(),
+            };
+            let requests = test.requests.clone();
+
+            let stream = ParquetRecordBatchStreamBuilder::new(test)
+                .await
+                .unwrap()
+                .with_predicate(predicate)
+                .build()
+                .unwrap();
+            let batches: Vec<_> = stream.try_collect().await.unwrap();
+
+            let actual: Vec<_> = batches
+                .iter()
+                .flat_map(|b| b.column(1).as_primitive::<Int32Type>().values().to_vec())
+                .collect();
+            assert_eq!(actual, expected);
+
+            if expected.is_empty() {
+                // Only the bloom filter of row group 0 is read
+                assert_eq!(requests.lock().unwrap().len(), 1);
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_row_filter_with_index() {
         let testdata = arrow::util::test_util::parquet_test_data();
@@ -1574,6 +1676,8 @@ mod tests {
             filter: None,
             limit: None,
             offset: None,
+            predicate: None,
+            props: Arc::new(ReaderProperties::builder().build()),
         };
 
         let mut skip = true;
@@ -1612,6 +1716,43 @@ mod tests {
         assert_eq!(&requests[..], &expected_page_requests)
     }
 
+    #[tokio::test]
+    async fn test_verify_page_checksum() {
+        let array = Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([("a", array)]).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_write_page_checksum(true)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        // Corrupt the last byte of the data page
+        let metadata = Arc::new(parse_metadata(&Bytes::from(buf.clone())).unwrap());
+        let (start, len) = metadata.row_group(0).column(0).byte_range();
+        buf[(start + len - 1) as usize] ^= 0xFF;
+
+        let async_reader = TestReader {
+            data: Bytes::from(buf),
+            metadata,
+            requests: Default::default(),
+        };
+
+        let options = ArrowReaderOptions::new().with_verify_page_checksum(true);
+        let stream = ParquetRecordBatchStreamBuilder::new_with_options(async_reader, options)
+            .await
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let err = stream.try_collect::<Vec<_>>().await.unwrap_err();
+        let expected = format!("Checksum mismatch: page 0 of column chunk a at offset {start}");
+        assert!(err.to_string().contains(&expected), "{err}");
+    }
+
     #[tokio::test]
     async fn test_batch_size_overallocate() {
         let testdata = arrow::util::test_util::parquet_test_data();
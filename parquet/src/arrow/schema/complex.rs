@@ -19,6 +19,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::arrow::schema::primitive::convert_primitive;
+use crate::arrow::schema::union_struct_fields;
 use crate::arrow::{ProjectionMask, PARQUET_FIELD_ID_META_KEY};
 use crate::basic::{ConvertedType, Repetition};
 use crate::errors::ParquetError;
@@ -175,8 +176,18 @@ impl Visitor {
 
         let parquet_fields = struct_type.get_fields();
 
+        // A union is stored as a struct of its type ids and children
+        let union_hint = match &context.data_type {
+            Some(DataType::Union(fields, mode)) => Some((fields.clone(), *mode)),
+            _ => None,
+        };
+        let hint = match &union_hint {
+            Some((fields, _)) => Some(DataType::Struct(union_struct_fields(fields))),
+            None => context.data_type,
+        };
+
         // Extract the arrow fields
-        let arrow_fields = match &context.data_type {
+        let arrow_fields = match &hint {
             Some(DataType::Struct(fields)) => {
                 if fields.len() != parquet_fields.len() {
                     return Err(arrow_err!(
@@ -235,11 +246,19 @@ impl Visitor {
             return Ok(None);
         }
 
+        let arrow_type = match union_hint {
+            // The union can only be reconstructed if the type ids and all children are projected
+            Some((fields, mode)) if children.len() == parquet_fields.len() => {
+                DataType::Union(fields, mode)
+            }
+            _ => DataType::Struct(child_fields.finish().fields),
+        };
+
         let struct_field = ParquetField {
             rep_level,
             def_level,
             nullable,
-            arrow_type: DataType::Struct(child_fields.finish().fields),
+            arrow_type,
             field_type: ParquetFieldType::Group { children },
         };
 
@@ -29,7 +29,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use arrow_ipc::writer;
-use arrow_schema::{DataType, Field, Fields, Schema, TimeUnit};
+use arrow_schema::{DataType, Field, Fields, Schema, TimeUnit, UnionFields};
 
 use crate::basic::{
     ConvertedType, LogicalType, Repetition, TimeUnit as ParquetTimeUnit, Type as PhysicalType,
@@ -46,6 +46,9 @@ pub(crate) use complex::{ParquetField, ParquetFieldType};
 
 use super::PARQUET_FIELD_ID_META_KEY;
 
+/// The name of the column storing the type ids of a [`DataType::Union`]
+pub(crate) const UNION_TYPE_ID_COLUMN: &str = "type_id";
+
 /// Convert Parquet schema to Arrow schema including optional metadata
 ///
 /// Attempts to decode any existing Arrow schema metadata, falling back
@@ -448,7 +451,10 @@ fn arrow_to_parquet_type(field: &Field) -> Result<Type> {
             .with_repetition(repetition)
             .with_id(id)
             .build(),
-        DataType::Duration(_) => Err(arrow_err!("Converting Duration to parquet not supported",)),
+        DataType::Duration(_) => Type::primitive_type_builder(name, PhysicalType::INT64)
+            .with_repetition(repetition)
+            .with_id(id)
+            .build(),
         DataType::Interval(_) => {
             Type::primitive_type_builder(name, PhysicalType::FIXED_LEN_BYTE_ARRAY)
                 .with_converted_type(ConvertedType::INTERVAL)
@@ -564,18 +570,43 @@ fn arrow_to_parquet_type(field: &Field) -> Result<Type> {
                 ))
             }
         }
-        DataType::Union(_, _) => unimplemented!("See ARROW-8817."),
+        DataType::Union(fields, _) => {
+            // Unions are stored as a struct of the type ids and a nullable field per child
+            let struct_field = field
+                .clone()
+                .with_data_type(DataType::Struct(union_struct_fields(fields)));
+            arrow_to_parquet_type(&struct_field)
+        }
         DataType::Dictionary(_, ref value) => {
             // Dictionary encoding not handled at the schema level
             let dict_field = field.clone().with_data_type(value.as_ref().clone());
             arrow_to_parquet_type(&dict_field)
         }
-        DataType::RunEndEncoded(_, _) => Err(arrow_err!(
-            "Converting RunEndEncodedType to parquet not supported",
+        DataType::RunEndEncoded(_, values) if values.data_type().is_nested() => Err(arrow_err!(
+            "Converting RunEndEncodedType with nested values to parquet not supported",
         )),
+        DataType::RunEndEncoded(_, values) => {
+            // Run-end encoding not handled at the schema level
+            let values_field = field.clone().with_data_type(values.data_type().clone());
+            arrow_to_parquet_type(&values_field)
+        }
     }
 }
 
+/// Returns the [`Fields`] of the [`DataType::Struct`] used to store a [`DataType::Union`]
+///
+/// This is a non-nullable [`UNION_TYPE_ID_COLUMN`] of [`DataType::Int8`], followed by a
+/// nullable field for each child of the union, which is null for rows of a different type
+pub(crate) fn union_struct_fields(fields: &UnionFields) -> Fields {
+    std::iter::once(Field::new(UNION_TYPE_ID_COLUMN, DataType::Int8, false))
+        .chain(
+            fields
+                .iter()
+                .map(|(_, f)| f.as_ref().clone().with_nullable(true)),
+        )
+        .collect()
+}
+
 fn field_id(field: &Field) -> Option<i32> {
     let value = field.metadata().get(super::PARQUET_FIELD_ID_META_KEY)?;
     value.parse().ok() // Fail quietly if not a valid integer
@@ -587,7 +618,7 @@ mod tests {
 
     use std::{collections::HashMap, sync::Arc};
 
-    use arrow::datatypes::{DataType, Field, IntervalUnit, TimeUnit};
+    use arrow::datatypes::{DataType, Field, IntervalUnit, TimeUnit, UnionMode};
 
     use crate::arrow::PARQUET_FIELD_ID_META_KEY;
     use crate::file::metadata::KeyValue;
@@ -1453,6 +1484,13 @@ mod tests {
             REQUIRED FIXED_LEN_BYTE_ARRAY (13) decimal_fix_length (DECIMAL(30,2));
             REQUIRED FIXED_LEN_BYTE_ARRAY (16) decimal128 (DECIMAL(38,2));
             REQUIRED FIXED_LEN_BYTE_ARRAY (17) decimal256 (DECIMAL(39,2));
+            OPTIONAL INT64 duration;
+            OPTIONAL BINARY run_end_encoded (STRING);
+            REQUIRED GROUP union {
+                REQUIRED INT32 type_id (INT_8);
+                OPTIONAL INT32 int;
+                OPTIONAL BINARY string (STRING);
+            }
         }
         ";
         let parquet_group_type = parse_message_type(message_type).unwrap();
@@ -1532,6 +1570,29 @@ mod tests {
             Field::new("decimal_fix_length", DataType::Decimal128(30, 2), false),
             Field::new("decimal128", DataType::Decimal128(38, 2), false),
             Field::new("decimal256", DataType::Decimal256(39, 2), false),
+            Field::new("duration", DataType::Duration(TimeUnit::Millisecond), true),
+            Field::new(
+                "run_end_encoded",
+                DataType::RunEndEncoded(
+                    Arc::new(Field::new("run_ends", DataType::Int32, false)),
+                    Arc::new(Field::new("values", DataType::Utf8, true)),
+                ),
+                true,
+            ),
+            Field::new(
+                "union",
+                DataType::Union(
+                    UnionFields::new(
+                        vec![0, 1],
+                        vec![
+                            Field::new("int", DataType::Int32, false),
+                            Field::new("string", DataType::Utf8, false),
+                        ],
+                    ),
+                    UnionMode::Sparse,
+                ),
+                false,
+            ),
         ];
         let arrow_schema = Schema::new(arrow_fields);
         let converted_arrow_schema = arrow_to_parquet_schema(&arrow_schema).unwrap();
@@ -1686,11 +1747,10 @@ mod tests {
                 ),
                 Field::new("c25", DataType::Interval(IntervalUnit::YearMonth), true),
                 Field::new("c26", DataType::Interval(IntervalUnit::DayTime), true),
-                // Duration types not supported
-                // Field::new("c27", DataType::Duration(TimeUnit::Second), false),
-                // Field::new("c28", DataType::Duration(TimeUnit::Millisecond), false),
-                // Field::new("c29", DataType::Duration(TimeUnit::Microsecond), false),
-                // Field::new("c30", DataType::Duration(TimeUnit::Nanosecond), false),
+                Field::new("c27", DataType::Duration(TimeUnit::Second), false),
+                Field::new("c28", DataType::Duration(TimeUnit::Millisecond), false),
+                Field::new("c29", DataType::Duration(TimeUnit::Microsecond), false),
+                Field::new("c30", DataType::Duration(TimeUnit::Nanosecond), false),
                 Field::new_dict(
                     "c31",
                     DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
@@ -1761,6 +1821,28 @@ mod tests {
                     false, // fails to roundtrip keys_sorted
                     false,
                 ),
+                Field::new(
+                    "c42",
+                    DataType::RunEndEncoded(
+                        Arc::new(Field::new("run_ends", DataType::Int16, false)),
+                        Arc::new(Field::new("values", DataType::Utf8, true)),
+                    ),
+                    true,
+                ),
+                Field::new(
+                    "c43",
+                    DataType::Union(
+                        UnionFields::new(
+                            vec![2, 5],
+                            vec![
+                                Field::new("a", DataType::Int32, false),
+                                Field::new_list("b", Field::new("item", DataType::Utf8, true), true),
+                            ],
+                        ),
+                        UnionMode::Dense,
+                    ),
+                    false,
+                ),
             ],
             meta(&[("Key", "Value")]),
         );
@@ -47,6 +47,9 @@ fn apply_hint(parquet: DataType, hint: DataType) -> DataType {
         // Date64 doesn't have a corresponding LogicalType / ConvertedType
         (DataType::Int64, DataType::Date64) => hint,
 
+        // Duration doesn't have a corresponding LogicalType / ConvertedType
+        (DataType::Int64, DataType::Duration(_)) => hint,
+
         // Coerce Date32 back to Date64 (#1666)
         (DataType::Date32, DataType::Date64) => hint,
 
@@ -79,6 +82,15 @@ fn apply_hint(parquet: DataType, hint: DataType) -> DataType {
                 false => hinted,
             }
         }
+
+        // Potentially restore run-end encoding
+        (_, DataType::RunEndEncoded(_, value)) => {
+            let hinted = apply_hint(parquet, value.data_type().clone());
+            match &hinted == value.data_type() {
+                true => hint,
+                false => hinted,
+            }
+        }
         _ => parquet,
     }
 }
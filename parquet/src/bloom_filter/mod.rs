@@ -73,6 +73,12 @@
 //! [bf-formulae]: http://tfk.mit.edu/pdf/bloom.pdf
 
 use crate::data_type::AsBytes;
+#[cfg(feature = "encryption")]
+use crate::encryption::{
+    decrypt::{read_encrypted_module, CryptoContext},
+    encrypt::ColumnEncryptor,
+    modules::ModuleType,
+};
 use crate::errors::ParquetError;
 use crate::file::metadata::ColumnChunkMetaData;
 use crate::file::reader::ChunkReader;
@@ -84,7 +90,6 @@ use crate::thrift::{TCompactSliceInputProtocol, TSerializable};
 use bytes::Bytes;
 use std::hash::Hasher;
 use std::io::Write;
-use std::sync::Arc;
 use thrift::protocol::{TCompactOutputProtocol, TOutputProtocol};
 use twox_hash::XxHash64;
 
@@ -281,6 +286,25 @@ impl Sbbf {
         Ok(())
     }
 
+    /// Write the bloom filter data (header and then bitset) to the output, encrypting
+    /// the header and bitset as separate modules
+    #[cfg(feature = "encryption")]
+    pub(crate) fn write_encrypted<W: Write>(
+        &self,
+        mut writer: W,
+        column_encryptor: &ColumnEncryptor,
+    ) -> Result<(), ParquetError> {
+        let header =
+            column_encryptor.encrypt_thrift(ModuleType::BloomFilterHeader, &self.header())?;
+        writer.write_all(&header)?;
+
+        let mut bitset = Vec::with_capacity(self.0.len() * 32);
+        self.write_bitset(&mut bitset)?;
+        let bitset = column_encryptor.encrypt_module(ModuleType::BloomFilterBitset, &bitset)?;
+        writer.write_all(&bitset)?;
+        Ok(())
+    }
+
     /// Write the bitset in serialized form to the writer.
     fn write_bitset<W: Write>(&self, mut writer: W) -> Result<(), ParquetError> {
         for block in &self.0 {
@@ -307,7 +331,7 @@ impl Sbbf {
     /// Read a new bloom filter from the given offset in the given reader.
     pub(crate) fn read_from_column_chunk<R: ChunkReader>(
         column_metadata: &ColumnChunkMetaData,
-        reader: Arc<R>,
+        reader: &R,
     ) -> Result<Option<Self>, ParquetError> {
         let offset: u64 = if let Some(offset) = column_metadata.bloom_filter_offset() {
             offset
@@ -354,6 +378,37 @@ impl Sbbf {
         Ok(Some(Self::new(&bitset)))
     }
 
+    /// Read a new bloom filter from the given offset in the given reader, decrypting
+    /// its header and bitset with `crypto_context`.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn read_from_encrypted_column_chunk<R: ChunkReader>(
+        column_metadata: &ColumnChunkMetaData,
+        reader: &R,
+        crypto_context: &CryptoContext,
+    ) -> Result<Option<Self>, ParquetError> {
+        let offset: u64 = if let Some(offset) = column_metadata.bloom_filter_offset() {
+            offset
+                .try_into()
+                .map_err(|_| ParquetError::General("Bloom filter offset is invalid".to_string()))?
+        } else {
+            return Ok(None);
+        };
+
+        let mut read = reader.get_read(offset)?;
+        let header = read_encrypted_module(&mut read)?;
+        let header = crypto_context.decrypt_module(ModuleType::BloomFilterHeader, &header)?;
+        let (header, _) = read_bloom_filter_header_and_length(Bytes::from(header))?;
+
+        let bitset = read_encrypted_module(&mut read)?;
+        let bitset = crypto_context.decrypt_module(ModuleType::BloomFilterBitset, &bitset)?;
+        if header.num_bytes < 0 || header.num_bytes as usize != bitset.len() {
+            return Err(ParquetError::General(
+                "Bloom filter length is invalid".to_string(),
+            ));
+        }
+        Ok(Some(Self::new(&bitset)))
+    }
+
     #[inline]
     fn hash_to_block_index(&self, hash: u64) -> usize {
         // unchecked_mul is unstable, but in reality this is safe, we'd just use saturating mul
@@ -373,7 +428,7 @@ impl Sbbf {
     }
 
     /// Check if an [AsBytes] value is probably present or definitely absent in the filter
-    pub fn check<T: AsBytes>(&self, value: &T) -> bool {
+    pub fn check<T: AsBytes + ?Sized>(&self, value: &T) -> bool {
         self.check_hash(hash_as_bytes(value))
     }
 
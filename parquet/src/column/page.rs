@@ -163,8 +163,21 @@ impl CompressedPage {
         self.compressed_page.buffer()
     }
 
-    /// Returns the thrift page header
-    pub(crate) fn to_thrift_header(&self) -> PageHeader {
+    /// Replaces the potentially compressed buffer of this page, such as with its
+    /// encrypted form, keeping the uncompressed size
+    #[cfg(feature = "encryption")]
+    pub(crate) fn with_new_compressed_buffer(mut self, new_buffer: Bytes) -> Self {
+        match &mut self.compressed_page {
+            Page::DataPage { buf, .. } => *buf = new_buffer,
+            Page::DataPageV2 { buf, .. } => *buf = new_buffer,
+            Page::DictionaryPage { buf, .. } => *buf = new_buffer,
+        }
+        self
+    }
+
+    /// Returns the thrift page header, including a CRC32 checksum of the page data if
+    /// `checksum` is true
+    pub(crate) fn to_thrift_header(&self, checksum: bool) -> PageHeader {
         let uncompressed_size = self.uncompressed_size();
         let compressed_size = self.compressed_size();
         let num_values = self.num_values();
@@ -175,8 +188,7 @@ impl CompressedPage {
             type_: page_type.into(),
             uncompressed_page_size: uncompressed_size as i32,
             compressed_page_size: compressed_size as i32,
-            // TODO: Add support for crc checksum
-            crc: None,
+            crc: checksum.then(|| crc32fast::hash(self.data()) as i32),
             data_page_header: None,
             index_page_header: None,
             dictionary_page_header: None,
@@ -152,10 +152,10 @@ impl<T: DataType> ColumnValueEncoderImpl<T> {
                 update_min(&self.descr, &min, &mut self.min_value);
                 update_max(&self.descr, &max, &mut self.max_value);
             }
+        }
 
-            if let Some(var_bytes) = T::T::variable_length_bytes(slice) {
-                *self.variable_length_bytes.get_or_insert(0) += var_bytes;
-            }
+        if let Some(var_bytes) = T::T::variable_length_bytes(slice) {
+            *self.variable_length_bytes.get_or_insert(0) += var_bytes;
         }
 
         // encode the values into bloom filter if enabled
@@ -314,6 +314,7 @@ pub struct GenericColumnWriter<'a, E: ColumnValueEncoder> {
     descr: ColumnDescPtr,
     props: WriterPropertiesPtr,
     statistics_enabled: EnabledStatistics,
+    size_statistics_enabled: bool,
 
     page_writer: Box<dyn PageWriter + 'a>,
     codec: Compression,
@@ -355,6 +356,7 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         let encoder = E::try_new(&descr, props.as_ref()).unwrap();
 
         let statistics_enabled = props.statistics_enabled(descr.path());
+        let size_statistics_enabled = props.size_statistics_enabled(descr.path());
 
         let mut encodings = BTreeSet::new();
         // Used for level information
@@ -363,8 +365,8 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         let mut page_metrics = PageMetrics::new();
         let mut column_metrics = ColumnMetrics::<E::T>::new();
 
-        // Initialize level histograms if collecting page or chunk statistics
-        if statistics_enabled != EnabledStatistics::None {
+        // Initialize level histograms if collecting size statistics
+        if size_statistics_enabled {
             page_metrics = page_metrics
                 .with_repetition_level_histogram(descr.max_rep_level())
                 .with_definition_level_histogram(descr.max_def_level());
@@ -383,6 +385,7 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             descr,
             props,
             statistics_enabled,
+            size_statistics_enabled,
             page_writer,
             codec,
             compressor,
@@ -905,17 +908,23 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             _ => None,
         };
 
+        // The unencoded size is only recorded for BYTE_ARRAY columns, and must be
+        // present for every page, including those containing only nulls
+        let variable_length_bytes = match self.descr.physical_type() {
+            Type::BYTE_ARRAY if self.size_statistics_enabled => {
+                Some(values_data.variable_length_bytes.unwrap_or(0))
+            }
+            _ => None,
+        };
+
         // update column and offset index
-        self.update_column_offset_index(
-            page_statistics.as_ref(),
-            values_data.variable_length_bytes,
-        );
+        self.update_column_offset_index(page_statistics.as_ref(), variable_length_bytes);
 
         // Update histograms and variable_length_bytes in column_metrics
         self.column_metrics
             .update_from_page_metrics(&self.page_metrics);
         self.column_metrics
-            .update_variable_length_bytes(values_data.variable_length_bytes);
+            .update_variable_length_bytes(variable_length_bytes);
 
         let page_statistics = page_statistics.map(Statistics::from);
 
@@ -1120,8 +1129,11 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
                 stats => stats,
             };
 
+            builder = builder.set_statistics(statistics);
+        }
+
+        if self.size_statistics_enabled {
             builder = builder
-                .set_statistics(statistics)
                 .set_unencoded_byte_array_data_bytes(self.column_metrics.variable_length_bytes)
                 .set_repetition_level_histogram(
                     self.column_metrics.repetition_level_histogram.take(),
@@ -2726,6 +2738,71 @@ mod tests {
         assert_eq!(4, offset_index.page_locations[1].first_row_index);
     }
 
+    #[test]
+    fn test_column_size_statistics() {
+        let write = |props: WriterProperties| {
+            let page_writer = get_test_page_writer();
+            let mut writer =
+                get_test_column_writer::<ByteArrayType>(page_writer, 1, 1, Arc::new(props));
+            // first page has 2 rows, with values "a" and "bb"
+            writer
+                .write_batch(
+                    &[ByteArray::from("a"), ByteArray::from("bb")],
+                    Some(&[1, 0, 1]),
+                    Some(&[0, 1, 0]),
+                )
+                .unwrap();
+            writer.flush_data_pages().unwrap();
+            // second page has 2 rows, which are both null
+            writer
+                .write_batch(&[], Some(&[0, 0]), Some(&[0, 0]))
+                .unwrap();
+            writer.close().unwrap()
+        };
+
+        let r = write(WriterProperties::builder().build());
+        let column_index = r.column_index.unwrap();
+        let offset_index = r.offset_index.unwrap();
+
+        assert_eq!(r.metadata.unencoded_byte_array_data_bytes(), Some(3));
+        assert_eq!(
+            r.metadata.repetition_level_histogram().unwrap().values(),
+            &[4, 1]
+        );
+        assert_eq!(
+            r.metadata.definition_level_histogram().unwrap().values(),
+            &[3, 2]
+        );
+
+        // histograms of each page are concatenated
+        assert_eq!(
+            column_index.repetition_level_histograms,
+            Some(vec![2, 1, 2, 0])
+        );
+        assert_eq!(
+            column_index.definition_level_histograms,
+            Some(vec![1, 2, 2, 0])
+        );
+        assert_eq!(
+            offset_index.unencoded_byte_array_data_bytes,
+            Some(vec![3, 0])
+        );
+
+        let props = WriterProperties::builder()
+            .set_size_statistics_enabled(false)
+            .build();
+        let r = write(props);
+        let column_index = r.column_index.unwrap();
+        let offset_index = r.offset_index.unwrap();
+
+        assert_eq!(r.metadata.unencoded_byte_array_data_bytes(), None);
+        assert_eq!(r.metadata.repetition_level_histogram(), None);
+        assert_eq!(r.metadata.definition_level_histogram(), None);
+        assert_eq!(column_index.repetition_level_histograms, None);
+        assert_eq!(column_index.definition_level_histograms, None);
+        assert_eq!(offset_index.unencoded_byte_array_data_bytes, None);
+    }
+
     /// Verify min/max value truncation in the column index works as expected
     #[test]
     fn test_column_offset_index_metadata_truncating() {
@@ -0,0 +1,338 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! AES ciphers used to encrypt and decrypt Parquet modules
+//!
+//! Every encrypted module is stored as
+//!
+//! ```text
+//! +--------+-------+------------+-----+
+//! | length | nonce | ciphertext | tag |
+//! +--------+-------+------------+-----+
+//! ```
+//!
+//! where `length` is a 4 byte little endian integer containing the length of
+//! the remainder of the module. The 16 byte `tag` is only present for modules
+//! encrypted with AES-GCM.
+
+use crate::errors::{ParquetError, Result};
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::OsRng;
+use aes_gcm::aes::{Aes128, Aes192, Aes256};
+use aes_gcm::{AeadInPlace, AesGcm, KeyInit, Nonce, Tag};
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use std::fmt::Debug;
+
+/// Length of the little endian length prefix of an encrypted module
+pub(crate) const SIZE_LEN: usize = 4;
+/// Length of the random nonce of an encrypted module
+pub(crate) const NONCE_LEN: usize = 12;
+/// Length of the authentication tag of a module encrypted with AES-GCM
+pub(crate) const TAG_LEN: usize = 16;
+
+type Aes128Gcm = AesGcm<Aes128, aes_gcm::aead::consts::U12>;
+type Aes192Gcm = AesGcm<Aes192, aes_gcm::aead::consts::U12>;
+type Aes256Gcm = AesGcm<Aes256, aes_gcm::aead::consts::U12>;
+
+type Aes128Ctr = ctr::Ctr128BE<Aes128>;
+type Aes192Ctr = ctr::Ctr128BE<Aes192>;
+type Aes256Ctr = ctr::Ctr128BE<Aes256>;
+
+/// Decrypts a single encrypted module
+pub(crate) trait BlockDecryptor: Debug + Send + Sync {
+    /// Decrypts `length_and_ciphertext`, which includes the 4 byte length prefix,
+    /// returning the plaintext
+    fn decrypt(&self, length_and_ciphertext: &[u8], aad: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// Encrypts a single module
+pub(crate) trait BlockEncryptor: Debug + Send + Sync {
+    /// Encrypts `plaintext`, returning the module including its 4 byte length prefix
+    fn encrypt(&self, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// Returns an error if `key` is not a valid AES key
+pub(crate) fn validate_key(key: &[u8]) -> Result<()> {
+    match key.len() {
+        16 | 24 | 32 => Ok(()),
+        n => Err(general_err!(
+            "Invalid AES key length {}, expected 16, 24 or 32 bytes",
+            n
+        )),
+    }
+}
+
+/// Returns `len` cryptographically random bytes
+pub(crate) fn random_bytes(len: usize) -> Vec<u8> {
+    let mut bytes = vec![0; len];
+    OsRng.fill_bytes(&mut bytes);
+    bytes
+}
+
+/// Splits an encrypted module into its nonce and the remaining ciphertext,
+/// validating its length prefix
+fn split_module(length_and_ciphertext: &[u8], min_len: usize) -> Result<(&[u8], &[u8])> {
+    if length_and_ciphertext.len() < SIZE_LEN + min_len {
+        return Err(general_err!(
+            "Encrypted module of {} bytes is too short",
+            length_and_ciphertext.len()
+        ));
+    }
+    let (length, module) = length_and_ciphertext.split_at(SIZE_LEN);
+    let length = u32::from_le_bytes(length.try_into().unwrap()) as usize;
+    if length != module.len() {
+        return Err(general_err!(
+            "Encrypted module length {} does not match the {} bytes available",
+            length,
+            module.len()
+        ));
+    }
+    Ok(module.split_at(NONCE_LEN))
+}
+
+/// Writes the length prefix and nonce of a module with `ciphertext_len` bytes of ciphertext
+fn module_header(nonce: &[u8], ciphertext_len: usize) -> Result<Vec<u8>> {
+    let length = u32::try_from(NONCE_LEN + ciphertext_len)
+        .map_err(|_| general_err!("Encrypted module is too large"))?;
+    let mut out = Vec::with_capacity(SIZE_LEN + NONCE_LEN + ciphertext_len);
+    out.extend_from_slice(&length.to_le_bytes());
+    out.extend_from_slice(nonce);
+    Ok(out)
+}
+
+#[derive(Clone)]
+enum GcmCipher {
+    Aes128(Aes128Gcm),
+    Aes192(Aes192Gcm),
+    Aes256(Aes256Gcm),
+}
+
+/// AES-GCM cipher, used for all modules of `AES_GCM_V1` files and for all modules
+/// other than pages in `AES_GCM_CTR_V1` files
+#[derive(Clone)]
+pub(crate) struct AesGcmCipher {
+    cipher: GcmCipher,
+}
+
+impl Debug for AesGcmCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AesGcmCipher").finish_non_exhaustive()
+    }
+}
+
+impl AesGcmCipher {
+    /// Creates a new cipher for the provided key
+    pub(crate) fn try_new(key: &[u8]) -> Result<Self> {
+        validate_key(key)?;
+        let cipher = match key.len() {
+            16 => GcmCipher::Aes128(Aes128Gcm::new_from_slice(key).unwrap()),
+            24 => GcmCipher::Aes192(Aes192Gcm::new_from_slice(key).unwrap()),
+            _ => GcmCipher::Aes256(Aes256Gcm::new_from_slice(key).unwrap()),
+        };
+        Ok(Self { cipher })
+    }
+
+    /// Encrypts `plaintext` with the provided `nonce`, returning the encrypted module
+    ///
+    /// Nonces must never be reused with the same key, this is only exposed to allow
+    /// verifying the signature of plaintext footers
+    pub(crate) fn encrypt_with_nonce(
+        &self,
+        nonce: &[u8],
+        plaintext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>> {
+        if nonce.len() != NONCE_LEN {
+            return Err(general_err!("Invalid nonce length {}", nonce.len()));
+        }
+        let nonce = Nonce::from_slice(nonce);
+        let mut out = module_header(nonce, plaintext.len() + TAG_LEN)?;
+        let start = out.len();
+        out.extend_from_slice(plaintext);
+
+        let buffer = &mut out[start..];
+        let tag = match &self.cipher {
+            GcmCipher::Aes128(c) => c.encrypt_in_place_detached(nonce, aad, buffer),
+            GcmCipher::Aes192(c) => c.encrypt_in_place_detached(nonce, aad, buffer),
+            GcmCipher::Aes256(c) => c.encrypt_in_place_detached(nonce, aad, buffer),
+        }
+        .map_err(|_| general_err!("Failed to encrypt module"))?;
+        out.extend_from_slice(&tag);
+        Ok(out)
+    }
+}
+
+impl BlockEncryptor for AesGcmCipher {
+    fn encrypt(&self, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        self.encrypt_with_nonce(&random_bytes(NONCE_LEN), plaintext, aad)
+    }
+}
+
+impl BlockDecryptor for AesGcmCipher {
+    fn decrypt(&self, length_and_ciphertext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        let (nonce, ciphertext) = split_module(length_and_ciphertext, NONCE_LEN + TAG_LEN)?;
+        let (ciphertext, tag) = ciphertext.split_at(ciphertext.len() - TAG_LEN);
+        let nonce = Nonce::from_slice(nonce);
+        let tag = Tag::from_slice(tag);
+
+        let mut plaintext = ciphertext.to_vec();
+        let buffer = plaintext.as_mut_slice();
+        match &self.cipher {
+            GcmCipher::Aes128(c) => c.decrypt_in_place_detached(nonce, aad, buffer, tag),
+            GcmCipher::Aes192(c) => c.decrypt_in_place_detached(nonce, aad, buffer, tag),
+            GcmCipher::Aes256(c) => c.decrypt_in_place_detached(nonce, aad, buffer, tag),
+        }
+        .map_err(|_| {
+            general_err!("Failed to decrypt module, the key or additional authenticated data may be incorrect")
+        })?;
+        Ok(plaintext)
+    }
+}
+
+/// AES-CTR cipher, used for data and dictionary pages of `AES_GCM_CTR_V1` files
+///
+/// The 16 byte initialization vector is the nonce followed by a big endian counter
+/// starting at 1. As CTR mode is not authenticated, the additional authenticated data
+/// is ignored.
+#[derive(Clone)]
+pub(crate) struct AesCtrCipher {
+    key: Vec<u8>,
+}
+
+impl Debug for AesCtrCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AesCtrCipher").finish_non_exhaustive()
+    }
+}
+
+impl AesCtrCipher {
+    /// Creates a new cipher for the provided key
+    pub(crate) fn try_new(key: &[u8]) -> Result<Self> {
+        validate_key(key)?;
+        Ok(Self { key: key.to_vec() })
+    }
+
+    fn apply_keystream(&self, nonce: &[u8], buffer: &mut [u8]) {
+        let mut iv = [0_u8; 16];
+        iv[..NONCE_LEN].copy_from_slice(nonce);
+        iv[15] = 1;
+        let key = self.key.as_slice();
+        match key.len() {
+            16 => Aes128Ctr::new(key.into(), &iv.into()).apply_keystream(buffer),
+            24 => Aes192Ctr::new(key.into(), &iv.into()).apply_keystream(buffer),
+            _ => Aes256Ctr::new(key.into(), &iv.into()).apply_keystream(buffer),
+        }
+    }
+}
+
+impl BlockEncryptor for AesCtrCipher {
+    fn encrypt(&self, plaintext: &[u8], _aad: &[u8]) -> Result<Vec<u8>> {
+        let nonce = random_bytes(NONCE_LEN);
+        let mut out = module_header(&nonce, plaintext.len())?;
+        let start = out.len();
+        out.extend_from_slice(plaintext);
+        self.apply_keystream(&nonce, &mut out[start..]);
+        Ok(out)
+    }
+}
+
+impl BlockDecryptor for AesCtrCipher {
+    fn decrypt(&self, length_and_ciphertext: &[u8], _aad: &[u8]) -> Result<Vec<u8>> {
+        let (nonce, ciphertext) = split_module(length_and_ciphertext, NONCE_LEN)?;
+        let mut plaintext = ciphertext.to_vec();
+        self.apply_keystream(nonce, &mut plaintext);
+        Ok(plaintext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcm_round_trip() {
+        for key_len in [16, 24, 32] {
+            let key = random_bytes(key_len);
+            let cipher = AesGcmCipher::try_new(&key).unwrap();
+            let encrypted = cipher.encrypt(b"hello parquet", b"aad").unwrap();
+            assert_eq!(encrypted.len(), SIZE_LEN + NONCE_LEN + 13 + TAG_LEN);
+            let length = u32::from_le_bytes(encrypted[..4].try_into().unwrap());
+            assert_eq!(length as usize, encrypted.len() - SIZE_LEN);
+
+            let decrypted = cipher.decrypt(&encrypted, b"aad").unwrap();
+            assert_eq!(decrypted, b"hello parquet");
+
+            // Authentication fails with different additional authenticated data
+            cipher.decrypt(&encrypted, b"other").unwrap_err();
+
+            // Or a different key
+            let other = AesGcmCipher::try_new(&random_bytes(key_len)).unwrap();
+            other.decrypt(&encrypted, b"aad").unwrap_err();
+        }
+    }
+
+    #[test]
+    fn test_gcm_nonce() {
+        let cipher = AesGcmCipher::try_new(b"0123456789012345").unwrap();
+        let a = cipher.encrypt(b"data", b"").unwrap();
+        let b = cipher.encrypt(b"data", b"").unwrap();
+        assert_ne!(a, b);
+
+        let nonce = &a[SIZE_LEN..SIZE_LEN + NONCE_LEN];
+        let c = cipher.encrypt_with_nonce(nonce, b"data", b"").unwrap();
+        assert_eq!(a, c);
+    }
+
+    #[test]
+    fn test_ctr_round_trip() {
+        for key_len in [16, 24, 32] {
+            let key = random_bytes(key_len);
+            let cipher = AesCtrCipher::try_new(&key).unwrap();
+            let plaintext = (0..100_u8).collect::<Vec<_>>();
+            let encrypted = cipher.encrypt(&plaintext, b"").unwrap();
+            assert_eq!(encrypted.len(), SIZE_LEN + NONCE_LEN + plaintext.len());
+            assert_ne!(&encrypted[SIZE_LEN + NONCE_LEN..], plaintext.as_slice());
+
+            let decrypted = cipher.decrypt(&encrypted, b"").unwrap();
+            assert_eq!(decrypted, plaintext);
+        }
+    }
+
+    #[test]
+    fn test_invalid_module() {
+        let cipher = AesGcmCipher::try_new(b"0123456789012345").unwrap();
+        let err = cipher.decrypt(&[1, 2, 3], b"").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Encrypted module of 3 bytes is too short"
+        );
+
+        let mut encrypted = cipher.encrypt(b"data", b"").unwrap();
+        encrypted.push(0);
+        let err = cipher.decrypt(&encrypted, b"").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Encrypted module length 32 does not match the 33 bytes available"
+        );
+
+        let err = AesGcmCipher::try_new(b"short").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid AES key length 5, expected 16, 24 or 32 bytes"
+        );
+    }
+}
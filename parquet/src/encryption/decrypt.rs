@@ -0,0 +1,546 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Configuration and utilities for decrypting encrypted Parquet files
+
+use crate::encryption::ciphers::{
+    validate_key, AesCtrCipher, AesGcmCipher, BlockDecryptor, NONCE_LEN, SIZE_LEN, TAG_LEN,
+};
+use crate::encryption::modules::{create_footer_aad, create_module_aad, ModuleType};
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::RowGroupMetaData;
+use crate::format::{ColumnCryptoMetaData, EncryptionAlgorithm};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::io::Read;
+use std::sync::Arc;
+
+/// Retrieves the keys used to decrypt a file from their key metadata
+///
+/// Writers may store arbitrary key metadata alongside the encrypted footer and
+/// columns, typically an identifier of the key within a key management service.
+/// Implementations may be called once for each encrypted column chunk, and should
+/// cache keys if retrieving them is expensive.
+///
+/// # Example
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use std::sync::Arc;
+/// # use parquet::encryption::decrypt::{FileDecryptionProperties, KeyRetriever};
+/// # use parquet::errors::{ParquetError, Result};
+/// struct StaticKeys(HashMap<Vec<u8>, Vec<u8>>);
+///
+/// impl KeyRetriever for StaticKeys {
+///     fn retrieve_key(&self, key_metadata: &[u8]) -> Result<Vec<u8>> {
+///         self.0
+///             .get(key_metadata)
+///             .cloned()
+///             .ok_or_else(|| ParquetError::General("unknown key".to_string()))
+///     }
+/// }
+///
+/// let keys = StaticKeys(HashMap::from([(b"kf".to_vec(), b"0123456789012345".to_vec())]));
+/// let properties = FileDecryptionProperties::with_key_retriever(Arc::new(keys))
+///     .build()
+///     .unwrap();
+/// ```
+pub trait KeyRetriever: Send + Sync {
+    /// Returns the key identified by `key_metadata`
+    fn retrieve_key(&self, key_metadata: &[u8]) -> Result<Vec<u8>>;
+}
+
+#[derive(Clone)]
+enum DecryptionKeys {
+    Explicit {
+        footer_key: Vec<u8>,
+        column_keys: HashMap<String, Vec<u8>>,
+    },
+    ViaRetriever(Arc<dyn KeyRetriever>),
+}
+
+impl PartialEq for DecryptionKeys {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::Explicit {
+                    footer_key: a_footer,
+                    column_keys: a_columns,
+                },
+                Self::Explicit {
+                    footer_key: b_footer,
+                    column_keys: b_columns,
+                },
+            ) => a_footer == b_footer && a_columns == b_columns,
+            (Self::ViaRetriever(a), Self::ViaRetriever(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// Properties used to decrypt an encrypted Parquet file
+///
+/// The footer key is used to decrypt the footer, and any columns encrypted with
+/// the footer key. Columns encrypted with their own key additionally require a
+/// column key, identified by the dot separated path of the column.
+///
+/// Keys may either be provided up front with [`Self::builder`], or retrieved
+/// from their key metadata with a [`KeyRetriever`], see [`Self::with_key_retriever`].
+///
+/// # Example
+///
+/// ```
+/// # use parquet::encryption::decrypt::FileDecryptionProperties;
+/// let properties = FileDecryptionProperties::builder(b"0123456789012345".to_vec())
+///     .with_column_key("double_field", b"1234567890123450".to_vec())
+///     .with_column_key("float_field", b"1234567890123451".to_vec())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct FileDecryptionProperties {
+    keys: DecryptionKeys,
+    aad_prefix: Option<Vec<u8>>,
+    footer_signature_verification: bool,
+}
+
+impl Debug for FileDecryptionProperties {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Avoid leaking keys into logs
+        let keys = match &self.keys {
+            DecryptionKeys::Explicit { column_keys, .. } => {
+                format!("Explicit({} column keys)", column_keys.len())
+            }
+            DecryptionKeys::ViaRetriever(_) => "ViaRetriever".to_string(),
+        };
+        f.debug_struct("FileDecryptionProperties")
+            .field("keys", &keys)
+            .field("aad_prefix", &self.aad_prefix)
+            .field(
+                "footer_signature_verification",
+                &self.footer_signature_verification,
+            )
+            .finish()
+    }
+}
+
+impl FileDecryptionProperties {
+    /// Returns a [`DecryptionPropertiesBuilder`] for decrypting files with the
+    /// provided footer key
+    pub fn builder(footer_key: Vec<u8>) -> DecryptionPropertiesBuilder {
+        DecryptionPropertiesBuilder::new(DecryptionKeys::Explicit {
+            footer_key,
+            column_keys: HashMap::new(),
+        })
+    }
+
+    /// Returns a [`DecryptionPropertiesBuilder`] for decrypting files with keys
+    /// obtained from `key_retriever`
+    pub fn with_key_retriever(key_retriever: Arc<dyn KeyRetriever>) -> DecryptionPropertiesBuilder {
+        DecryptionPropertiesBuilder::new(DecryptionKeys::ViaRetriever(key_retriever))
+    }
+
+    /// Returns the AAD prefix to use for files that don't store it
+    pub fn aad_prefix(&self) -> Option<&[u8]> {
+        self.aad_prefix.as_deref()
+    }
+
+    /// Returns whether the signature of plaintext footers is verified
+    pub fn check_plaintext_footer_integrity(&self) -> bool {
+        self.footer_signature_verification
+    }
+
+    /// Returns the footer key, retrieving it from `key_metadata` if necessary
+    pub(crate) fn footer_key(&self, key_metadata: Option<&[u8]>) -> Result<Cow<'_, [u8]>> {
+        match &self.keys {
+            DecryptionKeys::Explicit { footer_key, .. } => Ok(Cow::Borrowed(footer_key)),
+            DecryptionKeys::ViaRetriever(retriever) => {
+                let key_metadata = key_metadata.ok_or_else(|| {
+                    general_err!("Key metadata is required to retrieve the footer key")
+                })?;
+                Ok(Cow::Owned(retriever.retrieve_key(key_metadata)?))
+            }
+        }
+    }
+
+    /// Returns the key of the column with dot separated path `column_name`,
+    /// retrieving it from `key_metadata` if necessary
+    pub(crate) fn column_key(
+        &self,
+        column_name: &str,
+        key_metadata: Option<&[u8]>,
+    ) -> Result<Cow<'_, [u8]>> {
+        match &self.keys {
+            DecryptionKeys::Explicit { column_keys, .. } => match column_keys.get(column_name) {
+                Some(key) => Ok(Cow::Borrowed(key)),
+                None => Err(general_err!(
+                    "No decryption key provided for column '{}'",
+                    column_name
+                )),
+            },
+            DecryptionKeys::ViaRetriever(retriever) => {
+                let key_metadata = key_metadata.ok_or_else(|| {
+                    general_err!(
+                        "Key metadata is required to retrieve the key of column '{}'",
+                        column_name
+                    )
+                })?;
+                Ok(Cow::Owned(retriever.retrieve_key(key_metadata)?))
+            }
+        }
+    }
+}
+
+/// Builder for [`FileDecryptionProperties`]
+pub struct DecryptionPropertiesBuilder {
+    keys: DecryptionKeys,
+    column_keys: HashMap<String, Vec<u8>>,
+    aad_prefix: Option<Vec<u8>>,
+    footer_signature_verification: bool,
+}
+
+impl DecryptionPropertiesBuilder {
+    fn new(keys: DecryptionKeys) -> Self {
+        Self {
+            keys,
+            column_keys: HashMap::new(),
+            aad_prefix: None,
+            footer_signature_verification: true,
+        }
+    }
+
+    /// Sets the key of the column with the dot separated path `column_name`
+    ///
+    /// Column keys can't be combined with a [`KeyRetriever`]
+    pub fn with_column_key(mut self, column_name: &str, decryption_key: Vec<u8>) -> Self {
+        self.column_keys
+            .insert(column_name.to_string(), decryption_key);
+        self
+    }
+
+    /// Sets the AAD prefix, which is required to decrypt files written with an
+    /// AAD prefix that isn't stored in the file
+    pub fn with_aad_prefix(mut self, aad_prefix: Vec<u8>) -> Self {
+        self.aad_prefix = Some(aad_prefix);
+        self
+    }
+
+    /// Disables verification of the signature of plaintext footers
+    ///
+    /// By default the signature of plaintext footers is verified, which requires
+    /// the footer key
+    pub fn disable_footer_signature_verification(mut self) -> Self {
+        self.footer_signature_verification = false;
+        self
+    }
+
+    /// Validates the configured keys and returns the [`FileDecryptionProperties`]
+    pub fn build(self) -> Result<FileDecryptionProperties> {
+        let keys = match self.keys {
+            DecryptionKeys::Explicit { footer_key, .. } => {
+                validate_key(&footer_key)?;
+                for key in self.column_keys.values() {
+                    validate_key(key)?;
+                }
+                DecryptionKeys::Explicit {
+                    footer_key,
+                    column_keys: self.column_keys,
+                }
+            }
+            DecryptionKeys::ViaRetriever(_) if !self.column_keys.is_empty() => {
+                return Err(general_err!(
+                    "Column keys can't be set when using a key retriever"
+                ))
+            }
+            keys => keys,
+        };
+        Ok(FileDecryptionProperties {
+            keys,
+            aad_prefix: self.aad_prefix,
+            footer_signature_verification: self.footer_signature_verification,
+        })
+    }
+}
+
+/// Decrypts the modules of a single encrypted file
+#[derive(Debug, Clone)]
+pub(crate) struct FileDecryptor {
+    decryption_properties: FileDecryptionProperties,
+    footer_key: Vec<u8>,
+    footer_cipher: Arc<AesGcmCipher>,
+    file_aad: Vec<u8>,
+    /// Whether pages are encrypted with AES-CTR rather than AES-GCM
+    ctr: bool,
+}
+
+impl PartialEq for FileDecryptor {
+    fn eq(&self, other: &Self) -> bool {
+        self.decryption_properties == other.decryption_properties
+            && self.footer_key == other.footer_key
+            && self.file_aad == other.file_aad
+            && self.ctr == other.ctr
+    }
+}
+
+impl FileDecryptor {
+    /// Creates a new [`FileDecryptor`] for a file encrypted with `algorithm`, whose
+    /// footer key is identified by `footer_key_metadata`
+    pub(crate) fn try_new(
+        decryption_properties: &FileDecryptionProperties,
+        footer_key_metadata: Option<&[u8]>,
+        algorithm: &EncryptionAlgorithm,
+    ) -> Result<Self> {
+        let (stored_aad_prefix, aad_file_unique, supply_aad_prefix, ctr) = match algorithm {
+            EncryptionAlgorithm::AESGCMV1(a) => (
+                a.aad_prefix.as_deref(),
+                a.aad_file_unique.as_deref(),
+                a.supply_aad_prefix,
+                false,
+            ),
+            EncryptionAlgorithm::AESGCMCTRV1(a) => (
+                a.aad_prefix.as_deref(),
+                a.aad_file_unique.as_deref(),
+                a.supply_aad_prefix,
+                true,
+            ),
+        };
+        let aad_file_unique = aad_file_unique
+            .ok_or_else(|| general_err!("Encrypted file is missing its unique AAD identifier"))?;
+
+        let aad_prefix = match (decryption_properties.aad_prefix(), stored_aad_prefix) {
+            (Some(provided), Some(stored)) if provided != stored => {
+                return Err(general_err!(
+                    "AAD prefix provided in the decryption properties does not match the AAD prefix stored in the file"
+                ))
+            }
+            (Some(prefix), _) | (None, Some(prefix)) => prefix,
+            (None, None) if supply_aad_prefix == Some(true) => {
+                return Err(general_err!(
+                    "Parquet file was encrypted with an AAD prefix that is not stored in the file, \
+                    but no AAD prefix was provided in the decryption properties"
+                ))
+            }
+            (None, None) => &[],
+        };
+
+        let mut file_aad = Vec::with_capacity(aad_prefix.len() + aad_file_unique.len());
+        file_aad.extend_from_slice(aad_prefix);
+        file_aad.extend_from_slice(aad_file_unique);
+
+        let footer_key = decryption_properties
+            .footer_key(footer_key_metadata)?
+            .into_owned();
+        let footer_cipher = Arc::new(AesGcmCipher::try_new(&footer_key)?);
+
+        Ok(Self {
+            decryption_properties: decryption_properties.clone(),
+            footer_key,
+            footer_cipher,
+            file_aad,
+            ctr,
+        })
+    }
+
+    /// Decrypts an encrypted footer, returning the serialized `FileMetaData`
+    pub(crate) fn decrypt_footer(&self, encrypted_footer: &[u8]) -> Result<Vec<u8>> {
+        let aad = create_footer_aad(&self.file_aad);
+        self.footer_cipher.decrypt(encrypted_footer, &aad)
+    }
+
+    /// Verifies the signature stored after a plaintext footer
+    ///
+    /// The signature is the nonce and AES-GCM tag of the footer encrypted with
+    /// the footer key, which is recomputed and compared with the stored tag.
+    pub(crate) fn verify_plaintext_footer_signature(
+        &self,
+        plaintext_footer: &[u8],
+        signature: &[u8],
+    ) -> Result<()> {
+        if !self
+            .decryption_properties
+            .check_plaintext_footer_integrity()
+        {
+            return Ok(());
+        }
+        if signature.len() != NONCE_LEN + TAG_LEN {
+            return Err(general_err!("Invalid plaintext footer signature"));
+        }
+        let (nonce, tag) = signature.split_at(NONCE_LEN);
+        let aad = create_footer_aad(&self.file_aad);
+        let encrypted = self
+            .footer_cipher
+            .encrypt_with_nonce(nonce, plaintext_footer, &aad)?;
+        if &encrypted[encrypted.len() - TAG_LEN..] != tag {
+            return Err(general_err!(
+                "Plaintext footer signature verification failed, the footer key may be incorrect"
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Decrypts the modules of a single column chunk
+#[derive(Debug, Clone)]
+pub(crate) struct CryptoContext {
+    row_group_ordinal: usize,
+    column_ordinal: usize,
+    metadata_decryptor: Arc<dyn BlockDecryptor>,
+    data_decryptor: Arc<dyn BlockDecryptor>,
+    file_aad: Vec<u8>,
+}
+
+impl CryptoContext {
+    /// Creates the [`CryptoContext`] of the column chunk at `column_ordinal` within the
+    /// row group at `row_group_ordinal`, encrypted as described by `crypto_metadata`
+    pub(crate) fn for_column(
+        file_decryptor: &FileDecryptor,
+        crypto_metadata: &ColumnCryptoMetaData,
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+    ) -> Result<Self> {
+        let (metadata_decryptor, data_decryptor): (
+            Arc<dyn BlockDecryptor>,
+            Arc<dyn BlockDecryptor>,
+        ) = match crypto_metadata {
+            ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_) => {
+                let data_decryptor: Arc<dyn BlockDecryptor> = match file_decryptor.ctr {
+                    true => Arc::new(AesCtrCipher::try_new(&file_decryptor.footer_key)?),
+                    false => file_decryptor.footer_cipher.clone(),
+                };
+                (file_decryptor.footer_cipher.clone(), data_decryptor)
+            }
+            ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(column_key) => {
+                let column_name = column_key.path_in_schema.join(".");
+                let key = file_decryptor
+                    .decryption_properties
+                    .column_key(&column_name, column_key.key_metadata.as_deref())?;
+                let metadata_decryptor = Arc::new(AesGcmCipher::try_new(&key)?);
+                let data_decryptor: Arc<dyn BlockDecryptor> = match file_decryptor.ctr {
+                    true => Arc::new(AesCtrCipher::try_new(&key)?),
+                    false => metadata_decryptor.clone(),
+                };
+                (metadata_decryptor, data_decryptor)
+            }
+        };
+
+        Ok(Self {
+            row_group_ordinal,
+            column_ordinal,
+            metadata_decryptor,
+            data_decryptor,
+            file_aad: file_decryptor.file_aad.clone(),
+        })
+    }
+
+    /// Returns the [`CryptoContext`] of the column chunk at `column_ordinal` within
+    /// `row_group`, or `None` if the column chunk isn't encrypted or there is no
+    /// `file_decryptor`
+    pub(crate) fn for_column_chunk(
+        file_decryptor: Option<&FileDecryptor>,
+        row_group: &RowGroupMetaData,
+        column_ordinal: usize,
+    ) -> Result<Option<Self>> {
+        let crypto_metadata = row_group.column(column_ordinal).crypto_metadata();
+        let (Some(file_decryptor), Some(crypto_metadata)) = (file_decryptor, crypto_metadata)
+        else {
+            return Ok(None);
+        };
+        let row_group_ordinal = row_group
+            .ordinal()
+            .ok_or_else(|| general_err!("Encrypted row group is missing its ordinal"))?;
+        let row_group_ordinal = usize::try_from(row_group_ordinal)
+            .map_err(|_| general_err!("Invalid row group ordinal {}", row_group_ordinal))?;
+        Self::for_column(
+            file_decryptor,
+            crypto_metadata,
+            row_group_ordinal,
+            column_ordinal,
+        )
+        .map(Some)
+    }
+
+    fn aad(&self, module_type: ModuleType, page_ordinal: Option<usize>) -> Result<Vec<u8>> {
+        create_module_aad(
+            &self.file_aad,
+            module_type,
+            self.row_group_ordinal,
+            self.column_ordinal,
+            page_ordinal,
+        )
+    }
+
+    /// Decrypts the header of the page at `page_ordinal`, or of the dictionary page
+    /// if `dictionary` is true
+    pub(crate) fn decrypt_page_header(
+        &self,
+        encrypted: &[u8],
+        page_ordinal: usize,
+        dictionary: bool,
+    ) -> Result<Vec<u8>> {
+        let aad = match dictionary {
+            true => self.aad(ModuleType::DictionaryPageHeader, None)?,
+            false => self.aad(ModuleType::DataPageHeader, Some(page_ordinal))?,
+        };
+        self.metadata_decryptor.decrypt(encrypted, &aad)
+    }
+
+    /// Decrypts the data of the page at `page_ordinal`, or of the dictionary page
+    /// if `dictionary` is true
+    pub(crate) fn decrypt_page(
+        &self,
+        encrypted: &[u8],
+        page_ordinal: usize,
+        dictionary: bool,
+    ) -> Result<Vec<u8>> {
+        let aad = match dictionary {
+            true => self.aad(ModuleType::DictionaryPage, None)?,
+            false => self.aad(ModuleType::DataPage, Some(page_ordinal))?,
+        };
+        self.data_decryptor.decrypt(encrypted, &aad)
+    }
+
+    /// Decrypts a module of `module_type` other than a page or page header, such
+    /// as the column metadata or the column and offset indexes
+    pub(crate) fn decrypt_module(
+        &self,
+        module_type: ModuleType,
+        encrypted: &[u8],
+    ) -> Result<Vec<u8>> {
+        let aad = self.aad(module_type, None)?;
+        self.metadata_decryptor.decrypt(encrypted, &aad)
+    }
+}
+
+/// Reads an encrypted module, including its length prefix, from `input`
+pub(crate) fn read_encrypted_module<T: Read>(input: &mut T) -> Result<Vec<u8>> {
+    let mut length = [0_u8; SIZE_LEN];
+    input.read_exact(&mut length)?;
+    let len = u32::from_le_bytes(length) as usize;
+
+    let mut module = Vec::with_capacity(SIZE_LEN + len);
+    module.extend_from_slice(&length);
+    let read = input.take(len as u64).read_to_end(&mut module)?;
+    if read != len {
+        return Err(eof_err!(
+            "Expected to read {} bytes of encrypted module, read only {}",
+            len,
+            read
+        ));
+    }
+    Ok(module)
+}
@@ -0,0 +1,480 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Configuration and utilities for writing encrypted Parquet files
+
+use crate::column::page::CompressedPage;
+use crate::encryption::ciphers::{
+    random_bytes, validate_key, AesCtrCipher, AesGcmCipher, BlockEncryptor, NONCE_LEN, SIZE_LEN,
+    TAG_LEN,
+};
+use crate::encryption::modules::{create_footer_aad, create_module_aad, ModuleType};
+use crate::errors::{ParquetError, Result};
+use crate::format::{
+    AesGcmCtrV1, AesGcmV1, ColumnCryptoMetaData, EncryptionAlgorithm, EncryptionWithColumnKey,
+    EncryptionWithFooterKey, PageHeader, PageType,
+};
+use crate::schema::types::{ColumnPath, SchemaDescriptor};
+use crate::thrift::TSerializable;
+use bytes::Bytes;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use thrift::protocol::TCompactOutputProtocol;
+
+/// Length of the unique identifier generated for each encrypted file
+const AAD_FILE_UNIQUE_LEN: usize = 8;
+
+/// The encryption algorithm used to encrypt a file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParquetCipher {
+    /// AES-GCM for all modules, authenticating both metadata and data (default)
+    #[default]
+    AesGcmV1,
+    /// AES-GCM for metadata and AES-CTR for pages, which is faster but doesn't
+    /// authenticate page data
+    AesGcmCtrV1,
+}
+
+#[derive(Clone, PartialEq)]
+struct EncryptionKey {
+    key: Vec<u8>,
+    key_metadata: Option<Vec<u8>>,
+}
+
+impl EncryptionKey {
+    fn new(key: Vec<u8>) -> Self {
+        Self {
+            key,
+            key_metadata: None,
+        }
+    }
+}
+
+/// Properties used to write an encrypted Parquet file
+///
+/// By default all columns, and the footer, are encrypted with the footer key.
+/// If any column keys are provided, only those columns are encrypted, each with
+/// its own key, and the remaining columns are written in plaintext.
+///
+/// Optional key metadata, typically an identifier of the key within a key management
+/// service, is stored in the file so readers can retrieve keys with a
+/// [`KeyRetriever`](crate::encryption::decrypt::KeyRetriever).
+///
+/// # Example
+///
+/// ```
+/// # use parquet::encryption::encrypt::FileEncryptionProperties;
+/// let properties = FileEncryptionProperties::builder(b"0123456789012345".to_vec())
+///     .with_footer_key_metadata(b"kf".to_vec())
+///     .with_column_key_and_metadata("x", b"1234567890123450".to_vec(), b"kc1".to_vec())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct FileEncryptionProperties {
+    footer_key: EncryptionKey,
+    column_keys: HashMap<String, EncryptionKey>,
+    cipher: ParquetCipher,
+    encrypt_footer: bool,
+    aad_prefix: Option<Vec<u8>>,
+    store_aad_prefix: bool,
+}
+
+impl Debug for FileEncryptionProperties {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Avoid leaking keys into logs
+        let mut columns = self.column_keys.keys().collect::<Vec<_>>();
+        columns.sort_unstable();
+        f.debug_struct("FileEncryptionProperties")
+            .field("encrypted_columns", &columns)
+            .field("cipher", &self.cipher)
+            .field("encrypt_footer", &self.encrypt_footer)
+            .field("aad_prefix", &self.aad_prefix)
+            .field("store_aad_prefix", &self.store_aad_prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FileEncryptionProperties {
+    /// Returns an [`EncryptionPropertiesBuilder`] for encrypting files with the
+    /// provided footer key
+    pub fn builder(footer_key: Vec<u8>) -> EncryptionPropertiesBuilder {
+        EncryptionPropertiesBuilder::new(footer_key)
+    }
+
+    /// Returns the encryption algorithm
+    pub fn cipher(&self) -> ParquetCipher {
+        self.cipher
+    }
+
+    /// Returns whether the footer is encrypted, if false the footer is written
+    /// in plaintext and signed with the footer key
+    pub fn encrypt_footer(&self) -> bool {
+        self.encrypt_footer
+    }
+
+    /// Returns the AAD prefix, if any
+    pub fn aad_prefix(&self) -> Option<&[u8]> {
+        self.aad_prefix.as_deref()
+    }
+
+    /// Returns whether the AAD prefix is stored in the file
+    pub fn store_aad_prefix(&self) -> bool {
+        self.store_aad_prefix
+    }
+
+    /// Returns whether the column with dot separated path `column_name` is encrypted
+    pub fn is_column_encrypted(&self, column_name: &str) -> bool {
+        self.column_keys.is_empty() || self.column_keys.contains_key(column_name)
+    }
+}
+
+/// Builder for [`FileEncryptionProperties`]
+pub struct EncryptionPropertiesBuilder {
+    footer_key: EncryptionKey,
+    column_keys: HashMap<String, EncryptionKey>,
+    cipher: ParquetCipher,
+    encrypt_footer: bool,
+    aad_prefix: Option<Vec<u8>>,
+    store_aad_prefix: bool,
+}
+
+impl EncryptionPropertiesBuilder {
+    fn new(footer_key: Vec<u8>) -> Self {
+        Self {
+            footer_key: EncryptionKey::new(footer_key),
+            column_keys: HashMap::new(),
+            cipher: ParquetCipher::default(),
+            encrypt_footer: true,
+            aad_prefix: None,
+            store_aad_prefix: true,
+        }
+    }
+
+    /// Sets the metadata stored in the file to identify the footer key
+    pub fn with_footer_key_metadata(mut self, key_metadata: Vec<u8>) -> Self {
+        self.footer_key.key_metadata = Some(key_metadata);
+        self
+    }
+
+    /// Encrypts the column with dot separated path `column_name` with its own key
+    pub fn with_column_key(mut self, column_name: &str, key: Vec<u8>) -> Self {
+        self.column_keys
+            .insert(column_name.to_string(), EncryptionKey::new(key));
+        self
+    }
+
+    /// Encrypts the column with dot separated path `column_name` with its own key,
+    /// storing `key_metadata` in the file to identify the key
+    pub fn with_column_key_and_metadata(
+        mut self,
+        column_name: &str,
+        key: Vec<u8>,
+        key_metadata: Vec<u8>,
+    ) -> Self {
+        let key = EncryptionKey {
+            key,
+            key_metadata: Some(key_metadata),
+        };
+        self.column_keys.insert(column_name.to_string(), key);
+        self
+    }
+
+    /// Sets the encryption algorithm, defaults to [`ParquetCipher::AesGcmV1`]
+    pub fn with_cipher(mut self, cipher: ParquetCipher) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    /// Sets whether to write the footer in plaintext, allowing readers without the
+    /// footer key to read the metadata and any unencrypted columns
+    pub fn with_plaintext_footer(mut self, plaintext_footer: bool) -> Self {
+        self.encrypt_footer = !plaintext_footer;
+        self
+    }
+
+    /// Sets an AAD prefix, binding the encrypted modules to some identifier of the
+    /// file, such as its path, so that files can't be swapped without detection
+    pub fn with_aad_prefix(mut self, aad_prefix: Vec<u8>) -> Self {
+        self.aad_prefix = Some(aad_prefix);
+        self
+    }
+
+    /// Sets whether to store the AAD prefix in the file, defaults to true
+    ///
+    /// If not stored, readers must provide the AAD prefix to decrypt the file
+    pub fn with_aad_prefix_storage(mut self, store_aad_prefix: bool) -> Self {
+        self.store_aad_prefix = store_aad_prefix;
+        self
+    }
+
+    /// Validates the configured keys and returns the [`FileEncryptionProperties`]
+    pub fn build(self) -> Result<FileEncryptionProperties> {
+        validate_key(&self.footer_key.key)?;
+        for key in self.column_keys.values() {
+            validate_key(&key.key)?;
+        }
+        Ok(FileEncryptionProperties {
+            footer_key: self.footer_key,
+            column_keys: self.column_keys,
+            cipher: self.cipher,
+            encrypt_footer: self.encrypt_footer,
+            aad_prefix: self.aad_prefix,
+            store_aad_prefix: self.store_aad_prefix,
+        })
+    }
+}
+
+/// Encrypts the modules of a single file
+#[derive(Debug)]
+pub(crate) struct FileEncryptor {
+    properties: FileEncryptionProperties,
+    aad_file_unique: Vec<u8>,
+    file_aad: Vec<u8>,
+    footer_cipher: Arc<AesGcmCipher>,
+}
+
+impl FileEncryptor {
+    /// Creates a new [`FileEncryptor`] for writing a file with `schema`
+    pub(crate) fn try_new(
+        properties: FileEncryptionProperties,
+        schema: &SchemaDescriptor,
+    ) -> Result<Self> {
+        for column_name in properties.column_keys.keys() {
+            if !schema
+                .columns()
+                .iter()
+                .any(|c| &c.path().string() == column_name)
+            {
+                return Err(general_err!(
+                    "Encryption key provided for column '{}', which is not in the schema",
+                    column_name
+                ));
+            }
+        }
+
+        let aad_file_unique = random_bytes(AAD_FILE_UNIQUE_LEN);
+        let mut file_aad = properties.aad_prefix.clone().unwrap_or_default();
+        file_aad.extend_from_slice(&aad_file_unique);
+        let footer_cipher = Arc::new(AesGcmCipher::try_new(&properties.footer_key.key)?);
+
+        Ok(Self {
+            properties,
+            aad_file_unique,
+            file_aad,
+            footer_cipher,
+        })
+    }
+
+    /// Returns the [`FileEncryptionProperties`]
+    pub(crate) fn properties(&self) -> &FileEncryptionProperties {
+        &self.properties
+    }
+
+    /// Returns the encryption algorithm to store in the file metadata
+    pub(crate) fn encryption_algorithm(&self) -> EncryptionAlgorithm {
+        let supply_aad_prefix = self
+            .properties
+            .aad_prefix
+            .as_ref()
+            .map(|_| !self.properties.store_aad_prefix)
+            .filter(|supply| *supply);
+        let aad_prefix = match self.properties.store_aad_prefix {
+            true => self.properties.aad_prefix.clone(),
+            false => None,
+        };
+        let aad_file_unique = Some(self.aad_file_unique.clone());
+        match self.properties.cipher {
+            ParquetCipher::AesGcmV1 => EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+                aad_prefix,
+                aad_file_unique,
+                supply_aad_prefix,
+            }),
+            ParquetCipher::AesGcmCtrV1 => EncryptionAlgorithm::AESGCMCTRV1(AesGcmCtrV1 {
+                aad_prefix,
+                aad_file_unique,
+                supply_aad_prefix,
+            }),
+        }
+    }
+
+    /// Returns the metadata identifying the footer key, if any
+    pub(crate) fn footer_key_metadata(&self) -> Option<&Vec<u8>> {
+        self.properties.footer_key.key_metadata.as_ref()
+    }
+
+    /// Returns the [`ColumnCryptoMetaData`] of the column at `column_path`, or `None`
+    /// if the column isn't encrypted
+    pub(crate) fn column_crypto_metadata(
+        &self,
+        column_path: &ColumnPath,
+    ) -> Option<ColumnCryptoMetaData> {
+        if self.properties.column_keys.is_empty() {
+            return Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(
+                EncryptionWithFooterKey {},
+            ));
+        }
+        let key = self.properties.column_keys.get(&column_path.string())?;
+        Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(
+            EncryptionWithColumnKey {
+                path_in_schema: column_path.parts().to_vec(),
+                key_metadata: key.key_metadata.clone(),
+            },
+        ))
+    }
+
+    /// Returns the [`ColumnEncryptor`] of the column chunk at `column_ordinal` within the
+    /// row group at `row_group_ordinal`, or `None` if the column isn't encrypted
+    pub(crate) fn column_encryptor(
+        &self,
+        column_path: &ColumnPath,
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+    ) -> Result<Option<ColumnEncryptor>> {
+        let key = match self.properties.column_keys.is_empty() {
+            true => &self.properties.footer_key,
+            false => match self.properties.column_keys.get(&column_path.string()) {
+                Some(key) => key,
+                None => return Ok(None),
+            },
+        };
+        let metadata_encryptor = Arc::new(AesGcmCipher::try_new(&key.key)?);
+        let data_encryptor: Arc<dyn BlockEncryptor> = match self.properties.cipher {
+            ParquetCipher::AesGcmV1 => metadata_encryptor.clone(),
+            ParquetCipher::AesGcmCtrV1 => Arc::new(AesCtrCipher::try_new(&key.key)?),
+        };
+        Ok(Some(ColumnEncryptor {
+            row_group_ordinal,
+            column_ordinal,
+            metadata_encryptor,
+            data_encryptor,
+            file_aad: self.file_aad.clone(),
+        }))
+    }
+
+    /// Encrypts the serialized `FileMetaData` of a file with an encrypted footer
+    pub(crate) fn encrypt_footer(&self, footer: &[u8]) -> Result<Vec<u8>> {
+        let aad = create_footer_aad(&self.file_aad);
+        self.footer_cipher.encrypt(footer, &aad)
+    }
+
+    /// Returns the signature written after the serialized `FileMetaData` of a file
+    /// with a plaintext footer, consisting of a nonce and the AES-GCM tag of the footer
+    pub(crate) fn sign_footer(&self, footer: &[u8]) -> Result<Vec<u8>> {
+        let aad = create_footer_aad(&self.file_aad);
+        let encrypted = self.footer_cipher.encrypt(footer, &aad)?;
+        let mut signature = Vec::with_capacity(NONCE_LEN + TAG_LEN);
+        signature.extend_from_slice(&encrypted[SIZE_LEN..SIZE_LEN + NONCE_LEN]);
+        signature.extend_from_slice(&encrypted[encrypted.len() - TAG_LEN..]);
+        Ok(signature)
+    }
+}
+
+/// Encrypts the modules of a single column chunk
+#[derive(Debug, Clone)]
+pub(crate) struct ColumnEncryptor {
+    row_group_ordinal: usize,
+    column_ordinal: usize,
+    metadata_encryptor: Arc<dyn BlockEncryptor>,
+    data_encryptor: Arc<dyn BlockEncryptor>,
+    file_aad: Vec<u8>,
+}
+
+impl ColumnEncryptor {
+    fn aad(&self, module_type: ModuleType, page_ordinal: Option<usize>) -> Result<Vec<u8>> {
+        create_module_aad(
+            &self.file_aad,
+            module_type,
+            self.row_group_ordinal,
+            self.column_ordinal,
+            page_ordinal,
+        )
+    }
+
+    /// Encrypts a module of `module_type` other than a page or page header, such
+    /// as the column metadata or the column and offset indexes
+    pub(crate) fn encrypt_module(
+        &self,
+        module_type: ModuleType,
+        plaintext: &[u8],
+    ) -> Result<Vec<u8>> {
+        let aad = self.aad(module_type, None)?;
+        self.metadata_encryptor.encrypt(plaintext, &aad)
+    }
+
+    /// Serializes and encrypts a thrift module of `module_type`
+    pub(crate) fn encrypt_thrift<T: TSerializable>(
+        &self,
+        module_type: ModuleType,
+        value: &T,
+    ) -> Result<Vec<u8>> {
+        self.encrypt_module(module_type, &serialize(value)?)
+    }
+}
+
+/// Encrypts the pages and page headers of a single column chunk
+#[derive(Debug)]
+pub(crate) struct PageEncryptor {
+    column: ColumnEncryptor,
+    page_ordinal: usize,
+}
+
+impl PageEncryptor {
+    pub(crate) fn new(column: ColumnEncryptor) -> Self {
+        Self {
+            column,
+            page_ordinal: 0,
+        }
+    }
+
+    /// Encrypts the data of `page`, returning a page whose buffer is the encrypted module
+    pub(crate) fn encrypt_page(&self, page: CompressedPage) -> Result<CompressedPage> {
+        let (module_type, page_ordinal) = match page.page_type() {
+            crate::basic::PageType::DICTIONARY_PAGE => (ModuleType::DictionaryPage, None),
+            _ => (ModuleType::DataPage, Some(self.page_ordinal)),
+        };
+        let aad = self.column.aad(module_type, page_ordinal)?;
+        let encrypted = self.column.data_encryptor.encrypt(page.data(), &aad)?;
+        Ok(page.with_new_compressed_buffer(Bytes::from(encrypted)))
+    }
+
+    /// Serializes and encrypts the `header` of a page previously encrypted with
+    /// [`Self::encrypt_page`], advancing to the next page
+    pub(crate) fn encrypt_page_header(&mut self, header: &PageHeader) -> Result<Vec<u8>> {
+        let aad = match header.type_ {
+            PageType::DICTIONARY_PAGE => self.column.aad(ModuleType::DictionaryPageHeader, None)?,
+            _ => {
+                let aad = self
+                    .column
+                    .aad(ModuleType::DataPageHeader, Some(self.page_ordinal))?;
+                self.page_ordinal += 1;
+                aad
+            }
+        };
+        self.column
+            .metadata_encryptor
+            .encrypt(&serialize(header)?, &aad)
+    }
+}
+
+fn serialize<T: TSerializable>(value: &T) -> Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    let mut protocol = TCompactOutputProtocol::new(&mut buffer);
+    value.write_to_out_protocol(&mut protocol)?;
+    Ok(buffer)
+}
@@ -0,0 +1,40 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [Parquet Modular Encryption]
+//!
+//! Encrypted files protect the confidentiality and integrity of their data and
+//! metadata by encrypting each module (page, page header, column metadata, index,
+//! bloom filter and footer) separately with AES-GCM, or with AES-CTR for pages when
+//! using [`ParquetCipher::AesGcmCtrV1`](encrypt::ParquetCipher::AesGcmCtrV1).
+//!
+//! Files are written with [`FileEncryptionProperties`](encrypt::FileEncryptionProperties),
+//! configured with [`WriterPropertiesBuilder::set_file_encryption_properties`], and read
+//! with [`FileDecryptionProperties`](decrypt::FileDecryptionProperties), configured with
+//! [`ReaderPropertiesBuilder::set_file_decryption_properties`] or, when reading arrow data,
+//! `ArrowReaderOptions::with_file_decryption_properties`.
+//!
+//! Requires the `encryption` feature to be enabled.
+//!
+//! [Parquet Modular Encryption]: https://github.com/apache/parquet-format/blob/master/Encryption.md
+//! [`WriterPropertiesBuilder::set_file_encryption_properties`]: crate::file::properties::WriterPropertiesBuilder::set_file_encryption_properties
+//! [`ReaderPropertiesBuilder::set_file_decryption_properties`]: crate::file::properties::ReaderPropertiesBuilder::set_file_decryption_properties
+
+pub(crate) mod ciphers;
+pub mod decrypt;
+pub mod encrypt;
+pub(crate) mod modules;
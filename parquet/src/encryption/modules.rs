@@ -0,0 +1,123 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Additional authenticated data (AAD) of encrypted modules
+
+use crate::errors::{ParquetError, Result};
+
+/// The type of an encrypted module, as stored in its AAD suffix
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+    ColumnIndex = 6,
+    OffsetIndex = 7,
+    BloomFilterHeader = 8,
+    BloomFilterBitset = 9,
+}
+
+/// Returns the AAD of the footer of a file with the provided file AAD
+pub(crate) fn create_footer_aad(file_aad: &[u8]) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(file_aad.len() + 1);
+    aad.extend_from_slice(file_aad);
+    aad.push(ModuleType::Footer as u8);
+    aad
+}
+
+/// Returns the AAD of a module within a column chunk
+///
+/// This is the file AAD, followed by the module type and the little endian
+/// 2 byte row group and column ordinals. Data pages and data page headers are
+/// additionally suffixed with the ordinal of the page within the column chunk,
+/// which must be provided as `page_ordinal`.
+pub(crate) fn create_module_aad(
+    file_aad: &[u8],
+    module_type: ModuleType,
+    row_group_ordinal: usize,
+    column_ordinal: usize,
+    page_ordinal: Option<usize>,
+) -> Result<Vec<u8>> {
+    if module_type == ModuleType::Footer {
+        return Ok(create_footer_aad(file_aad));
+    }
+
+    let mut aad = Vec::with_capacity(file_aad.len() + 7);
+    aad.extend_from_slice(file_aad);
+    aad.push(module_type as u8);
+    aad.extend_from_slice(&ordinal_bytes(row_group_ordinal, "row groups")?);
+    aad.extend_from_slice(&ordinal_bytes(column_ordinal, "columns")?);
+
+    if matches!(
+        module_type,
+        ModuleType::DataPage | ModuleType::DataPageHeader
+    ) {
+        let page_ordinal = page_ordinal
+            .ok_or_else(|| general_err!("Page ordinal required for {:?} module", module_type))?;
+        aad.extend_from_slice(&ordinal_bytes(page_ordinal, "pages per column chunk")?);
+    }
+    Ok(aad)
+}
+
+fn ordinal_bytes(ordinal: usize, what: &str) -> Result<[u8; 2]> {
+    let ordinal = i16::try_from(ordinal).map_err(|_| {
+        general_err!(
+            "Encrypted parquet files can't have more than {} {}: {}",
+            i16::MAX,
+            what,
+            ordinal
+        )
+    })?;
+    Ok(ordinal.to_le_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_aad() {
+        let file_aad = b"abc";
+        assert_eq!(create_footer_aad(file_aad), b"abc\x00");
+
+        let aad = create_module_aad(file_aad, ModuleType::ColumnMetaData, 1, 258, None).unwrap();
+        assert_eq!(aad, b"abc\x01\x01\x00\x02\x01");
+
+        let aad = create_module_aad(file_aad, ModuleType::DataPage, 0, 1, Some(3)).unwrap();
+        assert_eq!(aad, b"abc\x02\x00\x00\x01\x00\x03\x00");
+
+        // Dictionary pages don't include a page ordinal
+        let aad =
+            create_module_aad(file_aad, ModuleType::DictionaryPageHeader, 0, 1, Some(3)).unwrap();
+        assert_eq!(aad, b"abc\x05\x00\x00\x01\x00");
+
+        let err = create_module_aad(file_aad, ModuleType::DataPageHeader, 0, 0, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Page ordinal required for DataPageHeader module"
+        );
+
+        let err = create_module_aad(file_aad, ModuleType::OffsetIndex, 40000, 0, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Encrypted parquet files can't have more than 32767 row groups: 40000"
+        );
+    }
+}
@@ -43,6 +43,20 @@ pub enum ParquetError {
     /// Returned when reading into arrow or writing from arrow.
     ArrowError(String),
     IndexOutOfBound(usize, usize),
+    /// Page checksum mismatch.
+    /// Returned when the CRC32 checksum stored in a page header does not match the page data.
+    ChecksumMismatch {
+        /// The path of the column
+        column: String,
+        /// The file offset of the column chunk
+        column_chunk_offset: u64,
+        /// The index of the page within the column chunk, including any dictionary page
+        page: usize,
+        /// The checksum stored in the page header
+        expected: u32,
+        /// The checksum of the page data
+        actual: u32,
+    },
     /// An external error variant
     External(Box<dyn Error + Send + Sync>),
 }
@@ -60,6 +74,17 @@ impl std::fmt::Display for ParquetError {
             ParquetError::IndexOutOfBound(index, ref bound) => {
                 write!(fmt, "Index {index} out of bound: {bound}")
             }
+            ParquetError::ChecksumMismatch {
+                column,
+                column_chunk_offset,
+                page,
+                expected,
+                actual,
+            } => write!(
+                fmt,
+                "Checksum mismatch: page {page} of column chunk {column} at offset \
+                 {column_chunk_offset} has CRC32 {actual:#010x}, expected {expected:#010x}"
+            ),
             ParquetError::External(e) => write!(fmt, "External: {e}"),
         }
     }
@@ -22,8 +22,17 @@ use crate::thrift::{TCompactSliceInputProtocol, TSerializable};
 
 use crate::basic::ColumnOrder;
 
+#[cfg(feature = "encryption")]
+use crate::encryption::{
+    decrypt::{CryptoContext, FileDecryptionProperties, FileDecryptor},
+    modules::ModuleType,
+};
 use crate::errors::{ParquetError, Result};
-use crate::file::{metadata::*, reader::ChunkReader, FOOTER_SIZE, PARQUET_MAGIC};
+use crate::file::{
+    metadata::*, reader::ChunkReader, FOOTER_SIZE, PARQUET_MAGIC, PARQUET_MAGIC_ENCR_FOOTER,
+};
+#[cfg(feature = "encryption")]
+use crate::format::{ColumnMetaData as TColumnMetaData, FileCryptoMetaData as TFileCryptoMetaData};
 
 use crate::schema::types::{self, SchemaDescriptor};
 
@@ -52,6 +61,31 @@ use crate::schema::types::{self, SchemaDescriptor};
 /// [`decode_metadata`] for decoding the metadata from the bytes.
 /// [`decode_footer`] for decoding the metadata length from the footer.
 pub fn parse_metadata<R: ChunkReader>(chunk_reader: &R) -> Result<ParquetMetaData> {
+    let (metadata, encrypted_footer) = read_metadata(chunk_reader)?;
+    if encrypted_footer {
+        return Err(encrypted_footer_err());
+    }
+    decode_metadata(metadata.as_ref())
+}
+
+/// Reads the [ParquetMetaData] from the footer of a potentially encrypted parquet file.
+///
+/// Files with an encrypted footer can only be read if `decryption_properties`
+/// are provided. Files with a plaintext footer can be read without, but then
+/// only their unencrypted columns can be read.
+///
+/// See [`parse_metadata`] for more information.
+#[cfg(feature = "encryption")]
+pub fn parse_metadata_with_decryption<R: ChunkReader>(
+    chunk_reader: &R,
+    decryption_properties: Option<&FileDecryptionProperties>,
+) -> Result<ParquetMetaData> {
+    let (metadata, encrypted_footer) = read_metadata(chunk_reader)?;
+    decode_metadata_with_decryption(metadata.as_ref(), encrypted_footer, decryption_properties)
+}
+
+/// Reads the encoded metadata, returning it and whether it is encrypted
+fn read_metadata<R: ChunkReader>(chunk_reader: &R) -> Result<(bytes::Bytes, bool)> {
     // check file is large enough to hold footer
     let file_size = chunk_reader.len();
     if file_size < (FOOTER_SIZE as u64) {
@@ -65,7 +99,7 @@ pub fn parse_metadata<R: ChunkReader>(chunk_reader: &R) -> Result<ParquetMetaDat
         .get_read(file_size - 8)?
         .read_exact(&mut footer)?;
 
-    let metadata_len = decode_footer(&footer)?;
+    let (metadata_len, encrypted_footer) = decode_footer_with_encryption(&footer)?;
     let footer_metadata_len = FOOTER_SIZE + metadata_len;
 
     if footer_metadata_len > file_size as usize {
@@ -78,7 +112,10 @@ pub fn parse_metadata<R: ChunkReader>(chunk_reader: &R) -> Result<ParquetMetaDat
     }
 
     let start = file_size - footer_metadata_len as u64;
-    decode_metadata(chunk_reader.get_bytes(start, metadata_len)?.as_ref())
+    Ok((
+        chunk_reader.get_bytes(start, metadata_len)?,
+        encrypted_footer,
+    ))
 }
 
 /// Decodes [`ParquetMetaData`] from the provided bytes.
@@ -89,10 +126,113 @@ pub fn parse_metadata<R: ChunkReader>(chunk_reader: &R) -> Result<ParquetMetaDat
 ///
 /// [Parquet Spec]: https://github.com/apache/parquet-format#metadata
 pub fn decode_metadata(buf: &[u8]) -> Result<ParquetMetaData> {
-    // TODO: row group filtering
     let mut prot = TCompactSliceInputProtocol::new(buf);
     let t_file_metadata: TFileMetaData = TFileMetaData::read_from_in_protocol(&mut prot)
         .map_err(|e| ParquetError::General(format!("Could not parse metadata: {e}")))?;
+    convert_file_metadata(t_file_metadata)
+}
+
+/// Decodes [`ParquetMetaData`] from the provided bytes of a potentially encrypted file.
+///
+/// If `encrypted_footer` is true, as returned by [`decode_footer_with_encryption`],
+/// `buf` contains the `FileCryptoMetaData` followed by the encrypted `FileMetaData`,
+/// which requires `decryption_properties`. Otherwise `buf` contains the `FileMetaData`,
+/// followed by its signature if the file has encrypted columns.
+///
+/// The returned metadata retains the file decryptor, which is used to decrypt
+/// the column chunks of the file.
+#[cfg(feature = "encryption")]
+pub fn decode_metadata_with_decryption(
+    buf: &[u8],
+    encrypted_footer: bool,
+    decryption_properties: Option<&FileDecryptionProperties>,
+) -> Result<ParquetMetaData> {
+    let mut prot = TCompactSliceInputProtocol::new(buf);
+    let mut file_decryptor = None;
+    let decrypted_footer;
+
+    let footer = if encrypted_footer {
+        let decryption_properties = decryption_properties.ok_or_else(encrypted_footer_err)?;
+        let t_crypto_metadata = TFileCryptoMetaData::read_from_in_protocol(&mut prot)
+            .map_err(|e| general_err!("Could not parse crypto metadata: {}", e))?;
+        let decryptor = FileDecryptor::try_new(
+            decryption_properties,
+            t_crypto_metadata.key_metadata.as_deref(),
+            &t_crypto_metadata.encryption_algorithm,
+        )?;
+        decrypted_footer = decryptor.decrypt_footer(prot.as_slice())?;
+        file_decryptor = Some(decryptor);
+        decrypted_footer.as_slice()
+    } else {
+        buf
+    };
+
+    let mut prot = TCompactSliceInputProtocol::new(footer);
+    let mut t_file_metadata: TFileMetaData = TFileMetaData::read_from_in_protocol(&mut prot)
+        .map_err(|e| ParquetError::General(format!("Could not parse metadata: {e}")))?;
+
+    if !encrypted_footer {
+        // A plaintext footer of a file with encrypted columns is followed by its signature
+        if let (Some(algorithm), Some(decryption_properties)) = (
+            t_file_metadata.encryption_algorithm.as_ref(),
+            decryption_properties,
+        ) {
+            let decryptor = FileDecryptor::try_new(
+                decryption_properties,
+                t_file_metadata.footer_signing_key_metadata.as_deref(),
+                algorithm,
+            )?;
+            let signature = prot.as_slice();
+            let plaintext_footer = &footer[..footer.len() - signature.len()];
+            decryptor.verify_plaintext_footer_signature(plaintext_footer, signature)?;
+            file_decryptor = Some(decryptor);
+        }
+    }
+
+    if let Some(decryptor) = file_decryptor.as_ref() {
+        decrypt_column_metadata(&mut t_file_metadata, decryptor)?;
+    }
+
+    let mut metadata = convert_file_metadata(t_file_metadata)?;
+    metadata.set_file_decryptor(file_decryptor);
+    Ok(metadata)
+}
+
+/// Decrypts the encrypted column metadata of `t_file_metadata`, replacing any
+/// plaintext column metadata
+#[cfg(feature = "encryption")]
+fn decrypt_column_metadata(
+    t_file_metadata: &mut TFileMetaData,
+    file_decryptor: &FileDecryptor,
+) -> Result<()> {
+    for (rg_idx, rg) in t_file_metadata.row_groups.iter_mut().enumerate() {
+        let row_group_ordinal = *rg.ordinal.get_or_insert(rg_idx as i16) as usize;
+        for (column_idx, column) in rg.columns.iter_mut().enumerate() {
+            let (Some(crypto_metadata), Some(encrypted)) = (
+                column.crypto_metadata.as_ref(),
+                column.encrypted_column_metadata.as_ref(),
+            ) else {
+                continue;
+            };
+            let crypto_context = CryptoContext::for_column(
+                file_decryptor,
+                crypto_metadata,
+                row_group_ordinal,
+                column_idx,
+            )?;
+            let decrypted = crypto_context.decrypt_module(ModuleType::ColumnMetaData, encrypted)?;
+            let mut prot = TCompactSliceInputProtocol::new(&decrypted);
+            let column_metadata = TColumnMetaData::read_from_in_protocol(&mut prot)
+                .map_err(|e| general_err!("Could not parse column metadata: {}", e))?;
+            column.meta_data = Some(column_metadata);
+        }
+    }
+    Ok(())
+}
+
+/// Converts the thrift `FileMetaData` into [`ParquetMetaData`]
+fn convert_file_metadata(t_file_metadata: TFileMetaData) -> Result<ParquetMetaData> {
+    // TODO: row group filtering
     let schema = types::from_thrift(&t_file_metadata.schema)?;
     let schema_descr = Arc::new(SchemaDescriptor::new(schema));
     let mut row_groups = Vec::new();
@@ -124,15 +264,41 @@ pub fn decode_metadata(buf: &[u8]) -> Result<ParquetMetaData> {
 /// +-----+--------+
 /// ```
 pub fn decode_footer(slice: &[u8; FOOTER_SIZE]) -> Result<usize> {
+    match decode_footer_with_encryption(slice)? {
+        (metadata_len, false) => Ok(metadata_len),
+        (_, true) => Err(encrypted_footer_err()),
+    }
+}
+
+/// Decodes the Parquet footer returning the metadata length in bytes, and whether
+/// the metadata is encrypted
+///
+/// Files with an encrypted footer use the magic bytes 'PARE' instead of 'PAR1',
+/// see [`decode_footer`].
+pub fn decode_footer_with_encryption(slice: &[u8; FOOTER_SIZE]) -> Result<(usize, bool)> {
     // check this is indeed a parquet file
-    if slice[4..] != PARQUET_MAGIC {
+    let encrypted_footer = if slice[4..] == PARQUET_MAGIC {
+        false
+    } else if slice[4..] == PARQUET_MAGIC_ENCR_FOOTER {
+        true
+    } else {
         return Err(general_err!("Invalid Parquet file. Corrupt footer"));
-    }
+    };
 
     // get the metadata length from the footer
     let metadata_len = u32::from_le_bytes(slice[..4].try_into().unwrap());
     // u32 won't be larger than usize in most cases
-    Ok(metadata_len as usize)
+    Ok((metadata_len as usize, encrypted_footer))
+}
+
+fn encrypted_footer_err() -> ParquetError {
+    if cfg!(feature = "encryption") {
+        general_err!(
+            "Parquet file has an encrypted footer but no decryption properties were provided"
+        )
+    } else {
+        general_err!("Parquet file has an encrypted footer, which requires the encryption feature")
+    }
 }
 
 /// Parses column orders from Thrift definition.
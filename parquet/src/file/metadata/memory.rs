@@ -101,6 +101,31 @@ impl HeapSize for ColumnChunkMetaData {
             + self.unencoded_byte_array_data_bytes.heap_size()
             + self.repetition_level_histogram.heap_size()
             + self.definition_level_histogram.heap_size()
+            + self.column_crypto_metadata_heap_size()
+    }
+}
+
+impl ColumnChunkMetaData {
+    #[cfg(feature = "encryption")]
+    fn column_crypto_metadata_heap_size(&self) -> usize {
+        self.column_crypto_metadata.heap_size()
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    fn column_crypto_metadata_heap_size(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(feature = "encryption")]
+impl HeapSize for crate::format::ColumnCryptoMetaData {
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::ENCRYPTIONWITHFOOTERKEY(_) => 0,
+            Self::ENCRYPTIONWITHCOLUMNKEY(key) => {
+                key.path_in_schema.heap_size() + key.key_metadata.heap_size()
+            }
+        }
     }
 }
 
@@ -198,6 +223,11 @@ impl HeapSize for bool {
         0 // no heap allocations
     }
 }
+impl HeapSize for u8 {
+    fn heap_size(&self) -> usize {
+        0 // no heap allocations
+    }
+}
 impl HeapSize for i32 {
     fn heap_size(&self) -> usize {
         0 // no heap allocations
@@ -44,12 +44,16 @@ use crate::format::{
 };
 
 use crate::basic::{ColumnOrder, Compression, Encoding, Type};
+#[cfg(feature = "encryption")]
+use crate::encryption::decrypt::FileDecryptor;
 use crate::errors::{ParquetError, Result};
 pub(crate) use crate::file::metadata::memory::HeapSize;
 use crate::file::page_encoding_stats::{self, PageEncodingStats};
 use crate::file::page_index::index::Index;
 use crate::file::page_index::offset_index::OffsetIndexMetaData;
 use crate::file::statistics::{self, Statistics};
+#[cfg(feature = "encryption")]
+use crate::format::ColumnCryptoMetaData;
 use crate::schema::types::{
     ColumnDescPtr, ColumnDescriptor, ColumnPath, SchemaDescPtr, SchemaDescriptor,
     Type as SchemaType,
@@ -109,6 +113,9 @@ pub struct ParquetMetaData {
     column_index: Option<ParquetColumnIndex>,
     /// Offset index for each page in each column chunk
     offset_index: Option<ParquetOffsetIndex>,
+    /// Decryptor of an encrypted file, if any
+    #[cfg(feature = "encryption")]
+    file_decryptor: Option<FileDecryptor>,
 }
 
 impl ParquetMetaData {
@@ -120,6 +127,8 @@ impl ParquetMetaData {
             row_groups,
             column_index: None,
             offset_index: None,
+            #[cfg(feature = "encryption")]
+            file_decryptor: None,
         }
     }
 
@@ -136,6 +145,8 @@ impl ParquetMetaData {
             row_groups,
             column_index,
             offset_index,
+            #[cfg(feature = "encryption")]
+            file_decryptor: None,
         }
     }
 
@@ -214,6 +225,18 @@ impl ParquetMetaData {
             + self.offset_index.heap_size()
     }
 
+    /// Returns the decryptor of an encrypted file, if any
+    #[cfg(feature = "encryption")]
+    pub(crate) fn file_decryptor(&self) -> Option<&FileDecryptor> {
+        self.file_decryptor.as_ref()
+    }
+
+    /// Sets the decryptor of an encrypted file
+    #[cfg(feature = "encryption")]
+    pub(crate) fn set_file_decryptor(&mut self, file_decryptor: Option<FileDecryptor>) {
+        self.file_decryptor = file_decryptor;
+    }
+
     /// Override the column index
     #[cfg(feature = "arrow")]
     pub(crate) fn set_column_index(&mut self, index: Option<ParquetColumnIndex>) {
@@ -559,6 +582,8 @@ pub struct ColumnChunkMetaData {
     unencoded_byte_array_data_bytes: Option<i64>,
     repetition_level_histogram: Option<LevelHistogram>,
     definition_level_histogram: Option<LevelHistogram>,
+    #[cfg(feature = "encryption")]
+    column_crypto_metadata: Option<ColumnCryptoMetaData>,
 }
 
 /// Histograms for repetition and definition levels.
@@ -848,6 +873,22 @@ impl ColumnChunkMetaData {
         self.definition_level_histogram.as_ref()
     }
 
+    /// Returns how this column chunk is encrypted, or `None` if it isn't
+    #[cfg(feature = "encryption")]
+    pub fn crypto_metadata(&self) -> Option<&ColumnCryptoMetaData> {
+        self.column_crypto_metadata.as_ref()
+    }
+
+    #[cfg(feature = "encryption")]
+    fn column_crypto_metadata_thrift(&self) -> Option<ColumnCryptoMetaData> {
+        self.column_crypto_metadata.clone()
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    fn column_crypto_metadata_thrift(&self) -> Option<crate::format::ColumnCryptoMetaData> {
+        None
+    }
+
     /// Method to convert from Thrift.
     pub fn from_thrift(column_descr: ColumnDescPtr, cc: ColumnChunk) -> Result<Self> {
         if cc.meta_data.is_none() {
@@ -925,6 +966,8 @@ impl ColumnChunkMetaData {
             unencoded_byte_array_data_bytes,
             repetition_level_histogram,
             definition_level_histogram,
+            #[cfg(feature = "encryption")]
+            column_crypto_metadata: cc.crypto_metadata,
         };
         Ok(result)
     }
@@ -941,7 +984,7 @@ impl ColumnChunkMetaData {
             offset_index_length: self.offset_index_length,
             column_index_offset: self.column_index_offset,
             column_index_length: self.column_index_length,
-            crypto_metadata: None,
+            crypto_metadata: self.column_crypto_metadata_thrift(),
             encrypted_column_metadata: None,
         }
     }
@@ -1029,6 +1072,8 @@ impl ColumnChunkMetaDataBuilder {
             unencoded_byte_array_data_bytes: None,
             repetition_level_histogram: None,
             definition_level_histogram: None,
+            #[cfg(feature = "encryption")]
+            column_crypto_metadata: None,
         })
     }
 
@@ -1604,7 +1649,10 @@ mod tests {
         let row_group_meta_with_stats = vec![row_group_meta_with_stats];
 
         let parquet_meta = ParquetMetaData::new(file_metadata.clone(), row_group_meta_with_stats);
+        #[cfg(not(feature = "encryption"))]
         let base_expected_size = 2280;
+        #[cfg(feature = "encryption")]
+        let base_expected_size = 2640;
 
         assert_eq!(parquet_meta.memory_size(), base_expected_size);
 
@@ -1632,7 +1680,10 @@ mod tests {
             ]]),
         );
 
+        #[cfg(not(feature = "encryption"))]
         let bigger_expected_size = 2784;
+        #[cfg(feature = "encryption")]
+        let bigger_expected_size = 3144;
         // more set fields means more memory usage
         assert!(bigger_expected_size > base_expected_size);
         assert_eq!(parquet_meta.memory_size(), bigger_expected_size);
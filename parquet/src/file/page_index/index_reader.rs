@@ -19,8 +19,15 @@
 
 use crate::basic::Type;
 use crate::data_type::Int96;
+#[cfg(feature = "encryption")]
+use crate::encryption::{
+    decrypt::{CryptoContext, FileDecryptor},
+    modules::ModuleType,
+};
 use crate::errors::ParquetError;
-use crate::file::metadata::ColumnChunkMetaData;
+#[cfg(feature = "encryption")]
+use crate::file::metadata::RowGroupMetaData;
+use crate::file::metadata::{ColumnChunkMetaData, ParquetMetaData};
 use crate::file::page_index::index::{Index, NativeIndex};
 use crate::file::page_index::offset_index::OffsetIndexMetaData;
 use crate::file::reader::ChunkReader;
@@ -147,6 +154,97 @@ pub fn read_offset_indexes<R: ChunkReader>(
         .collect()
 }
 
+/// Reads the [`Index`] and [`OffsetIndexMetaData`] of all columns of the row group
+/// at `row_group_idx`, see [`read_columns_indexes`] and [`read_offset_indexes`].
+///
+/// The indexes of encrypted columns are decrypted with the file decryptor of
+/// `metadata`, and are skipped if the file decryptor is missing.
+pub(crate) fn read_row_group_page_indexes<R: ChunkReader>(
+    reader: &R,
+    metadata: &ParquetMetaData,
+    row_group_idx: usize,
+) -> Result<(Vec<Index>, Vec<OffsetIndexMetaData>), ParquetError> {
+    let row_group = metadata.row_group(row_group_idx);
+
+    #[cfg(feature = "encryption")]
+    if row_group
+        .columns()
+        .iter()
+        .any(|c| c.crypto_metadata().is_some())
+    {
+        return read_encrypted_page_indexes(reader, metadata.file_decryptor(), row_group);
+    }
+
+    let column_index = read_columns_indexes(reader, row_group.columns())?;
+    let offset_index = read_offset_indexes(reader, row_group.columns())?;
+    Ok((column_index, offset_index))
+}
+
+#[cfg(feature = "encryption")]
+fn read_encrypted_page_indexes<R: ChunkReader>(
+    reader: &R,
+    file_decryptor: Option<&FileDecryptor>,
+    row_group: &RowGroupMetaData,
+) -> Result<(Vec<Index>, Vec<OffsetIndexMetaData>), ParquetError> {
+    let chunks = row_group.columns();
+    let fetch = chunks.iter().fold(None, |range, c| {
+        acc_range(
+            acc_range(range, c.column_index_range()),
+            c.offset_index_range(),
+        )
+    });
+
+    let fetch = match fetch {
+        Some(r) => r,
+        None => return Ok((vec![Index::NONE; chunks.len()], vec![])),
+    };
+    let has_offset_index = chunks.iter().any(|c| c.offset_index_range().is_some());
+
+    let bytes = reader.get_bytes(fetch.start as _, fetch.end - fetch.start)?;
+    let get = |r: Range<usize>| &bytes[(r.start - fetch.start)..(r.end - fetch.start)];
+
+    let mut column_indexes = Vec::with_capacity(chunks.len());
+    let mut offset_indexes = Vec::with_capacity(chunks.len());
+    for (column_idx, c) in chunks.iter().enumerate() {
+        let crypto_context =
+            CryptoContext::for_column_chunk(file_decryptor, row_group, column_idx)?;
+        if c.crypto_metadata().is_some() && crypto_context.is_none() {
+            // The indexes of columns that can't be decrypted are skipped, reading
+            // the column itself will fail
+            column_indexes.push(Index::NONE);
+            if has_offset_index {
+                offset_indexes.push(OffsetIndexMetaData::try_new(OffsetIndex::new(
+                    vec![],
+                    None::<Vec<i64>>,
+                ))?);
+            }
+            continue;
+        }
+        let decrypt = |module_type: ModuleType, data: &[u8]| match &crypto_context {
+            Some(crypto_context) => crypto_context.decrypt_module(module_type, data),
+            None => Ok(data.to_vec()),
+        };
+
+        let column_index = match c.column_index_range() {
+            Some(r) => {
+                let data = decrypt(ModuleType::ColumnIndex, get(r))?;
+                decode_column_index(&data, c.column_type())?
+            }
+            None => Index::NONE,
+        };
+        column_indexes.push(column_index);
+
+        if has_offset_index {
+            let offset_index = match c.offset_index_range() {
+                Some(r) => decode_offset_index(&decrypt(ModuleType::OffsetIndex, get(r))?)?,
+                None => return Err(general_err!("missing offset index")),
+            };
+            offset_indexes.push(offset_index);
+        }
+    }
+    Ok((column_indexes, offset_indexes))
+}
+
 pub(crate) fn decode_offset_index(data: &[u8]) -> Result<OffsetIndexMetaData, ParquetError> {
     let mut prot = TCompactSliceInputProtocol::new(data);
     let offset = OffsetIndex::read_from_in_protocol(&mut prot)?;
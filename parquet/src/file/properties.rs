@@ -21,6 +21,8 @@ use std::{collections::HashMap, sync::Arc};
 
 use crate::basic::{Compression, Encoding};
 use crate::compression::{CodecOptions, CodecOptionsBuilder};
+#[cfg(feature = "encryption")]
+use crate::encryption::{decrypt::FileDecryptionProperties, encrypt::FileEncryptionProperties};
 use crate::file::metadata::KeyValue;
 use crate::format::SortingColumn;
 use crate::schema::types::ColumnPath;
@@ -41,6 +43,8 @@ pub const DEFAULT_DICTIONARY_PAGE_SIZE_LIMIT: usize = DEFAULT_PAGE_SIZE;
 pub const DEFAULT_DATA_PAGE_ROW_COUNT_LIMIT: usize = 20_000;
 /// Default value for [`WriterProperties::statistics_enabled`]
 pub const DEFAULT_STATISTICS_ENABLED: EnabledStatistics = EnabledStatistics::Page;
+/// Default value for [`WriterProperties::size_statistics_enabled`]
+pub const DEFAULT_SIZE_STATISTICS_ENABLED: bool = true;
 /// Default value for [`WriterProperties::max_statistics_size`]
 pub const DEFAULT_MAX_STATISTICS_SIZE: usize = 4096;
 /// Default value for [`WriterProperties::max_row_group_size`]
@@ -57,6 +61,8 @@ pub const DEFAULT_BLOOM_FILTER_FPP: f64 = 0.05;
 pub const DEFAULT_BLOOM_FILTER_NDV: u64 = 1_000_000_u64;
 /// Default values for [`WriterProperties::statistics_truncate_length`]
 pub const DEFAULT_STATISTICS_TRUNCATE_LENGTH: Option<usize> = None;
+/// Default value for [`WriterProperties::write_page_checksum`]
+pub const DEFAULT_WRITE_PAGE_CHECKSUM: bool = false;
 
 /// Parquet writer version.
 ///
@@ -161,6 +167,9 @@ pub struct WriterProperties {
     sorting_columns: Option<Vec<SortingColumn>>,
     column_index_truncate_length: Option<usize>,
     statistics_truncate_length: Option<usize>,
+    write_page_checksum: bool,
+    #[cfg(feature = "encryption")]
+    file_encryption_properties: Option<FileEncryptionProperties>,
 }
 
 impl Default for WriterProperties {
@@ -279,6 +288,19 @@ impl WriterProperties {
         self.statistics_truncate_length
     }
 
+    /// Returns `true` if a CRC32 checksum is written for each data and dictionary page.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_write_page_checksum`]
+    pub fn write_page_checksum(&self) -> bool {
+        self.write_page_checksum
+    }
+
+    /// Returns the properties used to encrypt the file, if any.
+    #[cfg(feature = "encryption")]
+    pub fn file_encryption_properties(&self) -> Option<&FileEncryptionProperties> {
+        self.file_encryption_properties.as_ref()
+    }
+
     /// Returns encoding for a data page, when dictionary encoding is enabled.
     /// This is not configurable.
     #[inline]
@@ -336,6 +358,17 @@ impl WriterProperties {
             .unwrap_or(DEFAULT_STATISTICS_ENABLED)
     }
 
+    /// Returns `true` if size statistics are written for a column.
+    ///
+    /// See [`WriterPropertiesBuilder::set_size_statistics_enabled`] for details.
+    pub fn size_statistics_enabled(&self, col: &ColumnPath) -> bool {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.size_statistics_enabled())
+            .or_else(|| self.default_column_properties.size_statistics_enabled())
+            .unwrap_or(DEFAULT_SIZE_STATISTICS_ENABLED)
+    }
+
     /// Returns max size for statistics.
     /// Only applicable if statistics are enabled.
     pub fn max_statistics_size(&self, col: &ColumnPath) -> usize {
@@ -375,6 +408,9 @@ pub struct WriterPropertiesBuilder {
     sorting_columns: Option<Vec<SortingColumn>>,
     column_index_truncate_length: Option<usize>,
     statistics_truncate_length: Option<usize>,
+    write_page_checksum: bool,
+    #[cfg(feature = "encryption")]
+    file_encryption_properties: Option<FileEncryptionProperties>,
 }
 
 impl WriterPropertiesBuilder {
@@ -395,6 +431,9 @@ impl WriterPropertiesBuilder {
             sorting_columns: None,
             column_index_truncate_length: DEFAULT_COLUMN_INDEX_TRUNCATE_LENGTH,
             statistics_truncate_length: DEFAULT_STATISTICS_TRUNCATE_LENGTH,
+            write_page_checksum: DEFAULT_WRITE_PAGE_CHECKSUM,
+            #[cfg(feature = "encryption")]
+            file_encryption_properties: None,
         }
     }
 
@@ -415,6 +454,9 @@ impl WriterPropertiesBuilder {
             sorting_columns: self.sorting_columns,
             column_index_truncate_length: self.column_index_truncate_length,
             statistics_truncate_length: self.statistics_truncate_length,
+            write_page_checksum: self.write_page_checksum,
+            #[cfg(feature = "encryption")]
+            file_encryption_properties: self.file_encryption_properties,
         }
     }
 
@@ -585,6 +627,20 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets if size statistics are written by default for all columns (defaults to `true`).
+    ///
+    /// Size statistics consist of the total unencoded size of `BYTE_ARRAY` values and
+    /// histograms of the repetition and definition levels. They allow readers to
+    /// estimate the memory required to decode a column before reading it.
+    ///
+    /// They are written to the column chunk metadata as `SizeStatistics`, and for each
+    /// page to the offset index and, if [`EnabledStatistics::Page`] is set, the column index.
+    pub fn set_size_statistics_enabled(mut self, value: bool) -> Self {
+        self.default_column_properties
+            .set_size_statistics_enabled(value);
+        self
+    }
+
     /// Sets default max statistics size for all columns (defaults to `4096`).
     ///
     /// Applicable only if statistics are enabled.
@@ -690,6 +746,14 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets if size statistics are written for a specific column.
+    ///
+    /// Takes precedence over [`Self::set_size_statistics_enabled`].
+    pub fn set_column_size_statistics_enabled(mut self, col: ColumnPath, value: bool) -> Self {
+        self.get_mut_props(col).set_size_statistics_enabled(value);
+        self
+    }
+
     /// Sets max size for statistics for a specific column.
     ///
     /// Takes precedence over [`Self::set_max_statistics_size`].
@@ -765,6 +829,29 @@ impl WriterPropertiesBuilder {
         self.statistics_truncate_length = max_length;
         self
     }
+
+    /// Sets whether to write a CRC32 checksum of each data and dictionary page into its
+    /// page header (defaults to `false`).
+    ///
+    /// Readers can use the checksums to detect corrupted pages, see
+    /// [`ReaderPropertiesBuilder::set_verify_page_checksum`]
+    pub fn set_write_page_checksum(mut self, value: bool) -> Self {
+        self.write_page_checksum = value;
+        self
+    }
+
+    /// Sets the properties used to encrypt the file (defaults to `None`, writing
+    /// an unencrypted file).
+    ///
+    /// See [`FileEncryptionProperties`] for details.
+    #[cfg(feature = "encryption")]
+    pub fn set_file_encryption_properties(
+        mut self,
+        file_encryption_properties: FileEncryptionProperties,
+    ) -> Self {
+        self.file_encryption_properties = Some(file_encryption_properties);
+        self
+    }
 }
 
 /// Controls the level of statistics to be computed by the writer and stored in
@@ -860,6 +947,7 @@ struct ColumnProperties {
     codec: Option<Compression>,
     dictionary_enabled: Option<bool>,
     statistics_enabled: Option<EnabledStatistics>,
+    size_statistics_enabled: Option<bool>,
     max_statistics_size: Option<usize>,
     /// bloom filter related properties
     bloom_filter_properties: Option<BloomFilterProperties>,
@@ -897,6 +985,11 @@ impl ColumnProperties {
         self.statistics_enabled = Some(enabled);
     }
 
+    /// Sets whether or not size statistics are enabled for this column.
+    fn set_size_statistics_enabled(&mut self, enabled: bool) {
+        self.size_statistics_enabled = Some(enabled);
+    }
+
     /// Sets max size for statistics for this column.
     fn set_max_statistics_size(&mut self, value: usize) {
         self.max_statistics_size = Some(value);
@@ -961,6 +1054,12 @@ impl ColumnProperties {
         self.statistics_enabled
     }
 
+    /// Returns `Some(true)` if size statistics are enabled for this column, if disabled
+    /// then returns `Some(false)`. If result is `None`, then no setting has been provided.
+    fn size_statistics_enabled(&self) -> Option<bool> {
+        self.size_statistics_enabled
+    }
+
     /// Returns optional max size in bytes for statistics.
     fn max_statistics_size(&self) -> Option<usize> {
         self.max_statistics_size
@@ -976,6 +1075,7 @@ impl ColumnProperties {
 pub type ReaderPropertiesPtr = Arc<ReaderProperties>;
 
 const DEFAULT_READ_BLOOM_FILTER: bool = false;
+const DEFAULT_VERIFY_PAGE_CHECKSUM: bool = false;
 
 /// Configuration settings for reading parquet files.
 ///
@@ -998,6 +1098,9 @@ const DEFAULT_READ_BLOOM_FILTER: bool = false;
 pub struct ReaderProperties {
     codec_options: CodecOptions,
     read_bloom_filter: bool,
+    verify_page_checksum: bool,
+    #[cfg(feature = "encryption")]
+    file_decryption_properties: Option<FileDecryptionProperties>,
 }
 
 impl ReaderProperties {
@@ -1015,6 +1118,17 @@ impl ReaderProperties {
     pub(crate) fn read_bloom_filter(&self) -> bool {
         self.read_bloom_filter
     }
+
+    /// Returns whether to verify the CRC32 checksums of pages
+    pub(crate) fn verify_page_checksum(&self) -> bool {
+        self.verify_page_checksum
+    }
+
+    /// Returns the properties used to decrypt encrypted files
+    #[cfg(feature = "encryption")]
+    pub(crate) fn file_decryption_properties(&self) -> Option<&FileDecryptionProperties> {
+        self.file_decryption_properties.as_ref()
+    }
 }
 
 /// Builder for parquet file reader configuration. See example on
@@ -1022,6 +1136,9 @@ impl ReaderProperties {
 pub struct ReaderPropertiesBuilder {
     codec_options_builder: CodecOptionsBuilder,
     read_bloom_filter: Option<bool>,
+    verify_page_checksum: Option<bool>,
+    #[cfg(feature = "encryption")]
+    file_decryption_properties: Option<FileDecryptionProperties>,
 }
 
 /// Reader properties builder.
@@ -1031,6 +1148,9 @@ impl ReaderPropertiesBuilder {
         Self {
             codec_options_builder: CodecOptionsBuilder::default(),
             read_bloom_filter: None,
+            verify_page_checksum: None,
+            #[cfg(feature = "encryption")]
+            file_decryption_properties: None,
         }
     }
 
@@ -1039,6 +1159,11 @@ impl ReaderPropertiesBuilder {
         ReaderProperties {
             codec_options: self.codec_options_builder.build(),
             read_bloom_filter: self.read_bloom_filter.unwrap_or(DEFAULT_READ_BLOOM_FILTER),
+            verify_page_checksum: self
+                .verify_page_checksum
+                .unwrap_or(DEFAULT_VERIFY_PAGE_CHECKSUM),
+            #[cfg(feature = "encryption")]
+            file_decryption_properties: self.file_decryption_properties,
         }
     }
 
@@ -1067,6 +1192,32 @@ impl ReaderPropertiesBuilder {
         self.read_bloom_filter = Some(value);
         self
     }
+
+    /// Enable/disable verifying page checksums
+    ///
+    /// If enabled, the CRC32 checksum of each page that has one is verified when the page
+    /// is read, returning [`ParquetError::ChecksumMismatch`] if it does not match.
+    /// Pages written without a checksum are not verified.
+    ///
+    /// By default page checksums are not verified.
+    ///
+    /// [`ParquetError::ChecksumMismatch`]: crate::errors::ParquetError::ChecksumMismatch
+    pub fn set_verify_page_checksum(mut self, value: bool) -> Self {
+        self.verify_page_checksum = Some(value);
+        self
+    }
+
+    /// Sets the properties used to decrypt encrypted files
+    ///
+    /// See [`FileDecryptionProperties`] for details.
+    #[cfg(feature = "encryption")]
+    pub fn set_file_decryption_properties(
+        mut self,
+        file_decryption_properties: FileDecryptionProperties,
+    ) -> Self {
+        self.file_decryption_properties = Some(file_decryption_properties);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -1093,6 +1244,7 @@ mod tests {
         assert_eq!(props.writer_version(), DEFAULT_WRITER_VERSION);
         assert_eq!(props.created_by(), DEFAULT_CREATED_BY);
         assert_eq!(props.key_value_metadata(), None);
+        assert_eq!(props.write_page_checksum(), DEFAULT_WRITE_PAGE_CHECKSUM);
         assert_eq!(props.encoding(&ColumnPath::from("col")), None);
         assert_eq!(
             props.compression(&ColumnPath::from("col")),
@@ -1106,6 +1258,10 @@ mod tests {
             props.statistics_enabled(&ColumnPath::from("col")),
             DEFAULT_STATISTICS_ENABLED
         );
+        assert_eq!(
+            props.size_statistics_enabled(&ColumnPath::from("col")),
+            DEFAULT_SIZE_STATISTICS_ENABLED
+        );
         assert_eq!(
             props.max_statistics_size(&ColumnPath::from("col")),
             DEFAULT_MAX_STATISTICS_SIZE
@@ -1181,17 +1337,20 @@ mod tests {
                 "key".to_string(),
                 "value".to_string(),
             )]))
+            .set_write_page_checksum(true)
             // global column settings
             .set_encoding(Encoding::DELTA_BINARY_PACKED)
             .set_compression(Compression::GZIP(Default::default()))
             .set_dictionary_enabled(false)
             .set_statistics_enabled(EnabledStatistics::None)
+            .set_size_statistics_enabled(false)
             .set_max_statistics_size(50)
             // specific column settings
             .set_column_encoding(ColumnPath::from("col"), Encoding::RLE)
             .set_column_compression(ColumnPath::from("col"), Compression::SNAPPY)
             .set_column_dictionary_enabled(ColumnPath::from("col"), true)
             .set_column_statistics_enabled(ColumnPath::from("col"), EnabledStatistics::Chunk)
+            .set_column_size_statistics_enabled(ColumnPath::from("col"), true)
             .set_column_max_statistics_size(ColumnPath::from("col"), 123)
             .set_column_bloom_filter_enabled(ColumnPath::from("col"), true)
             .set_column_bloom_filter_ndv(ColumnPath::from("col"), 100_u64)
@@ -1210,6 +1369,7 @@ mod tests {
                 KeyValue::new("key".to_string(), "value".to_string(),)
             ])
         );
+        assert!(props.write_page_checksum());
 
         assert_eq!(
             props.encoding(&ColumnPath::from("a")),
@@ -1224,6 +1384,7 @@ mod tests {
             props.statistics_enabled(&ColumnPath::from("a")),
             EnabledStatistics::None
         );
+        assert!(!props.size_statistics_enabled(&ColumnPath::from("a")));
         assert_eq!(props.max_statistics_size(&ColumnPath::from("a")), 50);
 
         assert_eq!(
@@ -1239,6 +1400,7 @@ mod tests {
             props.statistics_enabled(&ColumnPath::from("col")),
             EnabledStatistics::Chunk
         );
+        assert!(props.size_statistics_enabled(&ColumnPath::from("col")));
         assert_eq!(props.max_statistics_size(&ColumnPath::from("col")), 123);
         assert_eq!(
             props.bloom_filter_properties(&ColumnPath::from("col")),
@@ -1316,12 +1478,14 @@ mod tests {
 
         assert_eq!(props.codec_options(), &codec_options);
         assert!(!props.read_bloom_filter());
+        assert!(!props.verify_page_checksum());
     }
 
     #[test]
     fn test_reader_properties_builder() {
         let props = ReaderProperties::builder()
             .set_backward_compatible_lz4(false)
+            .set_verify_page_checksum(true)
             .build();
 
         let codec_options = CodecOptionsBuilder::default()
@@ -1329,6 +1493,7 @@ mod tests {
             .build();
 
         assert_eq!(props.codec_options(), &codec_options);
+        assert!(props.verify_page_checksum());
     }
 
     #[test]
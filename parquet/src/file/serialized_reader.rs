@@ -26,6 +26,8 @@ use crate::basic::{Encoding, Type};
 use crate::bloom_filter::Sbbf;
 use crate::column::page::{Page, PageMetadata, PageReader};
 use crate::compression::{create_codec, Codec};
+#[cfg(feature = "encryption")]
+use crate::encryption::decrypt::{read_encrypted_module, CryptoContext, FileDecryptor};
 use crate::errors::{ParquetError, Result};
 use crate::file::page_index::index_reader;
 use crate::file::page_index::offset_index::OffsetIndexMetaData;
@@ -39,7 +41,7 @@ use crate::file::{
 use crate::format::{PageHeader, PageLocation, PageType};
 use crate::record::reader::RowIter;
 use crate::record::Row;
-use crate::schema::types::Type as SchemaType;
+use crate::schema::types::{ColumnPath, Type as SchemaType};
 use crate::thrift::{TCompactSliceInputProtocol, TSerializable};
 use bytes::Bytes;
 use thrift::protocol::TCompactInputProtocol;
@@ -192,54 +194,62 @@ impl<R: 'static + ChunkReader> SerializedFileReader<R> {
     /// Creates file reader from a Parquet file with read options.
     /// Returns error if Parquet file does not exist or is corrupt.
     pub fn new_with_options(chunk_reader: R, options: ReadOptions) -> Result<Self> {
+        #[cfg(feature = "encryption")]
+        let metadata = footer::parse_metadata_with_decryption(
+            &chunk_reader,
+            options.props.file_decryption_properties(),
+        )?;
+        #[cfg(not(feature = "encryption"))]
         let metadata = footer::parse_metadata(&chunk_reader)?;
+
         let mut predicates = options.predicates;
-        let row_groups = metadata.row_groups().to_vec();
-        let mut filtered_row_groups = Vec::<RowGroupMetaData>::new();
-        for (i, rg_meta) in row_groups.into_iter().enumerate() {
+        let mut filtered_row_groups = Vec::<usize>::new();
+        for (i, rg_meta) in metadata.row_groups().iter().enumerate() {
             let mut keep = true;
             for predicate in &mut predicates {
-                if !predicate(&rg_meta, i) {
+                if !predicate(rg_meta, i) {
                     keep = false;
                     break;
                 }
             }
             if keep {
-                filtered_row_groups.push(rg_meta);
+                filtered_row_groups.push(i);
             }
         }
 
-        if options.enable_page_index {
+        let (column_index, offset_index) = if options.enable_page_index {
             let mut columns_indexes = vec![];
             let mut offset_indexes = vec![];
 
-            for rg in &mut filtered_row_groups {
-                let column_index = index_reader::read_columns_indexes(&chunk_reader, rg.columns())?;
-                let offset_index = index_reader::read_offset_indexes(&chunk_reader, rg.columns())?;
+            for i in &filtered_row_groups {
+                let (column_index, offset_index) =
+                    index_reader::read_row_group_page_indexes(&chunk_reader, &metadata, *i)?;
                 columns_indexes.push(column_index);
                 offset_indexes.push(offset_index);
             }
-
-            Ok(Self {
-                chunk_reader: Arc::new(chunk_reader),
-                metadata: Arc::new(ParquetMetaData::new_with_page_index(
-                    metadata.file_metadata().clone(),
-                    filtered_row_groups,
-                    Some(columns_indexes),
-                    Some(offset_indexes),
-                )),
-                props: Arc::new(options.props),
-            })
+            (Some(columns_indexes), Some(offset_indexes))
         } else {
-            Ok(Self {
-                chunk_reader: Arc::new(chunk_reader),
-                metadata: Arc::new(ParquetMetaData::new(
-                    metadata.file_metadata().clone(),
-                    filtered_row_groups,
-                )),
-                props: Arc::new(options.props),
-            })
-        }
+            (None, None)
+        };
+
+        #[allow(unused_mut)]
+        let mut filtered_metadata = ParquetMetaData::new_with_page_index(
+            metadata.file_metadata().clone(),
+            filtered_row_groups
+                .into_iter()
+                .map(|i| metadata.row_group(i).clone())
+                .collect(),
+            column_index,
+            offset_index,
+        );
+        #[cfg(feature = "encryption")]
+        filtered_metadata.set_file_decryptor(metadata.file_decryptor().cloned());
+
+        Ok(Self {
+            chunk_reader: Arc::new(chunk_reader),
+            metadata: Arc::new(filtered_metadata),
+            props: Arc::new(options.props),
+        })
     }
 }
 
@@ -269,12 +279,16 @@ impl<R: 'static + ChunkReader> FileReader for SerializedFileReader<R> {
         // Row groups should be processed sequentially.
         let props = Arc::clone(&self.props);
         let f = Arc::clone(&self.chunk_reader);
-        Ok(Box::new(SerializedRowGroupReader::new(
+        let row_group_reader = SerializedRowGroupReader::new(
             f,
             row_group_metadata,
             self.metadata.offset_index().map(|x| x[i].as_slice()),
             props,
-        )?))
+        )?;
+        #[cfg(feature = "encryption")]
+        let row_group_reader =
+            row_group_reader.with_file_decryptor(self.metadata.file_decryptor())?;
+        Ok(Box::new(row_group_reader))
     }
 
     fn get_row_iter(&self, projection: Option<SchemaType>) -> Result<RowIter> {
@@ -289,6 +303,8 @@ pub struct SerializedRowGroupReader<'a, R: ChunkReader> {
     offset_index: Option<&'a [OffsetIndexMetaData]>,
     props: ReaderPropertiesPtr,
     bloom_filters: Vec<Option<Sbbf>>,
+    #[cfg(feature = "encryption")]
+    file_decryptor: Option<&'a FileDecryptor>,
 }
 
 impl<'a, R: ChunkReader> SerializedRowGroupReader<'a, R> {
@@ -303,7 +319,11 @@ impl<'a, R: ChunkReader> SerializedRowGroupReader<'a, R> {
             metadata
                 .columns()
                 .iter()
-                .map(|col| Sbbf::read_from_column_chunk(col, chunk_reader.clone()))
+                .map(|col| match is_encrypted(col) {
+                    // Bloom filters of encrypted columns are read by `with_file_decryptor`
+                    true => Ok(None),
+                    false => Sbbf::read_from_column_chunk(col, chunk_reader.as_ref()),
+                })
                 .collect::<Result<Vec<_>>>()?
         } else {
             iter::repeat(None).take(metadata.columns().len()).collect()
@@ -314,8 +334,44 @@ impl<'a, R: ChunkReader> SerializedRowGroupReader<'a, R> {
             offset_index,
             props,
             bloom_filters,
+            #[cfg(feature = "encryption")]
+            file_decryptor: None,
         })
     }
+
+    /// Sets the decryptor used to decrypt the encrypted columns of this row group,
+    /// reading their bloom filters if enabled.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn with_file_decryptor(
+        mut self,
+        file_decryptor: Option<&'a FileDecryptor>,
+    ) -> Result<Self> {
+        if self.props.read_bloom_filter() {
+            for (i, col) in self.metadata.columns().iter().enumerate() {
+                if let Some(crypto_context) =
+                    CryptoContext::for_column_chunk(file_decryptor, self.metadata, i)?
+                {
+                    self.bloom_filters[i] = Sbbf::read_from_encrypted_column_chunk(
+                        col,
+                        self.chunk_reader.as_ref(),
+                        &crypto_context,
+                    )?;
+                }
+            }
+        }
+        self.file_decryptor = file_decryptor;
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "encryption")]
+pub(crate) fn is_encrypted(column: &ColumnChunkMetaData) -> bool {
+    column.crypto_metadata().is_some()
+}
+
+#[cfg(not(feature = "encryption"))]
+pub(crate) fn is_encrypted(_column: &ColumnChunkMetaData) -> bool {
+    false
 }
 
 impl<'a, R: 'static + ChunkReader> RowGroupReader for SerializedRowGroupReader<'a, R> {
@@ -334,6 +390,22 @@ impl<'a, R: 'static + ChunkReader> RowGroupReader for SerializedRowGroupReader<'
         let page_locations = self.offset_index.map(|x| x[i].page_locations.clone());
 
         let props = Arc::clone(&self.props);
+
+        #[cfg(feature = "encryption")]
+        {
+            let crypto_context =
+                CryptoContext::for_column_chunk(self.file_decryptor, self.metadata, i)?;
+            Ok(Box::new(SerializedPageReader::new_with_crypto_context(
+                Arc::clone(&self.chunk_reader),
+                col,
+                self.metadata.num_rows() as usize,
+                page_locations,
+                props,
+                crypto_context,
+            )?))
+        }
+
+        #[cfg(not(feature = "encryption"))]
         Ok(Box::new(SerializedPageReader::new_with_properties(
             Arc::clone(&self.chunk_reader),
             col,
@@ -496,6 +568,9 @@ enum SerializedPageReaderState {
 
         // If the next page header has already been "peeked", we will cache it and it`s length here
         next_page_header: Option<Box<PageHeader>>,
+
+        /// Whether the next page is expected to be the dictionary page
+        require_dictionary: bool,
     },
     Pages {
         /// Remaining page locations
@@ -507,6 +582,98 @@ enum SerializedPageReaderState {
     },
 }
 
+/// Reads the page headers and pages of a column chunk, decrypting them if the
+/// column chunk is encrypted
+#[derive(Default)]
+struct SerializedPageReaderContext {
+    /// Decrypts the modules of an encrypted column chunk
+    #[cfg(feature = "encryption")]
+    crypto_context: Option<CryptoContext>,
+    /// The ordinal of the next data page within the column chunk
+    #[cfg(feature = "encryption")]
+    page_ordinal: usize,
+}
+
+impl SerializedPageReaderContext {
+    /// Reads the header of the next page, returning the number of bytes read
+    #[cfg(not(feature = "encryption"))]
+    fn read_page_header_len<T: Read>(
+        &self,
+        input: &mut T,
+        _dictionary: bool,
+    ) -> Result<(usize, PageHeader)> {
+        read_page_header_len(input)
+    }
+
+    /// Decodes the header at the start of `buffer`, returning the header length
+    #[cfg(not(feature = "encryption"))]
+    fn decode_page_header(&self, buffer: &[u8], _dictionary: bool) -> Result<(usize, PageHeader)> {
+        let mut prot = TCompactSliceInputProtocol::new(buffer);
+        let header = PageHeader::read_from_in_protocol(&mut prot)?;
+        Ok((buffer.len() - prot.as_slice().len(), header))
+    }
+
+    /// Returns the data of the next page
+    #[cfg(not(feature = "encryption"))]
+    fn decrypt_page_data(&self, buffer: Bytes, _dictionary: bool) -> Result<Bytes> {
+        Ok(buffer)
+    }
+
+    /// Advances past a page that has been read or skipped
+    #[cfg(not(feature = "encryption"))]
+    fn page_consumed(&mut self, _dictionary: bool) {}
+
+    #[cfg(feature = "encryption")]
+    fn read_page_header_len<T: Read>(
+        &self,
+        input: &mut T,
+        dictionary: bool,
+    ) -> Result<(usize, PageHeader)> {
+        match &self.crypto_context {
+            None => read_page_header_len(input),
+            Some(crypto_context) => {
+                let module = read_encrypted_module(input)?;
+                let header =
+                    crypto_context.decrypt_page_header(&module, self.page_ordinal, dictionary)?;
+                let mut prot = TCompactSliceInputProtocol::new(&header);
+                let header = PageHeader::read_from_in_protocol(&mut prot)?;
+                Ok((module.len(), header))
+            }
+        }
+    }
+
+    #[cfg(feature = "encryption")]
+    fn decode_page_header(&self, buffer: &[u8], dictionary: bool) -> Result<(usize, PageHeader)> {
+        match &self.crypto_context {
+            None => {
+                let mut prot = TCompactSliceInputProtocol::new(buffer);
+                let header = PageHeader::read_from_in_protocol(&mut prot)?;
+                Ok((buffer.len() - prot.as_slice().len(), header))
+            }
+            Some(_) => self.read_page_header_len(&mut &buffer[..], dictionary),
+        }
+    }
+
+    #[cfg(feature = "encryption")]
+    fn decrypt_page_data(&self, buffer: Bytes, dictionary: bool) -> Result<Bytes> {
+        match &self.crypto_context {
+            None => Ok(buffer),
+            Some(crypto_context) => {
+                let decrypted =
+                    crypto_context.decrypt_page(&buffer, self.page_ordinal, dictionary)?;
+                Ok(Bytes::from(decrypted))
+            }
+        }
+    }
+
+    #[cfg(feature = "encryption")]
+    fn page_consumed(&mut self, dictionary: bool) {
+        if !dictionary {
+            self.page_ordinal += 1;
+        }
+    }
+}
+
 /// A serialized implementation for Parquet [`PageReader`].
 pub struct SerializedPageReader<R: ChunkReader> {
     /// The chunk reader
@@ -519,6 +686,20 @@ pub struct SerializedPageReader<R: ChunkReader> {
     physical_type: Type,
 
     state: SerializedPageReaderState,
+
+    context: SerializedPageReaderContext,
+
+    /// Whether to verify the CRC32 checksums of pages
+    verify_checksum: bool,
+
+    /// The path of this column chunk, used to report checksum mismatches
+    column_path: ColumnPath,
+
+    /// The file offset of this column chunk, used to report checksum mismatches
+    column_chunk_offset: u64,
+
+    /// The index of the next page within this column chunk, including any dictionary page
+    page_index: usize,
 }
 
 impl<R: ChunkReader> SerializedPageReader<R> {
@@ -541,6 +722,50 @@ impl<R: ChunkReader> SerializedPageReader<R> {
         page_locations: Option<Vec<PageLocation>>,
         props: ReaderPropertiesPtr,
     ) -> Result<Self> {
+        Self::new_with_context(
+            reader,
+            meta,
+            total_rows,
+            page_locations,
+            props,
+            SerializedPageReaderContext::default(),
+        )
+    }
+
+    /// Creates a new serialized page reader for a potentially encrypted column chunk,
+    /// decrypting its pages with `crypto_context`
+    #[cfg(feature = "encryption")]
+    pub(crate) fn new_with_crypto_context(
+        reader: Arc<R>,
+        meta: &ColumnChunkMetaData,
+        total_rows: usize,
+        page_locations: Option<Vec<PageLocation>>,
+        props: ReaderPropertiesPtr,
+        crypto_context: Option<CryptoContext>,
+    ) -> Result<Self> {
+        let context = SerializedPageReaderContext {
+            crypto_context,
+            page_ordinal: 0,
+        };
+        Self::new_with_context(reader, meta, total_rows, page_locations, props, context)
+    }
+
+    fn new_with_context(
+        reader: Arc<R>,
+        meta: &ColumnChunkMetaData,
+        total_rows: usize,
+        page_locations: Option<Vec<PageLocation>>,
+        props: ReaderPropertiesPtr,
+        context: SerializedPageReaderContext,
+    ) -> Result<Self> {
+        #[cfg(feature = "encryption")]
+        if meta.crypto_metadata().is_some() && context.crypto_context.is_none() {
+            return Err(general_err!(
+                "Column {} is encrypted, but no decryption properties were provided",
+                meta.column_path()
+            ));
+        }
+
         let decompressor = create_codec(meta.compression(), props.codec_options())?;
         let (start, len) = meta.byte_range();
 
@@ -565,6 +790,7 @@ impl<R: ChunkReader> SerializedPageReader<R> {
                 offset: start as usize,
                 remaining_bytes: len as usize,
                 next_page_header: None,
+                require_dictionary: meta.dictionary_page_offset().is_some(),
             },
         };
 
@@ -573,10 +799,39 @@ impl<R: ChunkReader> SerializedPageReader<R> {
             decompressor,
             state,
             physical_type: meta.column_type(),
+            context,
+            verify_checksum: props.verify_page_checksum(),
+            column_path: meta.column_path().clone(),
+            column_chunk_offset: start,
+            page_index: 0,
         })
     }
 }
 
+/// Verifies the CRC32 checksum of the page `data` against the checksum in its `header`,
+/// if it has one
+fn verify_page_checksum(
+    header: &PageHeader,
+    data: &[u8],
+    column_path: &ColumnPath,
+    column_chunk_offset: u64,
+    page_index: usize,
+) -> Result<()> {
+    if let Some(expected) = header.crc {
+        let actual = crc32fast::hash(data);
+        if actual != expected as u32 {
+            return Err(ParquetError::ChecksumMismatch {
+                column: column_path.string(),
+                column_chunk_offset,
+                page: page_index,
+                expected: expected as u32,
+                actual,
+            });
+        }
+    }
+    Ok(())
+}
+
 impl<R: ChunkReader> Iterator for SerializedPageReader<R> {
     type Item = Result<Page>;
 
@@ -593,16 +848,19 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
                     offset,
                     remaining_bytes: remaining,
                     next_page_header,
+                    require_dictionary,
                 } => {
                     if *remaining == 0 {
                         return Ok(None);
                     }
 
+                    let dictionary = *require_dictionary;
                     let mut read = self.reader.get_read(*offset as u64)?;
                     let header = if let Some(header) = next_page_header.take() {
                         *header
                     } else {
-                        let (header_len, header) = read_page_header_len(&mut read)?;
+                        let (header_len, header) =
+                            self.context.read_page_header_len(&mut read, dictionary)?;
                         *offset += header_len;
                         *remaining -= header_len;
                         header
@@ -611,6 +869,9 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
                     *offset += data_len;
                     *remaining -= data_len;
 
+                    let page_index = self.page_index;
+                    self.page_index += 1;
+
                     if header.type_ == PageType::INDEX_PAGE {
                         continue;
                     }
@@ -626,9 +887,25 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
                         ));
                     }
 
+                    if self.verify_checksum {
+                        verify_page_checksum(
+                            &header,
+                            &buffer,
+                            &self.column_path,
+                            self.column_chunk_offset,
+                            page_index,
+                        )?;
+                    }
+
+                    let buffer = self
+                        .context
+                        .decrypt_page_data(Bytes::from(buffer), dictionary)?;
+                    self.context.page_consumed(dictionary);
+                    *require_dictionary = false;
+
                     decode_page(
                         header,
-                        Bytes::from(buffer),
+                        buffer,
                         self.physical_type,
                         self.decompressor.as_mut(),
                     )?
@@ -638,23 +915,36 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
                     dictionary_page,
                     ..
                 } => {
-                    let front = match dictionary_page
-                        .take()
-                        .or_else(|| page_locations.pop_front())
-                    {
-                        Some(front) => front,
-                        None => return Ok(None),
+                    let (front, dictionary) = match dictionary_page.take() {
+                        Some(front) => (front, true),
+                        None => match page_locations.pop_front() {
+                            Some(front) => (front, false),
+                            None => return Ok(None),
+                        },
                     };
 
                     let page_len = front.compressed_page_size as usize;
 
                     let buffer = self.reader.get_bytes(front.offset as u64, page_len)?;
 
-                    let mut prot = TCompactSliceInputProtocol::new(buffer.as_ref());
-                    let header = PageHeader::read_from_in_protocol(&mut prot)?;
-                    let offset = buffer.len() - prot.as_slice().len();
+                    let (offset, header) = self.context.decode_page_header(&buffer, dictionary)?;
+
+                    let page_index = self.page_index;
+                    self.page_index += 1;
+                    if self.verify_checksum {
+                        verify_page_checksum(
+                            &header,
+                            &buffer[offset..],
+                            &self.column_path,
+                            self.column_chunk_offset,
+                            page_index,
+                        )?;
+                    }
 
-                    let bytes = buffer.slice(offset..);
+                    let bytes = self
+                        .context
+                        .decrypt_page_data(buffer.slice(offset..), dictionary)?;
+                    self.context.page_consumed(dictionary);
                     decode_page(
                         header,
                         bytes,
@@ -674,6 +964,7 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
                 offset,
                 remaining_bytes,
                 next_page_header,
+                require_dictionary,
             } => {
                 loop {
                     if *remaining_bytes == 0 {
@@ -689,7 +980,9 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
                         }
                     } else {
                         let mut read = self.reader.get_read(*offset as u64)?;
-                        let (header_len, header) = read_page_header_len(&mut read)?;
+                        let (header_len, header) = self
+                            .context
+                            .read_page_header_len(&mut read, *require_dictionary)?;
                         *offset += header_len;
                         *remaining_bytes -= header_len;
                         let page_meta = if let Ok(page_meta) = (&header).try_into() {
@@ -738,6 +1031,7 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
                 offset,
                 remaining_bytes,
                 next_page_header,
+                require_dictionary,
             } => {
                 if let Some(buffered_header) = next_page_header.take() {
                     // The next page header has already been peeked, so just advance the offset
@@ -745,15 +1039,22 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
                     *remaining_bytes -= buffered_header.compressed_page_size as usize;
                 } else {
                     let mut read = self.reader.get_read(*offset as u64)?;
-                    let (header_len, header) = read_page_header_len(&mut read)?;
+                    let (header_len, header) = self
+                        .context
+                        .read_page_header_len(&mut read, *require_dictionary)?;
                     let data_page_size = header.compressed_page_size as usize;
                     *offset += header_len + data_page_size;
                     *remaining_bytes -= header_len + data_page_size;
                 }
+                self.context.page_consumed(*require_dictionary);
+                self.page_index += 1;
+                *require_dictionary = false;
                 Ok(())
             }
             SerializedPageReaderState::Pages { page_locations, .. } => {
                 page_locations.pop_front();
+                self.context.page_consumed(false);
+                self.page_index += 1;
 
                 Ok(())
             }
@@ -775,9 +1076,10 @@ mod tests {
     use crate::basic::{self, ColumnOrder};
     use crate::column::reader::ColumnReader;
     use crate::data_type::private::ParquetValueType;
-    use crate::data_type::{AsBytes, FixedLenByteArrayType};
+    use crate::data_type::{AsBytes, FixedLenByteArrayType, Int32Type};
     use crate::file::page_index::index::{Index, NativeIndex};
     use crate::file::page_index::index_reader::{read_columns_indexes, read_offset_indexes};
+    use crate::file::properties::WriterProperties;
     use crate::file::writer::SerializedFileWriter;
     use crate::record::RowAccessor;
     use crate::schema::parser::parse_message_type;
@@ -1742,6 +2044,92 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_page_checksum() {
+        let message_type = "
+        message test_schema {
+          REQUIRED INT32 value;
+        }
+        ";
+        let schema = Arc::new(parse_message_type(message_type).unwrap());
+
+        let write = |checksum: bool| {
+            let props = WriterProperties::builder()
+                .set_write_page_checksum(checksum)
+                .build();
+            let mut out = Vec::with_capacity(1024);
+            let mut writer =
+                SerializedFileWriter::new(&mut out, schema.clone(), Arc::new(props)).unwrap();
+            let mut r = writer.next_row_group().unwrap();
+            let mut c = r.next_column().unwrap().unwrap();
+            c.typed::<Int32Type>()
+                .write_batch(&[1, 2, 3, 1, 2, 3], None, None)
+                .unwrap();
+            c.close().unwrap();
+            r.close().unwrap();
+            writer.close().unwrap();
+            out
+        };
+
+        let read = |data: Vec<u8>, verify: bool| {
+            let props = ReaderProperties::builder()
+                .set_verify_page_checksum(verify)
+                .build();
+            let options = ReadOptionsBuilder::new()
+                .with_reader_properties(props)
+                .build();
+            let reader = SerializedFileReader::new_with_options(Bytes::from(data), options)?;
+            let mut pages = reader.get_row_group(0)?.get_column_page_reader(0)?;
+            let mut num_pages = 0;
+            while pages.get_next_page()?.is_some() {
+                num_pages += 1;
+            }
+            Ok::<_, ParquetError>(num_pages)
+        };
+
+        let data = write(false);
+        let reader = SerializedFileReader::new(Bytes::from(data.clone())).unwrap();
+        let column = reader.metadata().row_group(0).column(0);
+        let (start, _) = column.byte_range();
+        let header = read_page_header(&mut &data[start as usize..]).unwrap();
+        assert_eq!(header.crc, None);
+        assert_eq!(read(data, true).unwrap(), 2);
+
+        let data = write(true);
+        let reader = SerializedFileReader::new(Bytes::from(data.clone())).unwrap();
+        let column = reader.metadata().row_group(0).column(0);
+        let (start, _) = column.byte_range();
+        let (header_len, header) = read_page_header_len(&mut &data[start as usize..]).unwrap();
+        let dictionary_start = start as usize + header_len;
+        let dictionary_end = dictionary_start + header.compressed_page_size as usize;
+        assert_eq!(
+            header.crc,
+            Some(crc32fast::hash(&data[dictionary_start..dictionary_end]) as i32)
+        );
+        assert_eq!(read(data.clone(), true).unwrap(), 2);
+
+        // Corrupt the dictionary page
+        let mut corrupt = data;
+        corrupt[dictionary_start] ^= 0xFF;
+        assert_eq!(read(corrupt.clone(), false).unwrap(), 2);
+        let err = read(corrupt, true).unwrap_err();
+        match err {
+            ParquetError::ChecksumMismatch {
+                column,
+                column_chunk_offset,
+                page,
+                expected,
+                ..
+            } => {
+                assert_eq!(column, "value");
+                assert_eq!(column_chunk_offset, start);
+                assert_eq!(page, 0);
+                assert_eq!(expected, header.crc.unwrap() as u32);
+            }
+            _ => panic!("unexpected error: {err}"),
+        }
+    }
+
     #[test]
     fn test_multi_gz() {
         let file = get_test_file("concatenated_gzip_members.parquet");
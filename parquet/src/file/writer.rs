@@ -33,9 +33,16 @@ use crate::column::{
     writer::{get_column_writer, ColumnWriter},
 };
 use crate::data_type::DataType;
+#[cfg(feature = "encryption")]
+use crate::encryption::{
+    encrypt::{ColumnEncryptor, FileEncryptor, PageEncryptor},
+    modules::ModuleType,
+};
 use crate::errors::{ParquetError, Result};
 use crate::file::properties::{BloomFilterPosition, WriterPropertiesPtr};
 use crate::file::reader::ChunkReader;
+#[cfg(feature = "encryption")]
+use crate::file::PARQUET_MAGIC_ENCR_FOOTER;
 use crate::file::{metadata::*, PARQUET_MAGIC};
 use crate::schema::types::{self, ColumnDescPtr, SchemaDescPtr, SchemaDescriptor, TypePtr};
 
@@ -153,6 +160,8 @@ pub struct SerializedFileWriter<W: Write> {
     // kv_metadatas will be appended to `props` when `write_metadata`
     kv_metadatas: Vec<KeyValue>,
     finished: bool,
+    #[cfg(feature = "encryption")]
+    file_encryptor: Option<Arc<FileEncryptor>>,
 }
 
 impl<W: Write> Debug for SerializedFileWriter<W> {
@@ -171,11 +180,14 @@ impl<W: Write + Send> SerializedFileWriter<W> {
     /// Creates new file writer.
     pub fn new(buf: W, schema: TypePtr, properties: WriterPropertiesPtr) -> Result<Self> {
         let mut buf = TrackedWrite::new(buf);
-        Self::start_file(&mut buf)?;
+        let descr = SchemaDescriptor::new(schema.clone());
+        #[cfg(feature = "encryption")]
+        let file_encryptor = Self::get_file_encryptor(&properties, &descr)?;
+        Self::start_file(&properties, &mut buf)?;
         Ok(Self {
             buf,
-            schema: schema.clone(),
-            descr: Arc::new(SchemaDescriptor::new(schema)),
+            schema,
+            descr: Arc::new(descr),
             props: properties,
             row_groups: vec![],
             bloom_filters: vec![],
@@ -184,9 +196,23 @@ impl<W: Write + Send> SerializedFileWriter<W> {
             row_group_index: 0,
             kv_metadatas: Vec::new(),
             finished: false,
+            #[cfg(feature = "encryption")]
+            file_encryptor,
         })
     }
 
+    /// Creates the [`FileEncryptor`] for the file, if encryption is enabled
+    #[cfg(feature = "encryption")]
+    fn get_file_encryptor(
+        properties: &WriterPropertiesPtr,
+        descr: &SchemaDescriptor,
+    ) -> Result<Option<Arc<FileEncryptor>>> {
+        properties
+            .file_encryption_properties()
+            .map(|props| FileEncryptor::try_new(props.clone(), descr).map(Arc::new))
+            .transpose()
+    }
+
     /// Creates new row group from this file writer.
     /// In case of IO error or Thrift error, returns `Err`.
     ///
@@ -204,6 +230,8 @@ impl<W: Write + Send> SerializedFileWriter<W> {
         let row_bloom_filters = &mut self.bloom_filters;
         let row_column_indexes = &mut self.column_indexes;
         let row_offset_indexes = &mut self.offset_indexes;
+        #[cfg(feature = "encryption")]
+        let file_encryptor = self.file_encryptor.clone();
         let on_close = move |buf,
                              mut metadata,
                              row_group_bloom_filter,
@@ -214,9 +242,13 @@ impl<W: Write + Send> SerializedFileWriter<W> {
             row_offset_indexes.push(row_group_offset_index);
             // write bloom filters out immediately after the row group if requested
             match bloom_filter_position {
-                BloomFilterPosition::AfterRowGroup => {
-                    write_bloom_filters(buf, row_bloom_filters, &mut metadata)?
-                }
+                BloomFilterPosition::AfterRowGroup => write_bloom_filters(
+                    buf,
+                    row_bloom_filters,
+                    &mut metadata,
+                    #[cfg(feature = "encryption")]
+                    file_encryptor.as_deref(),
+                )?,
                 BloomFilterPosition::End => (),
             };
             row_groups.push(metadata);
@@ -230,6 +262,8 @@ impl<W: Write + Send> SerializedFileWriter<W> {
             ordinal as i16,
             Some(Box::new(on_close)),
         );
+        #[cfg(feature = "encryption")]
+        let row_group_writer = row_group_writer.with_file_encryptor(self.file_encryptor.clone());
         Ok(row_group_writer)
     }
 
@@ -256,8 +290,62 @@ impl<W: Write + Send> SerializedFileWriter<W> {
     }
 
     /// Writes magic bytes at the beginning of the file.
-    fn start_file(buf: &mut TrackedWrite<W>) -> Result<()> {
-        buf.write_all(&PARQUET_MAGIC)?;
+    fn start_file(properties: &WriterPropertiesPtr, buf: &mut TrackedWrite<W>) -> Result<()> {
+        buf.write_all(get_file_magic(properties))?;
+        Ok(())
+    }
+
+    /// Returns the encryptor of the column chunk at `column_idx` within the row
+    /// group at `row_group_idx`, if the column is encrypted
+    #[cfg(feature = "encryption")]
+    fn get_column_encryptor(
+        &self,
+        row_group_idx: usize,
+        column_idx: usize,
+    ) -> Result<Option<ColumnEncryptor>> {
+        match &self.file_encryptor {
+            Some(encryptor) => encryptor.column_encryptor(
+                self.descr.column(column_idx).path(),
+                row_group_idx,
+                column_idx,
+            ),
+            None => Ok(None),
+        }
+    }
+
+    /// Serializes a page index `module` of the column chunk at `column_idx` within the
+    /// row group at `row_group_idx`, encrypting it if the column is encrypted
+    #[cfg(feature = "encryption")]
+    fn write_page_index<T: TSerializable>(
+        &mut self,
+        module: &T,
+        row_group_idx: usize,
+        column_idx: usize,
+        module_type: ModuleType,
+    ) -> Result<()> {
+        match self.get_column_encryptor(row_group_idx, column_idx)? {
+            Some(encryptor) => {
+                let encrypted = encryptor.encrypt_thrift(module_type, module)?;
+                self.buf.write_all(&encrypted)?;
+            }
+            None => {
+                let mut protocol = TCompactOutputProtocol::new(&mut self.buf);
+                module.write_to_out_protocol(&mut protocol)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Serializes a page index `module` of a column chunk
+    #[cfg(not(feature = "encryption"))]
+    fn write_page_index<T: TSerializable>(
+        &mut self,
+        module: &T,
+        _row_group_idx: usize,
+        _column_idx: usize,
+    ) -> Result<()> {
+        let mut protocol = TCompactOutputProtocol::new(&mut self.buf);
+        module.write_to_out_protocol(&mut protocol)?;
         Ok(())
     }
 
@@ -268,11 +356,16 @@ impl<W: Write + Send> SerializedFileWriter<W> {
         // write offset index to the file
         for (row_group_idx, row_group) in row_groups.iter_mut().enumerate() {
             for (column_idx, column_metadata) in row_group.columns.iter_mut().enumerate() {
-                match &self.offset_indexes[row_group_idx][column_idx] {
+                match self.offset_indexes[row_group_idx][column_idx].take() {
                     Some(offset_index) => {
                         let start_offset = self.buf.bytes_written();
-                        let mut protocol = TCompactOutputProtocol::new(&mut self.buf);
-                        offset_index.write_to_out_protocol(&mut protocol)?;
+                        self.write_page_index(
+                            &offset_index,
+                            row_group_idx,
+                            column_idx,
+                            #[cfg(feature = "encryption")]
+                            ModuleType::OffsetIndex,
+                        )?;
                         let end_offset = self.buf.bytes_written();
                         // set offset and index for offset index
                         column_metadata.offset_index_offset = Some(start_offset as i64);
@@ -293,11 +386,16 @@ impl<W: Write + Send> SerializedFileWriter<W> {
         // write column index to the file
         for (row_group_idx, row_group) in row_groups.iter_mut().enumerate() {
             for (column_idx, column_metadata) in row_group.columns.iter_mut().enumerate() {
-                match &self.column_indexes[row_group_idx][column_idx] {
+                match self.column_indexes[row_group_idx][column_idx].take() {
                     Some(column_index) => {
                         let start_offset = self.buf.bytes_written();
-                        let mut protocol = TCompactOutputProtocol::new(&mut self.buf);
-                        column_index.write_to_out_protocol(&mut protocol)?;
+                        self.write_page_index(
+                            &column_index,
+                            row_group_idx,
+                            column_idx,
+                            #[cfg(feature = "encryption")]
+                            ModuleType::ColumnIndex,
+                        )?;
                         let end_offset = self.buf.bytes_written();
                         // set offset and index for offset index
                         column_metadata.column_index_offset = Some(start_offset as i64);
@@ -318,7 +416,13 @@ impl<W: Write + Send> SerializedFileWriter<W> {
 
         // write out any remaining bloom filters after all row groups
         for row_group in &mut self.row_groups {
-            write_bloom_filters(&mut self.buf, &mut self.bloom_filters, row_group)?;
+            write_bloom_filters(
+                &mut self.buf,
+                &mut self.bloom_filters,
+                row_group,
+                #[cfg(feature = "encryption")]
+                self.file_encryptor.as_deref(),
+            )?;
         }
 
         let mut row_groups = self
@@ -332,6 +436,9 @@ impl<W: Write + Send> SerializedFileWriter<W> {
         self.write_column_indexes(&mut row_groups)?;
         self.write_offset_indexes(&mut row_groups)?;
 
+        #[cfg(feature = "encryption")]
+        self.encrypt_column_metadata(&mut row_groups)?;
+
         let key_value_metadata = match self.props.key_value_metadata() {
             Some(kv) => Some(kv.iter().chain(&self.kv_metadatas).cloned().collect()),
             None if self.kv_metadatas.is_empty() => None,
@@ -351,7 +458,7 @@ impl<W: Write + Send> SerializedFileWriter<W> {
         // But for simplicity we always set this field.
         let column_orders = Some(column_orders);
 
-        let file_metadata = parquet::FileMetaData {
+        let mut file_metadata = parquet::FileMetaData {
             num_rows,
             row_groups,
             key_value_metadata,
@@ -365,20 +472,114 @@ impl<W: Write + Send> SerializedFileWriter<W> {
 
         // Write file metadata
         let start_pos = self.buf.bytes_written();
-        {
-            let mut protocol = TCompactOutputProtocol::new(&mut self.buf);
-            file_metadata.write_to_out_protocol(&mut protocol)?;
-        }
+        self.write_file_metadata(&mut file_metadata)?;
         let end_pos = self.buf.bytes_written();
 
         // Write footer
         let metadata_len = (end_pos - start_pos) as u32;
 
         self.buf.write_all(&metadata_len.to_le_bytes())?;
-        self.buf.write_all(&PARQUET_MAGIC)?;
+        self.buf.write_all(get_file_magic(&self.props))?;
         Ok(file_metadata)
     }
 
+    /// Sets the crypto metadata of the encrypted column chunks in `row_groups`, encrypting
+    /// their column metadata if it can't be protected by an encrypted footer
+    ///
+    /// The plaintext column metadata is kept with the statistics removed if the footer
+    /// isn't encrypted, so that readers without the column key can still read the
+    /// unencrypted columns of the file
+    #[cfg(feature = "encryption")]
+    fn encrypt_column_metadata(&self, row_groups: &mut [RowGroup]) -> Result<()> {
+        let Some(file_encryptor) = self.file_encryptor.as_ref() else {
+            return Ok(());
+        };
+        let properties = file_encryptor.properties();
+        for (row_group_idx, row_group) in row_groups.iter_mut().enumerate() {
+            for (column_idx, column_chunk) in row_group.columns.iter_mut().enumerate() {
+                let column_path = self.descr.column(column_idx).path().clone();
+                let Some(crypto_metadata) = file_encryptor.column_crypto_metadata(&column_path)
+                else {
+                    continue;
+                };
+                let uses_column_key = matches!(
+                    crypto_metadata,
+                    parquet::ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(_)
+                );
+                column_chunk.crypto_metadata = Some(crypto_metadata);
+
+                if properties.encrypt_footer() && !uses_column_key {
+                    continue;
+                }
+                let Some(column_metadata) = column_chunk.meta_data.as_mut() else {
+                    continue;
+                };
+                let encryptor = file_encryptor
+                    .column_encryptor(&column_path, row_group_idx, column_idx)?
+                    .ok_or_else(|| general_err!("Missing encryptor for column {}", column_path))?;
+                column_chunk.encrypted_column_metadata =
+                    Some(encryptor.encrypt_thrift(ModuleType::ColumnMetaData, column_metadata)?);
+
+                if properties.encrypt_footer() {
+                    column_chunk.meta_data = None;
+                } else {
+                    column_metadata.statistics = None;
+                    column_metadata.encoding_stats = None;
+                    column_metadata.size_statistics = None;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Serializes `file_metadata` to the file
+    #[cfg(not(feature = "encryption"))]
+    fn write_file_metadata(&mut self, file_metadata: &mut parquet::FileMetaData) -> Result<()> {
+        let mut protocol = TCompactOutputProtocol::new(&mut self.buf);
+        file_metadata.write_to_out_protocol(&mut protocol)?;
+        Ok(())
+    }
+
+    /// Serializes `file_metadata` to the file
+    ///
+    /// With encryption enabled, an encrypted footer consists of the `FileCryptoMetaData`
+    /// followed by the encrypted `FileMetaData`, while a plaintext footer is followed
+    /// by its signature
+    #[cfg(feature = "encryption")]
+    fn write_file_metadata(&mut self, file_metadata: &mut parquet::FileMetaData) -> Result<()> {
+        let Some(file_encryptor) = self.file_encryptor.clone() else {
+            let mut protocol = TCompactOutputProtocol::new(&mut self.buf);
+            file_metadata.write_to_out_protocol(&mut protocol)?;
+            return Ok(());
+        };
+
+        if file_encryptor.properties().encrypt_footer() {
+            let crypto_metadata = parquet::FileCryptoMetaData {
+                encryption_algorithm: file_encryptor.encryption_algorithm(),
+                key_metadata: file_encryptor.footer_key_metadata().cloned(),
+            };
+            let mut protocol = TCompactOutputProtocol::new(&mut self.buf);
+            crypto_metadata.write_to_out_protocol(&mut protocol)?;
+
+            let mut footer = Vec::new();
+            let mut protocol = TCompactOutputProtocol::new(&mut footer);
+            file_metadata.write_to_out_protocol(&mut protocol)?;
+            self.buf
+                .write_all(&file_encryptor.encrypt_footer(&footer)?)?;
+        } else {
+            file_metadata.encryption_algorithm = Some(file_encryptor.encryption_algorithm());
+            file_metadata.footer_signing_key_metadata =
+                file_encryptor.footer_key_metadata().cloned();
+
+            let mut footer = Vec::new();
+            let mut protocol = TCompactOutputProtocol::new(&mut footer);
+            file_metadata.write_to_out_protocol(&mut protocol)?;
+            self.buf.write_all(&footer)?;
+            self.buf.write_all(&file_encryptor.sign_footer(&footer)?)?;
+        }
+        Ok(())
+    }
+
     #[inline]
     fn assert_previous_writer_closed(&self) -> Result<()> {
         if self.finished {
@@ -430,6 +631,27 @@ impl<W: Write + Send> SerializedFileWriter<W> {
     pub fn bytes_written(&self) -> usize {
         self.buf.bytes_written()
     }
+
+    /// Returns the [`FileEncryptor`] of this file, if encryption is enabled
+    #[cfg(all(feature = "encryption", feature = "arrow"))]
+    pub(crate) fn file_encryptor(&self) -> Option<Arc<FileEncryptor>> {
+        self.file_encryptor.clone()
+    }
+}
+
+/// Returns the magic bytes written at the start and end of a file written with `properties`
+#[cfg(feature = "encryption")]
+fn get_file_magic(properties: &WriterPropertiesPtr) -> &'static [u8; 4] {
+    match properties.file_encryption_properties() {
+        Some(p) if p.encrypt_footer() => &PARQUET_MAGIC_ENCR_FOOTER,
+        _ => &PARQUET_MAGIC,
+    }
+}
+
+/// Returns the magic bytes written at the start and end of a file written with `properties`
+#[cfg(not(feature = "encryption"))]
+fn get_file_magic(_properties: &WriterPropertiesPtr) -> &'static [u8; 4] {
+    &PARQUET_MAGIC
 }
 
 /// Serialize all the bloom filters of the given row group to the given buffer,
@@ -438,6 +660,7 @@ fn write_bloom_filters<W: Write + Send>(
     buf: &mut TrackedWrite<W>,
     bloom_filters: &mut [Vec<Option<Sbbf>>],
     row_group: &mut RowGroupMetaData,
+    #[cfg(feature = "encryption")] file_encryptor: Option<&FileEncryptor>,
 ) -> Result<()> {
     // iter row group
     // iter each column
@@ -452,6 +675,21 @@ fn write_bloom_filters<W: Write + Send>(
     for (column_idx, column_chunk) in row_group.columns_mut().iter_mut().enumerate() {
         if let Some(bloom_filter) = bloom_filters[row_group_idx][column_idx].take() {
             let start_offset = buf.bytes_written();
+            #[cfg(feature = "encryption")]
+            let column_encryptor = match file_encryptor {
+                Some(encryptor) => encryptor.column_encryptor(
+                    column_chunk.column_path(),
+                    row_group_idx,
+                    column_idx,
+                )?,
+                None => None,
+            };
+            #[cfg(feature = "encryption")]
+            match column_encryptor {
+                Some(encryptor) => bloom_filter.write_encrypted(&mut *buf, &encryptor)?,
+                None => bloom_filter.write(&mut *buf)?,
+            }
+            #[cfg(not(feature = "encryption"))]
             bloom_filter.write(&mut *buf)?;
             let end_offset = buf.bytes_written();
             // set offset and index for bloom filter
@@ -493,6 +731,8 @@ pub struct SerializedRowGroupWriter<'a, W: Write> {
     row_group_index: i16,
     file_offset: i64,
     on_close: Option<OnCloseRowGroup<'a, W>>,
+    #[cfg(feature = "encryption")]
+    file_encryptor: Option<Arc<FileEncryptor>>,
 }
 
 impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
@@ -529,9 +769,39 @@ impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
             offset_indexes: Vec::with_capacity(num_columns),
             total_bytes_written: 0,
             total_uncompressed_bytes: 0,
+            #[cfg(feature = "encryption")]
+            file_encryptor: None,
         }
     }
 
+    /// Sets the [`FileEncryptor`] used to encrypt the pages of the columns of this row group
+    #[cfg(feature = "encryption")]
+    pub(crate) fn with_file_encryptor(
+        mut self,
+        file_encryptor: Option<Arc<FileEncryptor>>,
+    ) -> Self {
+        self.file_encryptor = file_encryptor;
+        self
+    }
+
+    /// Returns the [`PageEncryptor`] for the column at `column_idx`, if it is encrypted
+    #[cfg(feature = "encryption")]
+    fn get_page_encryptor(
+        &self,
+        column: &ColumnDescPtr,
+        column_idx: usize,
+    ) -> Result<Option<PageEncryptor>> {
+        let Some(file_encryptor) = self.file_encryptor.as_ref() else {
+            return Ok(None);
+        };
+        let column_encryptor = file_encryptor.column_encryptor(
+            column.path(),
+            self.row_group_index as usize,
+            column_idx,
+        )?;
+        Ok(column_encryptor.map(PageEncryptor::new))
+    }
+
     /// Advance `self.column_index` returning the next [`ColumnDescPtr`] if any
     fn next_column_desc(&mut self) -> Option<ColumnDescPtr> {
         let ret = self.descr.columns().get(self.column_index)?.clone();
@@ -590,9 +860,19 @@ impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
         Ok(match self.next_column_desc() {
             Some(column) => {
                 let props = self.props.clone();
+                #[cfg(feature = "encryption")]
+                let page_encryptor = self.get_page_encryptor(&column, self.column_index - 1)?;
                 let (buf, on_close) = self.get_on_close();
-                let page_writer = Box::new(SerializedPageWriter::new(buf));
-                Some(factory(column, props, page_writer, Box::new(on_close))?)
+                let page_writer =
+                    SerializedPageWriter::new(buf).with_page_checksum(props.write_page_checksum());
+                #[cfg(feature = "encryption")]
+                let page_writer = page_writer.with_page_encryptor(page_encryptor);
+                Some(factory(
+                    column,
+                    props,
+                    Box::new(page_writer),
+                    Box::new(on_close),
+                )?)
             }
             None => None,
         })
@@ -614,6 +894,9 @@ impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
     /// or encoding parquet data to temporary in-memory buffers
     ///
     /// See [`Self::next_column`] for writing data that isn't already encoded
+    ///
+    /// When writing an encrypted file, the column chunk is copied as is, and so must
+    /// already have been encrypted for its position within this file
     pub fn append_column<R: ChunkReader>(
         &mut self,
         reader: &R,
@@ -763,12 +1046,33 @@ impl<'a> SerializedColumnWriter<'a> {
 /// `SerializedPageWriter` should not be used after calling `close()`.
 pub struct SerializedPageWriter<'a, W: Write> {
     sink: &'a mut TrackedWrite<W>,
+    write_checksum: bool,
+    #[cfg(feature = "encryption")]
+    page_encryptor: Option<PageEncryptor>,
 }
 
 impl<'a, W: Write> SerializedPageWriter<'a, W> {
     /// Creates new page writer.
     pub fn new(sink: &'a mut TrackedWrite<W>) -> Self {
-        Self { sink }
+        Self {
+            sink,
+            write_checksum: false,
+            #[cfg(feature = "encryption")]
+            page_encryptor: None,
+        }
+    }
+
+    /// Sets whether to write a CRC32 checksum of each page into its header
+    pub fn with_page_checksum(mut self, write_checksum: bool) -> Self {
+        self.write_checksum = write_checksum;
+        self
+    }
+
+    /// Sets the [`PageEncryptor`] used to encrypt the pages and page headers
+    #[cfg(feature = "encryption")]
+    pub(crate) fn with_page_encryptor(mut self, page_encryptor: Option<PageEncryptor>) -> Self {
+        self.page_encryptor = page_encryptor;
+        self
     }
 
     /// Serializes page header into Thrift.
@@ -776,6 +1080,12 @@ impl<'a, W: Write> SerializedPageWriter<'a, W> {
     #[inline]
     fn serialize_page_header(&mut self, header: parquet::PageHeader) -> Result<usize> {
         let start_pos = self.sink.bytes_written();
+        #[cfg(feature = "encryption")]
+        if let Some(page_encryptor) = self.page_encryptor.as_mut() {
+            let encrypted = page_encryptor.encrypt_page_header(&header)?;
+            self.sink.write_all(&encrypted)?;
+            return Ok(self.sink.bytes_written() - start_pos);
+        }
         {
             let mut protocol = TCompactOutputProtocol::new(&mut self.sink);
             header.write_to_out_protocol(&mut protocol)?;
@@ -786,10 +1096,16 @@ impl<'a, W: Write> SerializedPageWriter<'a, W> {
 
 impl<'a, W: Write + Send> PageWriter for SerializedPageWriter<'a, W> {
     fn write_page(&mut self, page: CompressedPage) -> Result<PageWriteSpec> {
+        #[cfg(feature = "encryption")]
+        let page = match self.page_encryptor.as_ref() {
+            Some(page_encryptor) => page_encryptor.encrypt_page(page)?,
+            None => page,
+        };
+
         let page_type = page.page_type();
         let start_pos = self.sink.bytes_written() as u64;
 
-        let page_header = page.to_thrift_header();
+        let page_header = page.to_thrift_header(self.write_checksum);
         let header_size = self.serialize_page_header(page_header)?;
         self.sink.write_all(page.data())?;
 
@@ -0,0 +1,432 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests for reading and writing encrypted parquet files
+
+use arrow::util::test_util::parquet_test_data;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float32Type, Float64Type, Int32Type};
+use arrow_array::{ArrayRef, Float32Array, Int32Array, RecordBatch, StringArray};
+use bytes::Bytes;
+use parquet::arrow::arrow_reader::{
+    ArrowReaderOptions, ParquetRecordBatchReaderBuilder, RowSelection, RowSelector,
+};
+use parquet::arrow::ArrowWriter;
+use parquet::data_type::ByteArray;
+use parquet::encryption::decrypt::{FileDecryptionProperties, KeyRetriever};
+use parquet::encryption::encrypt::{FileEncryptionProperties, ParquetCipher};
+use parquet::errors::{ParquetError, Result};
+use parquet::file::properties::{EnabledStatistics, ReaderProperties, WriterProperties};
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use parquet::file::serialized_reader::ReadOptionsBuilder;
+use std::collections::HashMap;
+use std::fs::File;
+use std::sync::Arc;
+
+const FOOTER_KEY: &[u8] = b"0123456789012345";
+const COLUMN_KEY_1: &[u8] = b"1234567890123450";
+const COLUMN_KEY_2: &[u8] = b"1234567890123451";
+
+/// Returns the decryption properties of the encrypted files in parquet-testing
+fn test_file_decryption_properties() -> FileDecryptionProperties {
+    FileDecryptionProperties::builder(FOOTER_KEY.to_vec())
+        .with_column_key("double_field", COLUMN_KEY_1.to_vec())
+        .with_column_key("float_field", COLUMN_KEY_2.to_vec())
+        .build()
+        .unwrap()
+}
+
+fn read_test_file(name: &str, properties: FileDecryptionProperties) -> Result<Vec<RecordBatch>> {
+    let path = format!("{}/{name}", parquet_test_data());
+    let file = File::open(path).unwrap();
+    let options = ArrowReaderOptions::new().with_file_decryption_properties(properties);
+    let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options)?.build()?;
+    Ok(reader.collect::<Result<Vec<_>, _>>()?)
+}
+
+/// Checks the data of the encrypted files in parquet-testing
+fn verify_test_file_batches(batches: &[RecordBatch]) {
+    let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+    assert_eq!(num_rows, 50);
+
+    let mut row = 0;
+    for batch in batches {
+        let doubles = batch.column_by_name("double_field").unwrap();
+        let floats = batch.column_by_name("float_field").unwrap();
+        let doubles = doubles.as_primitive::<Float64Type>();
+        let floats = floats.as_primitive::<Float32Type>();
+        for i in 0..batch.num_rows() {
+            assert_eq!(doubles.value(i), row as f64 * 1.1111111);
+            assert_eq!(floats.value(i), row as f32 * 1.1);
+            row += 1;
+        }
+    }
+}
+
+#[test]
+fn test_read_uniform_encryption() {
+    let properties = FileDecryptionProperties::builder(FOOTER_KEY.to_vec())
+        .build()
+        .unwrap();
+    let batches = read_test_file("uniform_encryption.parquet.encrypted", properties).unwrap();
+    verify_test_file_batches(&batches);
+}
+
+#[test]
+fn test_read_encrypted_test_files() {
+    let files = [
+        "encrypt_columns_and_footer.parquet.encrypted",
+        "encrypt_columns_plaintext_footer.parquet.encrypted",
+        "encrypt_columns_and_footer_aad.parquet.encrypted",
+        "encrypt_columns_and_footer_ctr.parquet.encrypted",
+    ];
+    for file in files {
+        let batches = read_test_file(file, test_file_decryption_properties()).unwrap();
+        verify_test_file_batches(&batches);
+    }
+}
+
+#[test]
+fn test_read_disabled_aad_storage() {
+    let file = "encrypt_columns_and_footer_disable_aad_storage.parquet.encrypted";
+    let properties = FileDecryptionProperties::builder(FOOTER_KEY.to_vec())
+        .with_column_key("double_field", COLUMN_KEY_1.to_vec())
+        .with_column_key("float_field", COLUMN_KEY_2.to_vec())
+        .with_aad_prefix(b"tester".to_vec())
+        .build()
+        .unwrap();
+    let batches = read_test_file(file, properties).unwrap();
+    verify_test_file_batches(&batches);
+
+    let err = read_test_file(file, test_file_decryption_properties()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parquet error: Parquet file was encrypted with an AAD prefix that is not stored in the file, \
+        but no AAD prefix was provided in the decryption properties"
+    );
+}
+
+struct TestKeyRetriever(HashMap<Vec<u8>, Vec<u8>>);
+
+impl KeyRetriever for TestKeyRetriever {
+    fn retrieve_key(&self, key_metadata: &[u8]) -> Result<Vec<u8>> {
+        self.0.get(key_metadata).cloned().ok_or_else(|| {
+            ParquetError::General(format!("Unknown key {}", key_metadata.escape_ascii()))
+        })
+    }
+}
+
+fn test_key_retriever() -> Arc<TestKeyRetriever> {
+    Arc::new(TestKeyRetriever(HashMap::from([
+        (b"kf".to_vec(), FOOTER_KEY.to_vec()),
+        (b"kc1".to_vec(), COLUMN_KEY_1.to_vec()),
+        (b"kc2".to_vec(), COLUMN_KEY_2.to_vec()),
+    ])))
+}
+
+#[test]
+fn test_read_with_key_retriever() {
+    let properties = FileDecryptionProperties::with_key_retriever(test_key_retriever())
+        .build()
+        .unwrap();
+    let file = "encrypt_columns_and_footer.parquet.encrypted";
+    let batches = read_test_file(file, properties).unwrap();
+    verify_test_file_batches(&batches);
+}
+
+fn test_batch() -> RecordBatch {
+    let ints = (0..100).collect::<Int32Array>();
+    let floats = (0..100)
+        .map(|i| (i % 7 != 0).then_some(i as f32 / 2.0))
+        .collect::<Float32Array>();
+    let strings = (0..100)
+        .map(|i| Some(format!("value {}", i % 13)))
+        .collect::<StringArray>();
+    RecordBatch::try_from_iter([
+        ("int_col", Arc::new(ints) as ArrayRef),
+        ("float_col", Arc::new(floats) as ArrayRef),
+        ("string_col", Arc::new(strings) as ArrayRef),
+    ])
+    .unwrap()
+}
+
+fn write_encrypted(
+    batch: &RecordBatch,
+    encryption_properties: FileEncryptionProperties,
+) -> Result<Bytes> {
+    let props = WriterProperties::builder()
+        .set_max_row_group_size(40)
+        .set_data_page_row_count_limit(10)
+        .set_write_batch_size(10)
+        .set_statistics_enabled(EnabledStatistics::Page)
+        .set_bloom_filter_enabled(true)
+        .set_file_encryption_properties(encryption_properties)
+        .build();
+    let mut buffer = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props))?;
+    writer.write(batch)?;
+    writer.close()?;
+    Ok(Bytes::from(buffer))
+}
+
+fn read_encrypted(
+    data: &Bytes,
+    decryption_properties: Option<FileDecryptionProperties>,
+) -> Result<RecordBatch> {
+    let mut options = ArrowReaderOptions::new().with_page_index(true);
+    if let Some(properties) = decryption_properties {
+        options = options.with_file_decryption_properties(properties);
+    }
+    let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)?;
+    let schema = builder.schema().clone();
+    let batches = builder.build()?.collect::<Result<Vec<_>, _>>()?;
+    Ok(arrow::compute::concat_batches(&schema, &batches)?)
+}
+
+#[test]
+fn test_round_trip_uniform_encryption() {
+    let batch = test_batch();
+    let properties = FileEncryptionProperties::builder(FOOTER_KEY.to_vec())
+        .build()
+        .unwrap();
+    let data = write_encrypted(&batch, properties).unwrap();
+    assert_eq!(&data[..4], b"PARE");
+    assert_eq!(&data[data.len() - 4..], b"PARE");
+
+    let decryption = FileDecryptionProperties::builder(FOOTER_KEY.to_vec())
+        .build()
+        .unwrap();
+    let read = read_encrypted(&data, Some(decryption)).unwrap();
+    assert_eq!(read, batch);
+
+    let err = read_encrypted(&data, None).unwrap_err();
+    assert!(err.to_string().contains("encrypted footer"), "{err}");
+
+    let wrong_key = FileDecryptionProperties::builder(COLUMN_KEY_1.to_vec())
+        .build()
+        .unwrap();
+    read_encrypted(&data, Some(wrong_key)).unwrap_err();
+}
+
+#[test]
+fn test_round_trip_column_keys() {
+    let batch = test_batch();
+    for cipher in [ParquetCipher::AesGcmV1, ParquetCipher::AesGcmCtrV1] {
+        let properties = FileEncryptionProperties::builder(FOOTER_KEY.to_vec())
+            .with_footer_key_metadata(b"kf".to_vec())
+            .with_column_key_and_metadata("int_col", COLUMN_KEY_1.to_vec(), b"kc1".to_vec())
+            .with_column_key_and_metadata("float_col", COLUMN_KEY_2.to_vec(), b"kc2".to_vec())
+            .with_cipher(cipher)
+            .build()
+            .unwrap();
+        let data = write_encrypted(&batch, properties).unwrap();
+
+        let decryption = FileDecryptionProperties::builder(FOOTER_KEY.to_vec())
+            .with_column_key("int_col", COLUMN_KEY_1.to_vec())
+            .with_column_key("float_col", COLUMN_KEY_2.to_vec())
+            .build()
+            .unwrap();
+        assert_eq!(read_encrypted(&data, Some(decryption)).unwrap(), batch);
+
+        let decryption = FileDecryptionProperties::with_key_retriever(test_key_retriever())
+            .build()
+            .unwrap();
+        assert_eq!(read_encrypted(&data, Some(decryption)).unwrap(), batch);
+
+        let decryption = FileDecryptionProperties::builder(FOOTER_KEY.to_vec())
+            .with_column_key("int_col", COLUMN_KEY_1.to_vec())
+            .build()
+            .unwrap();
+        let err = read_encrypted(&data, Some(decryption)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: No decryption key provided for column 'float_col'"
+        );
+    }
+}
+
+#[test]
+fn test_round_trip_plaintext_footer() {
+    let batch = test_batch();
+    let properties = FileEncryptionProperties::builder(FOOTER_KEY.to_vec())
+        .with_column_key("float_col", COLUMN_KEY_1.to_vec())
+        .with_plaintext_footer(true)
+        .build()
+        .unwrap();
+    let data = write_encrypted(&batch, properties).unwrap();
+    assert_eq!(&data[data.len() - 4..], b"PAR1");
+
+    let decryption = FileDecryptionProperties::builder(FOOTER_KEY.to_vec())
+        .with_column_key("float_col", COLUMN_KEY_1.to_vec())
+        .build()
+        .unwrap();
+    assert_eq!(read_encrypted(&data, Some(decryption)).unwrap(), batch);
+
+    // The footer signature is verified unless disabled
+    let decryption = FileDecryptionProperties::builder(COLUMN_KEY_2.to_vec())
+        .with_column_key("float_col", COLUMN_KEY_1.to_vec())
+        .build()
+        .unwrap();
+    read_encrypted(&data, Some(decryption)).unwrap_err();
+
+    let decryption = FileDecryptionProperties::builder(COLUMN_KEY_2.to_vec())
+        .with_column_key("float_col", COLUMN_KEY_1.to_vec())
+        .disable_footer_signature_verification()
+        .build()
+        .unwrap();
+    assert_eq!(read_encrypted(&data, Some(decryption)).unwrap(), batch);
+
+    // The unencrypted columns can be read without any keys
+    let options = ArrowReaderOptions::new().with_page_index(true);
+    let builder =
+        ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options).unwrap();
+    let metadata = builder.metadata().clone();
+    let float_column = metadata.row_group(0).column(1);
+    assert!(float_column.crypto_metadata().is_some());
+    assert!(float_column.statistics().is_none());
+    assert!(metadata.row_group(0).column(0).statistics().is_some());
+
+    let mask = parquet::arrow::ProjectionMask::leaves(builder.parquet_schema(), [0, 2]);
+    let reader = builder.with_projection(mask).build().unwrap();
+    let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    let read = arrow::compute::concat_batches(&batches[0].schema(), &batches).unwrap();
+    assert_eq!(read.column(0), batch.column(0));
+    assert_eq!(read.column(1), batch.column(2));
+
+    let err = read_encrypted(&data, None).unwrap_err().to_string();
+    assert!(
+        err.contains(
+            "Column \"float_col\" is encrypted, but no decryption properties were provided"
+        ),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_round_trip_aad_prefix() {
+    let batch = test_batch();
+    for store_aad_prefix in [true, false] {
+        let properties = FileEncryptionProperties::builder(FOOTER_KEY.to_vec())
+            .with_aad_prefix(b"file_1".to_vec())
+            .with_aad_prefix_storage(store_aad_prefix)
+            .build()
+            .unwrap();
+        let data = write_encrypted(&batch, properties).unwrap();
+
+        let decryption = FileDecryptionProperties::builder(FOOTER_KEY.to_vec())
+            .with_aad_prefix(b"file_1".to_vec())
+            .build()
+            .unwrap();
+        assert_eq!(read_encrypted(&data, Some(decryption)).unwrap(), batch);
+
+        let decryption = FileDecryptionProperties::builder(FOOTER_KEY.to_vec())
+            .with_aad_prefix(b"file_2".to_vec())
+            .build()
+            .unwrap();
+        read_encrypted(&data, Some(decryption)).unwrap_err();
+
+        let decryption = FileDecryptionProperties::builder(FOOTER_KEY.to_vec())
+            .build()
+            .unwrap();
+        let result = read_encrypted(&data, Some(decryption));
+        assert_eq!(result.is_ok(), store_aad_prefix);
+    }
+}
+
+#[test]
+fn test_round_trip_row_selection() {
+    let batch = test_batch();
+    let properties = FileEncryptionProperties::builder(FOOTER_KEY.to_vec())
+        .with_column_key("string_col", COLUMN_KEY_1.to_vec())
+        .build()
+        .unwrap();
+    let data = write_encrypted(&batch, properties).unwrap();
+
+    let decryption = FileDecryptionProperties::builder(FOOTER_KEY.to_vec())
+        .with_column_key("string_col", COLUMN_KEY_1.to_vec())
+        .build()
+        .unwrap();
+    let options = ArrowReaderOptions::new()
+        .with_page_index(true)
+        .with_file_decryption_properties(decryption);
+    let builder =
+        ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options).unwrap();
+    let metadata = builder.metadata().clone();
+    assert_eq!(metadata.num_row_groups(), 3);
+    let offset_index = metadata.offset_index().unwrap();
+    assert_eq!(offset_index[0][2].page_locations().len(), 4);
+
+    // Skip pages in the middle of the first row group, and the whole second row group
+    let selection = RowSelection::from(vec![
+        RowSelector::select(5),
+        RowSelector::skip(70),
+        RowSelector::select(25),
+    ]);
+    let reader = builder.with_row_selection(selection).build().unwrap();
+    let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    let read = arrow::compute::concat_batches(&batches[0].schema(), &batches).unwrap();
+    let expected =
+        arrow::compute::concat_batches(&batch.schema(), &[batch.slice(0, 5), batch.slice(75, 25)])
+            .unwrap();
+    assert_eq!(read, expected);
+    let ints = read.column(0).as_primitive::<Int32Type>();
+    assert_eq!(ints.value(5), 75);
+}
+
+#[test]
+fn test_read_bloom_filters() {
+    let batch = test_batch();
+    let properties = FileEncryptionProperties::builder(FOOTER_KEY.to_vec())
+        .with_column_key("string_col", COLUMN_KEY_1.to_vec())
+        .with_plaintext_footer(true)
+        .build()
+        .unwrap();
+    let data = write_encrypted(&batch, properties).unwrap();
+
+    let decryption = FileDecryptionProperties::builder(FOOTER_KEY.to_vec())
+        .with_column_key("string_col", COLUMN_KEY_1.to_vec())
+        .build()
+        .unwrap();
+    let reader_properties = ReaderProperties::builder()
+        .set_read_bloom_filter(true)
+        .set_file_decryption_properties(decryption)
+        .build();
+    let options = ReadOptionsBuilder::new()
+        .with_reader_properties(reader_properties)
+        .with_page_index()
+        .build();
+    let reader = SerializedFileReader::new_with_options(data.clone(), options).unwrap();
+    let row_group = reader.get_row_group(0).unwrap();
+
+    let ints = row_group.get_column_bloom_filter(0).unwrap();
+    assert!(ints.check(&5_i32));
+    let strings = row_group.get_column_bloom_filter(2).unwrap();
+    assert!(strings.check(&ByteArray::from("value 3")));
+    assert!(!strings.check(&ByteArray::from("value 30")));
+
+    // The bloom filters of encrypted columns are not read without decryption properties
+    let reader_properties = ReaderProperties::builder()
+        .set_read_bloom_filter(true)
+        .build();
+    let options = ReadOptionsBuilder::new()
+        .with_reader_properties(reader_properties)
+        .build();
+    let reader = SerializedFileReader::new_with_options(data, options).unwrap();
+    let row_group = reader.get_row_group(0).unwrap();
+    assert!(row_group.get_column_bloom_filter(0).is_some());
+    assert!(row_group.get_column_bloom_filter(2).is_none());
+}